@@ -0,0 +1,132 @@
+//! Time sources for animation and frame timing
+//!
+//! Everything that measures time reads it from a [`Clock`], so tests can
+//! swap the wall clock for a [`MockClock`] they advance by hand and get
+//! exactly the same frames every run.
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A source of the current time
+pub trait Clock: Send + Sync + Debug {
+    /// Time since the clock's own fixed origin; never goes backwards
+    fn now(&self) -> Duration;
+}
+
+/// The wall clock, measured from when it was created
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    /// Create a clock reading zero now
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests
+///
+/// Clones share the same time, so a test can hold one and hand another to
+/// the code under test.
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// Create a clock reading zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now += by;
+        }
+    }
+
+    /// Move the clock to `to`, if that isn't earlier than it reads already
+    pub fn set(&self, to: Duration) {
+        if let Ok(mut now) = self.now.lock() {
+            *now = (*now).max(to);
+        }
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.now.lock().map_or(Duration::ZERO, |now| *now)
+    }
+}
+
+/// The time a clock has moved between successive readings, for animations
+/// advanced a frame at a time
+#[derive(Debug, Clone)]
+pub struct FrameClock {
+    clock: Arc<dyn Clock>,
+    last: Duration,
+}
+
+impl FrameClock {
+    /// Start measuring from `clock`'s current time
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        let last = clock.now();
+        Self { clock, last }
+    }
+
+    /// Seconds since the last call, or since the frame clock was created
+    pub fn delta(&mut self) -> f32 {
+        let now = self.clock.now();
+        let delta = now.saturating_sub(self.last);
+        self.last = now;
+        delta.as_secs_f32()
+    }
+}
+
+impl PartialEq for FrameClock {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.clock, &other.clock) && self.last == other.last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_moves_only_when_told() {
+        let clock = MockClock::new();
+        let shared: Arc<dyn Clock> = Arc::new(clock.clone());
+        let mut frames = FrameClock::new(shared.clone());
+        assert_eq!(shared.now(), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(shared.now(), Duration::from_millis(250));
+        assert_eq!(frames.delta(), 0.25);
+        assert_eq!(frames.delta(), 0.0);
+
+        // Time never runs backwards
+        clock.set(Duration::from_millis(100));
+        assert_eq!(shared.now(), Duration::from_millis(250));
+        clock.set(Duration::from_secs(1));
+        assert_eq!(frames.delta(), 0.75);
+    }
+}
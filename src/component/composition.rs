@@ -414,45 +414,6 @@ macro_rules! slotted_props {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::component::ComponentBase;
-
-    struct TestComponent {
-        base: ComponentBase,
-    }
-
-    #[derive(Clone)]
-    struct TestProps;
-    // Using the blanket implementation of Props instead of implementing it manually
-
-    impl Component for TestComponent {
-        type Props = TestProps;
-
-        fn component_id(&self) -> ComponentId {
-            self.base.id()
-        }
-
-        fn create(_props: Self::Props, context: Context) -> Self {
-            Self {
-                base: ComponentBase::new(context),
-            }
-        }
-
-        fn update(&mut self, _props: Self::Props) -> Result<(), ComponentError> {
-            Ok(())
-        }
-
-        fn render(&self) -> Result<Vec<Node>, ComponentError> {
-            Ok(vec![])
-        }
-
-        fn as_any(&self) -> &dyn std::any::Any {
-            self
-        }
-
-        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-            self
-        }
-    }
 
     #[test]
     fn test_slot_creation() {
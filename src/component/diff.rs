@@ -0,0 +1,440 @@
+//! Incremental diffing between node trees
+//!
+//! Rather than repainting a freshly rendered tree from scratch, [`diff`]
+//! compares it with the previous frame's tree and returns the [`Patch`]es
+//! that turn one into the other, so only the subtrees they touch need
+//! repainting. Children are matched by [`Node::key`] where they have one,
+//! so reordering a keyed list moves nodes instead of rebuilding them;
+//! children without a key are matched by position among their unkeyed
+//! siblings.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::component::{ComponentId, Node};
+use crate::layout::Rect;
+use crate::renderer::{ImageData, ImageFit};
+use crate::style::Style;
+
+/// Everything a node paints apart from its children
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeProps {
+    /// Node attributes
+    pub attributes: HashMap<String, String>,
+    /// Visual style
+    pub style: Style,
+    /// Layout rectangle, relative to the parent node's origin
+    pub layout_rect: Rect,
+    /// Text content
+    pub text: Option<String>,
+    /// Image painted into the node's bounds
+    pub image: Option<ImageData>,
+    /// How the image is scaled into the node's bounds
+    pub image_fit: ImageFit,
+}
+
+/// One change in turning an old node tree into a new one
+///
+/// Paths are child indices leading down from the root. Patches are applied
+/// in order, and each path refers to the tree as the patches before it
+/// have left it.
+#[derive(Debug, Clone)]
+pub enum Patch {
+    /// Replace the node at `path` and everything under it
+    Replace { path: Vec<usize>, node: Node },
+    /// Insert `node` as child `index` of the node at `parent`
+    Insert {
+        parent: Vec<usize>,
+        index: usize,
+        node: Node,
+    },
+    /// Remove child `index` of the node at `parent`
+    Remove { parent: Vec<usize>, index: usize },
+    /// Move child `from` of the node at `parent` to index `to`
+    Move {
+        parent: Vec<usize>,
+        from: usize,
+        to: usize,
+    },
+    /// Replace the props of the node at `path`, keeping its children
+    Update {
+        path: Vec<usize>,
+        props: Box<NodeProps>,
+    },
+}
+
+impl Patch {
+    /// Path of the node whose subtree this patch changes, and so needs
+    /// repainting
+    pub fn target(&self) -> &[usize] {
+        match self {
+            Patch::Replace { path, .. } | Patch::Update { path, .. } => path,
+            Patch::Insert { parent, .. }
+            | Patch::Remove { parent, .. }
+            | Patch::Move { parent, .. } => parent,
+        }
+    }
+}
+
+/// The patches turning `old` into `new`; empty if they paint the same
+pub fn diff(old: &Node, new: &Node) -> Vec<Patch> {
+    let mut patches = Vec::new();
+    if same_identity(old, new) {
+        diff_node(old, new, &mut Vec::new(), &mut patches);
+    } else {
+        patches.push(Patch::Replace {
+            path: Vec::new(),
+            node: new.clone(),
+        });
+    }
+    patches
+}
+
+/// The IDs of the components in the nodes `patch` would take out of `root`
+/// for good: those in a removed subtree, or in a replaced one but not its
+/// replacement
+pub(super) fn removed_components(root: &Node, patch: &Patch) -> Vec<ComponentId> {
+    let (old, new) = match patch {
+        Patch::Remove { parent, index } => (&node_ref(root, parent).children()[*index], None),
+        Patch::Replace { path, node } => (node_ref(root, path), Some(node)),
+        _ => return Vec::new(),
+    };
+    let mut kept = Vec::new();
+    if let Some(new) = new {
+        component_ids(new, &mut kept);
+    }
+    let mut removed = Vec::new();
+    component_ids(old, &mut removed);
+    removed.retain(|id| !kept.contains(id));
+    removed
+}
+
+/// Collect the IDs of the components in `node`'s subtree
+fn component_ids(node: &Node, ids: &mut Vec<ComponentId>) {
+    if let Some(instance) = node.component() {
+        if let Ok(component) = instance.instance.lock() {
+            ids.push(component.component_id());
+        }
+    }
+    for child in node.children() {
+        component_ids(child, ids);
+    }
+}
+
+/// Apply `patches` from [`diff`] to `root`, turning the old tree into the
+/// new one
+///
+/// The hook state of components whose nodes are removed is left alone;
+/// [`Context::apply_patches`](crate::component::Context::apply_patches)
+/// drops it as well.
+///
+/// # Panics
+///
+/// Panics if a patch's path doesn't lead to a node, which only happens
+/// when the patches were computed against a different tree.
+pub fn apply_patches(root: &mut Node, patches: Vec<Patch>) {
+    for patch in patches {
+        match patch {
+            Patch::Replace { path, node } => *node_at(root, &path) = node,
+            Patch::Insert {
+                parent,
+                index,
+                node,
+            } => node_at(root, &parent).insert_child(index, node),
+            Patch::Remove { parent, index } => {
+                node_at(root, &parent).children_mut().remove(index);
+            }
+            Patch::Move { parent, from, to } => {
+                let children = node_at(root, &parent).children_mut();
+                let child = children.remove(from);
+                children.insert(to, child);
+            }
+            Patch::Update { path, props } => node_at(root, &path).set_props(*props),
+        }
+    }
+}
+
+pub(super) fn node_at<'a>(root: &'a mut Node, path: &[usize]) -> &'a mut Node {
+    path.iter()
+        .fold(root, |node, &index| &mut node.children_mut()[index])
+}
+
+fn node_ref<'a>(root: &'a Node, path: &[usize]) -> &'a Node {
+    path.iter()
+        .fold(root, |node, &index| &node.children()[index])
+}
+
+/// Whether `new` can be patched from `old` rather than replacing it
+fn same_identity(old: &Node, new: &Node) -> bool {
+    let component_type = |node: &Node| node.component().map(|component| component.type_id);
+    old.key() == new.key() && component_type(old) == component_type(new)
+}
+
+fn diff_node(old: &Node, new: &Node, path: &mut Vec<usize>, patches: &mut Vec<Patch>) {
+    if !old.same_props(new) {
+        patches.push(Patch::Update {
+            path: path.clone(),
+            props: Box::new(new.props()),
+        });
+    }
+
+    let sources = diff_children(old, new, path, patches);
+    for (index, source) in sources.into_iter().enumerate() {
+        if let Some(source) = source {
+            path.push(index);
+            diff_node(
+                &old.children()[source],
+                &new.children()[index],
+                path,
+                patches,
+            );
+            path.pop();
+        }
+    }
+}
+
+/// Push the patches that put the right children in the right places under
+/// the node at `path`; returns, for each new child, the old child it was
+/// matched with
+fn diff_children(
+    old: &Node,
+    new: &Node,
+    path: &[usize],
+    patches: &mut Vec<Patch>,
+) -> Vec<Option<usize>> {
+    let old_children = old.children();
+    let mut keyed = HashMap::new();
+    let mut unkeyed = Vec::new();
+    for (index, child) in old_children.iter().enumerate() {
+        match child.key() {
+            Some(key) => {
+                keyed.entry(key).or_insert(index);
+            }
+            None => unkeyed.push(index),
+        }
+    }
+
+    // Only the first of several siblings sharing a key can be matched
+    let mut seen = HashSet::new();
+    for key in new.children().iter().filter_map(Node::key) {
+        if !seen.insert(key) {
+            log::warn!("Duplicate key {key:?} among the children of {path:?}");
+        }
+    }
+
+    let mut unkeyed = unkeyed.into_iter();
+    let sources: Vec<Option<usize>> = new
+        .children()
+        .iter()
+        .map(|child| {
+            let candidate = match child.key() {
+                Some(key) => keyed.remove(key),
+                None => unkeyed.next(),
+            };
+            candidate.filter(|&index| same_identity(&old_children[index], child))
+        })
+        .collect();
+
+    // Old children nobody matched go first, from the back so the indices
+    // of the rest hold
+    let mut matched = vec![false; old_children.len()];
+    for &source in sources.iter().flatten() {
+        matched[source] = true;
+    }
+    for index in (0..old_children.len()).rev() {
+        if !matched[index] {
+            patches.push(Patch::Remove {
+                parent: path.to_vec(),
+                index,
+            });
+        }
+    }
+
+    // Then each new child in turn is moved or inserted into place; the ones
+    // before it are already settled, so it is never found further forward
+    let mut current: Vec<Option<usize>> = (0..old_children.len())
+        .filter(|&index| matched[index])
+        .map(Some)
+        .collect();
+    for (index, &source) in sources.iter().enumerate() {
+        match source {
+            Some(source) => {
+                let from = current
+                    .iter()
+                    .position(|&child| child == Some(source))
+                    .expect("matched children are present");
+                if from != index {
+                    patches.push(Patch::Move {
+                        parent: path.to_vec(),
+                        from,
+                        to: index,
+                    });
+                    current.remove(from);
+                    current.insert(index, Some(source));
+                }
+            }
+            None => {
+                patches.push(Patch::Insert {
+                    parent: path.to_vec(),
+                    index,
+                    node: new.children()[index].clone(),
+                });
+                current.insert(index, None);
+            }
+        }
+    }
+
+    sources
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{Component, ComponentError, ComponentId, ComponentInstance, Context};
+
+    // Component holding state that a remount would lose
+    struct Counter {
+        id: ComponentId,
+        count: u32,
+    }
+
+    impl Component for Counter {
+        type Props = u32;
+
+        fn component_id(&self) -> ComponentId {
+            self.id
+        }
+
+        fn create(count: u32, _context: Context) -> Self {
+            Self {
+                id: ComponentId::new(),
+                count,
+            }
+        }
+
+        fn update(&mut self, _count: u32) -> Result<(), ComponentError> {
+            Ok(())
+        }
+
+        fn render(&self) -> Result<Vec<Node>, ComponentError> {
+            Ok(vec![])
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn counter(key: &str, count: u32) -> Node {
+        let component = Counter::create(count, Context::new());
+        let mut node = Node::new(Some(ComponentInstance::new(component, count)));
+        node.set_key(key);
+        node
+    }
+
+    fn count(node: &Node) -> u32 {
+        let instance = node.component().unwrap().instance.lock().unwrap();
+        instance.as_any().downcast_ref::<Counter>().unwrap().count
+    }
+
+    fn item(key: Option<&str>, text: &str) -> Node {
+        let mut node = Node::default();
+        if let Some(key) = key {
+            node.set_key(key);
+        }
+        node.set_text(text);
+        node
+    }
+
+    fn list(items: &[Node]) -> Node {
+        let mut root = Node::default();
+        for child in items {
+            root.add_child(child.clone());
+        }
+        root
+    }
+
+    fn texts(node: &Node) -> Vec<&str> {
+        node.children()
+            .iter()
+            .map(|child| child.text().unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn test_keyed_reorder_moves_nodes() {
+        let [a, b, c] = ["a", "b", "c"].map(|key| item(Some(key), key));
+        let mut old = list(&[a.clone(), b.clone(), c.clone()]);
+        let new = list(&[c, a, b]);
+        let c_id = old.children()[2].id_value();
+
+        let patches = diff(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(
+            &patches[0],
+            Patch::Move { parent, from: 2, to: 0 } if parent.is_empty()
+        ));
+
+        // The moved node is the old one, not a rebuilt copy
+        apply_patches(&mut old, patches);
+        assert_eq!(texts(&old), ["c", "a", "b"]);
+        assert_eq!(old.children()[0].id_value(), c_id);
+        assert!(diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_keyed_components_keep_state_across_reorder() {
+        // A freshly rendered tree carries fresh component state
+        let mut old = list(&[counter("a", 1), counter("b", 2)]);
+        let new = list(&[counter("new", 0), counter("b", 0), counter("a", 0)]);
+
+        // Inserting at the top doesn't rebuild the rows below it
+        let patches = diff(&old, &new);
+        assert_eq!(patches.len(), 2);
+        assert!(matches!(patches[0], Patch::Insert { index: 0, .. }));
+        assert!(matches!(patches[1], Patch::Move { from: 2, to: 1, .. }));
+
+        apply_patches(&mut old, patches);
+        let counts: Vec<u32> = old.children().iter().map(count).collect();
+        assert_eq!(counts, [0, 2, 1]);
+    }
+
+    #[test]
+    fn test_prop_change_updates_without_remounting() {
+        let mut old = list(&[item(None, "one"), item(None, "two")]);
+        let new = list(&[item(None, "one"), item(None, "changed")]);
+        let id = old.children()[1].id_value();
+
+        let patches = diff(&old, &new);
+        assert_eq!(patches.len(), 1);
+        assert!(matches!(&patches[0], Patch::Update { path, .. } if *path == [1]));
+        assert_eq!(patches[0].target(), [1]);
+
+        apply_patches(&mut old, patches);
+        assert_eq!(texts(&old), ["one", "changed"]);
+        assert_eq!(old.children()[1].id_value(), id);
+    }
+
+    #[test]
+    fn test_inserts_removes_and_replaced_root() {
+        let mut old = list(&[item(Some("a"), "a"), item(Some("b"), "b"), item(None, "x")]);
+        let new = list(&[item(Some("b"), "b"), item(Some("d"), "d")]);
+
+        let patches = diff(&old, &new);
+        assert!(matches!(patches[0], Patch::Remove { index: 2, .. }));
+        assert!(matches!(patches[1], Patch::Remove { index: 0, .. }));
+        assert!(matches!(patches[2], Patch::Insert { index: 1, .. }));
+        assert_eq!(patches.len(), 3);
+        apply_patches(&mut old, patches);
+        assert_eq!(texts(&old), ["b", "d"]);
+
+        // A root under a different key can't be patched
+        let mut other = new.clone();
+        other.set_key("other");
+        let patches = diff(&new, &other);
+        assert!(matches!(&patches[..], [Patch::Replace { path, .. }] if path.is_empty()));
+    }
+}
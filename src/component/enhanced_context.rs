@@ -249,7 +249,7 @@ mod tests {
         fn request_update(&mut self) -> Result<(), ComponentError> {
             self.context
                 .request_update(self.id)
-                .map_err(|e| ComponentError::UpdateError(e))
+                .map_err(ComponentError::UpdateError)
         }
 
         fn as_any(&self) -> &dyn std::any::Any {
@@ -289,6 +289,11 @@ mod tests {
             Ok(())
         }
 
+        fn any_on_props_update(&mut self, _prev_props: &dyn Props) -> Result<(), ComponentError> {
+            self.lifecycle_events.push("on_props_update".to_string());
+            Ok(())
+        }
+
         fn any_before_unmount(&mut self) -> Result<(), ComponentError> {
             self.lifecycle_events.push("before_unmount".to_string());
             Ok(())
@@ -182,6 +182,11 @@ where
         self.wrapped_component.after_update()
     }
 
+    fn on_props_update(&mut self, prev_props: &Self::Props) -> Result<(), ComponentError> {
+        let wrapped_props = H::transform_props(prev_props);
+        self.wrapped_component.on_props_update(&wrapped_props)
+    }
+
     fn before_unmount(&mut self) -> Result<(), ComponentError> {
         self.wrapped_component.before_unmount()
     }
@@ -0,0 +1,256 @@
+//! Component-local state that survives re-renders
+//!
+//! A component's render takes its [`Hooks`] from
+//! [`Context::hooks`](crate::component::Context::hooks) and calls
+//! [`Hooks::use_state`] once for each piece of state, in the same order on
+//! every render. The state belongs to the component's ID, so it lasts as
+//! long as the component instance does: through re-renders, and through
+//! keyed reordering, which moves instances rather than rebuilding them.
+//! The component tree drops it when the component is unmounted.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::component::{ComponentId, UpdateScheduler};
+
+/// One `use_state` value
+type Slot = Arc<Mutex<Box<dyn Any + Send>>>;
+
+/// Every component's `use_state` values, in call order
+pub(crate) type HookStore = Arc<Mutex<HashMap<ComponentId, Vec<Slot>>>>;
+
+/// Access to one component's state for the duration of a render
+#[derive(Debug)]
+pub struct Hooks {
+    store: HookStore,
+    scheduler: Arc<Mutex<UpdateScheduler>>,
+    component_id: ComponentId,
+    /// Index of the next `use_state` call
+    cursor: usize,
+}
+
+impl Hooks {
+    pub(crate) fn new(
+        store: HookStore,
+        scheduler: Arc<Mutex<UpdateScheduler>>,
+        component_id: ComponentId,
+    ) -> Self {
+        Self {
+            store,
+            scheduler,
+            component_id,
+            cursor: 0,
+        }
+    }
+
+    /// The current value of the next piece of state, `initial` the first
+    /// time, and a setter that replaces it and schedules the component to
+    /// re-render
+    ///
+    /// State is matched to calls by order, so a render must make the same
+    /// calls in the same order every time; a call finding state of another
+    /// type there starts over from `initial`.
+    pub fn use_state<T: Clone + Send + 'static>(
+        &mut self,
+        initial: T,
+    ) -> (T, impl Fn(T) + Send + Sync + 'static) {
+        let slot = {
+            let mut store = self.store.lock().unwrap_or_else(PoisonError::into_inner);
+            let slots = store.entry(self.component_id).or_default();
+            if slots.len() == self.cursor {
+                slots.push(Arc::new(Mutex::new(Box::new(initial.clone()))));
+            }
+            Arc::clone(&slots[self.cursor])
+        };
+        self.cursor += 1;
+
+        let value = {
+            let mut stored = slot.lock().unwrap_or_else(PoisonError::into_inner);
+            match stored.downcast_ref::<T>() {
+                Some(value) => value.clone(),
+                None => {
+                    log::warn!(
+                        "State {} of {:?} changed type between renders",
+                        self.cursor - 1,
+                        self.component_id
+                    );
+                    *stored = Box::new(initial.clone());
+                    initial
+                }
+            }
+        };
+
+        let scheduler = Arc::clone(&self.scheduler);
+        let component_id = self.component_id;
+        let set = move |value: T| {
+            *slot.lock().unwrap_or_else(PoisonError::into_inner) = Box::new(value);
+            if let Ok(mut scheduler) = scheduler.lock() {
+                scheduler.schedule_update(component_id);
+            }
+        };
+        (value, set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component::{
+        apply_patches, diff, Component, ComponentError, ComponentId, ComponentInstance,
+        ComponentTree, Context, Node,
+    };
+
+    // Counter keeping its count in hook state rather than a field
+    struct Counter {
+        id: ComponentId,
+        context: Context,
+    }
+
+    impl Counter {
+        /// Render, bumping the count first if `increment` is set
+        fn count(&self, increment: bool) -> u32 {
+            let mut hooks = self.context.hooks(self.id);
+            let (count, set_count) = hooks.use_state(0u32);
+            let (label, _) = hooks.use_state(String::from("clicks"));
+            assert_eq!(label, "clicks");
+            if increment {
+                set_count(count + 1);
+            }
+            count
+        }
+    }
+
+    impl Component for Counter {
+        type Props = ();
+
+        fn component_id(&self) -> ComponentId {
+            self.id
+        }
+
+        fn create(_props: (), context: Context) -> Self {
+            Self {
+                id: ComponentId::new(),
+                context,
+            }
+        }
+
+        fn update(&mut self, _props: ()) -> Result<(), ComponentError> {
+            Ok(())
+        }
+
+        fn render(&self) -> Result<Vec<Node>, ComponentError> {
+            let mut node = Node::default();
+            node.set_text(self.count(false).to_string());
+            Ok(vec![node])
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn counter(context: &Context, key: &str) -> Node {
+        let component = Counter::create((), context.clone());
+        let mut node = Node::new(Some(ComponentInstance::new(component, ())));
+        node.set_key(key);
+        node
+    }
+
+    fn count(node: &Node) -> u32 {
+        let instance = node.component().unwrap().instance.lock().unwrap();
+        let counter = instance.as_any().downcast_ref::<Counter>().unwrap();
+        counter.count(false)
+    }
+
+    #[test]
+    fn test_counter_state_persists_across_renders() {
+        let context = Context::new();
+        let counter = Counter::create((), context.clone());
+        assert_eq!(counter.count(true), 0);
+        assert_eq!(counter.count(true), 1);
+        assert_eq!(counter.render().unwrap()[0].text(), Some("2"));
+        assert!(context.has_pending_update(counter.id));
+
+        // State is per component, not shared between instances
+        let other = Counter::create((), context.child());
+        assert_eq!(other.count(false), 0);
+    }
+
+    #[test]
+    fn test_state_follows_keyed_reorder_and_drops_on_unmount() {
+        let context = Context::new();
+        let mut root = Node::default();
+        root.add_child(counter(&context, "a"));
+        root.add_child(counter(&context, "b"));
+        {
+            let instance = root.children()[0]
+                .component()
+                .unwrap()
+                .instance
+                .lock()
+                .unwrap();
+            let a = instance.as_any().downcast_ref::<Counter>().unwrap();
+            a.count(true);
+            a.count(true);
+        }
+
+        // A re-render builds fresh instances; reconciling keeps the old ones
+        let mut rendered = Node::default();
+        rendered.add_child(counter(&context, "b"));
+        rendered.add_child(counter(&context, "a"));
+        let patches = diff(&root, &rendered);
+        apply_patches(&mut root, patches);
+        let counts: Vec<u32> = root.children().iter().map(count).collect();
+        assert_eq!(counts, [0, 2]);
+
+        // Unmounting drops the state along with the component
+        let tree = ComponentTree::new(context.clone());
+        let component = Counter::create((), context.clone());
+        let id = component.id;
+        component.count(true);
+        tree.add_component(ComponentInstance::new(component, ()))
+            .unwrap();
+        tree.mount_component(id).unwrap();
+        tree.unmount_component(id).unwrap();
+        let mut hooks = context.hooks(id);
+        assert_eq!(hooks.use_state(0u32).0, 0);
+    }
+
+    #[test]
+    fn test_state_drops_with_removed_keyed_node() {
+        let context = Context::new();
+        let mut root = Node::default();
+        root.add_child(counter(&context, "a"));
+        root.add_child(counter(&context, "b"));
+        let id = |node: &Node| {
+            let instance = node.component().unwrap().instance.lock().unwrap();
+            instance.component_id()
+        };
+        let (a, b) = (id(&root.children()[0]), id(&root.children()[1]));
+        context.hooks(a).use_state(0u32).1(5);
+        context.hooks(b).use_state(0u32).1(7);
+
+        // Reconciling away "a" drops its state but not its sibling's
+        let mut rendered = Node::default();
+        rendered.add_child(counter(&context, "b"));
+        let patches = diff(&root, &rendered);
+        context.apply_patches(&mut root, patches);
+        assert_eq!(root.children().len(), 1);
+        assert_eq!(context.hooks(a).use_state(0u32).0, 0);
+        assert_eq!(context.hooks(b).use_state(0u32).0, 7);
+
+        // As does removing a component from the tree without mounting it
+        let tree = ComponentTree::new(context.clone());
+        let component = Counter::create((), context.clone());
+        let id = component.id;
+        component.count(true);
+        tree.add_component(ComponentInstance::new(component, ()))
+            .unwrap();
+        tree.remove_component(id).unwrap();
+        assert_eq!(context.hooks(id).use_state(0u32).0, 0);
+    }
+}
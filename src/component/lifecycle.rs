@@ -3,7 +3,8 @@
 use std::sync::{Arc, Mutex};
 
 use crate::component::{
-    ComponentError, ComponentInstance, Context, LifecyclePhase, UnmountContext, UnmountReason,
+    ComponentError, ComponentId, ComponentInstance, Context, LifecyclePhase, MountContext,
+    UnmountContext, UnmountReason,
 };
 
 /// Manages the lifecycle of components
@@ -37,6 +38,18 @@ impl LifecycleManager {
     pub fn get_component(&self) -> &Arc<Mutex<ComponentInstance>> {
         &self.component
     }
+
+    /// ID of the managed component
+    fn component_id(&self) -> Result<ComponentId, ComponentError> {
+        let component_instance = self.component.lock().map_err(|_| {
+            ComponentError::LockError("Failed to lock component for its ID".to_string())
+        })?;
+        let inner_component = component_instance.instance.lock().map_err(|_| {
+            ComponentError::LockError("Failed to lock inner component for its ID".to_string())
+        })?;
+        Ok(inner_component.component_id())
+    }
+
     /// Initialize the component (post-creation)
     pub fn initialize(&mut self) -> Result<(), ComponentError> {
         if self.phase != LifecyclePhase::Created {
@@ -69,6 +82,15 @@ impl LifecycleManager {
     }
     /// Mount the component to the tree
     pub fn mount(&mut self) -> Result<(), ComponentError> {
+        let mount_context = MountContext::new(self.component_id()?);
+        self.mount_with_context(mount_context)
+    }
+
+    /// Mount the component, telling its `on_mount` hook about `mount_context`
+    pub fn mount_with_context(
+        &mut self,
+        mount_context: MountContext,
+    ) -> Result<(), ComponentError> {
         if self.phase != LifecyclePhase::Created {
             return Err(ComponentError::InvalidLifecycleTransition(
                 self.phase,
@@ -76,25 +98,6 @@ impl LifecycleManager {
             ));
         }
 
-        // Create mount context
-        let mount_context = crate::component::MountContext::new(
-            self.component
-                .lock()
-                .map_err(|_| {
-                    ComponentError::LockError(
-                        "Failed to lock component for mount context".to_string(),
-                    )
-                })?
-                .instance
-                .lock()
-                .map_err(|_| {
-                    ComponentError::LockError(
-                        "Failed to lock inner component for mount context".to_string(),
-                    )
-                })?
-                .component_id(),
-        );
-
         // Before mount phase - call before_mount hook
         let before_mount_result = {
             let component_instance = self.component.lock().map_err(|_| {
@@ -131,11 +134,8 @@ impl LifecycleManager {
             self.context
                 .execute_lifecycle_hooks(LifecyclePhase::Mounting, &mut **inner_component);
 
-            // Call the enhanced mount method with context
-            inner_component.any_on_mount(&mount_context)?;
-
-            // Call the basic mount method through AnyComponent for backward compatibility
-            inner_component.any_mount()
+            // The default on_mount calls the basic mount, so only one is called here
+            inner_component.any_on_mount(&mount_context)
         };
 
         // Set mounted phase after successful mount
@@ -228,10 +228,10 @@ impl LifecycleManager {
                 instance.any_update(props_for_update)?;
             }
 
-            // Update the props in ComponentInstance
-            component.props = props;
+            // Update the props in ComponentInstance, keeping the old ones for on_props_update
+            let prev_props = std::mem::replace(&mut component.props, props);
 
-            // Call after_update
+            // Call on_props_update and after_update
             {
                 let mut instance = component.instance.lock().map_err(|_| {
                     ComponentError::LockError(
@@ -239,6 +239,7 @@ impl LifecycleManager {
                     )
                 })?;
 
+                instance.any_on_props_update(&*prev_props)?;
                 instance.any_after_update()
             }
         };
@@ -254,6 +255,16 @@ impl LifecycleManager {
 
     /// Unmount the component from the tree
     pub fn unmount(&mut self) -> Result<(), ComponentError> {
+        let unmount_context = UnmountContext::new(self.component_id()?, UnmountReason::Removed);
+        self.unmount_with_context(unmount_context)
+    }
+
+    /// Unmount the component, telling its `on_unmount` hook about
+    /// `unmount_context`
+    pub fn unmount_with_context(
+        &mut self,
+        unmount_context: UnmountContext,
+    ) -> Result<(), ComponentError> {
         if self.phase != LifecyclePhase::Mounted {
             return Err(ComponentError::InvalidLifecycleTransition(
                 self.phase,
@@ -289,26 +300,6 @@ impl LifecycleManager {
         self.phase = LifecyclePhase::Unmounting;
         self.context.set_lifecycle_phase(LifecyclePhase::Unmounting);
 
-        // Create unmount context
-        let unmount_context = UnmountContext::new(
-            self.component
-                .lock()
-                .map_err(|_| {
-                    ComponentError::LockError(
-                        "Failed to lock component for unmount context".to_string(),
-                    )
-                })?
-                .instance
-                .lock()
-                .map_err(|_| {
-                    ComponentError::LockError(
-                        "Failed to lock inner component for unmount context".to_string(),
-                    )
-                })?
-                .component_id(),
-            UnmountReason::Removed,
-        );
-
         let unmount_result = if let Ok(component_instance) = self.component.lock() {
             let mut inner_component = component_instance.instance.lock().map_err(|_| {
                 ComponentError::LockError("Failed to lock inner component for unmount".to_string())
@@ -318,11 +309,8 @@ impl LifecycleManager {
             self.context
                 .execute_lifecycle_hooks(LifecyclePhase::Unmounting, &mut **inner_component);
 
-            // Call the enhanced unmount method with context
-            inner_component.any_on_unmount(&unmount_context)?;
-
-            // Call the basic unmount method for backward compatibility
-            inner_component.any_unmount()
+            // The default on_unmount calls the basic unmount, so only one is called here
+            inner_component.any_on_unmount(&unmount_context)
         } else {
             Err(ComponentError::LockError(
                 "Failed to lock component instance during unmounting".to_string(),
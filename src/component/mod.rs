@@ -5,14 +5,17 @@
 
 mod composition;
 mod context;
+mod diff;
 mod enhanced_context;
 mod error;
 mod hoc;
+mod hooks;
 mod lifecycle;
 mod node;
 mod performance;
 pub mod props;
 mod state_tracking;
+mod transition;
 mod tree;
 mod update_scheduler;
 
@@ -26,12 +29,14 @@ pub use composition::{
     SlottedProps,
 };
 pub use context::{callback, Callback, ContextProvider};
+pub use diff::{apply_patches, diff, NodeProps, Patch};
 pub use enhanced_context::Context as EnhancedContext;
 pub use error::ComponentError;
 pub use hoc::{
     HOCWrapper, HigherOrderComponent, LoggedComponent, MonitoredComponent, WithLogging,
     WithPerformanceMonitoring,
 };
+pub use hooks::Hooks;
 pub use lifecycle::LifecycleManager;
 // Import Node from our own node module instead of component_single
 pub use node::Node;
@@ -43,6 +48,7 @@ pub use state_tracking::{
     ChangePriority, StateChange, StateChanges, StateSnapshot, StateTracker, StateTrackingConfig,
     StateValue,
 };
+pub use transition::{TransitionProperty, Transitions};
 pub use tree::{ComponentTree, TreeError, TreeResult};
 pub use update_scheduler::{UpdatePriority, UpdateScheduler as ModuleUpdateScheduler};
 
@@ -509,6 +515,10 @@ pub trait AnyComponent: Send + Sync + std::any::Any {
 
     /// Called after the component has updated
     fn any_after_update(&mut self) -> Result<(), ComponentError>;
+
+    /// Called after an update with the props the component had before it
+    fn any_on_props_update(&mut self, prev_props: &dyn Props) -> Result<(), ComponentError>;
+
     /// Called before component is unmounted
     fn any_before_unmount(&mut self) -> Result<(), ComponentError>;
 
@@ -613,6 +623,14 @@ pub trait Component: AnyComponent + Send + Sync + std::any::Any {
     fn after_update(&mut self) -> Result<(), ComponentError> {
         Ok(())
     }
+
+    /// Called after an update with the props the component had before it,
+    /// for reacting to what changed, such as restarting a timer when its
+    /// duration prop changes
+    fn on_props_update(&mut self, _prev_props: &Self::Props) -> Result<(), ComponentError> {
+        Ok(())
+    }
+
     /// Called before component is unmounted
     /// Automatic cleanup of state subscriptions happens after this
     fn before_unmount(&mut self) -> Result<(), ComponentError> {
@@ -841,6 +859,18 @@ impl<T: Component> AnyComponent for T {
     fn any_after_update(&mut self) -> Result<(), ComponentError> {
         Component::after_update(self)
     }
+
+    fn any_on_props_update(&mut self, prev_props: &dyn Props) -> Result<(), ComponentError> {
+        if let Some(typed_props) = prev_props.as_any().downcast_ref::<T::Props>() {
+            Component::on_props_update(self, typed_props)
+        } else {
+            Err(ComponentError::PropsMismatch {
+                expected: TypeId::of::<T::Props>(),
+                got: prev_props.as_any().type_id(),
+            })
+        }
+    }
+
     fn any_before_unmount(&mut self) -> Result<(), ComponentError> {
         Component::before_unmount(self)
     }
@@ -898,6 +928,9 @@ pub struct Context {
 
     /// Update scheduler for batching state changes
     update_scheduler: Arc<Mutex<UpdateScheduler>>,
+
+    /// Component-local state from `Hooks::use_state`
+    hooks: hooks::HookStore,
 }
 
 /// Manages batched updates for improved performance
@@ -965,6 +998,22 @@ impl Context {
             lifecycle_phase: LifecyclePhase::Created,
             context_provider: ContextProvider::new(),
             update_scheduler: Arc::new(Mutex::new(UpdateScheduler::default())),
+            hooks: hooks::HookStore::default(),
+        }
+    }
+
+    /// Create a context for a child component
+    ///
+    /// The child shares this context's state, events, and update scheduler,
+    /// and sees every value provided here or above; values it provides
+    /// itself are visible only to its own descendants.
+    pub fn child(&self) -> Self {
+        Self {
+            id: ComponentId::new(),
+            lifecycle_hooks: Arc::new(Mutex::new(LifecycleHooks::new())),
+            lifecycle_phase: LifecyclePhase::Created,
+            context_provider: ContextProvider::with_parent(self.context_provider.clone()),
+            ..self.clone()
         }
     }
 
@@ -1009,6 +1058,36 @@ impl Context {
         }
     }
 
+    /// Hooks for a render of the component `component_id`, giving it state
+    /// that lasts until it is unmounted
+    pub fn hooks(&self, component_id: ComponentId) -> Hooks {
+        Hooks::new(
+            Arc::clone(&self.hooks),
+            Arc::clone(&self.update_scheduler),
+            component_id,
+        )
+    }
+
+    /// Drop the hook state of an unmounted component
+    pub(crate) fn drop_hooks(&self, component_id: ComponentId) {
+        if let Ok(mut hooks) = self.hooks.lock() {
+            hooks.remove(&component_id);
+        }
+    }
+
+    /// Apply `patches` to `root` as [`apply_patches`] does, dropping the
+    /// hook state of the components whose nodes they remove or replace
+    ///
+    /// Components a replacement node carries over keep their state.
+    pub fn apply_patches(&self, root: &mut Node, patches: Vec<Patch>) {
+        for patch in patches {
+            for component_id in diff::removed_components(root, &patch) {
+                self.drop_hooks(component_id);
+            }
+            apply_patches(root, vec![patch]);
+        }
+    }
+
     /// Create a reactive state that triggers component updates
     pub fn create_reactive_state<T>(&self, initial_value: T, component_id: ComponentId) -> State<T>
     where
@@ -3,9 +3,12 @@
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
-use crate::component::ComponentInstance;
+use crate::component::{ComponentInstance, NodeProps};
 use crate::events::delegation::EventDelegate;
 use crate::events::Event;
+use crate::layout::{Display, Rect};
+use crate::renderer::{ImageData, ImageFit};
+use crate::style::Style;
 
 /// A node in the UI tree with event delegation support
 #[derive(Debug, Clone)]
@@ -23,6 +26,27 @@ pub struct Node {
     /// Unique identifier for this node
     id: usize,
 
+    /// Identity among siblings that survives reordering when diffing
+    key: Option<String>,
+
+    /// Visual style used by renderers when painting this node
+    style: Style,
+
+    /// Stylesheet classes, resolved into `style` by `Stylesheet::apply`
+    class: Vec<String>,
+
+    /// Computed layout rectangle, relative to the parent node's origin
+    layout_rect: Rect,
+
+    /// Text content painted inside this node, if any
+    text: Option<String>,
+
+    /// Image painted into this node's bounds, if any
+    image: Option<ImageData>,
+
+    /// How the image is scaled into this node's bounds
+    image_fit: ImageFit,
+
     /// Event delegate for this node (not cloneable, so wrapped in Arc)
     event_delegate: Option<Arc<Mutex<EventDelegate>>>,
 }
@@ -39,6 +63,13 @@ impl Node {
             attributes: HashMap::new(),
             children: Vec::new(),
             id,
+            key: None,
+            style: Style::default(),
+            class: Vec::new(),
+            layout_rect: Rect::zero(),
+            text: None,
+            image: None,
+            image_fit: ImageFit::Fill,
             event_delegate: Some(Arc::new(Mutex::new(EventDelegate::new(Some(id))))),
         }
     }
@@ -53,6 +84,17 @@ impl Node {
         self.id
     }
 
+    /// Get the key that identifies this node among its siblings
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Set the key that identifies this node among its siblings, so diffing
+    /// matches it to its old self even after siblings are reordered
+    pub fn set_key(&mut self, key: impl Into<String>) {
+        self.key = Some(key.into());
+    }
+
     /// Get a reference to the node's children
     pub fn children(&self) -> &[Node] {
         &self.children
@@ -63,6 +105,25 @@ impl Node {
         &mut self.children
     }
 
+    /// Whether the node takes part in layout and paint, which it doesn't
+    /// with `display: none`
+    pub fn is_displayed(&self) -> bool {
+        self.style
+            .layout_style
+            .as_ref()
+            .is_none_or(|layout| layout.display != Display::None)
+    }
+
+    /// Get the node's children in paint order
+    ///
+    /// Children are stably sorted by `z-index` (unset counts as 0), so among
+    /// equal z-indices later siblings still paint over earlier ones.
+    pub fn children_in_paint_order(&self) -> Vec<&Node> {
+        let mut children: Vec<&Node> = self.children.iter().collect();
+        children.sort_by_key(|child| child.style.z_index.unwrap_or(0));
+        children
+    }
+
     /// Get the node's event delegate
     pub fn event_delegate(&self) -> Option<Arc<Mutex<EventDelegate>>> {
         self.event_delegate.clone()
@@ -70,6 +131,12 @@ impl Node {
 
     /// Add a child node
     pub fn add_child(&mut self, child: Node) {
+        let index = self.children.len();
+        self.insert_child(index, child);
+    }
+
+    /// Insert a child node at `index`, shifting later children along
+    pub fn insert_child(&mut self, index: usize, child: Node) {
         // Set up event delegation relationship
         if let Some(parent_delegate) = &self.event_delegate {
             if let Some(child_delegate) = &child.event_delegate {
@@ -83,7 +150,7 @@ impl Node {
             }
         }
 
-        self.children.push(child);
+        self.children.insert(index, child);
     }
 
     /// Add an attribute
@@ -114,6 +181,104 @@ impl Node {
     pub fn attributes(&self) -> &HashMap<String, String> {
         &self.attributes
     }
+
+    /// Get the node's visual style
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+
+    /// Get the node's visual style mutably
+    pub fn style_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+
+    /// Replace the node's visual style
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Get the node's stylesheet classes, in the order they apply
+    pub fn classes(&self) -> &[String] {
+        &self.class
+    }
+
+    /// Add a stylesheet class, overriding the classes added before it
+    pub fn add_class(&mut self, class: impl Into<String>) {
+        self.class.push(class.into());
+    }
+
+    /// Get the node's layout rectangle (relative to its parent)
+    pub fn layout_rect(&self) -> Rect {
+        self.layout_rect
+    }
+
+    /// Set the node's layout rectangle (relative to its parent)
+    pub fn set_layout_rect(&mut self, rect: Rect) {
+        self.layout_rect = rect;
+    }
+
+    /// Get the node's text content
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Set the node's text content
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = Some(text.into());
+    }
+
+    /// Get the node's image
+    pub fn image(&self) -> Option<&ImageData> {
+        self.image.as_ref()
+    }
+
+    /// Set the image painted to fill the node's bounds
+    pub fn set_image(&mut self, image: ImageData) {
+        self.image = Some(image);
+    }
+
+    /// Get how the node's image is scaled into its bounds
+    pub fn image_fit(&self) -> ImageFit {
+        self.image_fit
+    }
+
+    /// Set how the node's image is scaled into its bounds
+    pub fn set_image_fit(&mut self, fit: ImageFit) {
+        self.image_fit = fit;
+    }
+
+    /// Get everything the node paints apart from its children
+    pub fn props(&self) -> NodeProps {
+        NodeProps {
+            attributes: self.attributes.clone(),
+            style: self.style.clone(),
+            layout_rect: self.layout_rect,
+            text: self.text.clone(),
+            image: self.image.clone(),
+            image_fit: self.image_fit,
+        }
+    }
+
+    /// Replace everything the node paints apart from its children
+    pub fn set_props(&mut self, props: NodeProps) {
+        self.attributes = props.attributes;
+        self.style = props.style;
+        self.layout_rect = props.layout_rect;
+        self.text = props.text;
+        self.image = props.image;
+        self.image_fit = props.image_fit;
+    }
+
+    /// Whether everything the node paints apart from its children matches
+    /// `other`
+    pub(super) fn same_props(&self, other: &Node) -> bool {
+        self.attributes == other.attributes
+            && self.style == other.style
+            && self.layout_rect == other.layout_rect
+            && self.text == other.text
+            && self.image == other.image
+            && self.image_fit == other.image_fit
+    }
 }
 
 impl Default for Node {
@@ -126,6 +291,13 @@ impl Default for Node {
             attributes: HashMap::new(),
             children: Vec::new(),
             id,
+            key: None,
+            style: Style::default(),
+            class: Vec::new(),
+            layout_rect: Rect::zero(),
+            text: None,
+            image: None,
+            image_fit: ImageFit::Fill,
             event_delegate: Some(Arc::new(Mutex::new(EventDelegate::new(Some(id))))),
         }
     }
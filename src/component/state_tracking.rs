@@ -410,7 +410,8 @@ impl StateChanges {
 
     /// Sort changes by priority
     pub fn sort_by_priority(&mut self) {
-        self.changes.sort_by(|a, b| b.priority.cmp(&a.priority));
+        self.changes
+            .sort_by_key(|change| std::cmp::Reverse(change.priority));
     }
 }
 
@@ -0,0 +1,443 @@
+//! Animated transitions between style values
+//!
+//! A node opts in through its style's `transition_property`,
+//! `transition_duration`, `transition_timing_function`, and
+//! `transition_delay`, as in CSS, with times in seconds. When
+//! [`Transitions::apply_patches`] updates a node whose transitioned
+//! properties changed, the node keeps showing the old values, and each
+//! [`Transitions::tick`] then eases them toward the new ones; a further
+//! change mid-transition heads for the newer value from wherever the old
+//! transition had got to, rather than snapping back to its start.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::clock::{Clock, FrameClock};
+
+use crate::component::diff::node_at;
+use crate::component::{apply_patches, Node, Patch};
+use crate::kit::utils::animation::Animation;
+use crate::style::{Color, Style, TimingFunction, Transform};
+
+/// A style property that can be transitioned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionProperty {
+    Opacity,
+    BackgroundColor,
+    /// Translations, scales, and rotations; other transforms change
+    /// without transitioning
+    Transform,
+}
+
+impl TransitionProperty {
+    /// Every property that can be transitioned, which `all` stands for
+    pub const ALL: [TransitionProperty; 3] = [
+        TransitionProperty::Opacity,
+        TransitionProperty::BackgroundColor,
+        TransitionProperty::Transform,
+    ];
+
+    /// The property called `name`, spelled as in CSS or as the style field:
+    /// `background-color` or `background_color`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().replace('_', "-").as_str() {
+            "opacity" => Some(TransitionProperty::Opacity),
+            "background-color" => Some(TransitionProperty::BackgroundColor),
+            "transform" => Some(TransitionProperty::Transform),
+            _ => None,
+        }
+    }
+
+    /// The properties `style` transitions, in the order it lists them
+    pub fn transitioned(style: &Style) -> Vec<Self> {
+        let names = style.transition_property.as_deref().unwrap_or_default();
+        if names.iter().any(|name| name.trim() == "all") {
+            return Self::ALL.to_vec();
+        }
+        let mut properties = Vec::new();
+        for property in names.iter().filter_map(|name| Self::from_name(name)) {
+            if !properties.contains(&property) {
+                properties.push(property);
+            }
+        }
+        properties
+    }
+
+    /// The property's value in `style` as numbers to interpolate, or `None`
+    /// if it can't be interpolated
+    pub(crate) fn read(self, style: &Style) -> Option<Vec<f32>> {
+        match self {
+            TransitionProperty::Opacity => Some(vec![style.opacity.unwrap_or(1.0)]),
+            TransitionProperty::BackgroundColor => {
+                let (r, g, b, a) = match &style.background_color {
+                    Some(color) => color.to_rgba()?,
+                    None => (0.0, 0.0, 0.0, 0.0),
+                };
+                Some(vec![r, g, b, a])
+            }
+            TransitionProperty::Transform => {
+                decompose(style.transform.as_ref().unwrap_or(&Transform::None))
+            }
+        }
+    }
+
+    /// Set the property in `style` from numbers made by `read`
+    pub(crate) fn write(self, style: &mut Style, value: &[f32]) {
+        match self {
+            TransitionProperty::Opacity => style.opacity = Some(value[0]),
+            TransitionProperty::BackgroundColor => {
+                style.background_color = Some(Color::Rgba(value[0], value[1], value[2], value[3]));
+            }
+            TransitionProperty::Transform => style.transform = Some(compose(value)),
+        }
+    }
+
+    /// Set the property in `to` to its value in `from`
+    pub(crate) fn copy(self, from: &Style, to: &mut Style) {
+        match self {
+            TransitionProperty::Opacity => to.opacity = from.opacity,
+            TransitionProperty::BackgroundColor => {
+                to.background_color = from.background_color.clone();
+            }
+            TransitionProperty::Transform => to.transform = from.transform.clone(),
+        }
+    }
+
+    /// Whether `style` sets the property
+    pub(crate) fn is_set(self, style: &Style) -> bool {
+        match self {
+            TransitionProperty::Opacity => style.opacity.is_some(),
+            TransitionProperty::BackgroundColor => style.background_color.is_some(),
+            TransitionProperty::Transform => style.transform.is_some(),
+        }
+    }
+
+    /// Whether the property has the same value in `a` and `b`
+    fn same(self, a: &Style, b: &Style) -> bool {
+        match self {
+            TransitionProperty::Opacity => a.opacity == b.opacity,
+            TransitionProperty::BackgroundColor => a.background_color == b.background_color,
+            TransitionProperty::Transform => a.transform == b.transform,
+        }
+    }
+}
+
+/// One property of one node on its way to a new value
+#[derive(Debug, Clone)]
+struct Tween {
+    from: Vec<f32>,
+    to: Vec<f32>,
+    /// Style holding just the exact value to land on
+    target: Style,
+    /// Seconds left before the transition starts moving
+    delay: f32,
+    /// Eased progress from 0 to 1
+    progress: Animation,
+}
+
+impl Tween {
+    fn value(&self) -> Vec<f32> {
+        let t = self.progress.value();
+        self.from
+            .iter()
+            .zip(&self.to)
+            .map(|(from, to)| from + (to - from) * t)
+            .collect()
+    }
+}
+
+/// The transitions running in a retained node tree
+///
+/// Patches from [`diff`](crate::component::diff) go through
+/// [`apply_patches`](Self::apply_patches) instead of the free function so
+/// changed values can be eased in, and [`tick`](Self::tick), or
+/// [`update`](Self::update) with a clock, advances them once a frame before
+/// the tree is painted.
+#[derive(Debug, Default)]
+pub struct Transitions {
+    /// Running transitions, by node ID and property
+    running: HashMap<(usize, TransitionProperty), Tween>,
+    /// Time source for `update`, if one was given
+    clock: Option<FrameClock>,
+}
+
+impl Transitions {
+    /// Create a set with nothing running
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Have `update` advance the transitions by the time passed on
+    /// `clock`, starting now
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(FrameClock::new(clock));
+        self
+    }
+
+    /// Advance every transition in `root` by the time passed on the clock
+    /// since it was given or last updated, as [`tick`](Self::tick) does
+    ///
+    /// Without a clock the transitions stay where they are.
+    pub fn update(&mut self, root: &mut Node) -> bool {
+        let dt = self.clock.as_mut().map_or(0.0, FrameClock::delta);
+        self.tick(root, dt)
+    }
+
+    /// Whether any transition is still running, and so another frame is
+    /// needed
+    pub fn is_running(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    /// Apply `patches` to `root` as [`apply_patches`] does, starting a
+    /// transition for each transitioned property an update changes
+    pub fn apply_patches(&mut self, root: &mut Node, patches: Vec<Patch>) {
+        for patch in patches {
+            match patch {
+                Patch::Update { path, mut props } => {
+                    let node = node_at(root, &path);
+                    self.start(node.id_value(), node.style(), &mut props.style);
+                    node.set_props(*props);
+                }
+                patch => apply_patches(root, vec![patch]),
+            }
+        }
+    }
+
+    /// Start or retarget the transitions of the node `id` going from
+    /// `current` to `new`, leaving `new` showing the current values
+    fn start(&mut self, id: usize, current: &Style, new: &mut Style) {
+        let transitioned = TransitionProperty::transitioned(new);
+        let duration = new.transition_duration.unwrap_or(0.0);
+        let delay = new.transition_delay.unwrap_or(0.0).max(0.0);
+        let easing = new
+            .transition_timing_function
+            .clone()
+            .unwrap_or(TimingFunction::Ease);
+
+        for property in TransitionProperty::ALL {
+            let key = (id, property);
+            // A property no longer transitioned lands on its new value
+            if !transitioned.contains(&property) || duration <= 0.0 {
+                self.running.remove(&key);
+                continue;
+            }
+            let changed = match self.running.get(&key) {
+                Some(tween) => !property.same(&tween.target, new),
+                None => !property.same(current, new),
+            };
+            if changed {
+                match (property.read(current), property.read(new)) {
+                    (Some(from), Some(to)) => {
+                        let mut target = Style::default();
+                        property.copy(new, &mut target);
+                        self.running.insert(
+                            key,
+                            Tween {
+                                from,
+                                to,
+                                target,
+                                delay,
+                                progress: Animation::new(0.0, 1.0, duration, easing.clone()),
+                            },
+                        );
+                    }
+                    // Values that can't be interpolated snap
+                    _ => {
+                        self.running.remove(&key);
+                        continue;
+                    }
+                }
+            }
+            if self.running.contains_key(&key) {
+                property.copy(current, new);
+            }
+        }
+    }
+
+    /// Advance every transition in `root` by `dt` seconds, writing the
+    /// values they've reached into the nodes' styles
+    ///
+    /// Returns whether any transition is still running. Transitions of
+    /// nodes no longer in the tree are dropped.
+    pub fn tick(&mut self, root: &mut Node, dt: f32) -> bool {
+        let mut seen = HashSet::new();
+        self.tick_node(root, dt.max(0.0), &mut seen);
+        self.running.retain(|key, _| seen.contains(key));
+        self.is_running()
+    }
+
+    fn tick_node(
+        &mut self,
+        node: &mut Node,
+        dt: f32,
+        seen: &mut HashSet<(usize, TransitionProperty)>,
+    ) {
+        for property in TransitionProperty::ALL {
+            let key = (node.id_value(), property);
+            let Some(tween) = self.running.get_mut(&key) else {
+                continue;
+            };
+            let waited = dt.min(tween.delay);
+            tween.delay -= waited;
+            tween.progress.sample(dt - waited);
+            if tween.progress.is_finished() {
+                property.copy(&tween.target, node.style_mut());
+            } else {
+                property.write(node.style_mut(), &tween.value());
+                seen.insert(key);
+            }
+        }
+        for child in node.children_mut() {
+            self.tick_node(child, dt, seen);
+        }
+    }
+}
+
+/// Translation, scale, and rotation making up `transform`, as
+/// `[tx, ty, sx, sy, degrees]`; `None` for transforms beyond those
+fn decompose(transform: &Transform) -> Option<Vec<f32>> {
+    let (tx, ty, sx, sy, degrees) = match *transform {
+        Transform::None => (0.0, 0.0, 1.0, 1.0, 0.0),
+        Transform::Translate(x, y) => (x, y, 1.0, 1.0, 0.0),
+        Transform::TranslateX(x) => (x, 0.0, 1.0, 1.0, 0.0),
+        Transform::TranslateY(y) => (0.0, y, 1.0, 1.0, 0.0),
+        Transform::Scale(x, y) => (0.0, 0.0, x, y, 0.0),
+        Transform::ScaleX(x) => (0.0, 0.0, x, 1.0, 0.0),
+        Transform::ScaleY(y) => (0.0, 0.0, 1.0, y, 0.0),
+        Transform::Rotate(degrees) => (0.0, 0.0, 1.0, 1.0, degrees),
+        _ => return None,
+    };
+    Some(vec![tx, ty, sx, sy, degrees])
+}
+
+/// The transform from `decompose`d parts, translating, then rotating, then
+/// scaling
+fn compose(parts: &[f32]) -> Transform {
+    let [tx, ty, sx, sy, degrees] = parts else {
+        return Transform::None;
+    };
+    let mut transforms = Vec::new();
+    if *tx != 0.0 || *ty != 0.0 {
+        transforms.push(Transform::Translate(*tx, *ty));
+    }
+    if *degrees != 0.0 {
+        transforms.push(Transform::Rotate(*degrees));
+    }
+    if *sx != 1.0 || *sy != 1.0 {
+        transforms.push(Transform::Scale(*sx, *sy));
+    }
+    match transforms.len() {
+        0 => Transform::None,
+        1 => transforms.remove(0),
+        _ => Transform::Multiple(transforms),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::component::diff;
+    use std::time::Duration;
+
+    fn faded(opacity: f32) -> Node {
+        let mut node = Node::default();
+        let style = node.style_mut();
+        style.opacity = Some(opacity);
+        style.transition_property = Some(vec!["opacity".to_string()]);
+        style.transition_duration = Some(0.2);
+        style.transition_timing_function = Some(TimingFunction::Linear);
+        node
+    }
+
+    fn opacity(node: &Node) -> f32 {
+        node.style().opacity.unwrap()
+    }
+
+    #[test]
+    fn test_opacity_interpolates_to_new_value() {
+        let mut transitions = Transitions::new();
+        let mut tree = faded(1.0);
+        let patches = diff(&tree, &faded(0.0));
+        transitions.apply_patches(&mut tree, patches);
+
+        // The change doesn't show until the transition moves it
+        assert_eq!(opacity(&tree), 1.0);
+        assert!(transitions.tick(&mut tree, 0.05));
+        assert!((opacity(&tree) - 0.75).abs() < 1e-5);
+        assert!(transitions.tick(&mut tree, 0.05));
+        assert!((opacity(&tree) - 0.5).abs() < 1e-5);
+        assert!(!transitions.tick(&mut tree, 0.1));
+        assert_eq!(opacity(&tree), 0.0);
+
+        // Properties not listed change at once
+        let mut snapped = faded(0.0);
+        snapped.style_mut().background_color = Some(Color::Hex("#ff0000".to_string()));
+        let patches = diff(&tree, &snapped);
+        transitions.apply_patches(&mut tree, patches);
+        assert!(!transitions.is_running());
+        assert!(tree.style().background_color.is_some());
+    }
+
+    #[test]
+    fn test_update_advances_by_the_injected_clock() {
+        let clock = MockClock::new();
+        let mut transitions = Transitions::new().with_clock(Arc::new(clock.clone()));
+        let mut tree = faded(1.0);
+        let patches = diff(&tree, &faded(0.0));
+        transitions.apply_patches(&mut tree, patches);
+
+        // Nothing moves until the clock does
+        assert!(transitions.update(&mut tree));
+        assert_eq!(opacity(&tree), 1.0);
+        clock.advance(Duration::from_millis(100));
+        assert!(transitions.update(&mut tree));
+        assert!((opacity(&tree) - 0.5).abs() < 1e-5);
+        clock.advance(Duration::from_millis(100));
+        assert!(!transitions.update(&mut tree));
+        assert_eq!(opacity(&tree), 0.0);
+    }
+
+    #[test]
+    fn test_interruption_retargets_from_current_value() {
+        let mut transitions = Transitions::new();
+        let mut tree = faded(1.0);
+        let patches = diff(&tree, &faded(0.0));
+        transitions.apply_patches(&mut tree, patches);
+        transitions.tick(&mut tree, 0.1);
+        assert!((opacity(&tree) - 0.5).abs() < 1e-5);
+
+        // Heading back to 1.0 starts from 0.5, not from 0.0 or 1.0
+        let patches = diff(&tree, &faded(1.0));
+        transitions.apply_patches(&mut tree, patches);
+        assert!((opacity(&tree) - 0.5).abs() < 1e-5);
+        transitions.tick(&mut tree, 0.1);
+        assert!((opacity(&tree) - 0.75).abs() < 1e-5);
+
+        // Re-rendering the same target mid-transition doesn't restart it
+        let patches = diff(&tree, &faded(1.0));
+        transitions.apply_patches(&mut tree, patches);
+        assert!((opacity(&tree) - 0.75).abs() < 1e-5);
+        assert!(!transitions.tick(&mut tree, 0.1));
+        assert_eq!(opacity(&tree), 1.0);
+    }
+
+    #[test]
+    fn test_transforms_compose_between_parts() {
+        let parts = decompose(&Transform::Rotate(90.0)).unwrap();
+        assert_eq!(compose(&parts), Transform::Rotate(90.0));
+        assert_eq!(
+            compose(&decompose(&Transform::None).unwrap()),
+            Transform::None
+        );
+        assert_eq!(
+            compose(&[10.0, 0.0, 2.0, 2.0, 0.0]),
+            Transform::Multiple(vec![
+                Transform::Translate(10.0, 0.0),
+                Transform::Scale(2.0, 2.0)
+            ])
+        );
+        assert!(decompose(&Transform::SkewX(10.0)).is_none());
+    }
+}
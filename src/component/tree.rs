@@ -6,9 +6,10 @@
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
-#[cfg(test)]
-use crate::component::LifecyclePhase;
-use crate::component::{ComponentId, ComponentInstance, Context, LifecycleManager, Node};
+use crate::component::{
+    ComponentId, ComponentInstance, Context, LifecycleManager, LifecyclePhase, MountContext, Node,
+    UnmountContext, UnmountReason,
+};
 
 /// Result type for tree operations
 pub type TreeResult<T> = Result<T, TreeError>;
@@ -146,13 +147,21 @@ impl ComponentTree {
         Ok(id)
     }
 
-    /// Remove a component from the tree
+    /// Remove a component and its descendants from the tree
+    ///
+    /// Mounted components are unmounted first, children before their parents.
     pub fn remove_component(&self, id: ComponentId) -> TreeResult<()> {
         // Check if component exists
         if !self.has_component(id) {
             return Err(TreeError::ComponentNotFound(id));
         }
 
+        self.unmount_subtree(id, UnmountReason::Removed)?;
+        self.detach_component(id)
+    }
+
+    /// Drop a component and its descendants from every map
+    fn detach_component(&self, id: ComponentId) -> TreeResult<()> {
         // Get children to remove recursively
         let children: Vec<ComponentId> = {
             let children_map = self
@@ -165,7 +174,7 @@ impl ComponentTree {
 
         // Remove all children first (recursively)
         for child_id in children {
-            self.remove_component(child_id)?;
+            self.detach_component(child_id)?;
         }
 
         // Remove from parent's children list
@@ -210,6 +219,9 @@ impl ComponentTree {
             lifecycle_managers.remove(&id);
         }
 
+        // A component removed without ever being mounted still has its state
+        self.context.drop_hooks(id);
+
         {
             let mut children = self
                 .children
@@ -386,24 +398,75 @@ impl ComponentTree {
 
     /// Mount a component
     pub fn mount_component(&self, id: ComponentId) -> TreeResult<()> {
+        let mount_context = match self.get_parent(id)? {
+            Some(parent_id) => MountContext::with_parent(id, parent_id),
+            None => MountContext::new(id),
+        };
+
         let lifecycle_manager = self.get_lifecycle_manager(id)?;
         let mut manager = lifecycle_manager
             .write()
             .map_err(|_| TreeError::LockError("Failed to lock lifecycle manager".to_string()))?;
 
-        manager.mount().map_err(TreeError::LifecycleError)?;
+        manager
+            .mount_with_context(mount_context)
+            .map_err(TreeError::LifecycleError)?;
 
         Ok(())
     }
 
     /// Unmount a component
     pub fn unmount_component(&self, id: ComponentId) -> TreeResult<()> {
+        self.unmount_component_with_reason(id, UnmountReason::Removed)
+    }
+
+    /// Unmount a component, telling its `on_unmount` hook why
+    fn unmount_component_with_reason(
+        &self,
+        id: ComponentId,
+        reason: UnmountReason,
+    ) -> TreeResult<()> {
+        let unmount_context = match self.get_parent(id)? {
+            Some(parent_id) => UnmountContext::with_parent(id, parent_id, reason),
+            None => UnmountContext::new(id, reason),
+        };
+
         let lifecycle_manager = self.get_lifecycle_manager(id)?;
         let mut manager = lifecycle_manager
             .write()
             .map_err(|_| TreeError::LockError("Failed to lock lifecycle manager".to_string()))?;
 
-        manager.unmount().map_err(TreeError::LifecycleError)?;
+        manager
+            .unmount_with_context(unmount_context)
+            .map_err(TreeError::LifecycleError)?;
+        self.context.drop_hooks(id);
+
+        Ok(())
+    }
+
+    /// Whether a component is currently mounted
+    fn is_mounted(&self, id: ComponentId) -> TreeResult<bool> {
+        let lifecycle_manager = self.get_lifecycle_manager(id)?;
+        let manager = lifecycle_manager
+            .read()
+            .map_err(|_| TreeError::LockError("Failed to read lifecycle manager".to_string()))?;
+
+        Ok(manager.current_phase() == LifecyclePhase::Mounted)
+    }
+
+    /// Unmount the mounted components under and including `id`, children
+    /// before their parents
+    ///
+    /// `id` is told `reason`; its descendants are told their parent was
+    /// unmounted.
+    fn unmount_subtree(&self, id: ComponentId, reason: UnmountReason) -> TreeResult<()> {
+        for child_id in self.get_children(id)? {
+            self.unmount_subtree(child_id, UnmountReason::ParentUnmounted)?;
+        }
+
+        if self.is_mounted(id)? {
+            self.unmount_component_with_reason(id, reason)?;
+        }
 
         Ok(())
     }
@@ -467,18 +530,14 @@ impl ComponentTree {
         Ok(())
     }
 
-    /// Recursively unmount a component and all its children
+    /// Recursively unmount a component and all its children, bottom-up
     pub fn unmount_component_tree(&self, id: ComponentId) -> TreeResult<()> {
-        // First unmount all children recursively (bottom-up approach)
-        let children = self.get_children(id)?;
-        for child_id in children {
-            self.unmount_component_tree(child_id)?;
+        if !self.is_mounted(id)? {
+            // Report the invalid transition for the component itself
+            return self.unmount_component(id);
         }
 
-        // Then unmount this component
-        self.unmount_component(id)?;
-
-        Ok(())
+        self.unmount_subtree(id, UnmountReason::Removed)
     }
 
     /// Update a component with new props
@@ -604,6 +663,7 @@ impl std::fmt::Debug for ComponentTree {
 mod tests {
     use super::*;
     use crate::component::{Component, ComponentError, Context, Node};
+    use std::sync::Mutex;
 
     // Simple test component
     struct TestComponent {
@@ -664,6 +724,71 @@ mod tests {
         ComponentInstance::new(component, props)
     }
 
+    // Component recording its lifecycle hooks into a log shared by the tree
+    struct LoggingComponent {
+        id: ComponentId,
+        props: LoggingProps,
+    }
+
+    #[derive(Clone)]
+    struct LoggingProps {
+        name: String,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl LoggingComponent {
+        fn record(&self, event: String) {
+            self.props.log.lock().unwrap().push(event);
+        }
+    }
+
+    impl Component for LoggingComponent {
+        type Props = LoggingProps;
+
+        fn component_id(&self) -> ComponentId {
+            self.id
+        }
+
+        fn create(props: Self::Props, _context: Context) -> Self {
+            Self {
+                id: ComponentId::new(),
+                props,
+            }
+        }
+
+        fn on_mount(&mut self, _context: &MountContext) -> Result<(), ComponentError> {
+            self.record(format!("mount {}", self.props.name));
+            Ok(())
+        }
+
+        fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+            self.props = props;
+            Ok(())
+        }
+
+        fn on_props_update(&mut self, prev_props: &Self::Props) -> Result<(), ComponentError> {
+            self.record(format!("update {} -> {}", prev_props.name, self.props.name));
+            Ok(())
+        }
+
+        fn on_unmount(&mut self, context: &UnmountContext) -> Result<(), ComponentError> {
+            self.record(format!("unmount {} {:?}", self.props.name, context.reason));
+            Ok(())
+        }
+
+        fn render(&self) -> Result<Vec<Node>, ComponentError> {
+            Ok(vec![])
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
     #[test]
     fn test_component_tree_basic() {
         // Create tree
@@ -825,4 +950,58 @@ mod tests {
         // Should have updated 2 components
         assert_eq!(updated, 2);
     }
+
+    #[test]
+    fn test_lifecycle_hook_order_for_nested_components() {
+        let context = Context::new();
+        let tree = ComponentTree::new(context.clone());
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let add = |name: &str| {
+            let props = LoggingProps {
+                name: name.to_string(),
+                log: log.clone(),
+            };
+            let component = LoggingComponent::create(props.clone(), context.clone());
+            tree.add_component(ComponentInstance::new(component, props))
+                .unwrap()
+        };
+
+        // root -> (a -> a1), b
+        let root_id = add("root");
+        let a_id = add("a");
+        let a1_id = add("a1");
+        let b_id = add("b");
+        tree.set_root(root_id).unwrap();
+        tree.add_child(root_id, a_id).unwrap();
+        tree.add_child(a_id, a1_id).unwrap();
+        tree.add_child(root_id, b_id).unwrap();
+
+        tree.mount_component_tree(root_id).unwrap();
+        tree.update_component(
+            b_id,
+            LoggingProps {
+                name: "b2".to_string(),
+                log: log.clone(),
+            },
+        )
+        .unwrap();
+        tree.remove_component(root_id).unwrap();
+
+        // Parents mount before their children and unmount after them
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "mount root",
+                "mount a",
+                "mount a1",
+                "mount b",
+                "update b -> b2",
+                "unmount a1 ParentUnmounted",
+                "unmount a ParentUnmounted",
+                "unmount b2 ParentUnmounted",
+                "unmount root Removed",
+            ]
+        );
+        assert!(!tree.has_component(a1_id));
+    }
 }
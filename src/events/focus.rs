@@ -0,0 +1,345 @@
+//! Keyboard focus tracking and tab order
+//!
+//! Nodes opt into focus through their attributes: a `tabindex`, or a role
+//! that takes focus by default such as `button` or `textbox`. Controls
+//! marked `aria-disabled="true"` never take focus.
+
+use crate::component::Node;
+use crate::style::Outline;
+
+/// Roles that take keyboard focus without an explicit `tabindex`
+const FOCUSABLE_ROLES: &[&str] = &[
+    "button", "checkbox", "radio", "slider", "combobox", "textbox", "switch", "tab",
+];
+
+/// The tab index a node declares, or `None` if it can't be focused at all
+///
+/// Roles in [`FOCUSABLE_ROLES`] default to 0. A negative index can be
+/// focused with [`FocusManager::set_focus`] but is skipped by Tab.
+fn tab_index(node: &Node) -> Option<i32> {
+    let attributes = node.attributes();
+    match attributes.get("tabindex") {
+        Some(index) => index.parse().ok(),
+        None => attributes
+            .get("role")
+            .filter(|role| FOCUSABLE_ROLES.contains(&role.as_str()))
+            .map(|_| 0),
+    }
+}
+
+fn is_disabled(node: &Node) -> bool {
+    node.attributes().get("aria-disabled").map(String::as_str) == Some("true")
+}
+
+/// Whether Tab can land on `node`
+pub fn is_focusable(node: &Node) -> bool {
+    !is_disabled(node) && tab_index(node).is_some_and(|index| index >= 0)
+}
+
+/// Declare `node` a tab stop, so [`FocusManager::sync`] registers it
+///
+/// Disabled controls stay registered, out of the tab order, so they can
+/// rejoin it in place once re-enabled.
+pub fn register_focusable(node: &mut Node, disabled: bool) {
+    let index = if disabled { "-1" } else { "0" };
+    node.add_attribute("tabindex".to_string(), index.to_string());
+}
+
+/// A node registered with the focus manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FocusEntry {
+    id: usize,
+    tab_index: i32,
+    disabled: bool,
+}
+
+impl FocusEntry {
+    fn tabbable(&self) -> bool {
+        !self.disabled && self.tab_index >= 0
+    }
+}
+
+/// Tracks which node has keyboard focus and the order Tab visits nodes in
+///
+/// Tab order follows the browser's: nodes with a positive tab index come
+/// first, lowest index first, then index-0 nodes in registration order.
+/// Registering a whole tree with [`FocusManager::sync`] registers it in
+/// tree order.
+#[derive(Debug, Default)]
+pub struct FocusManager {
+    /// Registered nodes, in registration order
+    entries: Vec<FocusEntry>,
+    /// Node holding focus
+    focused: Option<usize>,
+    /// Called with a node's ID when it gains focus
+    pub on_focus: Option<fn(usize)>,
+    /// Called with a node's ID when it loses focus
+    pub on_blur: Option<fn(usize)>,
+}
+
+impl FocusManager {
+    /// Create a focus manager with nothing registered
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register node `id` with `tab_index`, or update its tab index if it
+    /// is already registered
+    pub fn register(&mut self, id: usize, tab_index: i32) {
+        match self.entries.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => entry.tab_index = tab_index,
+            None => self.entries.push(FocusEntry {
+                id,
+                tab_index,
+                disabled: false,
+            }),
+        }
+    }
+
+    /// Stop tracking node `id`, blurring it if it had focus
+    pub fn unregister(&mut self, id: usize) {
+        if self.focused == Some(id) {
+            self.blur();
+        }
+        self.entries.retain(|entry| entry.id != id);
+    }
+
+    /// Enable or disable node `id`; disabling the focused node blurs it
+    pub fn set_disabled(&mut self, id: usize, disabled: bool) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.disabled = disabled;
+        }
+        if disabled && self.focused == Some(id) {
+            self.blur();
+        }
+    }
+
+    /// Re-register every focusable node in the tree under `root`, in tree
+    /// order, keeping focus where it was if that node is still there and
+    /// can still take focus
+    pub fn sync(&mut self, root: &Node) {
+        fn collect(node: &Node, entries: &mut Vec<FocusEntry>) {
+            if let Some(tab_index) = tab_index(node) {
+                entries.push(FocusEntry {
+                    id: node.id_value(),
+                    tab_index,
+                    disabled: is_disabled(node),
+                });
+            }
+            for child in node.children() {
+                collect(child, entries);
+            }
+        }
+
+        self.entries.clear();
+        collect(root, &mut self.entries);
+        if let Some(focused) = self.focused {
+            if !self.can_focus(focused) {
+                self.blur();
+            }
+        }
+    }
+
+    /// IDs of the nodes Tab visits, in order
+    pub fn tab_order(&self) -> Vec<usize> {
+        let mut order: Vec<&FocusEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.tabbable())
+            .collect();
+        // Stable, so equal indices keep registration order
+        order.sort_by_key(|entry| (entry.tab_index == 0, entry.tab_index));
+        order.into_iter().map(|entry| entry.id).collect()
+    }
+
+    /// The node holding focus
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Whether node `id` is registered and enabled
+    fn can_focus(&self, id: usize) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.id == id && !entry.disabled)
+    }
+
+    /// Move focus to node `id`, returning whether it took focus
+    ///
+    /// Unregistered and disabled nodes are refused, leaving focus alone.
+    /// Nodes with a negative tab index can be focused this way even though
+    /// Tab skips them.
+    pub fn set_focus(&mut self, id: usize) -> bool {
+        if !self.can_focus(id) {
+            return false;
+        }
+        if self.focused != Some(id) {
+            self.blur();
+            self.focused = Some(id);
+            if let Some(on_focus) = self.on_focus {
+                on_focus(id);
+            }
+        }
+        true
+    }
+
+    /// Clear focus, notifying `on_blur`
+    pub fn blur(&mut self) {
+        if let Some(id) = self.focused.take() {
+            if let Some(on_blur) = self.on_blur {
+                on_blur(id);
+            }
+        }
+    }
+
+    /// Move focus one place along the tab order, wrapping at the ends
+    fn cycle(&mut self, forward: bool) -> Option<usize> {
+        let order = self.tab_order();
+        if order.is_empty() {
+            return None;
+        }
+        let position = self
+            .focused
+            .and_then(|id| order.iter().position(|&entry| entry == id));
+        let next = match (position, forward) {
+            (Some(position), true) => (position + 1) % order.len(),
+            (Some(position), false) => (position + order.len() - 1) % order.len(),
+            (None, true) => 0,
+            (None, false) => order.len() - 1,
+        };
+        self.set_focus(order[next]);
+        self.focused
+    }
+
+    /// Focus the next node in tab order, wrapping to the first, and return
+    /// its ID
+    pub fn focus_next(&mut self) -> Option<usize> {
+        self.cycle(true)
+    }
+
+    /// Focus the previous node in tab order, wrapping to the last, and
+    /// return its ID
+    pub fn focus_prev(&mut self) -> Option<usize> {
+        self.cycle(false)
+    }
+
+    /// Handle a key press; returns whether the key moved focus
+    ///
+    /// Tab moves forwards and `Shift+Tab` backwards.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        match key {
+            "Tab" => self.focus_next().is_some(),
+            "Shift+Tab" => self.focus_prev().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Mark the focused node under `root` with `data-focused` and outline
+    /// it with `ring`, for the renderer to draw around it
+    pub fn apply_focus_ring(&self, root: &mut Node, ring: &Outline) {
+        fn mark(node: &mut Node, id: usize, ring: &Outline) -> bool {
+            if node.id_value() == id {
+                node.add_attribute("data-focused".to_string(), "true".to_string());
+                node.style_mut().outline = Some(ring.clone());
+                return true;
+            }
+            node.children_mut()
+                .iter_mut()
+                .any(|child| mark(child, id, ring))
+        }
+
+        if let Some(id) = self.focused {
+            mark(root, id, ring);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kit::theme::Theme;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn control(role: &str) -> Node {
+        let mut node = Node::default();
+        node.add_attribute("role".to_string(), role.to_string());
+        node
+    }
+
+    #[test]
+    fn test_tab_order_across_three_nodes() {
+        let mut root = Node::default();
+        let mut disabled = control("button");
+        disabled.add_attribute("aria-disabled".to_string(), "true".to_string());
+        let mut first = control("textbox");
+        first.add_attribute("tabindex".to_string(), "1".to_string());
+        for child in [
+            control("button"),
+            disabled,
+            Node::default(),
+            control("checkbox"),
+            first,
+        ] {
+            root.add_child(child);
+        }
+        let ids: Vec<usize> = root.children().iter().map(Node::id_value).collect();
+
+        let mut focus = FocusManager::new();
+        focus.sync(&root);
+        assert_eq!(focus.tab_order(), vec![ids[4], ids[0], ids[3]]);
+
+        assert_eq!(focus.focus_next(), Some(ids[4]));
+        assert_eq!(focus.focus_next(), Some(ids[0]));
+        assert_eq!(focus.focus_next(), Some(ids[3]));
+        assert!(focus.handle_key("Tab"));
+        assert_eq!(focus.focused(), Some(ids[4]));
+        assert!(focus.handle_key("Shift+Tab"));
+        assert_eq!(focus.focused(), Some(ids[3]));
+
+        assert!(!focus.set_focus(ids[1]));
+        assert!(!focus.set_focus(ids[2]));
+        assert_eq!(focus.focused(), Some(ids[3]));
+    }
+
+    #[test]
+    fn test_focus_and_blur_callbacks() {
+        static FOCUSED: AtomicUsize = AtomicUsize::new(0);
+        static BLURRED: AtomicUsize = AtomicUsize::new(0);
+
+        let mut focus = FocusManager {
+            on_focus: Some(|id| FOCUSED.store(id, Ordering::SeqCst)),
+            on_blur: Some(|id| BLURRED.store(id, Ordering::SeqCst)),
+            ..Default::default()
+        };
+        focus.register(7, 0);
+        focus.register(9, -1);
+        assert!(focus.set_focus(7));
+        assert_eq!(FOCUSED.load(Ordering::SeqCst), 7);
+
+        // Skipped by Tab, but focusable directly
+        assert_eq!(focus.tab_order(), vec![7]);
+        assert!(focus.set_focus(9));
+        assert_eq!(BLURRED.load(Ordering::SeqCst), 7);
+        assert_eq!(FOCUSED.load(Ordering::SeqCst), 9);
+
+        focus.set_disabled(9, true);
+        assert_eq!(focus.focused(), None);
+        assert_eq!(BLURRED.load(Ordering::SeqCst), 9);
+    }
+
+    #[test]
+    fn test_focused_node_gets_ring() {
+        let mut root = Node::default();
+        root.add_child(control("button"));
+        let mut focus = FocusManager::new();
+        focus.sync(&root);
+        focus.focus_next();
+
+        let theme = Theme::default();
+        focus.apply_focus_ring(&mut root, &theme.focus_ring());
+        let button = &root.children()[0];
+        assert!(button.attributes().contains_key("data-focused"));
+        let ring = button.style().outline.as_ref().unwrap();
+        assert_eq!(ring.width, theme.focus_ring_width);
+    }
+}
@@ -7,8 +7,9 @@
 use std::collections::VecDeque;
 
 use crate::{
-    component::ComponentId,
+    component::{ComponentId, Node},
     layout::{LayoutNode, Point, Rect},
+    style::{Overflow, PointerEvents, Visibility},
 };
 
 use super::EventError;
@@ -218,6 +219,47 @@ impl std::fmt::Display for HitTestStats {
     }
 }
 
+/// Find the topmost node of a rendered tree under `point`
+///
+/// `point` is in the root's coordinate space, and each node's layout rect is
+/// relative to its parent, as when painting. Children are tested in reverse
+/// paint order, so later siblings win. Nodes with any overflow but
+/// `visible` clip their descendants, and nodes whose effective
+/// `pointer-events` is `none` are never returned, though their descendants
+/// may opt back in. Hidden and undisplayed nodes, like the renderer, skip
+/// their whole subtree. Returns `None` when the point lies outside the root.
+pub fn hit_test(root: &Node, point: (f32, f32)) -> Option<&Node> {
+    let point = Point::new(point.0, point.1);
+    if !root.layout_rect().contains_point(point) {
+        return None;
+    }
+    hit_test_node(root, point, PointerEvents::Auto)
+}
+
+fn hit_test_node(node: &Node, point: Point, inherited: PointerEvents) -> Option<&Node> {
+    let rect = node.layout_rect();
+    let inside = rect.contains_point(point);
+    let hidden = matches!(
+        node.style().visibility,
+        Some(Visibility::Hidden | Visibility::Collapse)
+    );
+    if hidden
+        || !node.is_displayed()
+        || (!inside && node.style().overflow.is_some_and(Overflow::clips))
+    {
+        return None;
+    }
+
+    let pointer_events = node.style().pointer_events.unwrap_or(inherited);
+    let local = Point::new(point.x - rect.x(), point.y - rect.y());
+    // Topmost children are tested first
+    node.children_in_paint_order()
+        .into_iter()
+        .rev()
+        .find_map(|child| hit_test_node(child, local, pointer_events))
+        .or_else(|| (inside && pointer_events == PointerEvents::Auto).then_some(node))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -383,4 +425,91 @@ mod tests {
         assert!(!hit_tester.rect_intersects(rect1, rect3));
         assert!(!hit_tester.rect_intersects(rect3, rect1));
     }
+
+    fn node_at(x: f32, y: f32, width: f32, height: f32) -> Node {
+        let mut node = Node::default();
+        node.set_layout_rect(Rect::new(x, y, width, height));
+        node
+    }
+
+    #[test]
+    fn test_node_hit_test() {
+        let mut root = node_at(0.0, 0.0, 200.0, 200.0);
+        let mut panel = node_at(50.0, 50.0, 100.0, 100.0);
+        panel.add_child(node_at(10.0, 10.0, 20.0, 20.0));
+        root.add_child(panel);
+        // Overlaps the panel and is painted after it
+        root.add_child(node_at(100.0, 100.0, 50.0, 50.0));
+
+        let root_id = root.id_value();
+        let panel_id = root.children()[0].id_value();
+        let button_id = root.children()[0].children()[0].id_value();
+        let overlay_id = root.children()[1].id_value();
+
+        let hit = |point| hit_test(&root, point).map(Node::id_value);
+        assert_eq!(hit((5.0, 5.0)), Some(root_id));
+        assert_eq!(hit((70.0, 70.0)), Some(button_id));
+        assert_eq!(hit((55.0, 120.0)), Some(panel_id));
+        assert_eq!(hit((120.0, 120.0)), Some(overlay_id));
+        assert_eq!(hit((250.0, 5.0)), None);
+    }
+
+    #[test]
+    fn test_node_hit_test_clip_and_pointer_events() {
+        let mut root = node_at(0.0, 0.0, 200.0, 200.0);
+
+        let mut clipped = node_at(0.0, 0.0, 50.0, 50.0);
+        clipped.style_mut().overflow = Some(Overflow::Hidden);
+        clipped.add_child(node_at(40.0, 40.0, 100.0, 100.0));
+        root.add_child(clipped);
+
+        let mut passthrough = node_at(100.0, 0.0, 100.0, 100.0);
+        passthrough.style_mut().pointer_events = Some(PointerEvents::None);
+        passthrough.add_child(node_at(0.0, 0.0, 10.0, 10.0));
+        let mut opted_in = node_at(50.0, 50.0, 10.0, 10.0);
+        opted_in.style_mut().pointer_events = Some(PointerEvents::Auto);
+        passthrough.add_child(opted_in);
+        root.add_child(passthrough);
+
+        let root_id = root.id_value();
+        let overflowing_id = root.children()[0].children()[0].id_value();
+        let opted_in_id = root.children()[1].children()[1].id_value();
+
+        let hit = |point| hit_test(&root, point).map(Node::id_value);
+        assert_eq!(hit((45.0, 45.0)), Some(overflowing_id));
+        // Outside the clip the overflowing child can't be hit
+        assert_eq!(hit((80.0, 80.0)), Some(root_id));
+        // The pass-through panel and its inheriting child fall through
+        assert_eq!(hit((150.0, 20.0)), Some(root_id));
+        assert_eq!(hit((105.0, 5.0)), Some(root_id));
+        assert_eq!(hit((155.0, 55.0)), Some(opted_in_id));
+    }
+
+    #[test]
+    fn test_node_hit_test_respects_z_index() {
+        let mut root = node_at(0.0, 0.0, 200.0, 200.0);
+        let mut raised = node_at(0.0, 0.0, 100.0, 100.0);
+        raised.style_mut().z_index = Some(1);
+        root.add_child(raised);
+        root.add_child(node_at(50.0, 50.0, 100.0, 100.0));
+
+        let raised_id = root.children()[0].id_value();
+        let sibling_id = root.children()[1].id_value();
+        assert_eq!(
+            hit_test(&root, (75.0, 75.0)).map(Node::id_value),
+            Some(raised_id)
+        );
+        assert_eq!(
+            hit_test(&root, (125.0, 125.0)).map(Node::id_value),
+            Some(sibling_id)
+        );
+
+        // A hidden node, however high, lets the point through to what's
+        // painted beneath it
+        root.children_mut()[0].style_mut().visibility = Some(Visibility::Hidden);
+        assert_eq!(
+            hit_test(&root, (75.0, 75.0)).map(Node::id_value),
+            Some(sibling_id)
+        );
+    }
 }
@@ -0,0 +1,288 @@
+//! Keyboard events and their dispatch to the focused node
+//!
+//! A key event goes first to the node with focus, then bubbles up through
+//! its ancestors. Each handler returns whether it consumed the event; the
+//! first to return `true` stops it going any further, the way
+//! `preventDefault` plus `stopPropagation` would in the DOM.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::component::Node;
+use crate::events::focus::FocusManager;
+
+/// Modifier keys held during a key event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    /// No modifiers held
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Only Shift held
+    pub fn shift() -> Self {
+        Self {
+            shift: true,
+            ..Self::default()
+        }
+    }
+
+    /// Whether Ctrl, Alt, or Meta is held, turning a key into a shortcut
+    /// rather than text
+    pub fn is_command(&self) -> bool {
+        self.ctrl || self.alt || self.meta
+    }
+}
+
+/// Whether a key went down or came up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KeyEventKind {
+    /// The key was pressed, or repeated while held
+    #[default]
+    Down,
+    /// The key was released
+    Up,
+}
+
+/// A key press or release
+///
+/// `key` follows the DOM `KeyboardEvent.key` names: `"Escape"`, `"Enter"`,
+/// `"ArrowDown"`, `"Tab"`, or the character typed, such as `"a"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub key: String,
+    pub modifiers: Modifiers,
+    pub kind: KeyEventKind,
+}
+
+impl KeyEvent {
+    /// A key press with no modifiers
+    pub fn down(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            modifiers: Modifiers::none(),
+            kind: KeyEventKind::Down,
+        }
+    }
+
+    /// A key release with no modifiers
+    pub fn up(key: &str) -> Self {
+        Self {
+            kind: KeyEventKind::Up,
+            ..Self::down(key)
+        }
+    }
+
+    /// The same event with `modifiers` held
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Whether this is a key press rather than a release
+    pub fn is_down(&self) -> bool {
+        self.kind == KeyEventKind::Down
+    }
+
+    /// The character this press types, if it types one
+    pub fn text(&self) -> Option<char> {
+        let mut chars = self.key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if self.is_down() && !self.modifiers.is_command() => Some(c),
+            _ => None,
+        }
+    }
+}
+
+/// Something that reacts to key events bubbling through its node
+pub trait KeyHandler {
+    /// Handle `event`; returns whether it was consumed, which stops it
+    /// bubbling further
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool;
+}
+
+impl KeyHandler for FocusManager {
+    /// Tab and Shift-Tab move focus through the tab order
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        if !event.is_down() || event.key != "Tab" || event.modifiers.is_command() {
+            return false;
+        }
+        let moved = if event.modifiers.shift {
+            self.focus_prev()
+        } else {
+            self.focus_next()
+        };
+        moved.is_some()
+    }
+}
+
+type KeyCallback = Box<dyn FnMut(&KeyEvent) -> bool>;
+
+/// Routes key events to handlers registered against node IDs
+#[derive(Default)]
+pub struct KeyDispatcher {
+    handlers: HashMap<usize, Vec<KeyCallback>>,
+}
+
+impl fmt::Debug for KeyDispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyDispatcher")
+            .field("nodes", &self.handlers.len())
+            .finish()
+    }
+}
+
+impl KeyDispatcher {
+    /// Create a dispatcher with no handlers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `handler` for key events reaching node `node_id`
+    ///
+    /// A node's handlers run in the order they were added.
+    pub fn on_key(&mut self, node_id: usize, handler: impl FnMut(&KeyEvent) -> bool + 'static) {
+        self.handlers
+            .entry(node_id)
+            .or_default()
+            .push(Box::new(handler));
+    }
+
+    /// Route key events reaching node `node_id` to a shared component
+    pub fn attach<H: KeyHandler + 'static>(&mut self, node_id: usize, handler: Rc<RefCell<H>>) {
+        self.on_key(node_id, move |event| {
+            handler.borrow_mut().handle_key_event(event)
+        });
+    }
+
+    /// Drop every handler on node `node_id`
+    pub fn remove(&mut self, node_id: usize) {
+        self.handlers.remove(&node_id);
+    }
+
+    /// Deliver `event` to node `target` under `root` and then each of its
+    /// ancestors in turn, returning the node whose handler consumed it
+    ///
+    /// Returns `None` if nothing consumed the event or `target` isn't in
+    /// the tree.
+    pub fn dispatch(&mut self, root: &Node, target: usize, event: &KeyEvent) -> Option<usize> {
+        let mut path = Vec::new();
+        if !path_to(root, target, &mut path) {
+            return None;
+        }
+        for id in path.into_iter().rev() {
+            if let Some(handlers) = self.handlers.get_mut(&id) {
+                if handlers.iter_mut().any(|handler| handler(event)) {
+                    return Some(id);
+                }
+            }
+        }
+        None
+    }
+
+    /// Deliver `event` to the node `focus` has focused, or to `root` if
+    /// nothing has focus
+    pub fn dispatch_focused(
+        &mut self,
+        root: &Node,
+        focus: &FocusManager,
+        event: &KeyEvent,
+    ) -> Option<usize> {
+        let target = focus.focused().unwrap_or_else(|| root.id_value());
+        self.dispatch(root, target, event)
+    }
+}
+
+/// Collect the IDs from `node` down to `target` into `path`, returning
+/// whether `target` was found
+fn path_to(node: &Node, target: usize, path: &mut Vec<usize>) -> bool {
+    path.push(node.id_value());
+    if node.id_value() == target
+        || node
+            .children()
+            .iter()
+            .any(|child| path_to(child, target, path))
+    {
+        return true;
+    }
+    path.pop();
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::Component;
+    use crate::kit::components::modal::Modal;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_escape_bubbles_to_modal() {
+        static CLOSED: AtomicBool = AtomicBool::new(false);
+
+        let mut button = Node::default();
+        button.add_attribute("role".to_string(), "button".to_string());
+        let mut modal = Modal::default();
+        modal.open = true;
+        modal.content = vec![button];
+        modal.on_close = Some(|| CLOSED.store(true, Ordering::SeqCst));
+        let modal = Rc::new(RefCell::new(modal));
+        let root = modal.borrow().render().unwrap().remove(0);
+
+        let mut focus = FocusManager::new();
+        focus.sync(&root);
+        let button = focus.focus_next().unwrap();
+        assert_ne!(button, root.id_value());
+
+        let mut keys = KeyDispatcher::new();
+        keys.attach(root.id_value(), modal.clone());
+        let handled = keys.dispatch_focused(&root, &focus, &KeyEvent::down("Escape"));
+
+        assert_eq!(handled, Some(root.id_value()));
+        assert!(CLOSED.load(Ordering::SeqCst));
+        assert!(!modal.borrow().open);
+    }
+
+    #[test]
+    fn test_consumed_events_stop_bubbling() {
+        let mut parent = Node::default();
+        parent.add_child(Node::default());
+        let child = parent.children()[0].id_value();
+
+        let parent_calls = Rc::new(RefCell::new(Vec::new()));
+        let mut keys = KeyDispatcher::new();
+        let calls = parent_calls.clone();
+        keys.on_key(parent.id_value(), move |event| {
+            calls.borrow_mut().push(event.key.clone());
+            true
+        });
+        keys.on_key(child, |event| event.key == "Enter");
+
+        assert_eq!(
+            keys.dispatch(&parent, child, &KeyEvent::down("Enter")),
+            Some(child)
+        );
+        assert_eq!(
+            keys.dispatch(&parent, child, &KeyEvent::down("a")),
+            Some(parent.id_value())
+        );
+        assert_eq!(*parent_calls.borrow(), vec!["a".to_string()]);
+        assert_eq!(keys.dispatch(&parent, 0, &KeyEvent::down("a")), None);
+
+        let shortcut = KeyEvent::down("a").with_modifiers(Modifiers {
+            ctrl: true,
+            ..Modifiers::none()
+        });
+        assert_eq!(shortcut.text(), None);
+        assert_eq!(KeyEvent::down("a").text(), Some('a'));
+    }
+}
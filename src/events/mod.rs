@@ -6,19 +6,28 @@
 //! - Dispatcher for strongly-typed event handling
 //! - Event delegation for component event propagation
 //! - Layout-aware hit testing for precise event targeting
+//! - Keyboard and pointer dispatch with focus, bubbling, and capture
 //! - Component ID integration for efficient event routing
 
 pub mod delegation;
 pub mod dispatcher;
 pub mod emitter;
 pub mod event;
+pub mod focus;
 pub mod hit_testing;
+pub mod keyboard;
+pub mod pointer;
 
 pub use delegation::*;
 pub use dispatcher::Dispatcher;
 pub use emitter::EventEmitter;
 pub use event::Event;
+pub use focus::FocusManager;
 pub use hit_testing::*;
+pub use keyboard::{KeyDispatcher, KeyEvent, KeyEventKind, KeyHandler, Modifiers};
+pub use pointer::{
+    PointerButton, PointerDispatcher, PointerEvent, PointerEventKind, PointerHandler,
+};
 
 use crate::{
     component::ComponentId,
@@ -0,0 +1,379 @@
+//! Pointer events and their dispatch to the node under the pointer
+//!
+//! The target of a pointer event is whatever [`hit_test`] finds at its
+//! position, and the event bubbles up from there like a key event does.
+//! While a button is held the node it went down on captures the pointer,
+//! so a drag that wanders off a slider keeps moving the slider. Moving the
+//! pointer also synthesizes `Enter` and `Leave` events as it crosses node
+//! boundaries; those go to each node entered or left, without bubbling.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::component::Node;
+use crate::events::hit_testing::hit_test;
+
+/// Which pointer button an event concerns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PointerButton {
+    /// The main button, usually the left mouse button or a touch
+    #[default]
+    Primary,
+    /// Usually the right mouse button
+    Secondary,
+    /// Usually the wheel button
+    Middle,
+}
+
+/// What the pointer did
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PointerEventKind {
+    /// A button was pressed
+    Down,
+    /// A button was released
+    Up,
+    /// The pointer moved
+    #[default]
+    Move,
+    /// The pointer moved onto a node; synthesized during dispatch
+    Enter,
+    /// The pointer moved off a node; synthesized during dispatch
+    Leave,
+}
+
+/// A pointer press, release, or movement
+///
+/// `position` is in the root node's coordinate space, the space
+/// [`hit_test`] works in. Handlers also get `local`, the same point
+/// relative to the node handling the event, and `within`, whether the
+/// pointer is actually over that node; it may not be while the node holds
+/// capture.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PointerEvent {
+    pub position: (f32, f32),
+    /// The button pressed or released; `None` for movement
+    pub button: Option<PointerButton>,
+    pub kind: PointerEventKind,
+    /// `position` relative to the node handling the event
+    pub local: (f32, f32),
+    /// Whether the pointer is over the node handling the event
+    pub within: bool,
+}
+
+impl PointerEvent {
+    fn new(position: (f32, f32), button: Option<PointerButton>, kind: PointerEventKind) -> Self {
+        Self {
+            position,
+            button,
+            kind,
+            local: position,
+            within: true,
+        }
+    }
+
+    /// `button` pressed at `position`
+    pub fn down(position: (f32, f32), button: PointerButton) -> Self {
+        Self::new(position, Some(button), PointerEventKind::Down)
+    }
+
+    /// `button` released at `position`
+    pub fn up(position: (f32, f32), button: PointerButton) -> Self {
+        Self::new(position, Some(button), PointerEventKind::Up)
+    }
+
+    /// The pointer moved to `position`
+    pub fn moved(position: (f32, f32)) -> Self {
+        Self::new(position, None, PointerEventKind::Move)
+    }
+
+    /// Whether this is a press or release of the primary button
+    pub fn is_primary(&self) -> bool {
+        self.button == Some(PointerButton::Primary)
+    }
+
+    /// The same position as a synthesized event of `kind`
+    fn with_kind(self, kind: PointerEventKind) -> Self {
+        Self {
+            kind,
+            button: None,
+            ..self
+        }
+    }
+}
+
+/// Something that reacts to pointer events reaching its node
+pub trait PointerHandler {
+    /// Handle `event`; returns whether it was consumed, which stops it
+    /// bubbling further
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool;
+}
+
+type PointerCallback = Box<dyn FnMut(&PointerEvent) -> bool>;
+
+/// Routes pointer events to handlers registered against node IDs, tracking
+/// which nodes the pointer is over and which holds capture
+#[derive(Default)]
+pub struct PointerDispatcher {
+    handlers: HashMap<usize, Vec<PointerCallback>>,
+    /// Nodes under the pointer, from the root down to the hit node
+    hovered: Vec<usize>,
+    /// Node that received the last `Down`, until the matching `Up`
+    capture: Option<usize>,
+}
+
+impl fmt::Debug for PointerDispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PointerDispatcher")
+            .field("nodes", &self.handlers.len())
+            .field("hovered", &self.hovered)
+            .field("capture", &self.capture)
+            .finish()
+    }
+}
+
+impl PointerDispatcher {
+    /// Create a dispatcher with no handlers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `handler` for pointer events reaching node `node_id`
+    ///
+    /// A node's handlers run in the order they were added.
+    pub fn on_pointer(
+        &mut self,
+        node_id: usize,
+        handler: impl FnMut(&PointerEvent) -> bool + 'static,
+    ) {
+        self.handlers
+            .entry(node_id)
+            .or_default()
+            .push(Box::new(handler));
+    }
+
+    /// Route pointer events reaching node `node_id` to a shared component
+    pub fn attach<H: PointerHandler + 'static>(&mut self, node_id: usize, handler: Rc<RefCell<H>>) {
+        self.on_pointer(node_id, move |event| {
+            handler.borrow_mut().handle_pointer_event(event)
+        });
+    }
+
+    /// Drop every handler on node `node_id`
+    pub fn remove(&mut self, node_id: usize) {
+        self.handlers.remove(&node_id);
+    }
+
+    /// The deepest node under the pointer as of the last event
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered.last().copied()
+    }
+
+    /// The node holding pointer capture, if a button is down
+    pub fn capture(&self) -> Option<usize> {
+        self.capture
+    }
+
+    /// Deliver `event` under `root`, returning the node whose handler
+    /// consumed it
+    ///
+    /// `Enter` and `Leave` are synthesized first for any change in the
+    /// nodes under the pointer. The event then goes to the node holding
+    /// capture, or else the node under the pointer, and bubbles up through
+    /// its ancestors. A `Down` gives its target capture; an `Up` releases
+    /// it.
+    pub fn dispatch(&mut self, root: &Node, event: PointerEvent) -> Option<usize> {
+        let hit_path = match hit_test(root, event.position) {
+            Some(hit) => path_to(root, hit.id_value()),
+            None => Vec::new(),
+        };
+        self.update_hover(root, &hit_path, event);
+
+        let target_path = match self.capture {
+            Some(capture) => path_to(root, capture),
+            None => hit_path.clone(),
+        };
+        match event.kind {
+            PointerEventKind::Down => {
+                self.capture = target_path.last().map(|&(id, _)| id);
+            }
+            PointerEventKind::Up => self.capture = None,
+            _ => {}
+        }
+
+        for &(id, origin) in target_path.iter().rev() {
+            let within = hit_path.iter().any(|&(hit, _)| hit == id);
+            if self.deliver(id, origin, within, event) {
+                return Some(id);
+            }
+        }
+        None
+    }
+
+    /// Send `Leave` to nodes the pointer has left, deepest first, and
+    /// `Enter` to nodes it has entered, outermost first
+    fn update_hover(&mut self, root: &Node, hit_path: &[(usize, (f32, f32))], event: PointerEvent) {
+        let previous = std::mem::take(&mut self.hovered);
+        for &id in previous.iter().rev() {
+            if !hit_path.iter().any(|&(hit, _)| hit == id) {
+                // Nodes removed from the tree since get no origin to offset by
+                let origin = path_to(root, id).last().map_or((0.0, 0.0), |&(_, o)| o);
+                self.deliver(id, origin, false, event.with_kind(PointerEventKind::Leave));
+            }
+        }
+        for &(id, origin) in hit_path {
+            if !previous.contains(&id) {
+                self.deliver(id, origin, true, event.with_kind(PointerEventKind::Enter));
+            }
+        }
+        self.hovered = hit_path.iter().map(|&(id, _)| id).collect();
+    }
+
+    /// Run node `id`'s handlers on `event` translated to its `origin`,
+    /// returning whether one consumed it
+    fn deliver(
+        &mut self,
+        id: usize,
+        origin: (f32, f32),
+        within: bool,
+        event: PointerEvent,
+    ) -> bool {
+        let Some(handlers) = self.handlers.get_mut(&id) else {
+            return false;
+        };
+        let event = PointerEvent {
+            local: (event.position.0 - origin.0, event.position.1 - origin.1),
+            within,
+            ..event
+        };
+        handlers.iter_mut().any(|handler| handler(&event))
+    }
+}
+
+/// The IDs from `root` down to `target`, each with the position of its
+/// rect's origin in root coordinates; empty if `target` isn't in the tree
+fn path_to(root: &Node, target: usize) -> Vec<(usize, (f32, f32))> {
+    fn walk(
+        node: &Node,
+        parent: (f32, f32),
+        target: usize,
+        path: &mut Vec<(usize, (f32, f32))>,
+    ) -> bool {
+        let rect = node.layout_rect();
+        let origin = (parent.0 + rect.x(), parent.1 + rect.y());
+        path.push((node.id_value(), origin));
+        if node.id_value() == target
+            || node
+                .children()
+                .iter()
+                .any(|child| walk(child, origin, target, path))
+        {
+            return true;
+        }
+        path.pop();
+        false
+    }
+
+    let mut path = Vec::new();
+    walk(root, (0.0, 0.0), target, &mut path);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kit::components::slider::Slider;
+    use crate::layout::Rect;
+
+    fn row_of_two() -> (Node, usize, usize) {
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, 200.0, 100.0));
+        for x in [0.0, 100.0] {
+            let mut child = Node::default();
+            child.set_layout_rect(Rect::new(x, 0.0, 100.0, 100.0));
+            root.add_child(child);
+        }
+        let left = root.children()[0].id_value();
+        let right = root.children()[1].id_value();
+        (root, left, right)
+    }
+
+    fn record(
+        pointer: &mut PointerDispatcher,
+        id: usize,
+        name: &'static str,
+        log: &Rc<RefCell<Vec<String>>>,
+    ) {
+        let log = log.clone();
+        pointer.on_pointer(id, move |event| {
+            log.borrow_mut().push(format!("{name} {:?}", event.kind));
+            false
+        });
+    }
+
+    #[test]
+    fn test_enter_leave_across_adjacent_nodes() {
+        let (root, left, right) = row_of_two();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut pointer = PointerDispatcher::new();
+        record(&mut pointer, root.id_value(), "root", &log);
+        record(&mut pointer, left, "left", &log);
+        record(&mut pointer, right, "right", &log);
+
+        pointer.dispatch(&root, PointerEvent::moved((50.0, 50.0)));
+        assert_eq!(
+            log.take(),
+            ["root Enter", "left Enter", "left Move", "root Move"]
+        );
+
+        // Crossing into the sibling leaves one and enters the other, but
+        // the shared parent sees neither
+        pointer.dispatch(&root, PointerEvent::moved((150.0, 50.0)));
+        assert_eq!(
+            log.take(),
+            ["left Leave", "right Enter", "right Move", "root Move"]
+        );
+        assert_eq!(pointer.hovered(), Some(right));
+
+        pointer.dispatch(&root, PointerEvent::moved((250.0, 50.0)));
+        assert_eq!(log.take(), ["right Leave", "root Leave"]);
+        assert_eq!(pointer.hovered(), None);
+    }
+
+    #[test]
+    fn test_drag_keeps_capture_off_the_node() {
+        let mut slider = Slider::default();
+        slider.length = 100.0;
+        let slider = Rc::new(RefCell::new(slider));
+
+        let (root, left, right) = row_of_two();
+        let right_calls = Rc::new(RefCell::new(Vec::new()));
+        let mut pointer = PointerDispatcher::new();
+        pointer.attach(left, slider.clone());
+        record(&mut pointer, right, "right", &right_calls);
+
+        let handled = pointer.dispatch(
+            &root,
+            PointerEvent::down((25.0, 50.0), PointerButton::Primary),
+        );
+        assert_eq!(handled, Some(left));
+        assert_eq!(pointer.capture(), Some(left));
+        assert!(slider.borrow().is_dragging());
+
+        // Dragged past the slider's end onto its neighbour, which only
+        // hears about it as hover
+        assert_eq!(
+            pointer.dispatch(&root, PointerEvent::moved((150.0, 50.0))),
+            Some(left)
+        );
+        assert_eq!(slider.borrow().fraction(), 1.0);
+        pointer.dispatch(
+            &root,
+            PointerEvent::up((150.0, 50.0), PointerButton::Primary),
+        );
+        assert_eq!(right_calls.take(), ["right Enter"]);
+        assert_eq!(pointer.capture(), None);
+        assert!(!slider.borrow().is_dragging());
+    }
+}
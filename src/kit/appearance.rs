@@ -0,0 +1,207 @@
+// System appearance detection for OrbitKit
+//
+// With the `system-theme` feature, `system_color_scheme` asks the platform
+// whether the user prefers light or dark interfaces:
+//
+// - macOS: the global `AppleInterfaceStyle` default, read with
+//   `defaults read -g AppleInterfaceStyle`, which is `Dark` in dark mode and
+//   unset in light mode
+// - Windows: the `AppsUseLightTheme` value under
+//   `HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize`,
+//   read with `reg query`, which is 0 in dark mode
+// - Linux and the BSDs: the freedesktop `color-scheme` setting, read with
+//   `gsettings get org.gnome.desktop.interface color-scheme`, which is
+//   `'prefer-dark'` in dark mode
+// - The web, with the `web` feature: the `(prefers-color-scheme: dark)`
+//   media query
+//
+// Without the feature, or where the source can't be read, there's no
+// preference to report.
+
+use serde::{Deserialize, Serialize};
+
+/// Light or dark appearance, as the operating system reports the user's
+/// preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    /// Whether this is the dark scheme
+    pub fn is_dark(self) -> bool {
+        self == ColorScheme::Dark
+    }
+}
+
+#[cfg(feature = "desktop")]
+impl From<winit::window::Theme> for ColorScheme {
+    fn from(theme: winit::window::Theme) -> Self {
+        match theme {
+            winit::window::Theme::Light => ColorScheme::Light,
+            winit::window::Theme::Dark => ColorScheme::Dark,
+        }
+    }
+}
+
+/// The user's light or dark preference, if the platform reports one
+///
+/// This asks the platform afresh on every call, which on the desktop runs a
+/// short command, so call it when the preference may have changed rather
+/// than every frame.
+pub fn system_color_scheme() -> Option<ColorScheme> {
+    detect()
+}
+
+#[cfg(all(feature = "system-theme", target_os = "macos"))]
+fn detect() -> Option<ColorScheme> {
+    // The default is deleted rather than set to `Light` in light mode, so
+    // the command failing is itself an answer
+    let output = command_output("defaults", &["read", "-g", "AppleInterfaceStyle"]);
+    Some(parse_macos(output.as_deref().unwrap_or_default()))
+}
+
+#[cfg(all(feature = "system-theme", target_os = "windows"))]
+fn detect() -> Option<ColorScheme> {
+    let output = command_output(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "/v",
+            "AppsUseLightTheme",
+        ],
+    )?;
+    parse_windows(&output)
+}
+
+#[cfg(all(
+    feature = "system-theme",
+    unix,
+    not(target_os = "macos"),
+    not(target_arch = "wasm32")
+))]
+fn detect() -> Option<ColorScheme> {
+    let output = command_output(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "color-scheme"],
+    )?;
+    parse_freedesktop(&output)
+}
+
+#[cfg(all(feature = "system-theme", feature = "web", target_arch = "wasm32"))]
+fn detect() -> Option<ColorScheme> {
+    let query = web_sys::window()?
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()??;
+    Some(if query.matches() {
+        ColorScheme::Dark
+    } else {
+        ColorScheme::Light
+    })
+}
+
+#[cfg(not(all(
+    feature = "system-theme",
+    any(
+        target_os = "macos",
+        target_os = "windows",
+        all(unix, not(target_arch = "wasm32")),
+        all(feature = "web", target_arch = "wasm32")
+    )
+)))]
+fn detect() -> Option<ColorScheme> {
+    None
+}
+
+/// Standard output of `program`, if it ran and succeeded
+#[cfg(all(feature = "system-theme", not(target_arch = "wasm32")))]
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The scheme `defaults read -g AppleInterfaceStyle` printed
+#[cfg_attr(
+    not(all(feature = "system-theme", target_os = "macos")),
+    allow(dead_code)
+)]
+fn parse_macos(output: &str) -> ColorScheme {
+    if output.trim().eq_ignore_ascii_case("dark") {
+        ColorScheme::Dark
+    } else {
+        ColorScheme::Light
+    }
+}
+
+/// The scheme a `reg query` of `AppsUseLightTheme` printed, if it holds the
+/// value
+#[cfg_attr(
+    not(all(feature = "system-theme", target_os = "windows")),
+    allow(dead_code)
+)]
+fn parse_windows(output: &str) -> Option<ColorScheme> {
+    // The value's line reads `AppsUseLightTheme    REG_DWORD    0x0`
+    let line = output
+        .lines()
+        .find(|line| line.trim_start().starts_with("AppsUseLightTheme"))?;
+    let value = line.split_whitespace().last()?;
+    let light = u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()?;
+    Some(if light == 0 {
+        ColorScheme::Dark
+    } else {
+        ColorScheme::Light
+    })
+}
+
+/// The scheme `gsettings get` printed for `color-scheme`, if it states one
+#[cfg_attr(
+    not(all(
+        feature = "system-theme",
+        unix,
+        not(target_os = "macos"),
+        not(target_arch = "wasm32")
+    )),
+    allow(dead_code)
+)]
+fn parse_freedesktop(output: &str) -> Option<ColorScheme> {
+    match output.trim().trim_matches('\'') {
+        "prefer-dark" => Some(ColorScheme::Dark),
+        "prefer-light" | "default" => Some(ColorScheme::Light),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_platform_outputs() {
+        assert_eq!(parse_macos("Dark\n"), ColorScheme::Dark);
+        assert_eq!(parse_macos(""), ColorScheme::Light);
+
+        let dark = "\r\nHKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\r\n    AppsUseLightTheme    REG_DWORD    0x0\r\n";
+        assert_eq!(parse_windows(dark), Some(ColorScheme::Dark));
+        assert_eq!(
+            parse_windows(&dark.replace("0x0", "0x1")),
+            Some(ColorScheme::Light)
+        );
+        assert_eq!(parse_windows("ERROR: not found"), None);
+
+        assert_eq!(
+            parse_freedesktop("'prefer-dark'\n"),
+            Some(ColorScheme::Dark)
+        );
+        assert_eq!(parse_freedesktop("'default'\n"), Some(ColorScheme::Light));
+        assert_eq!(parse_freedesktop(""), None);
+    }
+}
@@ -0,0 +1,448 @@
+// Accordion component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::kit::utils::animation::Animation;
+use crate::layout::{EdgeValues, Rect};
+use crate::style::{Color, EdgeColors, FontWeight, Overflow, Point2D, TimingFunction, Transform};
+use std::any::Any;
+
+/// Height of each item header
+const HEADER_HEIGHT: f32 = 48.0;
+
+/// Size of the header chevron
+const CHEVRON_SIZE: f32 = 16.0;
+
+/// Chevron pointing right in a 16x16 box; rotated a quarter turn when open
+const CHEVRON: &str = "M6 4 L10 8 L6 12 L5 11 L8 8 L5 5 Z";
+
+/// How long a body takes to expand or collapse, in seconds
+const EXPAND_TRANSITION: f32 = 0.2;
+
+/// Horizontal padding inside headers and bodies
+const PADDING: f32 = 16.0;
+
+/// Height needed to show `node` and all its descendants, measured from the
+/// top of its parent
+fn extent(node: &Node) -> f32 {
+    let rect = node.layout_rect();
+    let children = node.children().iter().map(extent).fold(0.0, f32::max);
+    rect.y() + rect.height().max(children)
+}
+
+/// A collapsible section of an [`Accordion`]
+#[derive(Debug, Clone, Default)]
+pub struct AccordionItem {
+    /// Header text
+    pub header: String,
+    /// Body content; its layout rects are relative to the body
+    pub children: Vec<Node>,
+    /// Whether the body is expanded
+    pub open: bool,
+    /// Whether the header can be toggled
+    pub disabled: bool,
+}
+
+impl AccordionItem {
+    /// Create a collapsed, enabled item with no content
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Height of the body when fully expanded
+    ///
+    /// Measured from the children on every call, so content that changes
+    /// size is picked up by the next frame of the animation.
+    pub fn content_height(&self) -> f32 {
+        let content = self.children.iter().map(extent).fold(0.0, f32::max);
+        if content > 0.0 {
+            content + 2.0 * PADDING
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Accordion component
+#[derive(Debug)]
+pub struct Accordion {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// The sections, top to bottom
+    pub items: Vec<AccordionItem>,
+    /// Whether several sections can be open at once
+    pub allow_multiple: bool,
+    /// Width of the accordion
+    pub width: f32,
+    /// Toggle event handler, called with the item index and whether it is
+    /// now open
+    pub on_toggle: Option<fn(usize, bool)>,
+    /// Theme providing colors and the reduced-motion preference
+    pub theme: Theme,
+    /// How far each body is expanded, from 0.0 to 1.0, animated by `tick`
+    expansion: Vec<Animation>,
+    /// Index of the header with keyboard focus
+    focused: usize,
+}
+
+/// Accordion props
+#[derive(Debug, Clone)]
+pub struct AccordionProps {
+    /// The sections, top to bottom
+    pub items: Vec<AccordionItem>,
+    /// Whether several sections can be open at once
+    pub allow_multiple: Option<bool>,
+    /// Width of the accordion
+    pub width: Option<f32>,
+    /// Toggle event handler
+    pub on_toggle: Option<fn(usize, bool)>,
+}
+
+impl Default for Accordion {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            items: Vec::new(),
+            allow_multiple: false,
+            width: 400.0,
+            on_toggle: None,
+            theme: Theme::default(),
+            expansion: Vec::new(),
+            focused: 0,
+        }
+    }
+}
+
+impl Accordion {
+    /// Resting expansion of each item, for items that have not animated yet
+    fn sync_expansion(&mut self) {
+        let items = &self.items;
+        self.expansion.truncate(items.len());
+        for item in &items[self.expansion.len()..] {
+            let expansion = if item.open { 1.0 } else { 0.0 };
+            self.expansion
+                .push(Animation::at_rest(expansion, TimingFunction::EaseInOut));
+        }
+        if self.theme.reduced_motion {
+            self.snap();
+        }
+    }
+
+    /// Point each body's animation at its open state, covering the
+    /// remaining distance at full-transition speed
+    fn retarget(&mut self) {
+        for (expansion, item) in self.expansion.iter_mut().zip(&self.items) {
+            let target = if item.open { 1.0 } else { 0.0 };
+            let distance = (target - expansion.value()).abs();
+            expansion.retarget(target, EXPAND_TRANSITION * distance);
+        }
+    }
+
+    fn snap(&mut self) {
+        self.retarget();
+        for expansion in &mut self.expansion {
+            expansion.finish();
+        }
+    }
+
+    /// How far the body of item `index` is expanded, from 0.0 to 1.0
+    pub fn expansion(&self, index: usize) -> f32 {
+        match (self.expansion.get(index), self.items.get(index)) {
+            (Some(expansion), _) => expansion.value(),
+            (None, Some(item)) if item.open => 1.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Index of the header with keyboard focus
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    fn set_open(&mut self, index: usize, open: bool) {
+        if self.items[index].open == open {
+            return;
+        }
+        self.items[index].open = open;
+        if let Some(on_toggle) = self.on_toggle {
+            on_toggle(index, open);
+        }
+    }
+
+    /// Expand or collapse item `index`, as when its header is clicked
+    ///
+    /// Without `allow_multiple`, opening an item closes the others.
+    /// Disabled and out-of-range items are ignored.
+    pub fn toggle(&mut self, index: usize) {
+        let Some(item) = self.items.get(index) else {
+            return;
+        };
+        if item.disabled {
+            return;
+        }
+        let open = !item.open;
+        self.sync_expansion();
+        self.focused = index;
+
+        if open && !self.allow_multiple {
+            for other in 0..self.items.len() {
+                if other != index {
+                    self.set_open(other, false);
+                }
+            }
+        }
+        self.set_open(index, open);
+        if self.theme.reduced_motion {
+            self.snap();
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Up/Down move focus between headers, Home/End jump to the first/last
+    /// header, and Enter or Space toggles the focused item.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        let last = self.items.len().saturating_sub(1);
+        match key {
+            "ArrowDown" => self.focused = (self.focused + 1).min(last),
+            "ArrowUp" => self.focused = self.focused.saturating_sub(1),
+            "Home" => self.focused = 0,
+            "End" => self.focused = last,
+            "Enter" | " " => self.toggle(self.focused),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Whether any body is still expanding or collapsing
+    pub fn is_animating(&self) -> bool {
+        (0..self.items.len()).any(|index| {
+            let target = if self.items[index].open { 1.0 } else { 0.0 };
+            match self.expansion.get(index) {
+                Some(expansion) => !expansion.is_finished() || expansion.to != target,
+                None => false,
+            }
+        })
+    }
+
+    /// Advance the expand/collapse animations by `dt` seconds; returns
+    /// whether any are still running
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.sync_expansion();
+        self.retarget();
+        for expansion in &mut self.expansion {
+            expansion.sample(dt);
+        }
+        self.is_animating()
+    }
+
+    /// Total height at the current point of the animations
+    pub fn height(&self) -> f32 {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| HEADER_HEIGHT + item.content_height() * self.expansion(index))
+            .sum()
+    }
+}
+
+impl Component for Accordion {
+    type Props = AccordionProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut accordion = Self {
+            items: props.items,
+            allow_multiple: props.allow_multiple.unwrap_or(false),
+            on_toggle: props.on_toggle,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        accordion.width = props.width.unwrap_or(accordion.width);
+        // Initially open items are shown without animating into place
+        accordion.sync_expansion();
+        accordion
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.items = props.items;
+        self.allow_multiple = props.allow_multiple.unwrap_or(self.allow_multiple);
+        self.width = props.width.unwrap_or(self.width);
+        self.on_toggle = props.on_toggle;
+        self.sync_expansion();
+        self.focused = self.focused.min(self.items.len().saturating_sub(1));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let divider = Color::Hex(self.theme.border_color.clone());
+
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, self.height()));
+
+        let mut y = 0.0;
+        for (index, item) in self.items.iter().enumerate() {
+            let expansion = self.expansion(index);
+
+            let mut header = Node::default();
+            header.add_attribute("role".to_string(), "button".to_string());
+            header.add_attribute("aria-expanded".to_string(), item.open.to_string());
+            if item.disabled {
+                header.add_attribute("aria-disabled".to_string(), "true".to_string());
+            }
+            if index == self.focused {
+                header.add_attribute("data-focused".to_string(), "true".to_string());
+            }
+            header.set_layout_rect(Rect::new(0.0, y, self.width, HEADER_HEIGHT));
+            let style = header.style_mut();
+            style.border_width = Some(EdgeValues::new(0.0, 0.0, 1.0, 0.0));
+            style.border_color = Some(EdgeColors {
+                top: divider.clone(),
+                right: divider.clone(),
+                bottom: divider.clone(),
+                left: divider.clone(),
+            });
+
+            let mut label = Node::default();
+            label.set_layout_rect(Rect::new(
+                PADDING,
+                (HEADER_HEIGHT - 16.0) / 2.0,
+                self.width - 2.0 * PADDING - CHEVRON_SIZE,
+                16.0,
+            ));
+            label.set_text(item.header.clone());
+            let text_color = if item.disabled {
+                self.theme.disabled_color.clone()
+            } else {
+                self.theme.text_color.clone()
+            };
+            label.style_mut().color = Some(Color::Hex(text_color));
+            label.style_mut().font_weight = Some(FontWeight::SemiBold);
+            header.add_child(label);
+
+            let mut chevron = Node::default();
+            chevron.set_layout_rect(Rect::new(
+                self.width - PADDING - CHEVRON_SIZE,
+                (HEADER_HEIGHT - CHEVRON_SIZE) / 2.0,
+                CHEVRON_SIZE,
+                CHEVRON_SIZE,
+            ));
+            chevron.add_attribute("path".to_string(), CHEVRON.to_string());
+            let style = chevron.style_mut();
+            style.color = Some(Color::Hex(self.theme.muted_text_color.clone()));
+            style.transform = Some(Transform::Rotate(90.0 * expansion));
+            style.transform_origin = Some(Point2D {
+                x: CHEVRON_SIZE / 2.0,
+                y: CHEVRON_SIZE / 2.0,
+            });
+            header.add_child(chevron);
+            root.add_child(header);
+            y += HEADER_HEIGHT;
+
+            // Collapsed bodies are left out entirely
+            let body_height = item.content_height() * expansion;
+            if expansion > 0.0 {
+                let mut body = Node::default();
+                body.add_attribute("role".to_string(), "region".to_string());
+                body.add_attribute("aria-label".to_string(), item.header.clone());
+                body.set_layout_rect(Rect::new(0.0, y, self.width, body_height));
+                body.style_mut().overflow = Some(Overflow::Hidden);
+
+                let mut content = Node::default();
+                content.set_layout_rect(Rect::new(
+                    PADDING,
+                    PADDING,
+                    self.width - 2.0 * PADDING,
+                    (item.content_height() - 2.0 * PADDING).max(0.0),
+                ));
+                for child in &item.children {
+                    content.add_child(child.clone());
+                }
+                body.add_child(content);
+                root.add_child(body);
+            }
+            y += body_height;
+        }
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paragraph(height: f32) -> Node {
+        let mut node = Node::default();
+        node.set_layout_rect(Rect::new(0.0, 0.0, 200.0, height));
+        node
+    }
+
+    fn accordion() -> Accordion {
+        Accordion {
+            items: vec![
+                AccordionItem {
+                    children: vec![paragraph(40.0)],
+                    ..AccordionItem::new("Shipping")
+                },
+                AccordionItem {
+                    children: vec![paragraph(80.0)],
+                    ..AccordionItem::new("Returns")
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_single_open_closes_others() {
+        let mut accordion = accordion();
+        accordion.toggle(0);
+        accordion.tick(EXPAND_TRANSITION);
+        accordion.toggle(1);
+        assert!(!accordion.items[0].open);
+        assert!(accordion.items[1].open);
+
+        accordion.allow_multiple = true;
+        accordion.toggle(0);
+        assert!(accordion.items[0].open && accordion.items[1].open);
+    }
+
+    #[test]
+    fn test_body_animates_to_measured_height() {
+        let mut accordion = accordion();
+        assert!(accordion.handle_key("ArrowDown"));
+        assert!(accordion.handle_key("Enter"));
+        assert!(accordion.tick(EXPAND_TRANSITION / 2.0));
+
+        let full = 80.0 + 2.0 * PADDING;
+        let nodes = accordion.render().unwrap();
+        let body = &nodes[0].children()[2];
+        assert!((body.layout_rect().height() - full / 2.0).abs() < 1e-3);
+        let chevron = &nodes[0].children()[1].children()[1];
+        assert_eq!(chevron.style().transform, Some(Transform::Rotate(45.0)));
+
+        // Content that grows mid-animation retargets the body
+        accordion.items[1].children.push({
+            let mut more = paragraph(20.0);
+            more.set_layout_rect(Rect::new(0.0, 80.0, 200.0, 20.0));
+            more
+        });
+        assert!(!accordion.tick(EXPAND_TRANSITION));
+        assert_eq!(accordion.height(), 2.0 * HEADER_HEIGHT + full + 20.0);
+    }
+}
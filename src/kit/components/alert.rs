@@ -0,0 +1,361 @@
+// Alert component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::components::toast::Severity;
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::{EdgeValues, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, EdgeColors, FontWeight};
+use std::any::Any;
+
+/// Default alert width
+const DEFAULT_WIDTH: f32 = 400.0;
+
+/// Height of each line of text, and of the icon and close button
+const LINE_HEIGHT: f32 = 20.0;
+
+/// Space between the title and message lines
+const LINE_GAP: f32 = 4.0;
+
+/// Space between the icon, text, and close button
+const ICON_GAP: f32 = 8.0;
+
+/// Whether the alert is a solid block of its severity color or an outline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlertVariant {
+    #[default]
+    Filled,
+    Outlined,
+}
+
+/// Alert component, an inline status or validation message
+#[derive(Debug)]
+pub struct Alert {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// How serious the message is, which picks its color and icon
+    pub severity: Severity,
+    /// Bold heading above the message
+    pub title: Option<String>,
+    /// Message text
+    pub message: String,
+    /// Filled or outlined
+    pub variant: AlertVariant,
+    /// Whether to show a close button
+    pub dismissible: bool,
+    /// Whether to fit the title and message on one line
+    pub compact: bool,
+    /// Alert width
+    pub width: f32,
+    /// Called when the close button is pressed
+    pub on_dismiss: Option<fn()>,
+    /// Theme providing severity colors and spacing
+    pub theme: Theme,
+}
+
+/// Alert props
+#[derive(Debug, Clone)]
+pub struct AlertProps {
+    /// Message text
+    pub message: String,
+    /// How serious the message is
+    pub severity: Option<Severity>,
+    /// Bold heading above the message
+    pub title: Option<String>,
+    /// Filled or outlined
+    pub variant: Option<AlertVariant>,
+    /// Whether to show a close button
+    pub dismissible: Option<bool>,
+    /// Whether to fit the title and message on one line
+    pub compact: Option<bool>,
+    /// Alert width
+    pub width: Option<f32>,
+    /// Called when the close button is pressed
+    pub on_dismiss: Option<fn()>,
+}
+
+impl Default for Alert {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            severity: Severity::Info,
+            title: None,
+            message: String::new(),
+            variant: AlertVariant::Filled,
+            dismissible: false,
+            compact: false,
+            width: DEFAULT_WIDTH,
+            on_dismiss: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Alert {
+    /// An info alert showing `message`
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Dismiss the alert as if its close button were pressed; does nothing
+    /// unless it is dismissible
+    pub fn dismiss(&mut self) {
+        if self.dismissible {
+            if let Some(on_dismiss) = self.on_dismiss {
+                on_dismiss();
+            }
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Escape dismisses a dismissible alert.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if key != "Escape" || !self.dismissible {
+            return false;
+        }
+        self.dismiss();
+        true
+    }
+
+    /// Height the alert renders at
+    pub fn height(&self) -> f32 {
+        let lines = if self.title.is_some() && !self.compact {
+            2.0
+        } else {
+            1.0
+        };
+        let padding = self.padding();
+        2.0 * padding + lines * LINE_HEIGHT + (lines - 1.0) * LINE_GAP
+    }
+
+    fn padding(&self) -> f32 {
+        if self.compact {
+            self.theme.spacing(2)
+        } else {
+            self.theme.spacing(3)
+        }
+    }
+
+    /// Accent color and text color for the variant
+    fn colors(&self) -> (Color, Color) {
+        let accent = Color::Hex(self.severity.color(&self.theme));
+        let text = match (self.variant, self.severity) {
+            (AlertVariant::Outlined, _) => self.theme.text_color.clone(),
+            // The warning color is light, so dark text keeps enough contrast
+            (AlertVariant::Filled, Severity::Warning) => self.theme.text_color.clone(),
+            (AlertVariant::Filled, _) => "#ffffff".to_string(),
+        };
+        (accent, Color::Hex(text))
+    }
+
+    /// Render the alert, measuring a compact title with `measure` so the
+    /// message can follow it on the same line
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Vec<Node> {
+        let padding = self.padding();
+        let (accent, text_color) = self.colors();
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), self.severity.role().to_string());
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, self.height()));
+        let radius = self.theme.radius(RadiusSize::Md);
+        let style = root.style_mut();
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        match self.variant {
+            AlertVariant::Filled => style.background_color = Some(accent.clone()),
+            AlertVariant::Outlined => {
+                style.background_color = Some(Color::Hex(self.theme.background_color.clone()));
+                style.border_width = Some(EdgeValues::new(1.0, 1.0, 1.0, 1.0));
+                style.border_color = Some(EdgeColors {
+                    top: accent.clone(),
+                    right: accent.clone(),
+                    bottom: accent.clone(),
+                    left: accent.clone(),
+                });
+            }
+        }
+
+        let mut icon = Node::default();
+        icon.add_attribute("aria-hidden".to_string(), "true".to_string());
+        icon.set_layout_rect(Rect::new(padding, padding, LINE_HEIGHT, LINE_HEIGHT));
+        icon.set_text(self.severity.icon());
+        icon.style_mut().color = Some(match self.variant {
+            AlertVariant::Filled => text_color.clone(),
+            AlertVariant::Outlined => accent,
+        });
+        root.add_child(icon);
+
+        let text_x = padding + LINE_HEIGHT + ICON_GAP;
+        let close_width = if self.dismissible {
+            LINE_HEIGHT + ICON_GAP
+        } else {
+            0.0
+        };
+        let text_width = (self.width - text_x - padding - close_width).max(0.0);
+        let body_style = self.theme.text_style("body");
+
+        let mut message_x = text_x;
+        let mut message_y = padding;
+        if let Some(title) = &self.title {
+            let mut title_node = Node::default();
+            title_node.set_text(title.clone());
+            body_style.apply(title_node.style_mut());
+            title_node.style_mut().font_weight = Some(FontWeight::Bold);
+            title_node.style_mut().color = Some(text_color.clone());
+            if self.compact {
+                let (title_width, _) = measure.measure_text(title, body_style.font_size);
+                let title_width = title_width.min(text_width);
+                title_node.set_layout_rect(Rect::new(text_x, padding, title_width, LINE_HEIGHT));
+                message_x += title_width + ICON_GAP;
+            } else {
+                title_node.set_layout_rect(Rect::new(text_x, padding, text_width, LINE_HEIGHT));
+                message_y += LINE_HEIGHT + LINE_GAP;
+            }
+            root.add_child(title_node);
+        }
+
+        let mut message = Node::default();
+        message.set_layout_rect(Rect::new(
+            message_x,
+            message_y,
+            (text_x + text_width - message_x).max(0.0),
+            LINE_HEIGHT,
+        ));
+        message.set_text(self.message.clone());
+        body_style.apply(message.style_mut());
+        message.style_mut().color = Some(text_color.clone());
+        root.add_child(message);
+
+        if self.dismissible {
+            let mut close = Node::default();
+            close.add_attribute("role".to_string(), "button".to_string());
+            close.add_attribute("aria-label".to_string(), "Dismiss".to_string());
+            close.set_layout_rect(Rect::new(
+                self.width - padding - LINE_HEIGHT,
+                padding,
+                LINE_HEIGHT,
+                LINE_HEIGHT,
+            ));
+            close.set_text("×");
+            close.style_mut().color = Some(text_color);
+            root.add_child(close);
+        }
+
+        vec![root]
+    }
+}
+
+impl Component for Alert {
+    type Props = AlertProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            message: props.message,
+            severity: props.severity.unwrap_or_default(),
+            title: props.title,
+            variant: props.variant.unwrap_or_default(),
+            dismissible: props.dismissible.unwrap_or(false),
+            compact: props.compact.unwrap_or(false),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            on_dismiss: props.on_dismiss,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.message = props.message;
+        self.severity = props.severity.unwrap_or(self.severity);
+        self.title = props.title;
+        self.variant = props.variant.unwrap_or(self.variant);
+        self.dismissible = props.dismissible.unwrap_or(self.dismissible);
+        self.compact = props.compact.unwrap_or(self.compact);
+        self.width = props.width.unwrap_or(self.width);
+        self.on_dismiss = props.on_dismiss;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(self.render_with(&ApproximateTextMeasure))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_severity_drives_color_icon_and_role() {
+        let theme = Theme::default();
+        let alert = Alert {
+            severity: Severity::Error,
+            title: Some("Upload failed".to_string()),
+            variant: AlertVariant::Outlined,
+            ..Alert::new("The file is too large")
+        };
+        let root = &alert.render().unwrap()[0];
+        assert_eq!(root.attributes().get("role").unwrap(), "alert");
+        let border = root.style().border_color.as_ref().unwrap();
+        assert_eq!(border.left, Color::Hex(theme.error_color.clone()));
+
+        let children = root.children();
+        assert_eq!(children[0].text(), Some("✕"));
+        assert_eq!(children[1].text(), Some("Upload failed"));
+        assert!(children[2].layout_rect().y() > children[1].layout_rect().y());
+        assert_eq!(children.len(), 3);
+    }
+
+    #[test]
+    fn test_compact_dismissible_alert() {
+        static DISMISSED: AtomicBool = AtomicBool::new(false);
+
+        let mut alert = Alert {
+            severity: Severity::Success,
+            title: Some("Saved".to_string()),
+            compact: true,
+            dismissible: true,
+            on_dismiss: Some(|| DISMISSED.store(true, Ordering::SeqCst)),
+            ..Alert::new("Your changes are live")
+        };
+        let full_height = Alert {
+            compact: false,
+            title: Some("Saved".to_string()),
+            ..Alert::new("Your changes are live")
+        }
+        .height();
+        assert!(alert.height() < full_height);
+
+        let root = &alert.render().unwrap()[0];
+        assert_eq!(root.attributes().get("role").unwrap(), "status");
+        let (title, message) = (&root.children()[1], &root.children()[2]);
+        assert_eq!(title.layout_rect().y(), message.layout_rect().y());
+        assert!(message.layout_rect().x() > title.layout_rect().max_x());
+        let close = &root.children()[3];
+        assert_eq!(close.attributes().get("aria-label").unwrap(), "Dismiss");
+        assert!(close.layout_rect().max_x() <= alert.width);
+
+        assert!(alert.handle_key("Escape"));
+        assert!(DISMISSED.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,384 @@
+// Avatar component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::layout::{EdgeValues, Rect};
+use crate::renderer::ImageData;
+use crate::style::{BorderRadius, Color, EdgeColors, FontWeight, Overflow, TextAlign};
+use std::any::Any;
+
+/// Default avatar diameter
+const DEFAULT_SIZE: f32 = 40.0;
+
+/// Corner radius of square avatars, as a fraction of their size
+const SQUARE_RADIUS: f32 = 0.15;
+
+/// Fallback backgrounds, picked by hashing the name
+const PALETTE: [&str; 8] = [
+    "#e57373", "#f06292", "#ba68c8", "#7986cb", "#4fc3f7", "#4db6ac", "#aed581", "#ffb74d",
+];
+
+/// Avatar outline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvatarShape {
+    #[default]
+    Circle,
+    Square,
+}
+
+impl AvatarShape {
+    fn border_radius(self, size: f32) -> BorderRadius {
+        let radius = match self {
+            AvatarShape::Circle => size / 2.0,
+            AvatarShape::Square => size * SQUARE_RADIUS,
+        };
+        BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+/// Up to two initials from the first and last words of `name`
+pub fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.last().and_then(|word| word.chars().next());
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Background color for `name`, the same on every run and platform
+pub fn name_color(name: &str) -> &'static str {
+    // FNV-1a, so the color doesn't depend on the std hasher's seed or version
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    });
+    PALETTE[hash as usize % PALETTE.len()]
+}
+
+/// Avatar component
+#[derive(Debug, Clone)]
+pub struct Avatar {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Picture to show; initials are shown when absent or unusable
+    pub src: Option<ImageData>,
+    /// Name the initials, fallback color, and accessible label come from
+    pub name: Option<String>,
+    /// Width and height
+    pub size: f32,
+    /// Outline the avatar is clipped to
+    pub shape: AvatarShape,
+    /// Set once `src` failed to load, so the initials stay up
+    image_failed: bool,
+}
+
+/// Avatar props
+#[derive(Debug, Clone)]
+pub struct AvatarProps {
+    /// Picture to show
+    pub src: Option<ImageData>,
+    /// Name the initials and fallback color come from
+    pub name: Option<String>,
+    /// Width and height
+    pub size: Option<f32>,
+    /// Outline the avatar is clipped to
+    pub shape: Option<AvatarShape>,
+}
+
+impl Default for Avatar {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            src: None,
+            name: None,
+            size: DEFAULT_SIZE,
+            shape: AvatarShape::Circle,
+            image_failed: false,
+        }
+    }
+}
+
+impl Avatar {
+    /// Create an initials avatar for `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Record that `src` could not be loaded; initials are shown instead
+    /// until a new `src` is set through `update`
+    pub fn image_error(&mut self) {
+        self.image_failed = true;
+    }
+
+    /// The image that will be drawn, if the avatar isn't falling back to
+    /// initials
+    pub fn visible_image(&self) -> Option<&ImageData> {
+        if self.image_failed {
+            return None;
+        }
+        self.src.as_ref().filter(|image| image.is_valid())
+    }
+
+    fn render_node(&self, size: f32) -> Node {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "img".to_string());
+        if let Some(name) = &self.name {
+            root.add_attribute("aria-label".to_string(), name.clone());
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, size, size));
+        let style = root.style_mut();
+        style.border_radius = Some(self.shape.border_radius(size));
+        style.overflow = Some(Overflow::Hidden);
+
+        if let Some(image) = self.visible_image() {
+            root.set_image(image.clone());
+            return root;
+        }
+
+        let name = self.name.as_deref().unwrap_or("");
+        root.style_mut().background_color = Some(Color::Hex(name_color(name).to_string()));
+
+        let letters = initials(name);
+        let font_size = (size * 0.4).round();
+        let mut text = Node::default();
+        text.set_layout_rect(Rect::new(0.0, (size - font_size) / 2.0, size, font_size));
+        text.set_text(if letters.is_empty() {
+            "?".to_string()
+        } else {
+            letters
+        });
+        let style = text.style_mut();
+        style.font_size = Some(font_size);
+        style.font_weight = Some(FontWeight::SemiBold);
+        style.text_align = Some(TextAlign::Center);
+        style.color = Some(Color::Hex("#ffffff".to_string()));
+        root.add_child(text);
+
+        root
+    }
+}
+
+impl Component for Avatar {
+    type Props = AvatarProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, _context: Context) -> Self {
+        Self {
+            src: props.src,
+            name: props.name,
+            size: props.size.unwrap_or(DEFAULT_SIZE),
+            shape: props.shape.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // A different picture gets a fresh chance to load
+        if props.src != self.src {
+            self.image_failed = false;
+        }
+        self.src = props.src;
+        self.name = props.name;
+        self.size = props.size.unwrap_or(self.size);
+        self.shape = props.shape.unwrap_or(self.shape);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![self.render_node(self.size)])
+    }
+}
+
+/// A row of overlapping avatars, with a "+N" avatar for any beyond `max`
+#[derive(Debug)]
+pub struct AvatarGroup {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Avatars, front to back
+    pub avatars: Vec<Avatar>,
+    /// Most avatars to show before collapsing the rest into a count
+    pub max: Option<usize>,
+    /// Size every avatar in the group is drawn at
+    pub size: f32,
+    /// How far each avatar overlaps the previous one
+    pub overlap: f32,
+}
+
+/// Avatar group props
+#[derive(Debug, Clone)]
+pub struct AvatarGroupProps {
+    /// Avatars, front to back
+    pub avatars: Vec<Avatar>,
+    /// Most avatars to show before collapsing the rest into a count
+    pub max: Option<usize>,
+    /// Size every avatar in the group is drawn at
+    pub size: Option<f32>,
+    /// How far each avatar overlaps the previous one
+    pub overlap: Option<f32>,
+}
+
+impl Default for AvatarGroup {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            avatars: Vec::new(),
+            max: None,
+            size: DEFAULT_SIZE,
+            overlap: DEFAULT_SIZE / 4.0,
+        }
+    }
+}
+
+impl Component for AvatarGroup {
+    type Props = AvatarGroupProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, _context: Context) -> Self {
+        let size = props.size.unwrap_or(DEFAULT_SIZE);
+        Self {
+            avatars: props.avatars,
+            max: props.max,
+            size,
+            overlap: props.overlap.unwrap_or(size / 4.0),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.avatars = props.avatars;
+        self.max = props.max;
+        self.size = props.size.unwrap_or(self.size);
+        self.overlap = props.overlap.unwrap_or(self.overlap);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let shown = self.max.unwrap_or(usize::MAX).min(self.avatars.len());
+        let hidden = self.avatars.len() - shown;
+
+        let mut nodes: Vec<Node> = self.avatars[..shown]
+            .iter()
+            .map(|avatar| avatar.render_node(self.size))
+            .collect();
+        if hidden > 0 {
+            let mut more = Avatar::new(format!("+{hidden}")).render_node(self.size);
+            more.style_mut().background_color = Some(Color::Hex("#9e9e9e".to_string()));
+            if let Some(text) = more.children_mut().first_mut() {
+                text.set_text(format!("+{hidden}"));
+            }
+            more.add_attribute("aria-label".to_string(), format!("{hidden} more"));
+            nodes.push(more);
+        }
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "group".to_string());
+        let step = self.size - self.overlap;
+        let white = Color::Hex("#ffffff".to_string());
+        for (index, mut node) in nodes.into_iter().enumerate() {
+            // Earlier avatars sit on top, and a white ring separates them
+            node.set_layout_rect(Rect::new(index as f32 * step, 0.0, self.size, self.size));
+            let style = node.style_mut();
+            style.z_index = Some(-(index as i32));
+            style.border_width = Some(EdgeValues::uniform(2.0));
+            style.border_color = Some(EdgeColors {
+                top: white.clone(),
+                right: white.clone(),
+                bottom: white.clone(),
+                left: white.clone(),
+            });
+            root.add_child(node);
+        }
+        let count = root.children().len();
+        let width = if count == 0 {
+            0.0
+        } else {
+            step * (count - 1) as f32 + self.size
+        };
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, self.size));
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initials_and_stable_color() {
+        assert_eq!(initials("ada lovelace"), "AL");
+        assert_eq!(initials("Grace Brewster Hopper"), "GH");
+        assert_eq!(initials("Linus"), "L");
+        assert_eq!(initials("  "), "");
+        assert_eq!(name_color("Ada Lovelace"), name_color("Ada Lovelace"));
+        assert!(PALETTE.contains(&name_color("")));
+    }
+
+    #[test]
+    fn test_falls_back_to_initials_when_image_fails() {
+        let mut avatar = Avatar {
+            src: Some(ImageData::new(1, 1, vec![255, 0, 0, 255])),
+            ..Avatar::new("Ada Lovelace")
+        };
+        let node = &avatar.render().unwrap()[0];
+        assert!(node.image().is_some());
+        assert!(node.children().is_empty());
+
+        avatar.image_error();
+        let node = &avatar.render().unwrap()[0];
+        assert!(node.image().is_none());
+        assert_eq!(node.children()[0].text(), Some("AL"));
+
+        // Truncated pixel data never reaches the renderer
+        let broken = Avatar {
+            src: Some(ImageData::new(2, 2, vec![0; 4])),
+            ..Avatar::new("Ada")
+        };
+        assert!(broken.visible_image().is_none());
+    }
+
+    #[test]
+    fn test_group_overlaps_and_collapses_extra() {
+        let group = AvatarGroup {
+            avatars: ["A", "B", "C", "D"].into_iter().map(Avatar::new).collect(),
+            max: Some(2),
+            ..Default::default()
+        };
+        let root = &group.render().unwrap()[0];
+        assert_eq!(root.children().len(), 3);
+        assert_eq!(root.children()[1].layout_rect().x(), 30.0);
+        assert_eq!(root.children()[2].children()[0].text(), Some("+2"));
+        assert_eq!(root.layout_rect().width(), 100.0);
+    }
+}
@@ -0,0 +1,439 @@
+// Badge and Chip components for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, FontWeight};
+use std::any::Any;
+
+/// Badge height
+const BADGE_HEIGHT: f32 = 20.0;
+
+/// Diameter of a dot badge
+const DOT_SIZE: f32 = 8.0;
+
+/// Chip height
+const CHIP_HEIGHT: f32 = 28.0;
+
+/// Size of the chip icon and close button
+const CHIP_ICON_SIZE: f32 = 16.0;
+
+/// Counts above this are shown as "99+"
+const DEFAULT_MAX_COUNT: u32 = 99;
+
+fn pill(height: f32) -> BorderRadius {
+    let radius = height / 2.0;
+    BorderRadius {
+        top_left: radius,
+        top_right: radius,
+        bottom_right: radius,
+        bottom_left: radius,
+    }
+}
+
+/// Color variant for badges and chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgeVariant {
+    #[default]
+    Primary,
+    Secondary,
+    Success,
+    Warning,
+    Error,
+    Info,
+}
+
+impl BadgeVariant {
+    /// Background color for this variant from the theme
+    pub fn color(self, theme: &Theme) -> String {
+        match self {
+            BadgeVariant::Primary => theme.primary_color.clone(),
+            BadgeVariant::Secondary => theme.secondary_color.clone(),
+            BadgeVariant::Success => theme.success_color.clone(),
+            BadgeVariant::Warning => theme.warning_color.clone(),
+            BadgeVariant::Error => theme.error_color.clone(),
+            BadgeVariant::Info => theme.info_color.clone(),
+        }
+    }
+
+    /// Text color that reads on top of the variant color
+    fn text_color(self, theme: &Theme) -> String {
+        match self {
+            // The secondary and warning colors are light, so dark text
+            // keeps enough contrast
+            BadgeVariant::Secondary | BadgeVariant::Warning => theme.text_color.clone(),
+            _ => "#ffffff".to_string(),
+        }
+    }
+}
+
+/// Badge component
+#[derive(Debug)]
+pub struct Badge {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Text shown in the badge; takes precedence over `count`
+    pub text: Option<String>,
+    /// Number shown in the badge
+    pub count: Option<u32>,
+    /// Largest count shown as-is; larger counts show as "{max_count}+"
+    pub max_count: u32,
+    /// Color variant
+    pub variant: BadgeVariant,
+    /// Whether to show only a colored dot
+    pub dot: bool,
+    /// Theme providing the variant colors
+    pub theme: Theme,
+}
+
+/// Badge props
+#[derive(Debug, Clone)]
+pub struct BadgeProps {
+    /// Text shown in the badge
+    pub text: Option<String>,
+    /// Number shown in the badge
+    pub count: Option<u32>,
+    /// Largest count shown as-is
+    pub max_count: Option<u32>,
+    /// Color variant
+    pub variant: Option<BadgeVariant>,
+    /// Whether to show only a colored dot
+    pub dot: Option<bool>,
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            text: None,
+            count: None,
+            max_count: DEFAULT_MAX_COUNT,
+            variant: BadgeVariant::Primary,
+            dot: false,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Badge {
+    /// Text the badge displays, if any
+    pub fn label(&self) -> Option<String> {
+        if self.dot {
+            return None;
+        }
+        self.text.clone().or_else(|| {
+            self.count.map(|count| {
+                if count > self.max_count {
+                    format!("{}+", self.max_count)
+                } else {
+                    count.to_string()
+                }
+            })
+        })
+    }
+
+    /// Render the badge, sizing it with `measure`
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Vec<Node> {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "status".to_string());
+        let style = root.style_mut();
+        style.background_color = Some(Color::Hex(self.variant.color(&self.theme)));
+
+        let Some(label) = self.label() else {
+            root.set_layout_rect(Rect::new(0.0, 0.0, DOT_SIZE, DOT_SIZE));
+            root.style_mut().border_radius = Some(pill(DOT_SIZE));
+            return vec![root];
+        };
+
+        let text_style = self.theme.text_style("caption");
+        let (text_width, text_height) = measure.measure_text(&label, text_style.font_size);
+        // Never narrower than tall, so single digits render as a circle
+        let width = (text_width + 2.0 * self.theme.spacing(1)).max(BADGE_HEIGHT);
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, BADGE_HEIGHT));
+        root.style_mut().border_radius = Some(pill(BADGE_HEIGHT));
+        root.add_attribute("aria-label".to_string(), label.clone());
+
+        let mut text = Node::default();
+        text.set_layout_rect(Rect::new(
+            (width - text_width) / 2.0,
+            (BADGE_HEIGHT - text_height) / 2.0,
+            text_width,
+            text_height,
+        ));
+        text.set_text(label);
+        let style = text.style_mut();
+        text_style.apply(style);
+        style.font_weight = Some(FontWeight::SemiBold);
+        style.color = Some(Color::Hex(self.variant.text_color(&self.theme)));
+        root.add_child(text);
+
+        vec![root]
+    }
+}
+
+impl Component for Badge {
+    type Props = BadgeProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            text: props.text,
+            count: props.count,
+            max_count: props.max_count.unwrap_or(DEFAULT_MAX_COUNT),
+            variant: props.variant.unwrap_or_default(),
+            dot: props.dot.unwrap_or(false),
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.text = props.text;
+        self.count = props.count;
+        self.max_count = props.max_count.unwrap_or(self.max_count);
+        self.variant = props.variant.unwrap_or(self.variant);
+        self.dot = props.dot.unwrap_or(self.dot);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(self.render_with(&ApproximateTextMeasure))
+    }
+}
+
+/// Chip component: a compact, optionally removable tag
+#[derive(Debug)]
+pub struct Chip {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Chip text
+    pub label: String,
+    /// Leading icon glyph
+    pub icon: Option<String>,
+    /// Color variant
+    pub variant: BadgeVariant,
+    /// Whether the chip is disabled
+    pub disabled: bool,
+    /// Delete event handler; the close button is shown only when set
+    pub on_delete: Option<fn()>,
+    /// Theme providing the variant colors
+    pub theme: Theme,
+}
+
+/// Chip props
+#[derive(Debug, Clone)]
+pub struct ChipProps {
+    /// Chip text
+    pub label: String,
+    /// Leading icon glyph
+    pub icon: Option<String>,
+    /// Color variant
+    pub variant: Option<BadgeVariant>,
+    /// Whether the chip is disabled
+    pub disabled: Option<bool>,
+    /// Delete event handler
+    pub on_delete: Option<fn()>,
+}
+
+impl Default for Chip {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            label: String::new(),
+            icon: None,
+            variant: BadgeVariant::Secondary,
+            disabled: false,
+            on_delete: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Chip {
+    /// Delete the chip, as when its close button is clicked
+    pub fn delete(&mut self) {
+        if self.disabled {
+            return;
+        }
+        if let Some(on_delete) = self.on_delete {
+            on_delete();
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Backspace and Delete remove a deletable chip.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if !matches!(key, "Backspace" | "Delete") || self.on_delete.is_none() || self.disabled {
+            return false;
+        }
+        self.delete();
+        true
+    }
+
+    /// Render the chip, sizing it with `measure`
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Vec<Node> {
+        let text_color = Color::Hex(self.variant.text_color(&self.theme));
+        let padding = self.theme.spacing(3);
+        let gap = self.theme.spacing(1);
+
+        let mut root = Node::default();
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+            root.style_mut().opacity = Some(0.5);
+        }
+        let style = root.style_mut();
+        style.background_color = Some(Color::Hex(self.variant.color(&self.theme)));
+        style.border_radius = Some(pill(CHIP_HEIGHT));
+
+        let icon_y = (CHIP_HEIGHT - CHIP_ICON_SIZE) / 2.0;
+        let mut x = padding;
+        if let Some(icon) = &self.icon {
+            let mut icon_node = Node::default();
+            icon_node.set_layout_rect(Rect::new(x, icon_y, CHIP_ICON_SIZE, CHIP_ICON_SIZE));
+            icon_node.set_text(icon.clone());
+            icon_node.style_mut().color = Some(text_color.clone());
+            root.add_child(icon_node);
+            x += CHIP_ICON_SIZE + gap;
+        }
+
+        let text_style = self.theme.text_style("body");
+        let (text_width, text_height) = measure.measure_text(&self.label, text_style.font_size);
+        let mut label = Node::default();
+        label.set_layout_rect(Rect::new(
+            x,
+            (CHIP_HEIGHT - text_height) / 2.0,
+            text_width,
+            text_height,
+        ));
+        label.set_text(self.label.clone());
+        text_style.apply(label.style_mut());
+        label.style_mut().color = Some(text_color.clone());
+        root.add_child(label);
+        x += text_width;
+
+        if self.on_delete.is_some() {
+            x += gap;
+            let mut close = Node::default();
+            close.add_attribute("role".to_string(), "button".to_string());
+            close.add_attribute("aria-label".to_string(), format!("Remove {}", self.label));
+            close.set_layout_rect(Rect::new(x, icon_y, CHIP_ICON_SIZE, CHIP_ICON_SIZE));
+            close.set_text("×");
+            close.style_mut().color = Some(text_color);
+            root.add_child(close);
+            x += CHIP_ICON_SIZE;
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, x + padding, CHIP_HEIGHT));
+
+        vec![root]
+    }
+}
+
+impl Component for Chip {
+    type Props = ChipProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            label: props.label,
+            icon: props.icon,
+            variant: props.variant.unwrap_or(BadgeVariant::Secondary),
+            disabled: props.disabled.unwrap_or(false),
+            on_delete: props.on_delete,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.label = props.label;
+        self.icon = props.icon;
+        self.variant = props.variant.unwrap_or(self.variant);
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.on_delete = props.on_delete;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(self.render_with(&ApproximateTextMeasure))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_label_and_dot() {
+        let mut badge = Badge {
+            count: Some(150),
+            variant: BadgeVariant::Error,
+            ..Default::default()
+        };
+        assert_eq!(badge.label().as_deref(), Some("99+"));
+        let nodes = badge.render().unwrap();
+        assert_eq!(
+            nodes[0].style().background_color,
+            Some(Color::Hex("#ff0000".to_string()))
+        );
+        assert!(nodes[0].layout_rect().width() > BADGE_HEIGHT);
+
+        badge.count = Some(3);
+        let nodes = badge.render().unwrap();
+        assert_eq!(nodes[0].layout_rect().width(), BADGE_HEIGHT);
+
+        badge.dot = true;
+        let nodes = badge.render().unwrap();
+        assert!(nodes[0].children().is_empty());
+        assert_eq!(nodes[0].layout_rect().width(), DOT_SIZE);
+    }
+
+    #[test]
+    fn test_chip_sizes_to_content_and_deletes() {
+        let plain = Chip {
+            label: "Rust".to_string(),
+            ..Default::default()
+        };
+        let mut deletable = Chip {
+            label: "Rust".to_string(),
+            icon: Some("#".to_string()),
+            on_delete: Some(|| {}),
+            ..Default::default()
+        };
+
+        let plain_width = plain.render().unwrap()[0].layout_rect().width();
+        let nodes = deletable.render().unwrap();
+        assert_eq!(
+            nodes[0].layout_rect().width(),
+            plain_width + 2.0 * (CHIP_ICON_SIZE + plain.theme.spacing(1))
+        );
+        let close = nodes[0].children().last().unwrap();
+        assert_eq!(close.attributes().get("aria-label").unwrap(), "Remove Rust");
+
+        assert!(deletable.handle_key("Backspace"));
+        assert!(!Chip::default().handle_key("Backspace"));
+    }
+}
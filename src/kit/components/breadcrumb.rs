@@ -0,0 +1,457 @@
+// Breadcrumb component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::components::menu::{Menu, MenuItem};
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::{Point, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, FontWeight};
+use std::any::Any;
+
+/// Height of the row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Space either side of each item's label, inside its hit area
+const ITEM_PADDING: f32 = 4.0;
+
+/// Space either side of a separator
+const SEPARATOR_GAP: f32 = 4.0;
+
+/// Stands in for the collapsed items
+const ELLIPSIS: &str = "…";
+
+/// One level of the path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreadcrumbItem {
+    /// Passed to `on_navigate` when the item is chosen
+    pub id: String,
+    /// Text shown
+    pub label: String,
+}
+
+impl BreadcrumbItem {
+    /// An item with `id`, showing `label`
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// One slot in the rendered row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crumb {
+    /// The item at this index
+    Item(usize),
+    /// The collapsed middle items, which open in a menu
+    Ellipsis,
+}
+
+/// Breadcrumb component, the path from the root to the current page
+///
+/// Every item but the last links back up the path. When `max_width` is
+/// too narrow for them all, the items after the first are collapsed behind
+/// an ellipsis that opens them in a [`Menu`], keeping as many items
+/// nearest the current page as fit.
+#[derive(Debug)]
+pub struct Breadcrumb {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// The path, root first; the last item is the current page
+    pub items: Vec<BreadcrumbItem>,
+    /// Text between items
+    pub separator: String,
+    /// Width the row must fit in; unlimited when unset
+    pub max_width: Option<f32>,
+    /// Called with an item's ID when it is chosen
+    pub on_navigate: Option<fn(&str)>,
+    /// Menu listing the collapsed items
+    overflow: Menu,
+    /// Theme providing the link colors and text style
+    pub theme: Theme,
+}
+
+/// Breadcrumb props
+#[derive(Debug, Clone, Default)]
+pub struct BreadcrumbProps {
+    /// The path, root first
+    pub items: Vec<BreadcrumbItem>,
+    /// Text between items
+    pub separator: Option<String>,
+    /// Width the row must fit in
+    pub max_width: Option<f32>,
+    /// Called with an item's ID when it is chosen
+    pub on_navigate: Option<fn(&str)>,
+}
+
+impl Default for Breadcrumb {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            items: Vec::new(),
+            separator: "/".to_string(),
+            max_width: None,
+            on_navigate: None,
+            overflow: Menu::default(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Breadcrumb {
+    /// A breadcrumb showing `items`
+    pub fn new(items: Vec<BreadcrumbItem>) -> Self {
+        Self {
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn font_size(&self) -> f32 {
+        self.theme.text_style("body").font_size
+    }
+
+    fn text_width(&self, text: &str, measure: &dyn TextMeasure) -> f32 {
+        measure.measure_text(text, self.font_size()).0 + 2.0 * ITEM_PADDING
+    }
+
+    fn separator_width(&self, measure: &dyn TextMeasure) -> f32 {
+        measure.measure_text(&self.separator, self.font_size()).0 + 2.0 * SEPARATOR_GAP
+    }
+
+    /// The slots to show, measuring labels with `measure`
+    pub fn crumbs(&self, measure: &dyn TextMeasure) -> Vec<Crumb> {
+        let count = self.items.len();
+        let widths: Vec<f32> = self
+            .items
+            .iter()
+            .map(|item| self.text_width(&item.label, measure))
+            .collect();
+        let separator = self.separator_width(measure);
+        let total = widths.iter().sum::<f32>() + separator * count.saturating_sub(1) as f32;
+        let all = || (0..count).map(Crumb::Item).collect();
+        let Some(max_width) = self.max_width.filter(|&max| total > max && count > 2) else {
+            return all();
+        };
+
+        // The root, the ellipsis, and the current page always show; fill
+        // what's left with the items just above the current page
+        let mut used =
+            widths[0] + widths[count - 1] + self.text_width(ELLIPSIS, measure) + 2.0 * separator;
+        let mut first_shown = count - 1;
+        while first_shown > 1 && used + widths[first_shown - 1] + separator <= max_width {
+            first_shown -= 1;
+            used += widths[first_shown] + separator;
+        }
+        if first_shown == 1 {
+            return all();
+        }
+        let mut crumbs = vec![Crumb::Item(0), Crumb::Ellipsis];
+        crumbs.extend((first_shown..count).map(Crumb::Item));
+        crumbs
+    }
+
+    /// Indices of the items collapsed behind the ellipsis
+    pub fn hidden(&self, measure: &dyn TextMeasure) -> Vec<usize> {
+        let crumbs = self.crumbs(measure);
+        match crumbs.get(2) {
+            Some(&Crumb::Item(first_shown)) if crumbs[1] == Crumb::Ellipsis => {
+                (1..first_shown).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Each slot with its rect in the row
+    fn layout(&self, measure: &dyn TextMeasure) -> Vec<(Crumb, Rect)> {
+        let separator = self.separator_width(measure);
+        let mut x = 0.0;
+        self.crumbs(measure)
+            .into_iter()
+            .map(|crumb| {
+                let width = match crumb {
+                    Crumb::Item(index) => self.text_width(&self.items[index].label, measure),
+                    Crumb::Ellipsis => self.text_width(ELLIPSIS, measure),
+                };
+                let rect = Rect::new(x, 0.0, width, ROW_HEIGHT);
+                x += width + separator;
+                (crumb, rect)
+            })
+            .collect()
+    }
+
+    /// Choose the item at `index`, firing `on_navigate` unless it is the
+    /// current page
+    pub fn navigate(&mut self, index: usize) {
+        self.overflow.close();
+        if index + 1 >= self.items.len() {
+            return;
+        }
+        if let Some(on_navigate) = self.on_navigate {
+            on_navigate(&self.items[index].id);
+        }
+    }
+
+    /// Whether the menu of collapsed items is showing
+    pub fn is_overflow_open(&self) -> bool {
+        self.overflow.is_open()
+    }
+
+    /// Open the menu of collapsed items under the ellipsis, or close it if
+    /// it is showing
+    pub fn toggle_overflow(&mut self, measure: &dyn TextMeasure) {
+        if self.overflow.is_open() {
+            self.overflow.close();
+            return;
+        }
+        let Some((_, anchor)) = self
+            .layout(measure)
+            .into_iter()
+            .find(|(crumb, _)| *crumb == Crumb::Ellipsis)
+        else {
+            return;
+        };
+        self.overflow.items = self
+            .hidden(measure)
+            .into_iter()
+            .map(|index| {
+                let item = &self.items[index];
+                MenuItem::new(item.id.clone(), item.label.clone())
+            })
+            .collect();
+        self.overflow.on_select = self.on_navigate;
+        self.overflow.theme = self.theme.clone();
+        self.overflow.open_from(anchor);
+    }
+
+    /// Handle a click at `point` in the row's coordinates, measuring labels
+    /// with `measure`
+    ///
+    /// While the menu of collapsed items is open the click goes to it.
+    pub fn click(&mut self, point: (f32, f32), measure: &dyn TextMeasure) {
+        if self.overflow.pointer_down(point) {
+            return;
+        }
+        let hit = self
+            .layout(measure)
+            .into_iter()
+            .find(|(_, rect)| rect.contains_point(Point::new(point.0, point.1)));
+        match hit {
+            Some((Crumb::Item(index), _)) => self.navigate(index),
+            Some((Crumb::Ellipsis, _)) => self.toggle_overflow(measure),
+            None => {}
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Keys go to the menu of collapsed items while it is open.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        self.overflow.is_open() && self.overflow.handle_key(key)
+    }
+
+    fn crumb_node(&self, text: &str, rect: Rect) -> Node {
+        let mut node = Node::default();
+        node.set_layout_rect(rect);
+        node.set_text(text);
+        let style = node.style_mut();
+        style.font_size = Some(self.font_size());
+        let radius = self.theme.radius(RadiusSize::Sm);
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        node
+    }
+
+    /// Render the row, measuring labels with `measure`
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Node {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "navigation".to_string());
+        root.add_attribute("aria-label".to_string(), "Breadcrumb".to_string());
+
+        let separator_width = self.separator_width(measure);
+        let layout = self.layout(measure);
+        let width = layout.last().map_or(0.0, |(_, rect)| rect.max_x());
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, ROW_HEIGHT));
+
+        for (slot, (crumb, rect)) in layout.iter().enumerate() {
+            if slot > 0 {
+                let mut separator = self.crumb_node(
+                    &self.separator,
+                    Rect::new(rect.x() - separator_width, 0.0, separator_width, ROW_HEIGHT),
+                );
+                separator.add_attribute("aria-hidden".to_string(), "true".to_string());
+                separator.style_mut().color = Some(Color::Hex(self.theme.muted_text_color.clone()));
+                root.add_child(separator);
+            }
+
+            let node = match *crumb {
+                Crumb::Item(index) if index + 1 == self.items.len() => {
+                    let mut node = self.crumb_node(&self.items[index].label, *rect);
+                    node.add_attribute("aria-current".to_string(), "page".to_string());
+                    let style = node.style_mut();
+                    style.color = Some(Color::Hex(self.theme.text_color.clone()));
+                    style.font_weight = Some(FontWeight::SemiBold);
+                    node
+                }
+                Crumb::Item(index) => {
+                    let mut node = self.crumb_node(&self.items[index].label, *rect);
+                    node.add_attribute("role".to_string(), "link".to_string());
+                    register_focusable(&mut node, false);
+                    node.style_mut().color = Some(Color::Hex(self.theme.primary_color.clone()));
+                    node
+                }
+                Crumb::Ellipsis => {
+                    let mut node = self.crumb_node(ELLIPSIS, *rect);
+                    node.add_attribute("role".to_string(), "button".to_string());
+                    node.add_attribute("aria-label".to_string(), "Show hidden items".to_string());
+                    node.add_attribute("aria-haspopup".to_string(), "menu".to_string());
+                    node.add_attribute(
+                        "aria-expanded".to_string(),
+                        self.overflow.is_open().to_string(),
+                    );
+                    register_focusable(&mut node, false);
+                    node.style_mut().color = Some(Color::Hex(self.theme.primary_color.clone()));
+                    node
+                }
+            };
+            root.add_child(node);
+        }
+
+        if let Ok(panels) = self.overflow.render() {
+            for panel in panels {
+                root.add_child(panel);
+            }
+        }
+        root
+    }
+}
+
+impl KeyHandler for Breadcrumb {
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl Component for Breadcrumb {
+    type Props = BreadcrumbProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            items: props.items,
+            separator: props.separator.unwrap_or_else(|| "/".to_string()),
+            max_width: props.max_width,
+            on_navigate: props.on_navigate,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        if props.items != self.items {
+            self.overflow.close();
+        }
+        self.items = props.items;
+        self.separator = props.separator.unwrap_or_else(|| self.separator.clone());
+        self.max_width = props.max_width;
+        self.on_navigate = props.on_navigate;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(vec![self.render_with(&ApproximateTextMeasure)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    static NAVIGATED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    /// Every character 10 wide
+    struct FixedMeasure;
+
+    impl TextMeasure for FixedMeasure {
+        fn measure_text(&self, text: &str, _size: f32) -> (f32, f32) {
+            (text.chars().count() as f32 * 10.0, 16.0)
+        }
+    }
+
+    fn path() -> Breadcrumb {
+        let mut breadcrumb = Breadcrumb::new(
+            ["home", "docs", "guides", "layout", "grid"]
+                .into_iter()
+                .map(|id| BreadcrumbItem::new(id, id))
+                .collect(),
+        );
+        breadcrumb.on_navigate = Some(|id| NAVIGATED.lock().unwrap().push(id.to_string()));
+        breadcrumb
+    }
+
+    #[test]
+    fn test_last_item_is_current_and_not_a_link() {
+        let mut breadcrumb = path();
+        let root = breadcrumb.render_with(&FixedMeasure);
+        // Five items with a separator between each
+        assert_eq!(root.children().len(), 9);
+        assert_eq!(root.children()[1].text(), Some("/"));
+        let last = root.children().last().unwrap();
+        assert_eq!(last.attributes().get("aria-current").unwrap(), "page");
+        assert!(!last.attributes().contains_key("role"));
+        assert_eq!(root.children()[0].attributes().get("role").unwrap(), "link");
+
+        breadcrumb.navigate(4);
+        breadcrumb.click((10.0, 10.0), &FixedMeasure);
+        assert_eq!(*NAVIGATED.lock().unwrap(), ["home"]);
+    }
+
+    #[test]
+    fn test_collapses_middle_items_into_menu() {
+        let mut breadcrumb = path();
+        // home(48) / …(18) / layout(68) / grid(48), with 18-wide separators
+        breadcrumb.max_width = Some(260.0);
+        assert_eq!(
+            breadcrumb.crumbs(&FixedMeasure),
+            [
+                Crumb::Item(0),
+                Crumb::Ellipsis,
+                Crumb::Item(3),
+                Crumb::Item(4)
+            ]
+        );
+        assert_eq!(breadcrumb.hidden(&FixedMeasure), [1, 2]);
+
+        // Clicking the ellipsis lists the hidden items in a menu
+        breadcrumb.click((48.0 + 18.0 + 5.0, 10.0), &FixedMeasure);
+        assert!(breadcrumb.is_overflow_open());
+        let root = breadcrumb.render_with(&FixedMeasure);
+        let menu = root.children().last().unwrap();
+        assert_eq!(menu.attributes().get("role").unwrap(), "menu");
+
+        breadcrumb.toggle_overflow(&FixedMeasure);
+        assert!(!breadcrumb.is_overflow_open());
+
+        breadcrumb.max_width = Some(1000.0);
+        assert!(breadcrumb.hidden(&FixedMeasure).is_empty());
+    }
+}
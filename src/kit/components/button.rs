@@ -1,8 +1,26 @@
 // Button component for OrbitKit
 
 use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::events::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::kit::utils::animation::KeyframeAnimation;
+use crate::kit::utils::color;
+use crate::layout::{AlignItems, Dimension, EdgeValues, LayoutStyle, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, EdgeColors, Point2D};
 use std::any::Any;
 
+/// Seconds for the loading spinner to turn once
+const SPINNER_PERIOD: f32 = 0.8;
+
+/// Width of the loading spinner's ring
+const SPINNER_STROKE: f32 = 2.0;
+
+/// Opacity of the tint behind transparent variants under the pointer; twice
+/// this while pressed
+const HOVER_TINT: f32 = 0.08;
+
 /// Button size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ButtonSize {
@@ -19,6 +37,8 @@ pub enum ButtonVariant {
     Outline,
     Ghost,
     Link,
+    /// A filled button in the theme's error color, for destructive actions
+    Danger,
 }
 
 /// Button component that follows Orbit's design system
@@ -54,8 +74,27 @@ pub struct Button {
     pub disabled: bool,
     /// Size variant of the button
     pub size: ButtonSize,
+    /// Whether an action is in progress; shows a spinner and blocks clicks
+    pub loading: bool,
+    /// Whether the button stretches across its parent inside a
+    /// [`Layout`](super::layout::Layout)
+    pub full_width: bool,
+    /// Icon glyph shown before the text
+    pub leading_icon: Option<String>,
+    /// Icon glyph shown after the text
+    pub trailing_icon: Option<String>,
     /// Click event handler
     pub on_click: Option<fn()>,
+    /// Whether the pointer is over the button
+    hovered: bool,
+    /// Whether the button is held down
+    pressed: bool,
+    /// Whether the button has keyboard focus
+    focused: bool,
+    /// Turning of the loading spinner
+    spinner: KeyframeAnimation,
+    /// Theme providing colors, padding, and corner radius
+    pub theme: Theme,
 }
 
 /// Properties for the Button component
@@ -69,6 +108,14 @@ pub struct ButtonProps {
     pub disabled: Option<bool>,
     /// Size variant of the button
     pub size: Option<ButtonSize>,
+    /// Whether an action is in progress
+    pub loading: Option<bool>,
+    /// Whether the button stretches across its parent
+    pub full_width: Option<bool>,
+    /// Icon glyph shown before the text
+    pub leading_icon: Option<String>,
+    /// Icon glyph shown after the text
+    pub trailing_icon: Option<String>,
     /// Click event handler
     pub on_click: Option<fn()>,
 }
@@ -81,7 +128,283 @@ impl Default for Button {
             variant: ButtonVariant::Primary,
             disabled: false,
             size: ButtonSize::Medium,
+            loading: false,
+            full_width: false,
+            leading_icon: None,
+            trailing_icon: None,
             on_click: None,
+            hovered: false,
+            pressed: false,
+            focused: false,
+            spinner: KeyframeAnimation::spin(SPINNER_PERIOD),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Button {
+    /// Vertical and horizontal padding for the button's size, from the
+    /// theme's spacing scale
+    pub fn padding(&self) -> (f32, f32) {
+        match self.size {
+            ButtonSize::Small => (self.theme.spacing(1), self.theme.spacing(3)),
+            ButtonSize::Medium => (self.theme.spacing(2), self.theme.spacing(4)),
+            ButtonSize::Large => (self.theme.spacing(3), self.theme.spacing(5)),
+        }
+    }
+
+    /// Corner radius for the button's size, from the theme's radius scale
+    pub fn corner_radius(&self) -> f32 {
+        self.theme.radius(match self.size {
+            ButtonSize::Small => RadiusSize::Sm,
+            ButtonSize::Medium => RadiusSize::Md,
+            ButtonSize::Large => RadiusSize::Lg,
+        })
+    }
+
+    /// Whether the button responds to the pointer and keyboard; disabled and
+    /// loading buttons don't
+    pub fn is_interactive(&self) -> bool {
+        !self.disabled && !self.loading
+    }
+
+    /// Whether the pointer is over the button
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Whether the button is held down
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Give the button keyboard focus, or take it away
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Click the button, as a press and release over it would
+    pub fn click(&mut self) {
+        if !self.is_interactive() {
+            return;
+        }
+        if let Some(on_click) = self.on_click {
+            on_click();
+        }
+    }
+
+    /// Advance the loading spinner by `dt` seconds; returns whether it is
+    /// turning, which it never is under reduced motion
+    pub fn tick(&mut self, dt: f32) -> bool {
+        if !self.loading || self.theme.reduced_motion {
+            return false;
+        }
+        self.spinner.tick(dt)
+    }
+
+    /// Background and text colors for the variant, shaded for hover and
+    /// press
+    fn colors(&self) -> (Option<String>, String) {
+        let theme = &self.theme;
+        let shade = |base: &str| {
+            if !self.is_interactive() {
+                base.to_string()
+            } else if self.pressed {
+                theme.active_color(base)
+            } else if self.hovered {
+                theme.hover_color(base)
+            } else {
+                base.to_string()
+            }
+        };
+        // Transparent variants take a faint tint of their text color instead
+        let tint = |base: &str| {
+            let alpha = match (self.is_interactive(), self.pressed, self.hovered) {
+                (false, ..) => return None,
+                (true, true, _) => 2.0 * HOVER_TINT,
+                (true, false, true) => HOVER_TINT,
+                (true, false, false) => return None,
+            };
+            Some(color::with_alpha(base, alpha))
+        };
+
+        match self.variant {
+            ButtonVariant::Primary => (Some(shade(&theme.primary_color)), "#ffffff".to_string()),
+            ButtonVariant::Secondary => (
+                Some(shade(&theme.secondary_color)),
+                theme.text_color.clone(),
+            ),
+            ButtonVariant::Danger => (Some(shade(&theme.error_color)), "#ffffff".to_string()),
+            ButtonVariant::Outline | ButtonVariant::Ghost => {
+                (tint(&theme.primary_color), theme.primary_color.clone())
+            }
+            ButtonVariant::Link => (None, shade(&theme.primary_color)),
+        }
+    }
+
+    /// A ring `size` across with a gap in it, turned to the spinner's angle
+    fn spinner(&self, x: f32, y: f32, size: f32, color: &str) -> Node {
+        let mut spinner = Node::default();
+        spinner.add_attribute("role".to_string(), "progressbar".to_string());
+        spinner.set_layout_rect(Rect::new(x, y, size, size));
+        let ring = Color::Hex(color.to_string());
+        let gap = Color::Hex(color::with_alpha(color, 0.0));
+        let style = spinner.style_mut();
+        style.border_width = Some(EdgeValues::uniform(SPINNER_STROKE));
+        style.border_color = Some(EdgeColors {
+            top: gap,
+            right: ring.clone(),
+            bottom: ring.clone(),
+            left: ring,
+        });
+        style.border_radius = Some(BorderRadius {
+            top_left: size / 2.0,
+            top_right: size / 2.0,
+            bottom_right: size / 2.0,
+            bottom_left: size / 2.0,
+        });
+        self.spinner.apply(style);
+        style.transform_origin = Some(Point2D {
+            x: size / 2.0,
+            y: size / 2.0,
+        });
+        spinner
+    }
+
+    /// Render the button, sizing it to its label with `measure`
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Vec<Node> {
+        let (padding_y, padding_x) = self.padding();
+        let gap = self.theme.spacing(2);
+        let text_style = self.theme.text_style("button");
+        let (text_width, text_height) = measure.measure_text(&self.text, text_style.font_size);
+        let icon_size = text_style.font_size;
+        let (background, text_color) = self.colors();
+
+        // The spinner stands in for the leading icon while loading
+        let leading = self.loading || self.leading_icon.is_some();
+        let trailing = self.trailing_icon.is_some();
+        let width = text_width
+            + 2.0 * padding_x
+            + [leading, trailing].iter().filter(|&&icon| icon).count() as f32 * (icon_size + gap);
+        let height = text_height.max(icon_size) + 2.0 * padding_y;
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "button".to_string());
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+        if self.loading {
+            root.add_attribute("aria-busy".to_string(), "true".to_string());
+        }
+        register_focusable(&mut root, self.disabled);
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+        let radius = self.corner_radius();
+        let style = root.style_mut();
+        style.background_color = background.map(Color::Hex);
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        if self.variant == ButtonVariant::Outline {
+            let primary = Color::Hex(self.theme.primary_color.clone());
+            style.border_width = Some(EdgeValues::uniform(1.0));
+            style.border_color = Some(EdgeColors {
+                top: primary.clone(),
+                right: primary.clone(),
+                bottom: primary.clone(),
+                left: primary,
+            });
+        }
+        if self.disabled {
+            style.opacity = Some(0.5);
+        }
+        if self.focused && !self.disabled {
+            self.theme.apply_focus_ring(style);
+        }
+        if self.full_width {
+            style.layout_style = Some(LayoutStyle {
+                width: Dimension::Percent(100.0),
+                height: Dimension::Points(height),
+                align_self: Some(AlignItems::Stretch),
+                ..Default::default()
+            });
+        }
+
+        let icon_y = (height - icon_size) / 2.0;
+        let icon = |glyph: &str, x: f32| {
+            let mut node = Node::default();
+            node.add_attribute("aria-hidden".to_string(), "true".to_string());
+            node.set_layout_rect(Rect::new(x, icon_y, icon_size, icon_size));
+            node.set_text(glyph.to_string());
+            node.style_mut().color = Some(Color::Hex(text_color.clone()));
+            node
+        };
+
+        let mut x = padding_x;
+        if self.loading {
+            root.add_child(self.spinner(x, icon_y, icon_size, &text_color));
+            x += icon_size + gap;
+        } else if let Some(glyph) = &self.leading_icon {
+            root.add_child(icon(glyph, x));
+            x += icon_size + gap;
+        }
+
+        let mut label = Node::default();
+        label.set_layout_rect(Rect::new(
+            x,
+            (height - text_height) / 2.0,
+            text_width,
+            text_height,
+        ));
+        label.set_text(self.text.clone());
+        text_style.apply(label.style_mut());
+        label.style_mut().color = Some(Color::Hex(text_color.clone()));
+        root.add_child(label);
+        x += text_width;
+
+        if let Some(glyph) = &self.trailing_icon {
+            root.add_child(icon(glyph, x + gap));
+        }
+
+        vec![root]
+    }
+}
+
+impl PointerHandler for Button {
+    /// The pointer shades the button as it moves over and presses it. A
+    /// primary press and release over the button clicks it; the press is
+    /// consumed too, so the button keeps capture until the release
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool {
+        match event.kind {
+            PointerEventKind::Enter => {
+                self.hovered = true;
+                return false;
+            }
+            PointerEventKind::Leave => {
+                self.hovered = false;
+                return false;
+            }
+            _ => {}
+        }
+        if !self.is_interactive() || !event.is_primary() {
+            self.pressed = false;
+            return false;
+        }
+        match event.kind {
+            PointerEventKind::Down => {
+                self.pressed = true;
+                true
+            }
+            PointerEventKind::Up => {
+                self.pressed = false;
+                if event.within {
+                    self.click();
+                }
+                true
+            }
+            _ => false,
         }
     }
 }
@@ -93,14 +416,19 @@ impl Component for Button {
         self.id
     }
 
-    fn create(props: Self::Props, _context: Context) -> Self {
+    fn create(props: Self::Props, context: Context) -> Self {
         Self {
-            id: ComponentId::new(),
             text: props.text,
             variant: props.variant.unwrap_or(ButtonVariant::Primary),
             disabled: props.disabled.unwrap_or(false),
             size: props.size.unwrap_or(ButtonSize::Medium),
+            loading: props.loading.unwrap_or(false),
+            full_width: props.full_width.unwrap_or(false),
+            leading_icon: props.leading_icon,
+            trailing_icon: props.trailing_icon,
             on_click: props.on_click,
+            theme: Theme::current(&context),
+            ..Default::default()
         }
     }
 
@@ -109,6 +437,10 @@ impl Component for Button {
         self.variant = props.variant.unwrap_or(self.variant);
         self.disabled = props.disabled.unwrap_or(self.disabled);
         self.size = props.size.unwrap_or(self.size);
+        self.loading = props.loading.unwrap_or(self.loading);
+        self.full_width = props.full_width.unwrap_or(self.full_width);
+        self.leading_icon = props.leading_icon;
+        self.trailing_icon = props.trailing_icon;
         self.on_click = props.on_click;
         Ok(())
     }
@@ -122,8 +454,132 @@ impl Component for Button {
     }
 
     fn render(&self) -> Result<Vec<Node>, ComponentError> {
-        // For now, return an empty Vec since we're not yet using the Node system
-        // In a real implementation, this would return the actual DOM nodes
-        Ok(vec![])
+        Ok(self.render_with(&ApproximateTextMeasure))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::pointer::PointerButton;
+    use crate::style::Transform;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CLICKS: AtomicUsize = AtomicUsize::new(0);
+
+    fn pointer(kind: PointerEventKind) -> PointerEvent {
+        PointerEvent {
+            kind,
+            ..PointerEvent::down((4.0, 4.0), PointerButton::Primary)
+        }
+    }
+
+    fn background(button: &Button) -> Option<Color> {
+        button.render().unwrap()[0].style().background_color.clone()
+    }
+
+    #[test]
+    fn test_padding_and_radius_follow_theme_tokens() {
+        let button = Button {
+            text: "Save".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(button.padding(), (8.0, 16.0));
+
+        let roomy = Button {
+            text: "Save".to_string(),
+            theme: Theme::default().with_base_unit(8.0),
+            ..Default::default()
+        };
+        assert_eq!(roomy.padding(), (16.0, 32.0));
+        let root = &roomy.render().unwrap()[0];
+        assert_eq!(root.style().border_radius.as_ref().unwrap().top_left, 8.0);
+        assert_eq!(root.children()[0].layout_rect().x(), 32.0);
+    }
+
+    #[test]
+    fn test_variant_colors_shade_with_pointer_state() {
+        let theme = Theme::default();
+        let mut danger = Button {
+            variant: ButtonVariant::Danger,
+            ..Default::default()
+        };
+        assert_eq!(
+            background(&danger),
+            Some(Color::Hex(theme.error_color.clone()))
+        );
+
+        danger.handle_pointer_event(&pointer(PointerEventKind::Enter));
+        assert!(danger.is_hovered());
+        let hover = Color::Hex(theme.hover_color(&theme.error_color));
+        assert_eq!(background(&danger), Some(hover.clone()));
+        danger.handle_pointer_event(&pointer(PointerEventKind::Down));
+        assert!(danger.is_pressed());
+        let active = Color::Hex(theme.active_color(&theme.error_color));
+        assert_eq!(background(&danger), Some(active));
+        danger.handle_pointer_event(&pointer(PointerEventKind::Up));
+        assert_eq!(background(&danger), Some(hover));
+
+        // Ghost buttons are transparent until the pointer tints them
+        let mut ghost = Button {
+            variant: ButtonVariant::Ghost,
+            ..Default::default()
+        };
+        assert_eq!(background(&ghost), None);
+        ghost.handle_pointer_event(&pointer(PointerEventKind::Enter));
+        assert!(background(&ghost).is_some());
+
+        ghost.set_focused(true);
+        assert!(ghost.render().unwrap()[0].style().outline.is_some());
+    }
+
+    #[test]
+    fn test_loading_blocks_clicks_and_icons_flank_the_label() {
+        let mut button = Button {
+            text: "Send".to_string(),
+            size: ButtonSize::Large,
+            trailing_icon: Some("→".to_string()),
+            full_width: true,
+            on_click: Some(|| {
+                CLICKS.fetch_add(1, Ordering::SeqCst);
+            }),
+            ..Default::default()
+        };
+        let root = &button.render().unwrap()[0];
+        let [label, icon] = root.children() else {
+            panic!("expected a label and an icon");
+        };
+        assert!(icon.layout_rect().x() > label.layout_rect().max_x());
+        assert_eq!(
+            root.style().layout_style.as_ref().unwrap().width,
+            Dimension::Percent(100.0)
+        );
+        assert_eq!(
+            root.style().border_radius.as_ref().unwrap().top_left,
+            button.theme.radius(RadiusSize::Lg)
+        );
+
+        button.handle_pointer_event(&pointer(PointerEventKind::Down));
+        button.handle_pointer_event(&pointer(PointerEventKind::Up));
+        assert_eq!(CLICKS.load(Ordering::SeqCst), 1);
+
+        // While loading a spinner leads the label and clicks do nothing
+        button.loading = true;
+        let root = &button.render().unwrap()[0];
+        assert_eq!(root.attributes().get("aria-busy").unwrap(), "true");
+        let spinner = &root.children()[0];
+        assert_eq!(spinner.attributes().get("role").unwrap(), "progressbar");
+        assert!(!button.handle_pointer_event(&pointer(PointerEventKind::Down)));
+        button.click();
+        assert_eq!(CLICKS.load(Ordering::SeqCst), 1);
+
+        assert!(button.tick(SPINNER_PERIOD / 4.0));
+        let root = &button.render().unwrap()[0];
+        assert_eq!(
+            root.children()[0].style().transform,
+            Some(Transform::Rotate(90.0))
+        );
+        button.theme.reduced_motion = true;
+        assert!(!button.tick(0.1));
     }
 }
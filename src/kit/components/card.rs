@@ -1,14 +1,43 @@
 // Card component for OrbitKit
 
 use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::events::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::kit::utils::string::parse_px;
+use crate::layout::{EdgeValues, Rect};
+use crate::renderer::{ApproximateTextMeasure, ImageData, ImageFit, TextMeasure};
+use crate::style::{BorderRadius, BoxShadow, Color, EdgeColors, TextAlign};
+
+/// Height of the title row
+const TITLE_HEIGHT: f32 = 24.0;
+
+/// Default height of the media slot
+const DEFAULT_MEDIA_HEIGHT: f32 = 160.0;
 
 /// Card component
+///
+/// With no slots filled the card is a plain container whose size is left
+/// to layout. The slots stack from the top: `media` edge to edge, then the
+/// header's `title` and `action`, the `children` as the body, and the
+/// `footer`. Set `width` to have the card lay them out across it and size
+/// itself to fit.
 #[derive(Debug)]
 pub struct Card {
     /// Component ID for tracking
     id: ComponentId,
     /// Card title
     pub title: Option<String>,
+    /// Action shown at the end of the header, such as a menu glyph
+    pub action: Option<String>,
+    /// Action click handler
+    pub on_action: Option<fn()>,
+    /// Image spanning the top of the card, clipped to its corners
+    pub media: Option<ImageData>,
+    /// Height of the media slot
+    pub media_height: f32,
+    /// Text along the bottom of the card
+    pub footer: Option<String>,
     /// Card elevation (shadow level)
     pub elevation: u8,
     /// Card border radius
@@ -17,15 +46,36 @@ pub struct Card {
     pub bordered: bool,
     /// Card padding
     pub padding: String,
-    /// Child content
+    /// Child content, the card's body
     pub children: Option<String>,
+    /// Width to lay the slots out across
+    pub width: Option<f32>,
+    /// Whether the whole card is clickable; it rises a level under the
+    /// pointer
+    pub clickable: bool,
+    /// Click handler for a clickable card
+    pub on_click: Option<fn()>,
+    /// Whether the pointer is over the card
+    hovered: bool,
+    /// Theme providing the surface, border, and text colors
+    pub theme: Theme,
 }
 
 /// Card props
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CardProps {
     /// Card title
     pub title: Option<String>,
+    /// Action shown at the end of the header
+    pub action: Option<String>,
+    /// Action click handler
+    pub on_action: Option<fn()>,
+    /// Image spanning the top of the card
+    pub media: Option<ImageData>,
+    /// Height of the media slot
+    pub media_height: Option<f32>,
+    /// Text along the bottom of the card
+    pub footer: Option<String>,
     /// Card elevation (shadow level)
     pub elevation: Option<u8>,
     /// Card border radius
@@ -36,6 +86,12 @@ pub struct CardProps {
     pub padding: Option<String>,
     /// Child content
     pub children: Option<String>,
+    /// Width to lay the slots out across
+    pub width: Option<f32>,
+    /// Whether the whole card is clickable
+    pub clickable: Option<bool>,
+    /// Click handler for a clickable card
+    pub on_click: Option<fn()>,
 }
 
 impl Default for Card {
@@ -43,11 +99,126 @@ impl Default for Card {
         Self {
             id: ComponentId::new(),
             title: None,
+            action: None,
+            on_action: None,
+            media: None,
+            media_height: DEFAULT_MEDIA_HEIGHT,
+            footer: None,
             elevation: 1,
             border_radius: "4px".to_string(),
             bordered: false,
             padding: "16px".to_string(),
             children: None,
+            width: None,
+            clickable: false,
+            on_click: None,
+            hovered: false,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Card {
+    /// Elevation the card is drawn at, a level up while a clickable card is
+    /// under the pointer
+    pub fn current_elevation(&self) -> u8 {
+        if self.clickable && self.hovered {
+            self.elevation.saturating_add(1)
+        } else {
+            self.elevation
+        }
+    }
+
+    /// Drop shadow for the card's elevation, or `None` when the card is flat
+    ///
+    /// Renderers draw the shadow with the same corner radius as the card, so
+    /// only the offset, blur, and color come from the elevation.
+    pub fn box_shadow(&self) -> Option<BoxShadow> {
+        let elevation = self.current_elevation();
+        if elevation == 0 {
+            return None;
+        }
+        let level = elevation as f32;
+        Some(BoxShadow::new(
+            0.0,
+            level,
+            level * 3.0,
+            Color::Rgba(0.0, 0.0, 0.0, (0.1 + 0.04 * level).min(0.4)),
+        ))
+    }
+
+    /// Height taken by the media slot
+    fn media_extent(&self) -> f32 {
+        if self.media.is_some() {
+            self.media_height
+        } else {
+            0.0
+        }
+    }
+
+    /// Top-left corner of the content area, below the media, padding, and
+    /// header
+    pub fn content_origin(&self) -> (f32, f32) {
+        let padding = parse_px(&self.padding);
+        let header = if self.title.is_some() || self.action.is_some() {
+            TITLE_HEIGHT + padding / 2.0
+        } else {
+            0.0
+        };
+        (padding, self.media_extent() + padding + header)
+    }
+
+    /// Click the card, as a press and release over it would
+    pub fn click(&mut self) {
+        if !self.clickable {
+            return;
+        }
+        if let Some(on_click) = self.on_click {
+            on_click();
+        }
+    }
+
+    /// Run the header action, as when it is clicked
+    pub fn activate_action(&mut self) {
+        if let Some(on_action) = self.on_action {
+            on_action();
+        }
+    }
+
+    fn text(&self, text: &str, rect: Rect, style: &str, color: &str) -> Node {
+        let mut node = Node::default();
+        node.set_layout_rect(rect);
+        node.set_text(text.to_string());
+        self.theme.text_style(style).apply(node.style_mut());
+        node.style_mut().color = Some(Color::Hex(color.to_string()));
+        node
+    }
+}
+
+impl PointerHandler for Card {
+    /// A clickable card rises under the pointer and clicks on a primary
+    /// press and release over it
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool {
+        if !self.clickable {
+            return false;
+        }
+        match event.kind {
+            PointerEventKind::Enter => {
+                self.hovered = true;
+                false
+            }
+            PointerEventKind::Leave => {
+                self.hovered = false;
+                false
+            }
+            PointerEventKind::Down => event.is_primary(),
+            PointerEventKind::Up if event.is_primary() => {
+                if event.within {
+                    self.click();
+                }
+                true
+            }
+            _ => false,
         }
     }
 }
@@ -59,20 +230,39 @@ impl Component for Card {
         self.id
     }
 
-    fn create(props: Self::Props, _context: Context) -> Self {
+    fn create(props: Self::Props, context: Context) -> Self {
+        let theme = Theme::current(&context);
         Self {
-            id: ComponentId::new(),
             title: props.title,
+            action: props.action,
+            on_action: props.on_action,
+            media: props.media,
+            media_height: props.media_height.unwrap_or(DEFAULT_MEDIA_HEIGHT),
+            footer: props.footer,
             elevation: props.elevation.unwrap_or(1),
-            border_radius: props.border_radius.unwrap_or_else(|| "4px".to_string()),
+            border_radius: props
+                .border_radius
+                .unwrap_or_else(|| format!("{}px", theme.radius(RadiusSize::Md))),
             bordered: props.bordered.unwrap_or(false),
-            padding: props.padding.unwrap_or_else(|| "16px".to_string()),
+            padding: props
+                .padding
+                .unwrap_or_else(|| format!("{}px", theme.spacing(4))),
             children: props.children,
+            width: props.width,
+            clickable: props.clickable.unwrap_or(false),
+            on_click: props.on_click,
+            theme,
+            ..Default::default()
         }
     }
 
     fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
         self.title = props.title;
+        self.action = props.action;
+        self.on_action = props.on_action;
+        self.media = props.media;
+        self.media_height = props.media_height.unwrap_or(self.media_height);
+        self.footer = props.footer;
         self.elevation = props.elevation.unwrap_or(self.elevation);
         self.border_radius = props
             .border_radius
@@ -80,6 +270,9 @@ impl Component for Card {
         self.bordered = props.bordered.unwrap_or(self.bordered);
         self.padding = props.padding.unwrap_or_else(|| self.padding.clone());
         self.children = props.children;
+        self.width = props.width;
+        self.clickable = props.clickable.unwrap_or(self.clickable);
+        self.on_click = props.on_click;
         Ok(())
     }
 
@@ -92,8 +285,178 @@ impl Component for Card {
     }
 
     fn render(&self) -> Result<Vec<Node>, ComponentError> {
-        // For now, return an empty Vec since we're not yet using the Node system
-        // In a real implementation, this would return the actual DOM nodes
-        Ok(vec![])
+        let radius = parse_px(&self.border_radius);
+        let padding = parse_px(&self.padding);
+        let width = self.width.unwrap_or(0.0);
+        let inner_width = (width - 2.0 * padding).max(0.0);
+
+        // Without a width the card's own size is left to layout; callers
+        // that know it can set the root's rect
+        let mut root = Node::default();
+        if self.clickable {
+            root.add_attribute("role".to_string(), "button".to_string());
+            register_focusable(&mut root, false);
+        }
+        let style = root.style_mut();
+        style.background_color = Some(Color::Hex(self.theme.surface_color.clone()));
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        style.box_shadow = self.box_shadow().map(|shadow| vec![shadow]);
+        if self.bordered {
+            let border = Color::Hex(self.theme.border_color.clone());
+            style.border_width = Some(EdgeValues::new(1.0, 1.0, 1.0, 1.0));
+            style.border_color = Some(EdgeColors {
+                top: border.clone(),
+                right: border.clone(),
+                bottom: border.clone(),
+                left: border,
+            });
+        }
+
+        // The media's top corners follow the card's, so the image is
+        // clipped to them
+        if let Some(media) = &self.media {
+            let mut media_node = Node::default();
+            media_node.set_layout_rect(Rect::new(0.0, 0.0, width, self.media_height));
+            media_node.set_image(media.clone());
+            media_node.set_image_fit(ImageFit::Cover);
+            media_node.style_mut().border_radius = Some(BorderRadius {
+                top_left: radius,
+                top_right: radius,
+                bottom_right: 0.0,
+                bottom_left: 0.0,
+            });
+            root.add_child(media_node);
+        }
+
+        let text_color = &self.theme.text_color;
+        let header_y = self.media_extent() + padding;
+        let action_width = self.action.as_ref().map_or(0.0, |action| {
+            let font_size = self.theme.text_style("button").font_size;
+            ApproximateTextMeasure.measure_text(action, font_size).0
+        });
+        if let Some(title) = &self.title {
+            let title_width = (inner_width - action_width).max(0.0);
+            let rect = Rect::new(padding, header_y, title_width, TITLE_HEIGHT);
+            root.add_child(self.text(title, rect, "h5", text_color));
+        }
+        if let Some(action) = &self.action {
+            let x = (width - padding - action_width).max(padding);
+            let rect = Rect::new(x, header_y, action_width, TITLE_HEIGHT);
+            let mut action_node = self.text(action, rect, "button", &self.theme.primary_color);
+            action_node.add_attribute("role".to_string(), "button".to_string());
+            register_focusable(&mut action_node, self.on_action.is_none());
+            root.add_child(action_node);
+        }
+
+        let (x, mut y) = self.content_origin();
+        if let Some(children) = &self.children {
+            let mut content = self.text(children, Rect::new(x, y, 0.0, 0.0), "body", text_color);
+            if self.width.is_some() {
+                let font_size = self.theme.text_style("body").font_size;
+                let (_, height) = ApproximateTextMeasure.measure_text(children, font_size);
+                content.set_layout_rect(Rect::new(x, y, inner_width, height));
+                y += height + padding / 2.0;
+            }
+            root.add_child(content);
+        }
+
+        if let Some(footer) = &self.footer {
+            let height = self.theme.text_style("caption").line_height_px();
+            let rect = Rect::new(padding, y, inner_width, height);
+            let mut footer_node = self.text(footer, rect, "caption", &self.theme.muted_text_color);
+            footer_node.style_mut().text_align = Some(TextAlign::Left);
+            root.add_child(footer_node);
+            y += height + padding / 2.0;
+        }
+
+        if let Some(width) = self.width {
+            root.set_layout_rect(Rect::new(0.0, 0.0, width, y + padding / 2.0));
+        }
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::pointer::PointerButton;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CLICKS: AtomicUsize = AtomicUsize::new(0);
+
+    #[test]
+    fn test_plain_container_without_slots() {
+        let card = Card {
+            children: Some("Hello".to_string()),
+            ..Default::default()
+        };
+        let root = &card.render().unwrap()[0];
+        assert_eq!(root.layout_rect().width(), 0.0);
+        assert_eq!(root.children().len(), 1);
+        assert_eq!(root.children()[0].text(), Some("Hello"));
+        assert_eq!(root.children()[0].layout_rect().y(), 16.0);
+        assert_eq!(root.attributes().get("role"), None);
+    }
+
+    #[test]
+    fn test_slots_stack_below_clipped_media() {
+        let card = Card {
+            title: Some("Lamp".to_string()),
+            action: Some("⋯".to_string()),
+            media: Some(ImageData::new(2, 1, vec![255; 8])),
+            media_height: 120.0,
+            children: Some("A desk lamp".to_string()),
+            footer: Some("$40".to_string()),
+            width: Some(240.0),
+            ..Default::default()
+        };
+        let root = &card.render().unwrap()[0];
+        let [media, title, action, body, footer] = root.children() else {
+            panic!("expected media, header, body, and footer");
+        };
+        assert_eq!(media.image_fit(), ImageFit::Cover);
+        assert_eq!(media.layout_rect().width(), 240.0);
+        let corners = media.style().border_radius.as_ref().unwrap();
+        assert_eq!((corners.top_left, corners.bottom_left), (4.0, 0.0));
+
+        assert_eq!(title.layout_rect().y(), 136.0);
+        assert_eq!(action.layout_rect().max_x(), 224.0);
+        assert_eq!(card.content_origin(), (16.0, body.layout_rect().y()));
+        assert!(footer.layout_rect().y() > body.layout_rect().max_y());
+        assert!(root.layout_rect().height() > footer.layout_rect().max_y());
+    }
+
+    #[test]
+    fn test_clickable_card_rises_on_hover_and_clicks() {
+        let mut card = Card {
+            clickable: true,
+            on_click: Some(|| {
+                CLICKS.fetch_add(1, Ordering::SeqCst);
+            }),
+            ..Default::default()
+        };
+        let event = |kind| PointerEvent {
+            kind,
+            ..PointerEvent::down((4.0, 4.0), PointerButton::Primary)
+        };
+        let resting = card.box_shadow();
+        card.handle_pointer_event(&event(PointerEventKind::Enter));
+        assert_eq!(card.current_elevation(), 2);
+        assert_ne!(card.box_shadow(), resting);
+
+        assert!(card.handle_pointer_event(&event(PointerEventKind::Down)));
+        assert!(card.handle_pointer_event(&event(PointerEventKind::Up)));
+        assert_eq!(CLICKS.load(Ordering::SeqCst), 1);
+        card.handle_pointer_event(&event(PointerEventKind::Leave));
+        assert_eq!(card.current_elevation(), 1);
+
+        let root = &card.render().unwrap()[0];
+        assert_eq!(root.attributes().get("role").unwrap(), "button");
     }
 }
@@ -0,0 +1,319 @@
+// Checkbox component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::kit::theme::Theme;
+use crate::layout::{EdgeValues, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, EdgeColors};
+use std::any::Any;
+
+/// Size of the checkbox square in logical pixels
+const BOX_SIZE: f32 = 16.0;
+
+/// Gap between the box and its label
+const LABEL_GAP: f32 = 8.0;
+
+/// SVG path for the checkmark, in the box's coordinate space
+const CHECKMARK_PATH: &str = "M3 8.5 L4.5 7 L7 9.5 L11.5 5 L13 6.5 L7 12.5 Z";
+
+/// SVG path for the indeterminate dash, in the box's coordinate space
+const DASH_PATH: &str = "M4 7 H12 V9 H4 Z";
+
+/// Checkbox state, including the indeterminate (mixed) state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxState {
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckboxState {
+    /// Value of the `aria-checked` attribute for this state
+    pub fn aria_checked(self) -> &'static str {
+        match self {
+            CheckboxState::Unchecked => "false",
+            CheckboxState::Checked => "true",
+            CheckboxState::Indeterminate => "mixed",
+        }
+    }
+}
+
+/// Checkbox component
+#[derive(Debug)]
+pub struct Checkbox {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Current check state
+    pub state: CheckboxState,
+    /// Label shown next to the box
+    pub label: Option<String>,
+    /// Whether the checkbox is disabled
+    pub disabled: bool,
+    /// Change event handler, called with the new checked value
+    pub on_change: Option<fn(bool)>,
+    /// Theme the box and label are colored with
+    pub theme: Theme,
+}
+
+/// Checkbox props
+#[derive(Debug, Clone)]
+pub struct CheckboxProps {
+    /// Whether the checkbox is checked
+    pub checked: Option<bool>,
+    /// Whether the checkbox shows the indeterminate state; takes precedence
+    /// over `checked`
+    pub indeterminate: Option<bool>,
+    /// Label shown next to the box
+    pub label: Option<String>,
+    /// Whether the checkbox is disabled
+    pub disabled: Option<bool>,
+    /// Change event handler, called with the new checked value
+    pub on_change: Option<fn(bool)>,
+}
+
+impl Default for Checkbox {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            state: CheckboxState::Unchecked,
+            label: None,
+            disabled: false,
+            on_change: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Checkbox {
+    /// Whether the checkbox is checked; indeterminate counts as unchecked
+    pub fn is_checked(&self) -> bool {
+        self.state == CheckboxState::Checked
+    }
+
+    /// Toggle the checkbox in response to a click
+    ///
+    /// An indeterminate checkbox becomes checked. Disabled checkboxes ignore
+    /// clicks.
+    pub fn toggle(&mut self) {
+        if self.disabled {
+            return;
+        }
+        self.state = match self.state {
+            CheckboxState::Checked => CheckboxState::Unchecked,
+            CheckboxState::Unchecked | CheckboxState::Indeterminate => CheckboxState::Checked,
+        };
+        if let Some(on_change) = self.on_change {
+            on_change(self.is_checked());
+        }
+    }
+
+    fn state_from_props(props: &CheckboxProps, current: CheckboxState) -> CheckboxState {
+        if props.indeterminate == Some(true) {
+            return CheckboxState::Indeterminate;
+        }
+        match props.checked {
+            Some(true) => CheckboxState::Checked,
+            Some(false) => CheckboxState::Unchecked,
+            None if current == CheckboxState::Indeterminate => CheckboxState::Unchecked,
+            None => current,
+        }
+    }
+}
+
+impl Component for Checkbox {
+    type Props = CheckboxProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            theme: Theme::current(&context),
+            id: ComponentId::new(),
+            state: Self::state_from_props(&props, CheckboxState::Unchecked),
+            label: props.label,
+            disabled: props.disabled.unwrap_or(false),
+            on_change: props.on_change,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.state = Self::state_from_props(&props, self.state);
+        self.label = props.label;
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.on_change = props.on_change;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(self.render_with(&ApproximateTextMeasure))
+    }
+}
+
+impl Checkbox {
+    /// Render the checkbox, sizing its label with `measure`
+    ///
+    /// The root covers the label as well as the box, so clicking the label
+    /// toggles the checkbox too.
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Vec<Node> {
+        let accent = Color::Hex(if self.disabled {
+            self.theme.disabled_color.clone()
+        } else {
+            self.theme.primary_color.clone()
+        });
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "checkbox".to_string());
+        root.add_attribute(
+            "aria-checked".to_string(),
+            self.state.aria_checked().to_string(),
+        );
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+        register_focusable(&mut root, self.disabled);
+
+        let text_style = self.theme.text_style("body");
+        let label_size = self
+            .label
+            .as_ref()
+            .map(|label| measure.measure_text(label, text_style.font_size));
+        // The box is centered against a label taller than it
+        let height = label_size.map_or(BOX_SIZE, |(_, text_height)| text_height.max(BOX_SIZE));
+
+        let mut check_box = Node::default();
+        check_box.set_layout_rect(Rect::new(
+            0.0,
+            (height - BOX_SIZE) / 2.0,
+            BOX_SIZE,
+            BOX_SIZE,
+        ));
+        let style = check_box.style_mut();
+        style.border_width = Some(EdgeValues::new(1.5, 1.5, 1.5, 1.5));
+        style.border_color = Some(EdgeColors {
+            top: accent.clone(),
+            right: accent.clone(),
+            bottom: accent.clone(),
+            left: accent.clone(),
+        });
+        style.border_radius = Some(BorderRadius {
+            top_left: 3.0,
+            top_right: 3.0,
+            bottom_right: 3.0,
+            bottom_left: 3.0,
+        });
+
+        let mark = match self.state {
+            CheckboxState::Unchecked => None,
+            CheckboxState::Checked => Some(CHECKMARK_PATH),
+            CheckboxState::Indeterminate => Some(DASH_PATH),
+        };
+        if let Some(path) = mark {
+            check_box.style_mut().background_color = Some(accent);
+
+            let mut mark_node = Node::default();
+            mark_node.set_layout_rect(Rect::new(0.0, 0.0, BOX_SIZE, BOX_SIZE));
+            mark_node.add_attribute("path".to_string(), path.to_string());
+            mark_node.style_mut().color = Some(Color::Hex("#ffffff".to_string()));
+            check_box.add_child(mark_node);
+        }
+        root.add_child(check_box);
+
+        let mut width = BOX_SIZE;
+        if let (Some(label), Some((text_width, text_height))) = (&self.label, label_size) {
+            let mut label_node = Node::default();
+            label_node.set_layout_rect(Rect::new(
+                BOX_SIZE + LABEL_GAP,
+                (height - text_height) / 2.0,
+                text_width,
+                text_height,
+            ));
+            label_node.set_text(label.clone());
+            let style = label_node.style_mut();
+            text_style.apply(style);
+            style.color = Some(Color::Hex(self.theme.text_color.clone()));
+            root.add_child(label_node);
+            width += LABEL_GAP + text_width;
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+
+        vec![root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_cycles_state() {
+        let mut checkbox = Checkbox {
+            state: CheckboxState::Indeterminate,
+            ..Default::default()
+        };
+
+        checkbox.toggle();
+        assert_eq!(checkbox.state, CheckboxState::Checked);
+        checkbox.toggle();
+        assert_eq!(checkbox.state, CheckboxState::Unchecked);
+
+        checkbox.disabled = true;
+        checkbox.toggle();
+        assert_eq!(checkbox.state, CheckboxState::Unchecked);
+    }
+
+    #[test]
+    fn test_root_covers_the_label() {
+        let checkbox = Checkbox {
+            label: Some("Accept".to_string()),
+            ..Default::default()
+        };
+        let font_size = checkbox.theme.text_style("body").font_size;
+        let (text_width, _) = ApproximateTextMeasure.measure_text("Accept", font_size);
+
+        let root = &checkbox.render().unwrap()[0];
+        let label = root.children()[1].layout_rect();
+        assert_eq!(label.x(), BOX_SIZE + LABEL_GAP);
+        assert_eq!(label.width(), text_width);
+        assert_eq!(
+            root.layout_rect().width(),
+            BOX_SIZE + LABEL_GAP + text_width
+        );
+        assert!(root.layout_rect().height() >= label.max_y());
+
+        let unlabelled = &Checkbox::default().render().unwrap()[0];
+        assert_eq!(unlabelled.layout_rect().width(), BOX_SIZE);
+    }
+
+    #[test]
+    fn test_render_accessibility_attributes() {
+        let checkbox = Checkbox {
+            state: CheckboxState::Indeterminate,
+            label: Some("Accept".to_string()),
+            ..Default::default()
+        };
+
+        let nodes = checkbox.render().unwrap();
+        let root = &nodes[0];
+        assert_eq!(root.attributes().get("role").unwrap(), "checkbox");
+        assert_eq!(root.attributes().get("aria-checked").unwrap(), "mixed");
+        assert_eq!(root.children().len(), 2);
+        assert_eq!(
+            root.children()[0].children()[0]
+                .attributes()
+                .get("path")
+                .unwrap(),
+            DASH_PATH
+        );
+    }
+}
@@ -0,0 +1,308 @@
+// Divider component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::{AlignItems, Dimension, EdgeValues, LayoutStyle, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::Color;
+use std::any::Any;
+
+/// Default line thickness
+const DEFAULT_THICKNESS: f32 = 1.0;
+
+/// Space between a label and the lines either side of it
+const LABEL_GAP: f32 = 8.0;
+
+/// Which way the divider's line runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DividerOrientation {
+    /// A line across the page, separating stacked content
+    #[default]
+    Horizontal,
+    /// A line down the page, separating side-by-side content
+    Vertical,
+}
+
+/// Divider component, a separating line with an optional centered label
+///
+/// Inside a [`Layout`](super::layout::Layout) a horizontal divider
+/// stretches across a column and a vertical one down a row, unless
+/// `length` fixes it. A labelled divider positions its label and lines
+/// along `length`, so give it one or render it with
+/// [`Divider::render_in`] once its length is known.
+#[derive(Debug)]
+pub struct Divider {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Which way the line runs
+    pub orientation: DividerOrientation,
+    /// Line thickness
+    pub thickness: f32,
+    /// Line color, defaulting to the theme's border color
+    pub color: Option<String>,
+    /// Text centered on the line
+    pub label: Option<String>,
+    /// Space kept clear at each end of the line
+    pub inset: f32,
+    /// Length along the line; stretches to fill the parent when unset
+    pub length: Option<f32>,
+    /// Theme providing the default color and the label's text style
+    pub theme: Theme,
+}
+
+/// Divider props
+#[derive(Debug, Clone, Default)]
+pub struct DividerProps {
+    /// Which way the line runs
+    pub orientation: Option<DividerOrientation>,
+    /// Line thickness
+    pub thickness: Option<f32>,
+    /// Line color
+    pub color: Option<String>,
+    /// Text centered on the line
+    pub label: Option<String>,
+    /// Space kept clear at each end of the line
+    pub inset: Option<f32>,
+    /// Length along the line
+    pub length: Option<f32>,
+}
+
+impl Default for Divider {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            orientation: DividerOrientation::Horizontal,
+            thickness: DEFAULT_THICKNESS,
+            color: None,
+            label: None,
+            inset: 0.0,
+            length: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Divider {
+    /// A divider running the other way
+    pub fn vertical() -> Self {
+        Self {
+            orientation: DividerOrientation::Vertical,
+            ..Default::default()
+        }
+    }
+
+    fn line_color(&self) -> Color {
+        Color::Hex(
+            self.color
+                .clone()
+                .unwrap_or_else(|| self.theme.border_color.clone()),
+        )
+    }
+
+    /// Rect spanning `start..end` along the line, `breadth` across it and
+    /// centered in `across`
+    fn along(&self, start: f32, end: f32, breadth: f32, across: f32) -> Rect {
+        let offset = (across - breadth) / 2.0;
+        let length = (end - start).max(0.0);
+        match self.orientation {
+            DividerOrientation::Horizontal => Rect::new(start, offset, length, breadth),
+            DividerOrientation::Vertical => Rect::new(offset, start, breadth, length),
+        }
+    }
+
+    /// Render the divider `length` long, measuring its label with `measure`
+    pub fn render_in(&self, length: f32, measure: &dyn TextMeasure) -> Node {
+        let horizontal = self.orientation == DividerOrientation::Horizontal;
+        let text_style = self.theme.text_style("caption");
+        let label_size = self
+            .label
+            .as_ref()
+            .map(|label| measure.measure_text(label, text_style.font_size));
+        // How far the divider reaches across its line
+        let across = match label_size {
+            Some((_, height)) if horizontal => height.max(self.thickness),
+            Some((width, _)) => width.max(self.thickness),
+            None => self.thickness,
+        };
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "separator".to_string());
+        let orientation = if horizontal { "horizontal" } else { "vertical" };
+        root.add_attribute("aria-orientation".to_string(), orientation.to_string());
+        let (width, height) = if horizontal {
+            (length, across)
+        } else {
+            (across, length)
+        };
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+
+        let along = self.length.map_or(Dimension::Auto, Dimension::Points);
+        root.style_mut().layout_style = Some(if horizontal {
+            LayoutStyle {
+                width: along,
+                height: Dimension::Points(across),
+                margin: EdgeValues::new(0.0, self.inset, 0.0, self.inset),
+                align_self: Some(AlignItems::Stretch),
+                ..Default::default()
+            }
+        } else {
+            LayoutStyle {
+                width: Dimension::Points(across),
+                height: along,
+                margin: EdgeValues::new(self.inset, 0.0, self.inset, 0.0),
+                align_self: Some(AlignItems::Stretch),
+                ..Default::default()
+            }
+        });
+
+        let (Some(label), Some((label_width, label_height))) = (&self.label, label_size) else {
+            root.style_mut().background_color = Some(self.line_color());
+            return root;
+        };
+
+        // Lines either side of the label, which sits in the middle
+        let label_length = if horizontal {
+            label_width
+        } else {
+            label_height
+        };
+        let label_start = (length - label_length) / 2.0;
+        let label_end = label_start + label_length;
+        for (start, end) in [
+            (0.0, label_start - LABEL_GAP),
+            (label_end + LABEL_GAP, length),
+        ] {
+            let mut line = Node::default();
+            line.set_layout_rect(self.along(start, end, self.thickness, across));
+            line.style_mut().background_color = Some(self.line_color());
+            root.add_child(line);
+        }
+
+        let mut text = Node::default();
+        let label_breadth = if horizontal {
+            label_height
+        } else {
+            label_width
+        };
+        text.set_layout_rect(self.along(label_start, label_end, label_breadth, across));
+        text.set_text(label.clone());
+        text_style.apply(text.style_mut());
+        text.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+        root.add_child(text);
+
+        root
+    }
+}
+
+impl Component for Divider {
+    type Props = DividerProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            orientation: props.orientation.unwrap_or_default(),
+            thickness: props.thickness.unwrap_or(DEFAULT_THICKNESS),
+            color: props.color,
+            label: props.label,
+            inset: props.inset.unwrap_or(0.0),
+            length: props.length,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.orientation = props.orientation.unwrap_or(self.orientation);
+        self.thickness = props.thickness.unwrap_or(self.thickness);
+        self.color = props.color;
+        self.label = props.label;
+        self.inset = props.inset.unwrap_or(self.inset);
+        self.length = props.length;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let length = self.length.unwrap_or(0.0);
+        Ok(vec![self.render_in(length, &ApproximateTextMeasure)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kit::components::layout::{Direction, Layout};
+    use crate::layout::Size;
+
+    fn boxed(width: f32, height: f32) -> Node {
+        let mut node = Node::default();
+        node.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+        node
+    }
+
+    #[test]
+    fn test_divider_spans_cross_axis_with_inset() {
+        let divider = Divider {
+            thickness: 2.0,
+            inset: 16.0,
+            ..Default::default()
+        };
+        let mut column = Layout::default();
+        column.direction = Direction::Column;
+        column.children = vec![
+            boxed(50.0, 40.0),
+            divider.render().unwrap().remove(0),
+            boxed(50.0, 40.0),
+        ];
+        let root = column.render_in(Size::new(300.0, 200.0));
+        let line = root.children()[1].layout_rect();
+        assert_eq!(line, Rect::new(16.0, 40.0, 268.0, 2.0));
+        assert_eq!(root.children()[2].layout_rect().y(), 42.0);
+
+        let mut row = Layout::default();
+        row.direction = Direction::Row;
+        row.children = vec![
+            boxed(50.0, 40.0),
+            Divider::vertical().render().unwrap().remove(0),
+        ];
+        let root = row.render_in(Size::new(300.0, 200.0));
+        assert_eq!(
+            root.children()[1].layout_rect(),
+            Rect::new(50.0, 0.0, 1.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn test_label_is_centered_between_lines() {
+        let divider = Divider {
+            label: Some("or".to_string()),
+            length: Some(200.0),
+            ..Default::default()
+        };
+        let root = &divider.render().unwrap()[0];
+        assert_eq!(root.attributes().get("role").unwrap(), "separator");
+        let children = root.children();
+        let (left, right, label) = (
+            children[0].layout_rect(),
+            children[1].layout_rect(),
+            children[2].layout_rect(),
+        );
+        assert_eq!(children[2].text(), Some("or"));
+        assert!((label.x() + label.width() / 2.0 - 100.0).abs() < 1e-3);
+        assert_eq!(left.x(), 0.0);
+        assert_eq!(left.max_x(), label.x() - LABEL_GAP);
+        assert_eq!(right.x(), label.max_x() + LABEL_GAP);
+        assert_eq!(right.max_x(), 200.0);
+        assert!((left.width() - right.width()).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,538 @@
+// Drawer component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::events::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use crate::kit::components::scroll::ScrollView;
+use crate::kit::theme::Theme;
+use crate::kit::utils::animation::Animation;
+use crate::layout::{Point, Rect};
+use crate::style::{BoxShadow, Color, Overflow, TimingFunction};
+use std::any::Any;
+
+/// Stacking order for overlay drawers, level with modals
+const DRAWER_Z_INDEX: i32 = 2000;
+
+/// Default width, or height for top and bottom drawers
+const DEFAULT_SIZE: f32 = 280.0;
+
+/// Seconds to slide all the way in or out
+const DRAWER_TRANSITION: f32 = 0.25;
+
+/// Share of the drawer's size a swipe towards its edge must travel to
+/// close it; shorter swipes spring back
+const SWIPE_CLOSE_FRACTION: f32 = 0.3;
+
+/// Pointer travel below which a press and release count as a click
+const CLICK_SLOP: f32 = 4.0;
+
+/// Edge of the viewport a drawer slides in from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DrawerSide {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DrawerSide {
+    fn horizontal(self) -> bool {
+        matches!(self, DrawerSide::Left | DrawerSide::Right)
+    }
+
+    /// How far a pointer moving from `from` to `to` travels towards this
+    /// edge
+    fn towards(self, from: (f32, f32), to: (f32, f32)) -> f32 {
+        match self {
+            DrawerSide::Left => from.0 - to.0,
+            DrawerSide::Right => to.0 - from.0,
+            DrawerSide::Top => from.1 - to.1,
+            DrawerSide::Bottom => to.1 - from.1,
+        }
+    }
+}
+
+/// A swipe in progress
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Swipe {
+    /// Where the pointer went down
+    from: (f32, f32),
+    /// How far the panel has been dragged towards its edge
+    distance: f32,
+    /// Whether the pointer has strayed far enough to stop being a click
+    moved: bool,
+}
+
+/// Drawer component, a panel that slides in from an edge of the viewport
+///
+/// An overlay drawer covers the page with a backdrop, like
+/// [`Modal`](super::modal::Modal); clicking the backdrop or swiping the
+/// panel back towards its edge closes it. A `persistent` drawer has no
+/// backdrop and pushes the page aside instead: lay sibling content out in
+/// [`Drawer::main_rect`].
+#[derive(Debug)]
+pub struct Drawer {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Whether the drawer is open; it slides to match on subsequent ticks
+    pub open: bool,
+    /// Edge the drawer slides in from
+    pub side: DrawerSide,
+    /// Width of a left or right drawer, height of a top or bottom one
+    pub size: f32,
+    /// Content of the panel, scrolled when it overflows
+    pub content: Vec<Node>,
+    /// Size of the viewport the drawer slides across
+    pub viewport: (f32, f32),
+    /// Pushes sibling content aside instead of overlaying it
+    pub persistent: bool,
+    /// Close event handler
+    pub on_close: Option<fn()>,
+    /// How far open the drawer is, from 0 to 1
+    slide: Animation,
+    /// Swipe in progress, if any
+    swipe: Option<Swipe>,
+    /// How far the content is scrolled
+    scroll: (f32, f32),
+    /// Theme providing the panel colors and motion preference
+    pub theme: Theme,
+}
+
+/// Drawer props
+#[derive(Debug, Clone, Default)]
+pub struct DrawerProps {
+    /// Whether the drawer is open
+    pub open: Option<bool>,
+    /// Edge the drawer slides in from
+    pub side: Option<DrawerSide>,
+    /// Width or height of the panel
+    pub size: Option<f32>,
+    /// Content of the panel
+    pub content: Option<Vec<Node>>,
+    /// Size of the viewport the drawer slides across
+    pub viewport: Option<(f32, f32)>,
+    /// Pushes sibling content aside instead of overlaying it
+    pub persistent: Option<bool>,
+    /// Close event handler
+    pub on_close: Option<fn()>,
+}
+
+impl Default for Drawer {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            open: false,
+            side: DrawerSide::Left,
+            size: DEFAULT_SIZE,
+            content: Vec::new(),
+            viewport: (800.0, 600.0),
+            persistent: false,
+            on_close: None,
+            slide: Animation::at_rest(0.0, TimingFunction::EaseOut),
+            swipe: None,
+            scroll: (0.0, 0.0),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Drawer {
+    /// A closed drawer on `side`
+    pub fn new(side: DrawerSide) -> Self {
+        Self {
+            side,
+            ..Default::default()
+        }
+    }
+
+    /// Open the drawer; it slides in on subsequent `tick`s
+    pub fn show(&mut self) {
+        self.open = true;
+        self.slide_panel();
+    }
+
+    /// Close the drawer and fire `on_close`; it slides out on subsequent
+    /// `tick`s
+    pub fn close(&mut self) {
+        if !self.open {
+            return;
+        }
+        self.open = false;
+        self.swipe = None;
+        self.slide_panel();
+        if let Some(on_close) = self.on_close {
+            on_close();
+        }
+    }
+
+    /// How far open the drawer is, from 0 to 1, counting any swipe
+    pub fn progress(&self) -> f32 {
+        let dragged = self.swipe.map_or(0.0, |swipe| swipe.distance);
+        (self.slide.value() - dragged / self.size.max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+
+    /// Whether the drawer is still sliding towards `open`
+    pub fn is_animating(&self) -> bool {
+        let target = if self.open { 1.0 } else { 0.0 };
+        !self.slide.is_finished() || self.slide.to != target
+    }
+
+    /// Point the slide at the current state, at a constant speed however
+    /// far the panel has left to go
+    fn slide_panel(&mut self) {
+        let target = if self.open { 1.0 } else { 0.0 };
+        let distance = (target - self.slide.value()).abs();
+        self.slide.retarget(target, DRAWER_TRANSITION * distance);
+        if self.theme.reduced_motion {
+            self.slide.finish();
+        }
+    }
+
+    /// Advance the slide by `dt` seconds; returns whether it is still
+    /// animating
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.slide_panel();
+        self.slide.sample(dt);
+        self.is_animating()
+    }
+
+    /// Width and height of the panel
+    fn panel_size(&self) -> (f32, f32) {
+        if self.side.horizontal() {
+            (self.size, self.viewport.1)
+        } else {
+            (self.viewport.0, self.size)
+        }
+    }
+
+    /// Rectangle of the panel in viewport coordinates, partly off screen
+    /// while it slides
+    pub fn panel_rect(&self) -> Rect {
+        let (width, height) = self.panel_size();
+        let (viewport_width, viewport_height) = self.viewport;
+        let hidden = self.size * (1.0 - self.progress());
+        let (x, y) = match self.side {
+            DrawerSide::Left => (-hidden, 0.0),
+            DrawerSide::Right => (viewport_width - self.size + hidden, 0.0),
+            DrawerSide::Top => (0.0, -hidden),
+            DrawerSide::Bottom => (0.0, viewport_height - self.size + hidden),
+        };
+        Rect::new(x, y, width, height)
+    }
+
+    /// Where sibling content goes: the whole viewport under an overlay
+    /// drawer, and what the panel leaves free beside a persistent one
+    pub fn main_rect(&self) -> Rect {
+        let (viewport_width, viewport_height) = self.viewport;
+        if !self.persistent {
+            return Rect::new(0.0, 0.0, viewport_width, viewport_height);
+        }
+        let shown = self.size * self.progress();
+        match self.side {
+            DrawerSide::Left => Rect::new(shown, 0.0, viewport_width - shown, viewport_height),
+            DrawerSide::Right => Rect::new(0.0, 0.0, viewport_width - shown, viewport_height),
+            DrawerSide::Top => Rect::new(0.0, shown, viewport_width, viewport_height - shown),
+            DrawerSide::Bottom => Rect::new(0.0, 0.0, viewport_width, viewport_height - shown),
+        }
+    }
+
+    /// The scroll view holding the content, sized to the panel
+    fn body(&self) -> ScrollView {
+        let (width, height) = self.panel_size();
+        let mut body = ScrollView::default();
+        body.width = width;
+        body.height = height;
+        body.children = self.content.clone();
+        body.theme = self.theme.clone();
+        body.scroll_to(self.scroll);
+        body
+    }
+
+    /// Handle a wheel event over the panel, returning whether the content
+    /// scrolled
+    pub fn wheel(&mut self, delta: (f32, f32)) -> bool {
+        let mut body = self.body();
+        let scrolled = body.wheel(delta);
+        self.scroll = body.offset();
+        scrolled
+    }
+
+    /// Handle a click at a point in viewport coordinates
+    ///
+    /// Clicks outside the panel land on the backdrop and close an overlay
+    /// drawer.
+    pub fn click(&mut self, point: (f32, f32)) {
+        let inside = self
+            .panel_rect()
+            .contains_point(Point::new(point.0, point.1));
+        if self.open && !inside && !self.persistent {
+            self.close();
+        }
+    }
+
+    /// Start a swipe at `point` in viewport coordinates
+    pub fn press(&mut self, point: (f32, f32)) {
+        if self.open {
+            self.swipe = Some(Swipe {
+                from: point,
+                distance: 0.0,
+                moved: false,
+            });
+        }
+    }
+
+    /// Follow the pointer during a swipe; the panel is dragged along as it
+    /// moves towards the drawer's edge
+    pub fn drag_to(&mut self, point: (f32, f32)) {
+        let size = self.size;
+        let side = self.side;
+        if let Some(swipe) = &mut self.swipe {
+            let (dx, dy) = (point.0 - swipe.from.0, point.1 - swipe.from.1);
+            swipe.moved |= dx.abs().max(dy.abs()) > CLICK_SLOP;
+            swipe.distance = side.towards(swipe.from, point).clamp(0.0, size);
+        }
+    }
+
+    /// End a swipe at `point`, closing the drawer if it was dragged far
+    /// enough and otherwise letting it spring back open
+    ///
+    /// A press and release that barely moved is a click instead.
+    pub fn release(&mut self, point: (f32, f32)) {
+        let Some(swipe) = self.swipe else {
+            return;
+        };
+        if !swipe.moved {
+            self.swipe = None;
+            self.click(point);
+            return;
+        }
+        // Carry on sliding from wherever the swipe left the panel
+        self.slide = Animation::at_rest(self.progress(), TimingFunction::EaseOut);
+        self.swipe = None;
+        if swipe.distance >= self.size * SWIPE_CLOSE_FRACTION {
+            self.close();
+        } else {
+            self.slide_panel();
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Escape closes an open drawer.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if self.open && key == "Escape" {
+            self.close();
+            return true;
+        }
+        false
+    }
+}
+
+impl KeyHandler for Drawer {
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl PointerHandler for Drawer {
+    /// A press starts a swipe that follows the pointer until the release;
+    /// one that doesn't move is a click on the panel or backdrop
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool {
+        match event.kind {
+            PointerEventKind::Down if event.is_primary() && self.open => {
+                self.press(event.local);
+                true
+            }
+            PointerEventKind::Move if self.swipe.is_some() => {
+                self.drag_to(event.local);
+                true
+            }
+            PointerEventKind::Up if self.swipe.is_some() => {
+                self.release(event.local);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Component for Drawer {
+    type Props = DrawerProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut drawer = Self {
+            open: props.open.unwrap_or(false),
+            side: props.side.unwrap_or_default(),
+            size: props.size.unwrap_or(DEFAULT_SIZE),
+            content: props.content.unwrap_or_default(),
+            persistent: props.persistent.unwrap_or(false),
+            on_close: props.on_close,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        drawer.viewport = props.viewport.unwrap_or(drawer.viewport);
+        // A drawer created open starts open rather than sliding in
+        if drawer.open {
+            drawer.slide = Animation::at_rest(1.0, TimingFunction::EaseOut);
+        }
+        drawer
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.open = props.open.unwrap_or(self.open);
+        self.side = props.side.unwrap_or(self.side);
+        self.size = props.size.unwrap_or(self.size);
+        if let Some(content) = props.content {
+            self.content = content;
+            self.scroll = (0.0, 0.0);
+        }
+        self.viewport = props.viewport.unwrap_or(self.viewport);
+        self.persistent = props.persistent.unwrap_or(self.persistent);
+        self.on_close = props.on_close;
+        if !self.open {
+            self.swipe = None;
+        }
+        self.slide_panel();
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let progress = self.progress();
+        if !self.open && progress <= 0.0 {
+            return Ok(vec![]);
+        }
+
+        let mut panel = Node::default();
+        let panel_rect = self.panel_rect();
+        panel.set_layout_rect(panel_rect);
+        let style = panel.style_mut();
+        style.background_color = Some(Color::Hex(self.theme.surface_color.clone()));
+        style.overflow = Some(Overflow::Hidden);
+        panel.add_child(self.body().render()?.remove(0));
+
+        // A persistent drawer sits in the page beside its siblings
+        if self.persistent {
+            panel.add_attribute("role".to_string(), "complementary".to_string());
+            return Ok(vec![panel]);
+        }
+
+        panel.add_attribute("role".to_string(), "dialog".to_string());
+        panel.add_attribute("aria-modal".to_string(), "true".to_string());
+        panel.style_mut().box_shadow = Some(vec![BoxShadow::new(
+            0.0,
+            0.0,
+            16.0,
+            Color::Rgba(0.0, 0.0, 0.0, 0.2),
+        )]);
+
+        let (viewport_width, viewport_height) = self.viewport;
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, viewport_width, viewport_height));
+        root.style_mut().z_index = Some(DRAWER_Z_INDEX);
+
+        // The backdrop fades in and out with the slide
+        let mut backdrop = Node::default();
+        backdrop.set_layout_rect(Rect::new(0.0, 0.0, viewport_width, viewport_height));
+        backdrop.style_mut().background_color = Some(Color::Rgba(0.0, 0.0, 0.0, 0.5 * progress));
+        root.add_child(backdrop);
+        root.add_child(panel);
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CLOSED: AtomicUsize = AtomicUsize::new(0);
+
+    fn opened(side: DrawerSide) -> Drawer {
+        let mut drawer = Drawer::new(side);
+        drawer.show();
+        while drawer.tick(0.05) {}
+        drawer
+    }
+
+    #[test]
+    fn test_slides_in_from_side_and_backdrop_closes() {
+        let mut drawer = Drawer::new(DrawerSide::Right);
+        drawer.on_close = Some(|| {
+            CLOSED.fetch_add(1, Ordering::SeqCst);
+        });
+        assert!(drawer.render().unwrap().is_empty());
+
+        drawer.show();
+        assert!(drawer.tick(DRAWER_TRANSITION / 2.0));
+        let halfway = drawer.panel_rect().x();
+        assert!(halfway > 800.0 - DEFAULT_SIZE && halfway < 800.0);
+        while drawer.tick(0.05) {}
+        assert_eq!(drawer.panel_rect(), Rect::new(520.0, 0.0, 280.0, 600.0));
+
+        // Clicks on the panel don't close it; clicks on the backdrop do
+        drawer.click((600.0, 300.0));
+        assert!(drawer.open);
+        drawer.press((100.0, 300.0));
+        drawer.release((101.0, 300.0));
+        assert!(!drawer.open);
+        assert_eq!(CLOSED.load(Ordering::SeqCst), 1);
+        // The drawer keeps rendering until it has slid away
+        assert!(!drawer.render().unwrap().is_empty());
+        while drawer.tick(0.05) {}
+        assert!(drawer.render().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_swipe_towards_edge_closes() {
+        let mut drawer = opened(DrawerSide::Left);
+        drawer.press((200.0, 300.0));
+        drawer.drag_to((150.0, 300.0));
+        assert!((drawer.panel_rect().x() + 50.0).abs() < 1e-3);
+        drawer.release((150.0, 300.0));
+        assert!(drawer.open);
+        while drawer.tick(0.05) {}
+        assert_eq!(drawer.panel_rect().x(), 0.0);
+
+        drawer.press((200.0, 300.0));
+        drawer.drag_to((100.0, 300.0));
+        drawer.release((100.0, 300.0));
+        assert!(!drawer.open);
+    }
+
+    #[test]
+    fn test_persistent_pushes_content_and_content_scrolls() {
+        let mut drawer = Drawer::new(DrawerSide::Top);
+        drawer.persistent = true;
+        drawer.size = 200.0;
+        let mut tall = Node::default();
+        tall.set_layout_rect(Rect::new(0.0, 0.0, 800.0, 500.0));
+        drawer.content = vec![tall];
+        drawer.theme.reduced_motion = true;
+        drawer.show();
+
+        assert_eq!(drawer.main_rect(), Rect::new(0.0, 200.0, 800.0, 400.0));
+        let nodes = drawer.render().unwrap();
+        assert_eq!(nodes[0].attributes().get("role").unwrap(), "complementary");
+        drawer.click((400.0, 500.0));
+        assert!(drawer.open);
+
+        assert!(drawer.wheel((0.0, 1000.0)));
+        let nodes = drawer.render().unwrap();
+        let body = &nodes[0].children()[0];
+        assert_eq!(body.children()[0].layout_rect().y(), -300.0);
+    }
+}
@@ -0,0 +1,331 @@
+// Image component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use crate::renderer::{ImageData, ImageFit};
+use crate::style::{Color, Filter, Overflow, TextAlign};
+use std::any::Any;
+
+/// Blur radius a low-resolution placeholder is drawn with
+const BLUR_UP_RADIUS: f32 = 16.0;
+
+/// Where an image is in loading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageStatus {
+    /// Not loaded yet; the placeholder shows
+    #[default]
+    Loading,
+    /// Loaded and drawn
+    Loaded,
+    /// Could not be loaded or decoded; the fallback shows
+    Failed,
+}
+
+/// Image component
+///
+/// The image loads lazily: the host asks [`Image::request`] whether to
+/// start fetching `src` once the image is on screen, then reports back
+/// with [`Image::loaded`] or [`Image::failed`]. Until then the
+/// placeholder shows, drawn blurred so a low-resolution thumbnail sharpens
+/// into the full image when it arrives.
+#[derive(Debug)]
+pub struct Image {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Where the image is loaded from
+    pub src: String,
+    /// Text describing the image; empty marks it decorative
+    pub alt: String,
+    /// How the image is scaled into its box
+    pub fit: ImageFit,
+    /// Width of the box; follows the image's aspect ratio when unset
+    pub width: Option<f32>,
+    /// Height of the box; follows the image's aspect ratio when unset
+    pub height: Option<f32>,
+    /// Shown, blurred, while the image loads
+    pub placeholder: Option<ImageData>,
+    /// Shown if the image fails to load
+    pub fallback: Option<ImageData>,
+    /// Where loading has got to
+    status: ImageStatus,
+    /// Whether `src` has been handed to the host to fetch
+    requested: bool,
+    /// The loaded pixels
+    data: Option<ImageData>,
+    /// Theme providing the empty box's colors
+    pub theme: Theme,
+}
+
+/// Image props
+#[derive(Debug, Clone, Default)]
+pub struct ImageProps {
+    /// Where the image is loaded from
+    pub src: String,
+    /// Text describing the image
+    pub alt: Option<String>,
+    /// How the image is scaled into its box
+    pub fit: Option<ImageFit>,
+    /// Width of the box
+    pub width: Option<f32>,
+    /// Height of the box
+    pub height: Option<f32>,
+    /// Shown while the image loads
+    pub placeholder: Option<ImageData>,
+    /// Shown if the image fails to load
+    pub fallback: Option<ImageData>,
+}
+
+impl Default for Image {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            src: String::new(),
+            alt: String::new(),
+            fit: ImageFit::Contain,
+            width: None,
+            height: None,
+            placeholder: None,
+            fallback: None,
+            status: ImageStatus::Loading,
+            requested: false,
+            data: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Image {
+    /// An image loaded from `src`, described by `alt`
+    pub fn new(src: impl Into<String>, alt: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            alt: alt.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Where loading has got to
+    pub fn status(&self) -> ImageStatus {
+        self.status
+    }
+
+    /// The source to start fetching, the first time this is called while
+    /// the image is `in_view`
+    ///
+    /// Images off screen aren't fetched until they scroll into view, and
+    /// each source is only handed out once.
+    pub fn request(&mut self, in_view: bool) -> Option<&str> {
+        if !in_view || self.requested || self.status != ImageStatus::Loading {
+            return None;
+        }
+        self.requested = true;
+        Some(&self.src)
+    }
+
+    /// Report the decoded image; pixels that don't match their dimensions
+    /// count as a failure
+    pub fn loaded(&mut self, data: ImageData) {
+        if data.is_valid() {
+            self.data = Some(data);
+            self.status = ImageStatus::Loaded;
+        } else {
+            self.failed();
+        }
+    }
+
+    /// Report that the image could not be loaded
+    pub fn failed(&mut self) {
+        self.data = None;
+        self.status = ImageStatus::Failed;
+    }
+
+    /// The pixels on show, and whether they are the blurred placeholder
+    fn shown(&self) -> Option<(&ImageData, bool)> {
+        let valid = |image: &&ImageData| image.is_valid();
+        match self.status {
+            ImageStatus::Loaded => self.data.as_ref().map(|data| (data, false)),
+            ImageStatus::Loading => self
+                .placeholder
+                .as_ref()
+                .filter(valid)
+                .map(|data| (data, true)),
+            ImageStatus::Failed => self
+                .fallback
+                .as_ref()
+                .filter(valid)
+                .map(|data| (data, false)),
+        }
+    }
+
+    /// Size of the box
+    ///
+    /// A missing side follows the aspect ratio of the image on show; with
+    /// neither set the box takes the image's own size.
+    pub fn size(&self) -> (f32, f32) {
+        let natural = self
+            .shown()
+            .map(|(image, _)| (image.width as f32, image.height as f32));
+        match (self.width, self.height, natural) {
+            (Some(width), Some(height), _) => (width, height),
+            (Some(width), None, Some((w, h))) => (width, width * h / w),
+            (None, Some(height), Some((w, h))) => (height * w / h, height),
+            (Some(width), None, None) => (width, width),
+            (None, Some(height), None) => (height, height),
+            (None, None, natural) => natural.unwrap_or((0.0, 0.0)),
+        }
+    }
+}
+
+impl Component for Image {
+    type Props = ImageProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            src: props.src,
+            alt: props.alt.unwrap_or_default(),
+            fit: props.fit.unwrap_or(ImageFit::Contain),
+            width: props.width,
+            height: props.height,
+            placeholder: props.placeholder,
+            fallback: props.fallback,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        // A different source starts loading again from scratch
+        if props.src != self.src {
+            self.status = ImageStatus::Loading;
+            self.requested = false;
+            self.data = None;
+        }
+        self.src = props.src;
+        self.alt = props.alt.unwrap_or_default();
+        self.fit = props.fit.unwrap_or(self.fit);
+        self.width = props.width;
+        self.height = props.height;
+        self.placeholder = props.placeholder;
+        self.fallback = props.fallback;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let (width, height) = self.size();
+        let mut root = Node::default();
+        if self.alt.is_empty() {
+            root.add_attribute("aria-hidden".to_string(), "true".to_string());
+        } else {
+            root.add_attribute("role".to_string(), "img".to_string());
+            root.add_attribute("aria-label".to_string(), self.alt.clone());
+        }
+        if self.status == ImageStatus::Loading {
+            root.add_attribute("aria-busy".to_string(), "true".to_string());
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+        root.style_mut().overflow = Some(Overflow::Hidden);
+
+        match self.shown() {
+            Some((image, blurred)) => {
+                root.set_image(image.clone());
+                root.set_image_fit(self.fit);
+                if blurred {
+                    root.style_mut().filter = Some(vec![Filter::Blur(BLUR_UP_RADIUS)]);
+                }
+            }
+            None => {
+                root.style_mut().background_color =
+                    Some(Color::Hex(self.theme.surface_color.clone()));
+                // A broken image says what it was meant to show
+                if self.status == ImageStatus::Failed && !self.alt.is_empty() {
+                    let text_style = self.theme.text_style("caption");
+                    let mut text = Node::default();
+                    let top = ((height - text_style.font_size) / 2.0).max(0.0);
+                    text.set_layout_rect(Rect::new(0.0, top, width, text_style.font_size));
+                    text.set_text(self.alt.clone());
+                    text_style.apply(text.style_mut());
+                    text.style_mut().text_align = Some(TextAlign::Center);
+                    text.style_mut().color = Some(Color::Hex(self.theme.muted_text_color.clone()));
+                    root.add_child(text);
+                }
+            }
+        }
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixels(width: u32, height: u32) -> ImageData {
+        ImageData::new(width, height, vec![255; (width * height * 4) as usize])
+    }
+
+    #[test]
+    fn test_fit_rects() {
+        let bounds = Rect::new(0.0, 0.0, 100.0, 100.0);
+        let (src, dest) = ImageFit::Contain.rects(200, 100, bounds);
+        assert_eq!(src, Rect::new(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(dest, Rect::new(0.0, 25.0, 100.0, 50.0));
+
+        let (src, dest) = ImageFit::Cover.rects(200, 100, bounds);
+        assert_eq!(src, Rect::new(50.0, 0.0, 100.0, 100.0));
+        assert_eq!(dest, bounds);
+
+        let (src, dest) = ImageFit::Fill.rects(200, 100, bounds);
+        assert_eq!(src, Rect::new(0.0, 0.0, 200.0, 100.0));
+        assert_eq!(dest, bounds);
+    }
+
+    #[test]
+    fn test_lazy_load_with_placeholder_and_fallback() {
+        let mut image = Image::new("photo.png", "A photo");
+        image.width = Some(100.0);
+        image.placeholder = Some(pixels(4, 2));
+        image.fallback = Some(pixels(1, 1));
+
+        assert_eq!(image.request(false), None);
+        assert_eq!(image.request(true), Some("photo.png"));
+        assert_eq!(image.request(true), None);
+
+        // The thumbnail shows blurred, and sets the aspect ratio meanwhile
+        let node = &image.render().unwrap()[0];
+        assert_eq!(node.image().unwrap().width, 4);
+        assert_eq!(
+            node.style().filter,
+            Some(vec![Filter::Blur(BLUR_UP_RADIUS)])
+        );
+        assert_eq!(node.layout_rect().height(), 50.0);
+        assert_eq!(node.attributes().get("aria-label").unwrap(), "A photo");
+
+        image.loaded(pixels(8, 8));
+        let node = &image.render().unwrap()[0];
+        assert_eq!(image.status(), ImageStatus::Loaded);
+        assert_eq!(node.image().unwrap().width, 8);
+        assert_eq!(node.style().filter, None);
+        assert_eq!(node.image_fit(), ImageFit::Contain);
+
+        // Corrupt data falls back, and with no fallback the alt text shows
+        image.loaded(ImageData::new(2, 2, vec![0; 4]));
+        assert_eq!(image.status(), ImageStatus::Failed);
+        assert_eq!(image.render().unwrap()[0].image().unwrap().width, 1);
+        image.fallback = None;
+        let node = &image.render().unwrap()[0];
+        assert!(node.image().is_none());
+        assert_eq!(node.children()[0].text(), Some("A photo"));
+    }
+}
@@ -1,6 +1,30 @@
 // Input component for OrbitKit
 
 use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::{EdgeValues, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, EdgeColors};
+
+/// Default field width
+const DEFAULT_WIDTH: f32 = 240.0;
+
+/// Check of an input's value, returning the message to show if it's invalid
+pub type Validator = fn(&str) -> Result<(), String>;
+
+/// Validation state of an input, shown by its border and message colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputState {
+    /// Not validated, or nothing to report
+    #[default]
+    Default,
+    /// The value is invalid; the error message replaces the helper text
+    Error,
+    /// The value passed validation
+    Success,
+}
 
 /// Input component
 #[derive(Debug)]
@@ -19,12 +43,25 @@ pub struct Input {
     pub required: bool,
     /// Input label
     pub label: Option<String>,
-    /// Input error message
-    pub error: Option<String>,
+    /// Validation state
+    pub state: InputState,
+    /// Message shown in place of the helper text in the error state
+    pub error_message: Option<String>,
     /// Input helper text
     pub helper_text: Option<String>,
+    /// Adornment shown before the value, such as a currency symbol
+    pub prefix: Option<String>,
+    /// Adornment shown after the value, such as a unit or a clear icon
+    pub suffix: Option<String>,
     /// On change handler
     pub on_change: Option<fn(String)>,
+    /// Checks the value when the field loses focus; an `Err` puts the field
+    /// in the error state with that message, and `Ok` in the success state
+    pub on_validate: Option<Validator>,
+    /// Field width
+    pub width: f32,
+    /// Theme the field's colors, padding, and corners come from
+    pub theme: Theme,
 }
 
 /// Input props
@@ -42,12 +79,20 @@ pub struct InputProps {
     pub required: Option<bool>,
     /// Input label
     pub label: Option<String>,
-    /// Input error message
-    pub error: Option<String>,
+    /// Validation state
+    pub state: Option<InputState>,
+    /// Message shown in place of the helper text in the error state
+    pub error_message: Option<String>,
     /// Input helper text
     pub helper_text: Option<String>,
+    /// Adornment shown before the value
+    pub prefix: Option<String>,
+    /// Adornment shown after the value
+    pub suffix: Option<String>,
     /// On change handler
     pub on_change: Option<fn(String)>,
+    /// Checks the value when the field loses focus
+    pub on_validate: Option<Validator>,
 }
 
 impl Default for Input {
@@ -60,13 +105,107 @@ impl Default for Input {
             disabled: false,
             required: false,
             label: None,
-            error: None,
+            state: InputState::Default,
+            error_message: None,
             helper_text: None,
+            prefix: None,
+            suffix: None,
             on_change: None,
+            on_validate: None,
+            width: DEFAULT_WIDTH,
+            theme: Theme::default(),
         }
     }
 }
 
+impl Input {
+    /// Vertical and horizontal padding inside the field, from the theme's
+    /// spacing scale
+    pub fn padding(&self) -> (f32, f32) {
+        (self.theme.spacing(2), self.theme.spacing(3))
+    }
+
+    /// Corner radius of the field, from the theme's radius scale
+    pub fn corner_radius(&self) -> f32 {
+        self.theme.radius(RadiusSize::Md)
+    }
+
+    /// Run `on_validate` on the value, as when the field loses focus, and
+    /// move to the state it reports
+    ///
+    /// Returns the validation result, or `None` without a validator.
+    pub fn blur(&mut self) -> Option<Result<(), String>> {
+        let result = self.on_validate?(&self.value);
+        match &result {
+            Ok(()) => self.state = InputState::Success,
+            Err(message) => {
+                self.state = InputState::Error;
+                self.error_message = Some(message.clone());
+            }
+        }
+        Some(result)
+    }
+
+    /// Border color for the validation state
+    fn border_color(&self) -> &str {
+        match self.state {
+            InputState::Default => &self.theme.border_color,
+            InputState::Error => &self.theme.error_color,
+            InputState::Success => &self.theme.success_color,
+        }
+    }
+
+    /// Message under the field and its color; the error message only shows
+    /// in the error state, where it takes the helper text's place
+    fn message(&self) -> Option<(&str, &str)> {
+        let helper = self.helper_text.as_deref();
+        match self.state {
+            InputState::Error => self
+                .error_message
+                .as_deref()
+                .or(helper)
+                .map(|message| (message, self.theme.error_color.as_str())),
+            InputState::Success => helper.map(|helper| (helper, self.theme.success_color.as_str())),
+            InputState::Default => {
+                helper.map(|helper| (helper, self.theme.muted_text_color.as_str()))
+            }
+        }
+    }
+
+    fn caption(&self, text: &str, y: f32, color: &str) -> Node {
+        let style = self.theme.text_style("caption");
+        let mut node = Node::default();
+        node.set_layout_rect(Rect::new(0.0, y, self.width, style.line_height_px()));
+        node.set_text(text.to_string());
+        style.apply(node.style_mut());
+        node.style_mut().color = Some(Color::Hex(color.to_string()));
+        node
+    }
+}
+
+impl KeyHandler for Input {
+    /// Typed characters are appended to the value and Backspace deletes the
+    /// last one; anything else, such as Tab or Escape, bubbles on
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        if self.disabled || !event.is_down() {
+            return false;
+        }
+        if let Some(c) = event.text() {
+            self.value.push(c);
+        } else if event.key == "Backspace" && !event.modifiers.is_command() {
+            if self.value.pop().is_none() {
+                return true;
+            }
+        } else {
+            return false;
+        }
+        if let Some(on_change) = self.on_change {
+            on_change(self.value.clone());
+        }
+        true
+    }
+}
+
 impl Component for Input {
     type Props = InputProps;
 
@@ -74,7 +213,7 @@ impl Component for Input {
         self.id
     }
 
-    fn create(props: Self::Props, _context: Context) -> Self {
+    fn create(props: Self::Props, context: Context) -> Self {
         Self {
             id: ComponentId::new(),
             input_type: props.input_type.unwrap_or_else(|| "text".to_string()),
@@ -83,9 +222,15 @@ impl Component for Input {
             disabled: props.disabled.unwrap_or(false),
             required: props.required.unwrap_or(false),
             label: props.label,
-            error: props.error,
+            state: props.state.unwrap_or_default(),
+            error_message: props.error_message,
             helper_text: props.helper_text,
+            prefix: props.prefix,
+            suffix: props.suffix,
             on_change: props.on_change,
+            on_validate: props.on_validate,
+            width: DEFAULT_WIDTH,
+            theme: Theme::current(&context),
         }
     }
 
@@ -96,9 +241,13 @@ impl Component for Input {
         self.disabled = props.disabled.unwrap_or(self.disabled);
         self.required = props.required.unwrap_or(self.required);
         self.label = props.label;
-        self.error = props.error;
+        self.state = props.state.unwrap_or(self.state);
+        self.error_message = props.error_message;
         self.helper_text = props.helper_text;
+        self.prefix = props.prefix;
+        self.suffix = props.suffix;
         self.on_change = props.on_change;
+        self.on_validate = props.on_validate;
         Ok(())
     }
 
@@ -111,8 +260,197 @@ impl Component for Input {
     }
 
     fn render(&self) -> Result<Vec<Node>, ComponentError> {
-        // For now, return an empty Vec since we're not yet using the Node system
-        // In a real implementation, this would return the actual DOM nodes
-        Ok(vec![])
+        let (padding_y, padding_x) = self.padding();
+        let gap = self.theme.spacing(1);
+        let body = self.theme.text_style("body");
+        let caption_height = self.theme.text_style("caption").line_height_px();
+        let mut root = Node::default();
+        let mut y = 0.0;
+
+        if let Some(label) = &self.label {
+            root.add_child(self.caption(label, y, &self.theme.text_color));
+            y += caption_height + gap;
+        }
+
+        let height = body.line_height_px() + 2.0 * padding_y;
+        let mut field = Node::default();
+        field.add_attribute("role".to_string(), "textbox".to_string());
+        if self.required {
+            field.add_attribute("aria-required".to_string(), "true".to_string());
+        }
+        if self.disabled {
+            field.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+        register_focusable(&mut field, self.disabled);
+        if self.state == InputState::Error {
+            field.add_attribute("aria-invalid".to_string(), "true".to_string());
+        }
+        field.set_layout_rect(Rect::new(0.0, y, self.width, height));
+        let border = Color::Hex(self.border_color().to_string());
+        let radius = self.corner_radius();
+        let style = field.style_mut();
+        style.background_color = Some(Color::Hex(if self.disabled {
+            self.theme.disabled_color.clone()
+        } else {
+            self.theme.surface_color.clone()
+        }));
+        style.border_width = Some(EdgeValues::uniform(1.0));
+        style.border_color = Some(EdgeColors {
+            top: border.clone(),
+            right: border.clone(),
+            bottom: border.clone(),
+            left: border,
+        });
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+
+        let (text, color) = match (&self.placeholder, self.value.is_empty()) {
+            (Some(placeholder), true) => (placeholder.clone(), &self.theme.muted_text_color),
+            _ if self.input_type == "password" => (
+                "•".repeat(self.value.chars().count()),
+                &self.theme.text_color,
+            ),
+            _ => (self.value.clone(), &self.theme.text_color),
+        };
+        // Adornments take their room out of the value's, at either end
+        let adornment = |text: &str, x: f32| {
+            let (width, _) = ApproximateTextMeasure.measure_text(text, body.font_size);
+            let mut node = Node::default();
+            node.set_layout_rect(Rect::new(x, padding_y, width, body.line_height_px()));
+            node.set_text(text.to_string());
+            body.apply(node.style_mut());
+            node.style_mut().color = Some(Color::Hex(self.theme.muted_text_color.clone()));
+            node
+        };
+        let mut start = padding_x;
+        let mut end = self.width - padding_x;
+        if let Some(prefix) = &self.prefix {
+            let node = adornment(prefix, start);
+            start = node.layout_rect().max_x() + gap;
+            field.add_child(node);
+        }
+        if let Some(suffix) = &self.suffix {
+            let mut node = adornment(suffix, 0.0);
+            let width = node.layout_rect().width();
+            end -= width;
+            node.set_layout_rect(Rect::new(end, padding_y, width, body.line_height_px()));
+            end -= gap;
+            field.add_child(node);
+        }
+
+        let mut value = Node::default();
+        value.set_layout_rect(Rect::new(
+            start,
+            padding_y,
+            (end - start).max(0.0),
+            body.line_height_px(),
+        ));
+        value.set_text(text);
+        body.apply(value.style_mut());
+        value.style_mut().color = Some(Color::Hex(color.clone()));
+        field.add_child(value);
+        root.add_child(field);
+        y += height;
+
+        if let Some((message, color)) = self.message() {
+            y += gap;
+            root.add_child(self.caption(message, y, color));
+            y += caption_height;
+        }
+
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, y));
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_uses_theme_spacing_and_error_color() {
+        let input = Input {
+            placeholder: Some("Email".to_string()),
+            state: InputState::Error,
+            error_message: Some("Required".to_string()),
+            theme: Theme::default().with_base_unit(2.0),
+            ..Default::default()
+        };
+        assert_eq!(input.padding(), (4.0, 6.0));
+
+        let root = &input.render().unwrap()[0];
+        let field = &root.children()[0];
+        assert_eq!(field.attributes().get("aria-invalid").unwrap(), "true");
+        assert_eq!(field.style().border_radius.as_ref().unwrap().top_left, 2.0);
+        assert_eq!(field.children()[0].text(), Some("Email"));
+        assert_eq!(root.children()[1].text(), Some("Required"));
+    }
+
+    #[test]
+    fn test_validation_on_blur_sets_state_and_message() {
+        let mut input = Input {
+            value: "12".to_string(),
+            helper_text: Some("In dollars".to_string()),
+            prefix: Some("$".to_string()),
+            suffix: Some("×".to_string()),
+            on_validate: Some(|value| {
+                if value.parse::<u32>().is_ok() {
+                    Ok(())
+                } else {
+                    Err("Enter a whole number".to_string())
+                }
+            }),
+            ..Default::default()
+        };
+        let caption = |input: &Input| {
+            let root = &input.render().unwrap()[0];
+            root.children()[1].text().map(str::to_string)
+        };
+        assert_eq!(caption(&input).as_deref(), Some("In dollars"));
+
+        assert_eq!(input.blur(), Some(Ok(())));
+        assert_eq!(input.state, InputState::Success);
+        let root = &input.render().unwrap()[0];
+        let field = &root.children()[0];
+        let success = Color::Hex(input.theme.success_color.clone());
+        assert_eq!(field.style().border_color.as_ref().unwrap().top, success);
+
+        // The adornments sit either side of the value
+        let [prefix, suffix, value] = field.children() else {
+            panic!("expected a prefix, a suffix, and the value");
+        };
+        assert_eq!(prefix.text(), Some("$"));
+        assert_eq!(suffix.text(), Some("×"));
+        assert!(value.layout_rect().x() > prefix.layout_rect().max_x());
+        assert!(value.layout_rect().max_x() < suffix.layout_rect().x());
+
+        // An error replaces the helper text
+        input.value.push('x');
+        assert!(input.blur().unwrap().is_err());
+        assert_eq!(input.state, InputState::Error);
+        assert_eq!(caption(&input).as_deref(), Some("Enter a whole number"));
+        let root = &input.render().unwrap()[0];
+        let invalid = root.children()[0].attributes().get("aria-invalid");
+        assert_eq!(invalid.map(String::as_str), Some("true"));
+        assert_eq!(Input::default().blur(), None);
+    }
+
+    #[test]
+    fn test_key_events_edit_the_value() {
+        let mut input = Input::default();
+        for key in ["h", "i", "!", "Backspace"] {
+            assert!(input.handle_key_event(&KeyEvent::down(key)));
+        }
+        assert_eq!(input.value, "hi");
+
+        assert!(!input.handle_key_event(&KeyEvent::down("Escape")));
+        assert!(!input.handle_key_event(&KeyEvent::up("x")));
+        input.disabled = true;
+        assert!(!input.handle_key_event(&KeyEvent::down("x")));
+        assert_eq!(input.value, "hi");
     }
 }
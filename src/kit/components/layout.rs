@@ -1,8 +1,21 @@
 // Layout components for OrbitKit
 
 use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::responsive::Responsive;
+use crate::kit::theme::Theme;
+use crate::kit::utils::string::parse_px;
+use crate::layout::{
+    AlignItems, Dimension, EdgeValues, FlexDirection, FlexWrap, Gap, GridPlacement, JustifyContent,
+    LayoutEngine, LayoutNode, LayoutStyle, PositionType, Rect, Size,
+};
 use std::any::Any;
 
+/// Default container width
+const DEFAULT_WIDTH: f32 = 800.0;
+
+/// Default container height
+const DEFAULT_HEIGHT: f32 = 600.0;
+
 /// Layout component
 #[derive(Debug)]
 pub struct Layout {
@@ -10,6 +23,9 @@ pub struct Layout {
     id: ComponentId,
     /// Layout direction (row, column)
     pub direction: Direction,
+    /// Direction by viewport width, overriding `direction` when set, for
+    /// layouts that stack on narrow screens and sit side by side on wide ones
+    pub responsive_direction: Option<Responsive<Direction>>,
     /// Layout alignment
     pub align: Alignment,
     /// Layout justification
@@ -18,8 +34,22 @@ pub struct Layout {
     pub gap: String,
     /// Layout padding
     pub padding: String,
-    /// Child content
-    pub children: Option<String>,
+    /// Whether children that don't fit wrap onto further lines
+    pub wrap: bool,
+    /// Container width children are laid out in
+    pub width: f32,
+    /// Container height children are laid out in
+    pub height: f32,
+    /// Grid tracks to place children in instead of flowing them as a flex
+    /// line; direction, alignment, justification, gap, and wrap are then
+    /// ignored
+    pub grid: Option<Grid>,
+    /// Children, each sized by its own `layout_style` (for flex grow,
+    /// shrink, and basis, or grid placement) or, without one, by its
+    /// current layout rect
+    pub children: Vec<Node>,
+    /// Theme whose breakpoints `responsive_direction` resolves against
+    pub theme: Theme,
 }
 
 /// Layout props
@@ -27,6 +57,8 @@ pub struct Layout {
 pub struct LayoutProps {
     /// Layout direction (row, column)
     pub direction: Option<Direction>,
+    /// Direction by viewport width
+    pub responsive_direction: Option<Responsive<Direction>>,
     /// Layout alignment
     pub align: Option<Alignment>,
     /// Layout justification
@@ -35,8 +67,16 @@ pub struct LayoutProps {
     pub gap: Option<String>,
     /// Layout padding
     pub padding: Option<String>,
-    /// Child content
-    pub children: Option<String>,
+    /// Whether children that don't fit wrap onto further lines
+    pub wrap: Option<bool>,
+    /// Container width
+    pub width: Option<f32>,
+    /// Container height
+    pub height: Option<f32>,
+    /// Grid tracks, switching the layout to grid mode
+    pub grid: Option<Grid>,
+    /// Children to lay out
+    pub children: Vec<Node>,
 }
 
 /// Layout direction
@@ -49,6 +89,75 @@ pub enum Direction {
     Column,
 }
 
+/// Size of one grid column or row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridTrack {
+    /// A fixed number of pixels
+    Px(f32),
+    /// A share of whatever space the fixed and auto tracks leave over
+    Fr(f32),
+    /// As large as the largest item sitting only in this track
+    Auto,
+}
+
+impl GridTrack {
+    /// Parse a space-separated track list such as `"200px 1fr auto"`,
+    /// returning `None` if any track is malformed
+    pub fn parse_list(template: &str) -> Option<Vec<GridTrack>> {
+        template
+            .split_whitespace()
+            .map(|track| {
+                if track == "auto" {
+                    Some(GridTrack::Auto)
+                } else if let Some(fr) = track.strip_suffix("fr") {
+                    fr.parse()
+                        .ok()
+                        .filter(|fr: &f32| *fr >= 0.0)
+                        .map(GridTrack::Fr)
+                } else {
+                    track
+                        .strip_suffix("px")
+                        .unwrap_or(track)
+                        .parse()
+                        .ok()
+                        .filter(|px: &f32| *px >= 0.0)
+                        .map(GridTrack::Px)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Grid container settings
+///
+/// Rows beyond `grid_template_rows` are added as `Auto` tracks as items
+/// need them. Without any columns the grid is a single `1fr` column.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Grid {
+    pub grid_template_columns: Vec<GridTrack>,
+    pub grid_template_rows: Vec<GridTrack>,
+    pub column_gap: f32,
+    pub row_gap: f32,
+}
+
+impl Grid {
+    /// A grid with `columns` tracks, such as `"1fr 2fr 1fr"`; malformed
+    /// templates give the single-column grid
+    pub fn columns(columns: &str) -> Self {
+        Self {
+            grid_template_columns: GridTrack::parse_list(columns).unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the gap between both columns and rows
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.column_gap = gap;
+        self.row_gap = gap;
+        self
+    }
+}
+
 /// Layout alignment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Alignment {
@@ -86,11 +195,497 @@ impl Default for Layout {
         Self {
             id: ComponentId::new(),
             direction: Direction::default(),
+            responsive_direction: None,
             align: Alignment::default(),
             justify: Justification::default(),
             gap: "0px".to_string(),
             padding: "0px".to_string(),
-            children: None,
+            wrap: false,
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            grid: None,
+            children: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Layout {
+    /// The direction to lay children out in on a viewport `width` pixels
+    /// wide
+    pub fn direction_at(&self, width: f32) -> Direction {
+        match &self.responsive_direction {
+            Some(direction) => *direction.resolve(width, &self.theme.breakpoints),
+            None => self.direction,
+        }
+    }
+
+    /// Lay the children out in a container of `size` with the flexbox
+    /// engine, returning the container node with every child's rect set
+    ///
+    /// Children that still overflow the content box, because they can't
+    /// shrink and don't wrap, are clamped to it. With a `grid` set, children
+    /// are placed in grid cells instead. If the layout fails, the error is
+    /// logged and the children keep the rects they came with.
+    pub fn render_in(&self, size: Size) -> Node {
+        if let Some(grid) = &self.grid {
+            return self.render_grid(grid, size);
+        }
+        let padding = parse_px(&self.padding);
+        let mut container = LayoutNode::new(
+            self.id,
+            LayoutStyle {
+                flex_direction: match self.direction_at(size.width) {
+                    Direction::Row => FlexDirection::Row,
+                    Direction::Column => FlexDirection::Column,
+                },
+                flex_wrap: if self.wrap {
+                    FlexWrap::Wrap
+                } else {
+                    FlexWrap::NoWrap
+                },
+                justify_content: self.justify.into(),
+                align_items: self.align.into(),
+                gap: Gap::uniform(parse_px(&self.gap)),
+                padding: EdgeValues::uniform(padding),
+                width: Dimension::Points(size.width),
+                height: Dimension::Points(size.height),
+                ..Default::default()
+            },
+        );
+        for child in &self.children {
+            let rect = child.layout_rect();
+            let style = child.style().layout_style.clone().unwrap_or(LayoutStyle {
+                width: Dimension::Points(rect.width()),
+                height: Dimension::Points(rect.height()),
+                ..Default::default()
+            });
+            container.add_child(LayoutNode::new(ComponentId::new(), style));
+        }
+
+        let mut engine = LayoutEngine::new();
+        // Children keep the rects they came with if the layout fails
+        let laid_out = match engine.calculate_layout(&mut container, size) {
+            Ok(()) => true,
+            Err(error) => {
+                log::warn!("Layout failed, keeping child rects: {error}");
+                false
+            }
+        };
+        let content = container.layout.content_rect;
+
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, size.width, size.height));
+        for (child, layout) in self.children.iter().zip(&container.children) {
+            let mut child = child.clone();
+            if laid_out {
+                let rect = layout.layout.rect;
+                // Padding wider than the container leaves no content area
+                let x = rect
+                    .x()
+                    .max(content.x())
+                    .min(content.max_x().max(content.x()));
+                let y = rect
+                    .y()
+                    .max(content.y())
+                    .min(content.max_y().max(content.y()));
+                child.set_layout_rect(Rect::new(
+                    x,
+                    y,
+                    rect.width().min(content.max_x() - x).max(0.0),
+                    rect.height().min(content.max_y() - y).max(0.0),
+                ));
+            }
+            root.add_child(child);
+        }
+        root
+    }
+
+    /// Place the children in `grid` cells, each stretched to cover the
+    /// tracks it spans
+    fn render_grid(&self, grid: &Grid, size: Size) -> Node {
+        let padding = parse_px(&self.padding);
+        let columns = if grid.grid_template_columns.is_empty() {
+            vec![GridTrack::Fr(1.0)]
+        } else {
+            grid.grid_template_columns.clone()
+        };
+        // Undisplayed children take no cell
+        let shown: Vec<&Node> = self
+            .children
+            .iter()
+            .filter(|child| child.is_displayed())
+            .collect();
+        let placements = place_grid_items(&shown, columns.len());
+
+        let row_count = placements
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or(0)
+            .max(grid.grid_template_rows.len());
+        let mut rows = grid.grid_template_rows.clone();
+        rows.resize(row_count, GridTrack::Auto);
+
+        let mut column_content = vec![0.0_f32; columns.len()];
+        let mut row_content = vec![0.0_f32; rows.len()];
+        for (child, cell) in shown.iter().zip(&placements) {
+            let natural = natural_size(child);
+            if cell.column_span == 1 {
+                column_content[cell.column] = column_content[cell.column].max(natural.width);
+            }
+            if cell.row_span == 1 {
+                row_content[cell.row] = row_content[cell.row].max(natural.height);
+            }
+        }
+
+        let column_tracks = resolve_tracks(
+            &columns,
+            size.width - 2.0 * padding,
+            grid.column_gap,
+            &column_content,
+        );
+        let row_tracks = resolve_tracks(
+            &rows,
+            size.height - 2.0 * padding,
+            grid.row_gap,
+            &row_content,
+        );
+        let span = |tracks: &[(f32, f32)], start: usize, count: usize| {
+            let (offset, _) = tracks[start];
+            let (last_offset, last_size) = tracks[start + count - 1];
+            (padding + offset, last_offset + last_size - offset)
+        };
+
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, size.width, size.height));
+        let mut placements = placements.iter();
+        for child in &self.children {
+            let mut child = child.clone();
+            if child.is_displayed() {
+                let cell = placements.next().expect("a cell for every shown child");
+                let (x, width) = span(&column_tracks, cell.column, cell.column_span);
+                let (y, height) = span(&row_tracks, cell.row, cell.row_span);
+                child.set_layout_rect(Rect::new(x, y, width, height));
+            } else {
+                child.set_layout_rect(Rect::zero());
+            }
+            root.add_child(child);
+        }
+        root
+    }
+}
+
+/// Row of children `spacing` apart, a preset over [`Layout`]
+#[derive(Debug, Clone, Default)]
+pub struct HStack {
+    /// Gap between neighbouring children
+    pub spacing: f32,
+    /// Vertical alignment of the children
+    pub align: Alignment,
+    /// Children, sized as for [`Layout`]
+    pub children: Vec<Node>,
+}
+
+impl HStack {
+    /// A row of `children` `spacing` apart
+    pub fn new(spacing: f32, children: Vec<Node>) -> Self {
+        Self {
+            spacing,
+            children,
+            ..Default::default()
+        }
+    }
+
+    /// Lay the children out in a container of `size`
+    pub fn render_in(&self, size: Size) -> Node {
+        stack(Direction::Row, self.spacing, self.align, &self.children).render_in(size)
+    }
+}
+
+/// Column of children `spacing` apart, a preset over [`Layout`]
+#[derive(Debug, Clone, Default)]
+pub struct VStack {
+    /// Gap between neighbouring children
+    pub spacing: f32,
+    /// Horizontal alignment of the children
+    pub align: Alignment,
+    /// Children, sized as for [`Layout`]
+    pub children: Vec<Node>,
+}
+
+impl VStack {
+    /// A column of `children` `spacing` apart
+    pub fn new(spacing: f32, children: Vec<Node>) -> Self {
+        Self {
+            spacing,
+            children,
+            ..Default::default()
+        }
+    }
+
+    /// Lay the children out in a container of `size`
+    pub fn render_in(&self, size: Size) -> Node {
+        stack(Direction::Column, self.spacing, self.align, &self.children).render_in(size)
+    }
+}
+
+/// Children laid over one another, later ones on top, each absolutely
+/// positioned by `align` on both axes
+#[derive(Debug, Clone, Default)]
+pub struct ZStack {
+    /// Where each child sits in the stack; `Stretch` fills it
+    pub align: Alignment,
+    /// Children, sized as for [`Layout`]
+    pub children: Vec<Node>,
+}
+
+impl ZStack {
+    /// `children` laid over one another
+    pub fn new(children: Vec<Node>) -> Self {
+        Self {
+            children,
+            ..Default::default()
+        }
+    }
+
+    /// Lay the children out in a container of `size`
+    pub fn render_in(&self, size: Size) -> Node {
+        let place = |space: f32, length: f32| match self.align {
+            Alignment::Start | Alignment::Stretch => 0.0,
+            Alignment::Center => (space - length) / 2.0,
+            Alignment::End => space - length,
+        };
+        let children = self
+            .children
+            .iter()
+            .map(|child| {
+                let natural = natural_size(child);
+                let mut style = child.style().layout_style.clone().unwrap_or(LayoutStyle {
+                    width: Dimension::Points(natural.width),
+                    height: Dimension::Points(natural.height),
+                    ..Default::default()
+                });
+                style.position_type = PositionType::Absolute;
+                if self.align == Alignment::Stretch {
+                    // Insets on every side stretch the child between them
+                    style.width = Dimension::Auto;
+                    style.height = Dimension::Auto;
+                    style.right = Dimension::Points(0.0);
+                    style.bottom = Dimension::Points(0.0);
+                }
+                style.left = Dimension::Points(place(size.width, natural.width));
+                style.top = Dimension::Points(place(size.height, natural.height));
+                let mut child = child.clone();
+                child.style_mut().layout_style = Some(style);
+                child
+            })
+            .collect();
+        Layout {
+            children,
+            ..Default::default()
+        }
+        .render_in(size)
+    }
+}
+
+/// Space that grows to fill whatever its stack's other children leave,
+/// pushing them apart
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Spacer {
+    /// Length the spacer never shrinks below
+    pub min_length: f32,
+}
+
+impl From<Spacer> for Node {
+    fn from(spacer: Spacer) -> Self {
+        let mut node = Node::default();
+        node.add_attribute("aria-hidden".to_string(), "true".to_string());
+        node.style_mut().layout_style = Some(LayoutStyle {
+            flex_grow: 1.0,
+            flex_shrink: 0.0,
+            flex_basis: Dimension::Points(spacer.min_length),
+            ..Default::default()
+        });
+        node
+    }
+}
+
+/// A flex line of `children` along `direction`, `spacing` apart
+fn stack(direction: Direction, spacing: f32, align: Alignment, children: &[Node]) -> Layout {
+    Layout {
+        direction,
+        align,
+        gap: format!("{spacing}px"),
+        children: children.to_vec(),
+        ..Default::default()
+    }
+}
+
+/// The cells a grid item was placed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GridCell {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+/// Place `children` on a grid `column_count` tracks wide
+///
+/// Items with both a column and a row start go exactly there. The rest are
+/// auto-placed, left to right and top to bottom, into the first cells they
+/// fit without overlapping anything already placed; items with no start on
+/// either axis never back-fill holes left behind earlier auto-placed items.
+fn place_grid_items(children: &[&Node], column_count: usize) -> Vec<GridCell> {
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut cursor = (0, 0);
+    let mut cells = Vec::with_capacity(children.len());
+
+    let fits = |occupied: &[Vec<bool>], cell: &GridCell| {
+        (cell.row..cell.row + cell.row_span).all(|row| {
+            occupied.get(row).is_none_or(|line| {
+                line[cell.column..cell.column + cell.column_span]
+                    .iter()
+                    .all(|taken| !taken)
+            })
+        })
+    };
+
+    for child in children {
+        let (column_placement, row_placement) = match &child.style().layout_style {
+            Some(style) => (style.grid_column, style.grid_row),
+            None => (GridPlacement::auto(), GridPlacement::auto()),
+        };
+        let column_span = column_placement.span.clamp(1, column_count);
+        let last_column = column_count - column_span;
+        let mut cell = GridCell {
+            column: 0,
+            row: 0,
+            column_span,
+            row_span: row_placement.span.max(1),
+        };
+
+        match (column_placement.start, row_placement.start) {
+            (Some(column), Some(row)) => {
+                cell.column = column.min(last_column);
+                cell.row = row;
+            }
+            (Some(column), None) => {
+                cell.column = column.min(last_column);
+                while !fits(&occupied, &cell) {
+                    cell.row += 1;
+                }
+            }
+            (None, Some(row)) => {
+                cell.row = row;
+                cell.column = (0..=last_column)
+                    .find(|&column| fits(&occupied, &GridCell { column, ..cell }))
+                    .unwrap_or(0);
+            }
+            (None, None) => {
+                (cell.row, cell.column) = cursor;
+                loop {
+                    if cell.column > last_column {
+                        cell.row += 1;
+                        cell.column = 0;
+                    } else if fits(&occupied, &cell) {
+                        break;
+                    } else {
+                        cell.column += 1;
+                    }
+                }
+                cursor = (cell.row, cell.column + cell.column_span);
+            }
+        }
+
+        if occupied.len() < cell.row + cell.row_span {
+            occupied.resize(cell.row + cell.row_span, vec![false; column_count]);
+        }
+        for line in &mut occupied[cell.row..cell.row + cell.row_span] {
+            line[cell.column..cell.column + cell.column_span].fill(true);
+        }
+        cells.push(cell);
+    }
+    cells
+}
+
+/// Resolve `tracks` against `available` pixels, returning each track's
+/// offset and size
+///
+/// `Auto` tracks take their `content` size, and `Fr` tracks share out what
+/// is left after fixed and auto tracks and the gaps between them.
+fn resolve_tracks(
+    tracks: &[GridTrack],
+    available: f32,
+    gap: f32,
+    content: &[f32],
+) -> Vec<(f32, f32)> {
+    let gaps = gap * tracks.len().saturating_sub(1) as f32;
+    let mut fixed = 0.0;
+    let mut fractions = 0.0;
+    for (track, content) in tracks.iter().zip(content) {
+        match track {
+            GridTrack::Px(px) => fixed += px,
+            GridTrack::Auto => fixed += content,
+            GridTrack::Fr(fr) => fractions += fr,
+        }
+    }
+    let free = (available - fixed - gaps).max(0.0);
+
+    let mut offset = 0.0;
+    tracks
+        .iter()
+        .zip(content)
+        .map(|(track, content)| {
+            let size = match track {
+                GridTrack::Px(px) => *px,
+                GridTrack::Auto => *content,
+                GridTrack::Fr(fr) if fractions > 0.0 => free * fr / fractions,
+                GridTrack::Fr(_) => 0.0,
+            };
+            let track = (offset, size);
+            offset += size + gap;
+            track
+        })
+        .collect()
+}
+
+/// The size a child asks for: fixed `layout_style` dimensions where it has
+/// them, and its current layout rect otherwise
+fn natural_size(child: &Node) -> Size {
+    let rect = child.layout_rect();
+    let fixed = |dimension: Option<Dimension>, fallback: f32| match dimension {
+        Some(Dimension::Points(points)) => points,
+        _ => fallback,
+    };
+    let style = child.style().layout_style.as_ref();
+    Size::new(
+        fixed(style.map(|style| style.width), rect.width()),
+        fixed(style.map(|style| style.height), rect.height()),
+    )
+}
+
+impl From<Alignment> for AlignItems {
+    fn from(align: Alignment) -> Self {
+        match align {
+            Alignment::Start => AlignItems::FlexStart,
+            Alignment::Center => AlignItems::Center,
+            Alignment::End => AlignItems::FlexEnd,
+            Alignment::Stretch => AlignItems::Stretch,
+        }
+    }
+}
+
+impl From<Justification> for JustifyContent {
+    fn from(justify: Justification) -> Self {
+        match justify {
+            Justification::Start => JustifyContent::FlexStart,
+            Justification::Center => JustifyContent::Center,
+            Justification::End => JustifyContent::FlexEnd,
+            Justification::SpaceBetween => JustifyContent::SpaceBetween,
+            Justification::SpaceAround => JustifyContent::SpaceAround,
+            Justification::SpaceEvenly => JustifyContent::SpaceEvenly,
         }
     }
 }
@@ -102,24 +697,35 @@ impl Component for Layout {
         self.id
     }
 
-    fn create(props: Self::Props, _context: Context) -> Self {
+    fn create(props: Self::Props, context: Context) -> Self {
         Self {
             id: ComponentId::new(),
             direction: props.direction.unwrap_or_default(),
+            responsive_direction: props.responsive_direction,
             align: props.align.unwrap_or_default(),
             justify: props.justify.unwrap_or_default(),
             gap: props.gap.unwrap_or_else(|| "0px".to_string()),
             padding: props.padding.unwrap_or_else(|| "0px".to_string()),
+            wrap: props.wrap.unwrap_or(false),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            height: props.height.unwrap_or(DEFAULT_HEIGHT),
+            grid: props.grid,
             children: props.children,
+            theme: Theme::current(&context),
         }
     }
 
     fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
         self.direction = props.direction.unwrap_or(self.direction);
+        self.responsive_direction = props.responsive_direction;
         self.align = props.align.unwrap_or(self.align);
         self.justify = props.justify.unwrap_or(self.justify);
         self.gap = props.gap.unwrap_or_else(|| self.gap.clone());
         self.padding = props.padding.unwrap_or_else(|| self.padding.clone());
+        self.wrap = props.wrap.unwrap_or(self.wrap);
+        self.width = props.width.unwrap_or(self.width);
+        self.height = props.height.unwrap_or(self.height);
+        self.grid = props.grid;
         self.children = props.children;
         Ok(())
     }
@@ -133,8 +739,239 @@ impl Component for Layout {
     }
 
     fn render(&self) -> Result<Vec<Node>, ComponentError> {
-        // For now, return an empty Vec since we're not yet using the Node system
-        // In a real implementation, this would return the actual DOM nodes
-        Ok(vec![])
+        Ok(vec![self.render_in(Size::new(self.width, self.height))])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kit::components::Button;
+    use crate::kit::responsive::Breakpoints;
+
+    fn boxed(width: f32, height: f32) -> Node {
+        let mut node = Node::default();
+        node.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+        node
+    }
+
+    fn growing(grow: f32) -> Node {
+        let mut node = Node::default();
+        node.style_mut().layout_style = Some(LayoutStyle {
+            flex_grow: grow,
+            flex_basis: Dimension::Points(0.0),
+            height: Dimension::Points(40.0),
+            ..Default::default()
+        });
+        node
+    }
+
+    fn placed(column: GridPlacement, row: GridPlacement) -> Node {
+        let mut node = boxed(0.0, 30.0);
+        node.style_mut().layout_style = Some(LayoutStyle {
+            grid_column: column,
+            grid_row: row,
+            ..Default::default()
+        });
+        node
+    }
+
+    fn rects(layout: &Layout) -> Vec<Rect> {
+        let root = &layout.render().unwrap()[0];
+        root.children()
+            .iter()
+            .map(|child| child.layout_rect())
+            .collect()
+    }
+
+    #[test]
+    fn test_space_between_distributes_evenly() {
+        let layout = Layout {
+            justify: Justification::SpaceBetween,
+            padding: "10px".to_string(),
+            width: 340.0,
+            height: 100.0,
+            children: vec![boxed(40.0, 20.0), boxed(40.0, 20.0), boxed(40.0, 20.0)],
+            ..Default::default()
+        };
+        let xs: Vec<f32> = rects(&layout).iter().map(|rect| rect.x()).collect();
+        assert_eq!(xs, vec![10.0, 150.0, 290.0]);
+        assert!(rects(&layout).iter().all(|rect| rect.y() == 10.0));
+    }
+
+    #[test]
+    fn test_grow_ratios_and_gap() {
+        let layout = Layout {
+            gap: "20px".to_string(),
+            width: 320.0,
+            height: 100.0,
+            children: vec![growing(1.0), growing(3.0)],
+            ..Default::default()
+        };
+        let rects = rects(&layout);
+        assert_eq!(rects[0].width(), 75.0);
+        assert_eq!(rects[1].x(), 95.0);
+        assert_eq!(rects[1].width(), 225.0);
+        assert_eq!(rects[1].height(), 40.0);
+    }
+
+    #[test]
+    fn test_overflow_without_wrap_is_clamped() {
+        let mut rigid = boxed(0.0, 0.0);
+        rigid.style_mut().layout_style = Some(LayoutStyle {
+            width: Dimension::Points(150.0),
+            height: Dimension::Points(30.0),
+            flex_shrink: 0.0,
+            ..Default::default()
+        });
+        let mut layout = Layout {
+            width: 200.0,
+            height: 100.0,
+            children: vec![rigid.clone(), rigid],
+            ..Default::default()
+        };
+        let clamped = rects(&layout);
+        assert_eq!(clamped[1].x(), 150.0);
+        assert_eq!(clamped[1].width(), 50.0);
+
+        layout.wrap = true;
+        let wrapped = rects(&layout);
+        assert_eq!((wrapped[1].x(), wrapped[1].y()), (0.0, 30.0));
+        assert_eq!(wrapped[1].width(), 150.0);
+    }
+
+    #[test]
+    fn test_padding_larger_than_size_leaves_empty_child_rects() {
+        let layout = Layout {
+            padding: "50px".to_string(),
+            children: vec![boxed(40.0, 20.0)],
+            ..Default::default()
+        };
+        let root = layout.render_in(Size::new(80.0, 80.0));
+        let rect = root.children()[0].layout_rect();
+        assert_eq!((rect.x(), rect.y()), (50.0, 50.0));
+        assert_eq!((rect.width(), rect.height()), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_grid_fractions_and_auto_placement() {
+        let auto = || placed(GridPlacement::auto(), GridPlacement::auto());
+        let layout = Layout {
+            grid: Some(Grid {
+                row_gap: 10.0,
+                ..Grid::columns("1fr 2fr 1fr").gap(20.0)
+            }),
+            width: 400.0,
+            height: 300.0,
+            children: vec![
+                auto(),
+                auto(),
+                auto(),
+                placed(GridPlacement::span(2), GridPlacement::auto()),
+                auto(),
+            ],
+            ..Default::default()
+        };
+        let rects = rects(&layout);
+        let columns: Vec<(f32, f32)> = rects[..3]
+            .iter()
+            .map(|rect| (rect.x(), rect.width()))
+            .collect();
+        assert_eq!(columns, vec![(0.0, 90.0), (110.0, 180.0), (310.0, 90.0)]);
+        assert!(rects[..3].iter().all(|rect| rect.y() == 0.0));
+
+        assert_eq!(rects[3], Rect::new(0.0, 40.0, 290.0, 30.0));
+        assert_eq!(rects[4], Rect::new(310.0, 40.0, 90.0, 30.0));
+    }
+
+    #[test]
+    fn test_grid_explicit_placement_and_fixed_tracks() {
+        let layout = Layout {
+            grid: Some(Grid {
+                grid_template_columns: GridTrack::parse_list("100px auto 1fr").unwrap(),
+                grid_template_rows: vec![GridTrack::Px(50.0)],
+                ..Default::default()
+            }),
+            padding: "10px".to_string(),
+            width: 420.0,
+            height: 200.0,
+            children: vec![
+                placed(GridPlacement::at(2, 1), GridPlacement::at(1, 1)),
+                placed(GridPlacement::auto(), GridPlacement::at(0, 1)),
+                boxed(60.0, 20.0),
+            ],
+            ..Default::default()
+        };
+        let rects = rects(&layout);
+        assert_eq!(rects[0], Rect::new(170.0, 60.0, 240.0, 30.0));
+        assert_eq!(rects[1], Rect::new(10.0, 10.0, 100.0, 50.0));
+        assert_eq!(rects[2], Rect::new(110.0, 10.0, 60.0, 50.0));
+        assert_eq!(GridTrack::parse_list("1fr wide"), None);
+    }
+
+    #[test]
+    fn test_responsive_direction_stacks_below_md() {
+        let mut layout = Layout {
+            responsive_direction: Some(Responsive::new(Direction::Column).md(Direction::Row)),
+            ..Default::default()
+        };
+        assert_eq!(layout.direction_at(375.0), Direction::Column);
+        assert_eq!(layout.direction_at(1024.0), Direction::Row);
+
+        layout.theme.breakpoints = Breakpoints {
+            md: 1000.0,
+            ..Default::default()
+        };
+        assert_eq!(layout.direction_at(900.0), Direction::Column);
+
+        layout.responsive_direction = None;
+        assert_eq!(layout.direction_at(375.0), Direction::Row);
+    }
+
+    #[test]
+    fn test_spacer_pushes_buttons_apart() {
+        let button = |text: &str| {
+            let mut button = Button::default();
+            button.text = text.to_string();
+            button.render().unwrap().remove(0)
+        };
+        let row = HStack::new(
+            8.0,
+            vec![button("Back"), Spacer::default().into(), button("Next")],
+        );
+        let root = row.render_in(Size::new(300.0, 40.0));
+        let [back, spacer, next] = root.children() else {
+            panic!("expected two buttons around a spacer");
+        };
+        assert_eq!(back.layout_rect().x(), 0.0);
+        assert_eq!(next.layout_rect().max_x(), 300.0);
+        assert_eq!(spacer.layout_rect().x(), back.layout_rect().max_x() + 8.0);
+    }
+
+    #[test]
+    fn test_vstack_spacing_and_zstack_overlap() {
+        let column = VStack::new(10.0, vec![boxed(50.0, 20.0), boxed(50.0, 30.0)]);
+        let root = column.render_in(Size::new(100.0, 200.0));
+        assert_eq!(root.children()[1].layout_rect().y(), 30.0);
+
+        // Stacked children overlap, placed by the alignment
+        let mut layers = ZStack::new(vec![boxed(100.0, 100.0), boxed(40.0, 20.0)]);
+        layers.align = Alignment::Center;
+        let root = layers.render_in(Size::new(100.0, 100.0));
+        assert_eq!(
+            root.children()[0].layout_rect(),
+            Rect::new(0.0, 0.0, 100.0, 100.0)
+        );
+        assert_eq!(
+            root.children()[1].layout_rect(),
+            Rect::new(30.0, 40.0, 40.0, 20.0)
+        );
+
+        layers.align = Alignment::Stretch;
+        let root = layers.render_in(Size::new(100.0, 100.0));
+        assert_eq!(
+            root.children()[1].layout_rect(),
+            Rect::new(0.0, 0.0, 100.0, 100.0)
+        );
     }
 }
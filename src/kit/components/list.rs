@@ -0,0 +1,349 @@
+// VirtualList component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::components::scroll::ScrollView;
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// Default viewport width
+const DEFAULT_WIDTH: f32 = 300.0;
+
+/// Default viewport height
+const DEFAULT_HEIGHT: f32 = 400.0;
+
+/// Default row height, also the estimate for rows not yet measured
+const DEFAULT_ROW_HEIGHT: f32 = 32.0;
+
+/// Rows rendered beyond each edge of the viewport by default, so a short
+/// scroll doesn't show a gap before the next render
+const DEFAULT_OVERSCAN: usize = 3;
+
+/// Virtualized list component
+///
+/// Only the rows intersecting the viewport, plus `overscan` rows either
+/// side, are rendered at all. Rows are `row_height` tall until
+/// [`VirtualList::measure`] records their real height.
+#[derive(Debug)]
+pub struct VirtualList {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Number of rows
+    pub item_count: usize,
+    /// Height of each row, or the estimate for rows of varying height
+    pub row_height: f32,
+    /// Extra rows rendered above and below the viewport
+    pub overscan: usize,
+    /// Renders the row at an index; rows are left empty without one
+    pub render_item: Option<fn(usize) -> Node>,
+    /// Identifies the item at an index, keying its row so inserting items
+    /// doesn't rebuild the rows after them
+    ///
+    /// Rows `render_item` already keyed keep their key, and the rest are
+    /// keyed by index.
+    pub item_key: Option<fn(usize) -> String>,
+    /// Heights rows turned out to be, by index
+    measured: BTreeMap<usize, f32>,
+    /// Scroll state, whose content is a single spacer as tall as the list
+    scroll: ScrollView,
+}
+
+/// Virtual list props
+#[derive(Debug, Clone)]
+pub struct VirtualListProps {
+    /// Number of rows
+    pub item_count: usize,
+    /// Fixed or estimated row height
+    pub row_height: Option<f32>,
+    /// Viewport width
+    pub width: Option<f32>,
+    /// Viewport height
+    pub height: Option<f32>,
+    /// Extra rows rendered above and below the viewport
+    pub overscan: Option<usize>,
+    /// Row renderer
+    pub render_item: Option<fn(usize) -> Node>,
+    /// Identifies the item at an index
+    pub item_key: Option<fn(usize) -> String>,
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        let mut list = Self {
+            id: ComponentId::new(),
+            item_count: 0,
+            row_height: DEFAULT_ROW_HEIGHT,
+            overscan: DEFAULT_OVERSCAN,
+            render_item: None,
+            item_key: None,
+            measured: BTreeMap::new(),
+            scroll: ScrollView::default(),
+        };
+        list.scroll.width = DEFAULT_WIDTH;
+        list.scroll.height = DEFAULT_HEIGHT;
+        list
+    }
+}
+
+impl VirtualList {
+    /// A list of `item_count` rows in a viewport of `width` by `height`
+    pub fn new(item_count: usize, row_height: f32, width: f32, height: f32) -> Self {
+        let mut list = Self {
+            item_count,
+            row_height,
+            ..Default::default()
+        };
+        list.scroll.width = width;
+        list.scroll.height = height;
+        list.sync_content();
+        list
+    }
+
+    /// Height of the row at `index`, measured or estimated
+    pub fn height_of(&self, index: usize) -> f32 {
+        self.measured
+            .get(&index)
+            .copied()
+            .unwrap_or(self.row_height)
+    }
+
+    /// Distance from the top of the list to the row at `index`
+    ///
+    /// Only measured rows are visited, so this stays cheap on long lists
+    /// that have mostly been estimated.
+    pub fn offset_of(&self, index: usize) -> f32 {
+        let index = index.min(self.item_count);
+        let correction: f32 = self
+            .measured
+            .range(..index)
+            .map(|(_, height)| height - self.row_height)
+            .sum();
+        index as f32 * self.row_height + correction
+    }
+
+    /// Total height of every row
+    pub fn content_height(&self) -> f32 {
+        self.offset_of(self.item_count)
+    }
+
+    /// Index of the row covering `y` pixels from the top of the list
+    pub fn index_at(&self, y: f32) -> usize {
+        if self.item_count == 0 {
+            return 0;
+        }
+        // The last row whose top is at or above `y`
+        let (mut low, mut high) = (0, self.item_count - 1);
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            if self.offset_of(mid) <= y {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+
+    /// Indices of the rows to render: those in the viewport plus the
+    /// overscan either side
+    pub fn visible_range(&self) -> Range<usize> {
+        if self.item_count == 0 {
+            return 0..0;
+        }
+        let top = self.scroll.offset().1;
+        let first = self.index_at(top);
+        let last = self.index_at(top + self.scroll.height);
+        first.saturating_sub(self.overscan)..(last + 1 + self.overscan).min(self.item_count)
+    }
+
+    /// Record the real height of the row at `index`
+    pub fn measure(&mut self, index: usize, height: f32) {
+        if index >= self.item_count || !height.is_finite() || height < 0.0 {
+            return;
+        }
+        if self.measured.insert(index, height) != Some(height) {
+            self.sync_content();
+        }
+    }
+
+    /// Scroll so the row at `index` sits at the top of the viewport, or as
+    /// near as the end of the list allows
+    pub fn scroll_to_index(&mut self, index: usize) {
+        let offset = self.offset_of(index);
+        self.scroll.scroll_to((0.0, offset));
+    }
+
+    /// Current vertical scroll offset
+    pub fn offset(&self) -> f32 {
+        self.scroll.offset().1
+    }
+
+    /// Scroll to `offset` pixels from the top, clamped to the list
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.scroll.scroll_to((0.0, offset));
+    }
+
+    /// Handle a wheel event, returning whether the list scrolled
+    pub fn wheel(&mut self, delta: f32) -> bool {
+        self.scroll.wheel((0.0, delta))
+    }
+
+    /// Handle a pointer press, as on a [`ScrollView`]
+    pub fn press(&mut self, point: (f32, f32)) {
+        self.scroll.press(point);
+    }
+
+    /// Handle pointer movement while dragging
+    pub fn drag_to(&mut self, point: (f32, f32)) {
+        self.scroll.drag_to(point);
+    }
+
+    /// Handle the pointer being released
+    pub fn release(&mut self) {
+        self.scroll.release();
+    }
+
+    /// Resize the scroll spacer to the list's height and drop measurements
+    /// past the end, re-clamping the offset to match
+    fn sync_content(&mut self) {
+        self.measured.split_off(&self.item_count);
+        let mut spacer = Node::default();
+        spacer.set_layout_rect(Rect::new(
+            0.0,
+            0.0,
+            self.scroll.width,
+            self.content_height(),
+        ));
+        self.scroll.children = vec![spacer];
+        let offset = self.scroll.offset();
+        self.scroll.scroll_to(offset);
+    }
+}
+
+impl Component for VirtualList {
+    type Props = VirtualListProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut list = Self::new(
+            props.item_count,
+            props.row_height.unwrap_or(DEFAULT_ROW_HEIGHT),
+            props.width.unwrap_or(DEFAULT_WIDTH),
+            props.height.unwrap_or(DEFAULT_HEIGHT),
+        );
+        list.overscan = props.overscan.unwrap_or(DEFAULT_OVERSCAN);
+        list.render_item = props.render_item;
+        list.item_key = props.item_key;
+        list.scroll.theme = Theme::current(&context);
+        list
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.item_count = props.item_count;
+        self.row_height = props.row_height.unwrap_or(self.row_height);
+        self.scroll.width = props.width.unwrap_or(self.scroll.width);
+        self.scroll.height = props.height.unwrap_or(self.scroll.height);
+        self.overscan = props.overscan.unwrap_or(self.overscan);
+        self.render_item = props.render_item;
+        self.item_key = props.item_key;
+        self.sync_content();
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut nodes = self.scroll.render()?;
+        let root = &mut nodes[0];
+        root.add_attribute("role".to_string(), "list".to_string());
+
+        // Swap the spacer for the rows actually in view, keeping the
+        // content node the scroll view sized and offset
+        let content = &mut root.children_mut()[0];
+        content.children_mut().clear();
+        for index in self.visible_range() {
+            let mut row = self
+                .render_item
+                .map_or_else(Node::default, |render| render(index));
+            if row.key().is_none() {
+                row.set_key(
+                    self.item_key
+                        .map_or_else(|| index.to_string(), |key| key(index)),
+                );
+            }
+            row.set_layout_rect(Rect::new(
+                0.0,
+                self.offset_of(index),
+                self.scroll.width,
+                self.height_of(index),
+            ));
+            row.add_attribute("role".to_string(), "listitem".to_string());
+            row.add_attribute("aria-setsize".to_string(), self.item_count.to_string());
+            row.add_attribute("aria-posinset".to_string(), (index + 1).to_string());
+            content.add_child(row);
+        }
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labelled(index: usize) -> Node {
+        let mut node = Node::default();
+        node.set_text(format!("Item {index}"));
+        node
+    }
+
+    #[test]
+    fn test_large_list_renders_only_visible_rows() {
+        let mut list = VirtualList::new(100_000, 30.0, 300.0, 300.0);
+        list.render_item = Some(labelled);
+
+        let rows = |list: &VirtualList| {
+            let root = &list.render().unwrap()[0];
+            root.children()[0].children().to_vec()
+        };
+        // Ten rows fill the viewport, with three of overscan below
+        assert_eq!(rows(&list).len(), 14);
+
+        list.scroll_to_index(50_000);
+        assert_eq!(list.offset(), 1_500_000.0);
+        let visible = rows(&list);
+        assert_eq!(visible.len(), 17);
+        assert_eq!(visible[3].text(), Some("Item 50000"));
+        assert_eq!(visible[3].layout_rect().y(), 1_500_000.0);
+
+        list.scroll_to_index(100_000);
+        assert_eq!(list.offset(), list.content_height() - 300.0);
+        assert_eq!(list.visible_range().end, 100_000);
+    }
+
+    #[test]
+    fn test_measured_heights_shift_later_rows() {
+        let mut list = VirtualList::new(100, 30.0, 300.0, 300.0);
+        for index in 0..5 {
+            list.measure(index, 60.0);
+        }
+        list.measure(500, 60.0);
+        assert_eq!(list.offset_of(10), 450.0);
+        assert_eq!(list.content_height(), 3150.0);
+        assert_eq!(list.index_at(119.0), 1);
+        assert_eq!(list.index_at(300.0), 5);
+
+        list.scroll_to_index(5);
+        assert_eq!(list.visible_range(), 2..19);
+    }
+}
@@ -0,0 +1,730 @@
+// Menu component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::components::tooltip::Placement;
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::{EdgeValues, Point, Rect, Size};
+use crate::style::{BorderRadius, BoxShadow, Color, EdgeColors, TextAlign};
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+/// Stacking order for menu panels, level with other popups
+const MENU_Z_INDEX: i32 = 1000;
+
+/// Default panel width
+const DEFAULT_WIDTH: f32 = 220.0;
+
+/// Height of each item row
+const ROW_HEIGHT: f32 = 32.0;
+
+/// Height of a divider row, with its line through the middle
+const DIVIDER_HEIGHT: f32 = 9.0;
+
+/// Space above the first row and below the last
+const PANEL_PADDING: f32 = 4.0;
+
+/// Horizontal padding inside each row
+const ROW_PADDING: f32 = 12.0;
+
+/// Width kept for the icon column
+const ICON_WIDTH: f32 = 24.0;
+
+/// Gap between the trigger and the menu
+const MENU_GAP: f32 = 4.0;
+
+/// How long after the last typed character the next one starts a new
+/// type-ahead search instead of extending it
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// An entry in a [`Menu`]
+#[derive(Debug, Clone, Default)]
+pub struct MenuItem {
+    /// Identifier passed to `on_select`
+    pub id: String,
+    /// Label shown in the row
+    pub label: String,
+    /// Icon glyph shown before the label
+    pub icon: Option<String>,
+    /// Keyboard shortcut shown at the end of the row, such as `"Ctrl+S"`
+    pub shortcut: Option<String>,
+    /// Whether the item can be chosen
+    pub disabled: bool,
+    /// Whether this is a separator line rather than an item
+    pub divider: bool,
+    /// Items of the submenu this item opens
+    pub children: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// Create an enabled item
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a separator line
+    pub fn divider() -> Self {
+        Self {
+            divider: true,
+            ..Default::default()
+        }
+    }
+
+    /// Show `icon` before the label
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Show `shortcut` at the end of the row
+    pub fn with_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Open a submenu of `children` from this item
+    pub fn with_submenu(mut self, children: Vec<MenuItem>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Whether the item can be highlighted and chosen
+    fn is_enabled(&self) -> bool {
+        !self.divider && !self.disabled
+    }
+
+    fn has_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    fn height(&self) -> f32 {
+        if self.divider {
+            DIVIDER_HEIGHT
+        } else {
+            ROW_HEIGHT
+        }
+    }
+}
+
+/// An open panel: the top-level menu or a submenu
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Level {
+    /// Highlighted row
+    highlighted: Option<usize>,
+    /// Where the panel sits, in the anchor's coordinate space
+    rect: Rect,
+}
+
+/// Menu component, a floating panel of commands with nested submenus
+///
+/// Open it under a trigger with [`Menu::open_from`], or at the pointer as a
+/// context menu with [`Menu::open_at`]. Choosing an item or pressing
+/// outside the menu closes it.
+#[derive(Debug)]
+pub struct Menu {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Top-level items
+    pub items: Vec<MenuItem>,
+    /// Width of each panel
+    pub width: f32,
+    /// Size of the viewport the panels are kept inside
+    pub viewport: (f32, f32),
+    /// Called with an item's ID when it is chosen
+    pub on_select: Option<fn(&str)>,
+    /// Open panels, the top level first
+    levels: Vec<Level>,
+    /// Characters typed so far for type-ahead, and when the last arrived
+    type_ahead: (String, Option<Instant>),
+    /// Theme providing surface colors
+    pub theme: Theme,
+}
+
+/// Menu props
+#[derive(Debug, Clone, Default)]
+pub struct MenuProps {
+    /// Top-level items
+    pub items: Vec<MenuItem>,
+    /// Width of each panel
+    pub width: Option<f32>,
+    /// Size of the viewport the panels are kept inside
+    pub viewport: Option<(f32, f32)>,
+    /// Called with an item's ID when it is chosen
+    pub on_select: Option<fn(&str)>,
+}
+
+impl Default for Menu {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            items: Vec::new(),
+            width: DEFAULT_WIDTH,
+            viewport: (800.0, 600.0),
+            on_select: None,
+            levels: Vec::new(),
+            type_ahead: (String::new(), None),
+            theme: Theme::default(),
+        }
+    }
+}
+
+/// Height of a panel holding `items`
+fn panel_height(items: &[MenuItem]) -> f32 {
+    2.0 * PANEL_PADDING + items.iter().map(MenuItem::height).sum::<f32>()
+}
+
+/// Top of row `index` within its panel
+fn row_offset(items: &[MenuItem], index: usize) -> f32 {
+    PANEL_PADDING + items[..index].iter().map(MenuItem::height).sum::<f32>()
+}
+
+/// The next enabled item after `start`, forwards or backwards and wrapping;
+/// from the first or last item when nothing is highlighted
+fn next_enabled(items: &[MenuItem], start: Option<usize>, forward: bool) -> Option<usize> {
+    let len = items.len();
+    (1..=len)
+        .map(|step| match (start, forward) {
+            (Some(start), true) => (start + step) % len,
+            (Some(start), false) => (start + len - step % len) % len,
+            (None, true) => step - 1,
+            (None, false) => len - step,
+        })
+        .find(|&index| items[index].is_enabled())
+}
+
+impl Menu {
+    /// Whether the menu is showing
+    pub fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    /// Number of panels showing: 1 for the menu itself, plus one per open
+    /// submenu
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Index of the highlighted row in panel `level`
+    pub fn highlighted(&self, level: usize) -> Option<usize> {
+        self.levels.get(level)?.highlighted
+    }
+
+    /// Rect of panel `level`, in the anchor's coordinate space
+    pub fn panel_rect(&self, level: usize) -> Option<Rect> {
+        self.levels.get(level).map(|level| level.rect)
+    }
+
+    fn viewport_size(&self) -> Size {
+        Size::new(self.viewport.0, self.viewport.1)
+    }
+
+    /// Open the menu below `anchor`, such as the rect of the button that
+    /// opened it, flipping above it near the bottom of the viewport
+    pub fn open_from(&mut self, anchor: Rect) {
+        let size = Size::new(self.width, panel_height(&self.items));
+        // Centering on an anchor as wide as the panel lines the panel up
+        // with the anchor's leading edge
+        let aligned = Rect::new(anchor.x(), anchor.y(), size.width, anchor.height());
+        let (_, rect) = Placement::Bottom.place(aligned, size, MENU_GAP, self.viewport_size());
+        self.levels = vec![Level {
+            highlighted: None,
+            rect,
+        }];
+        self.type_ahead = (String::new(), None);
+    }
+
+    /// Open the menu at `point` as a context menu
+    pub fn open_at(&mut self, point: (f32, f32)) {
+        self.open_from(Rect::new(point.0, point.1 - MENU_GAP, 0.0, 0.0));
+    }
+
+    /// Close the menu and all its submenus
+    pub fn close(&mut self) {
+        self.levels.clear();
+    }
+
+    /// Items shown in panel `level`
+    fn items_at(&self, level: usize) -> &[MenuItem] {
+        let mut items = self.items.as_slice();
+        for open in &self.levels[..level] {
+            match open.highlighted.and_then(|index| items.get(index)) {
+                Some(item) => items = &item.children,
+                None => return &[],
+            }
+        }
+        items
+    }
+
+    /// Highlight row `index` of panel `level`, closing deeper panels; returns
+    /// whether the row could be highlighted
+    fn highlight(&mut self, level: usize, index: usize) -> bool {
+        let enabled = self
+            .items_at(level)
+            .get(index)
+            .is_some_and(MenuItem::is_enabled);
+        if level >= self.levels.len() || !enabled {
+            return false;
+        }
+        self.levels.truncate(level + 1);
+        self.levels[level].highlighted = Some(index);
+        true
+    }
+
+    /// Open the submenu of the highlighted row in panel `level`, beside
+    /// that row; returns whether there was one to open
+    fn open_submenu(&mut self, level: usize) -> bool {
+        let Some(index) = self.highlighted(level) else {
+            return false;
+        };
+        let items = self.items_at(level);
+        if !items[index].has_submenu() {
+            return false;
+        }
+        let size = Size::new(self.width, panel_height(&items[index].children));
+        let parent = self.levels[level].rect;
+        // As tall as the submenu, so centering on it lines the submenu's
+        // first row up with the row that opened it
+        let row_top = parent.y() + row_offset(items, index) - PANEL_PADDING;
+        let aligned = Rect::new(parent.x(), row_top, parent.width(), size.height);
+        let (_, rect) = Placement::Right.place(aligned, size, 0.0, self.viewport_size());
+        self.levels.truncate(level + 1);
+        self.levels.push(Level {
+            highlighted: None,
+            rect,
+        });
+        true
+    }
+
+    /// The pointer moved over row `index` of panel `level`: highlight it and
+    /// open its submenu, if it has one
+    pub fn hover(&mut self, level: usize, index: usize) {
+        if self.highlight(level, index) {
+            self.open_submenu(level);
+        }
+    }
+
+    /// Choose row `index` of panel `level`: open its submenu if it has one,
+    /// otherwise fire `on_select` and close the menu
+    pub fn activate(&mut self, level: usize, index: usize) {
+        if !self.highlight(level, index) {
+            return;
+        }
+        if self.open_submenu(level) {
+            let child = next_enabled(self.items_at(level + 1), None, true);
+            self.levels[level + 1].highlighted = child;
+            return;
+        }
+        let id = self.items_at(level)[index].id.clone();
+        self.close();
+        if let Some(on_select) = self.on_select {
+            on_select(&id);
+        }
+    }
+
+    /// The panel and row under `point`, if any
+    fn row_at(&self, point: (f32, f32)) -> Option<(usize, Option<usize>)> {
+        let point = Point::new(point.0, point.1);
+        // Deeper panels overlap shallower ones, so they are tested first
+        let level = (0..self.levels.len())
+            .rev()
+            .find(|&level| self.levels[level].rect.contains_point(point))?;
+        let items = self.items_at(level);
+        let y = point.y - self.levels[level].rect.y();
+        let row = (0..items.len())
+            .find(|&index| (row_offset(items, index)..row_offset(items, index + 1)).contains(&y));
+        Some((level, row))
+    }
+
+    /// Handle the pointer moving to `point`, in the anchor's coordinate
+    /// space, highlighting the row under it
+    pub fn pointer_move(&mut self, point: (f32, f32)) {
+        if let Some((level, Some(index))) = self.row_at(point) {
+            self.hover(level, index);
+        }
+    }
+
+    /// Handle a pointer press at `point`, in the anchor's coordinate space;
+    /// returns whether the menu handled it
+    ///
+    /// Pressing a row activates it, and pressing outside every panel closes
+    /// the menu.
+    pub fn pointer_down(&mut self, point: (f32, f32)) -> bool {
+        if !self.is_open() {
+            return false;
+        }
+        match self.row_at(point) {
+            Some((level, Some(index))) => self.activate(level, index),
+            Some((_, None)) => {}
+            None => self.close(),
+        }
+        true
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Up and Down move through the deepest open panel, Right or Enter open
+    /// a submenu, Left and Escape close one, Enter chooses an item, and
+    /// typed characters jump to the next item starting with them.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        self.handle_key_at(key, Instant::now())
+    }
+
+    /// [`Menu::handle_key`], with type-ahead timed against `now`
+    pub fn handle_key_at(&mut self, key: &str, now: Instant) -> bool {
+        let Some(level) = self.levels.len().checked_sub(1) else {
+            return false;
+        };
+        let current = self.levels[level].highlighted;
+        let mut chars = key.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if c != ' ' {
+                self.type_ahead_char(level, c, now);
+                return true;
+            }
+        }
+        self.type_ahead = (String::new(), None);
+
+        let items = self.items_at(level);
+        match key {
+            "ArrowDown" | "ArrowUp" | "Home" | "End" => {
+                let target = match key {
+                    "ArrowDown" => next_enabled(items, current, true),
+                    "ArrowUp" => next_enabled(items, current, false),
+                    "Home" => next_enabled(items, None, true),
+                    _ => next_enabled(items, None, false),
+                };
+                if let Some(target) = target {
+                    self.levels[level].highlighted = Some(target);
+                }
+            }
+            "ArrowRight" => {
+                if self.open_submenu(level) {
+                    let child = next_enabled(self.items_at(level + 1), None, true);
+                    self.levels[level + 1].highlighted = child;
+                }
+            }
+            "ArrowLeft" if level > 0 => {
+                self.levels.pop();
+            }
+            "Enter" | " " => {
+                if let Some(index) = current {
+                    self.activate(level, index);
+                }
+            }
+            "Escape" if level > 0 => {
+                self.levels.pop();
+            }
+            "Escape" => self.close(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Extend the type-ahead search with `c` and highlight the first item
+    /// in panel `level` whose label starts with it
+    ///
+    /// A fresh search starts after the highlighted row, so pressing the
+    /// same letter repeatedly cycles through the items starting with it.
+    fn type_ahead_char(&mut self, level: usize, c: char, now: Instant) {
+        let (buffer, last) = &mut self.type_ahead;
+        if last.is_none_or(|last| now.saturating_duration_since(last) > TYPE_AHEAD_TIMEOUT) {
+            buffer.clear();
+        }
+        *last = Some(now);
+        buffer.extend(c.to_lowercase());
+
+        // Lowercased labels of the items that can be highlighted
+        let labels: Vec<Option<String>> = self
+            .items_at(level)
+            .iter()
+            .map(|item| item.is_enabled().then(|| item.label.to_lowercase()))
+            .collect();
+        let current = self.levels[level].highlighted;
+        let search = |prefix: &str, skip_current: bool| {
+            let start = current.map_or(0, |index| index + usize::from(skip_current));
+            (0..labels.len())
+                .map(|step| (start + step) % labels.len())
+                .find(|&index| {
+                    labels[index]
+                        .as_ref()
+                        .is_some_and(|label| label.starts_with(prefix))
+                })
+        };
+        let buffer = &mut self.type_ahead.0;
+        let found = if buffer.chars().count() > 1 {
+            // Keep the current item while it still matches the longer
+            // prefix, otherwise start over from just this character
+            search(buffer, false).or_else(|| {
+                *buffer = c.to_lowercase().collect();
+                search(buffer, true)
+            })
+        } else {
+            search(buffer, true)
+        };
+        if let Some(index) = found {
+            self.levels.truncate(level + 1);
+            self.levels[level].highlighted = Some(index);
+        }
+    }
+
+    fn render_panel(&self, level: usize) -> Node {
+        let items = self.items_at(level);
+        let Level { highlighted, rect } = self.levels[level];
+
+        let mut panel = Node::default();
+        panel.add_attribute("role".to_string(), "menu".to_string());
+        panel.set_layout_rect(rect);
+        let border = Color::Hex(self.theme.border_color.clone());
+        let radius = self.theme.radius(RadiusSize::Md);
+        let style = panel.style_mut();
+        style.background_color = Some(Color::Hex(self.theme.surface_color.clone()));
+        style.border_width = Some(EdgeValues::uniform(1.0));
+        style.border_color = Some(EdgeColors {
+            top: border.clone(),
+            right: border.clone(),
+            bottom: border.clone(),
+            left: border.clone(),
+        });
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        style.box_shadow = Some(vec![BoxShadow::new(
+            0.0,
+            4.0,
+            12.0,
+            Color::Rgba(0.0, 0.0, 0.0, 0.15),
+        )]);
+        // Each submenu stacks above the panel it opened from
+        style.z_index = Some(MENU_Z_INDEX + level as i32);
+
+        let text = Color::Hex(self.theme.text_color.clone());
+        let muted = Color::Hex(self.theme.muted_text_color.clone());
+        for (index, item) in items.iter().enumerate() {
+            let top = row_offset(items, index);
+            let mut row = Node::default();
+            row.set_layout_rect(Rect::new(0.0, top, rect.width(), item.height()));
+            if item.divider {
+                row.add_attribute("role".to_string(), "separator".to_string());
+                let mut line = Node::default();
+                line.set_layout_rect(Rect::new(0.0, DIVIDER_HEIGHT / 2.0, rect.width(), 1.0));
+                line.style_mut().background_color = Some(border.clone());
+                row.add_child(line);
+                panel.add_child(row);
+                continue;
+            }
+
+            row.add_attribute("role".to_string(), "menuitem".to_string());
+            row.add_attribute("data-id".to_string(), item.id.clone());
+            if item.disabled {
+                row.add_attribute("aria-disabled".to_string(), "true".to_string());
+            }
+            if item.has_submenu() {
+                let expanded = self.levels.len() > level + 1 && highlighted == Some(index);
+                row.add_attribute("aria-haspopup".to_string(), "menu".to_string());
+                row.add_attribute("aria-expanded".to_string(), expanded.to_string());
+            }
+            if highlighted == Some(index) {
+                row.style_mut().background_color =
+                    Some(Color::Hex(self.theme.highlight_color.clone()));
+            }
+            let color = if item.disabled { &muted } else { &text };
+
+            if let Some(icon) = &item.icon {
+                let mut node = Node::default();
+                node.add_attribute("aria-hidden".to_string(), "true".to_string());
+                node.set_layout_rect(Rect::new(ROW_PADDING, 0.0, ICON_WIDTH, ROW_HEIGHT));
+                node.set_text(icon.clone());
+                node.style_mut().color = Some(color.clone());
+                row.add_child(node);
+            }
+
+            let label_x = ROW_PADDING + ICON_WIDTH;
+            let trailing = rect.width() / 3.0;
+            let mut label = Node::default();
+            label.set_layout_rect(Rect::new(
+                label_x,
+                0.0,
+                (rect.width() - label_x - trailing).max(0.0),
+                ROW_HEIGHT,
+            ));
+            label.set_text(item.label.clone());
+            label.style_mut().color = Some(color.clone());
+            row.add_child(label);
+
+            // A submenu's chevron takes the place of a shortcut
+            let end = if item.has_submenu() {
+                Some("›".to_string())
+            } else {
+                item.shortcut.clone()
+            };
+            if let Some(end) = end {
+                let mut node = Node::default();
+                node.set_layout_rect(Rect::new(
+                    rect.width() - ROW_PADDING - trailing,
+                    0.0,
+                    trailing,
+                    ROW_HEIGHT,
+                ));
+                node.set_text(end);
+                node.style_mut().color = Some(muted.clone());
+                node.style_mut().text_align = Some(TextAlign::Right);
+                row.add_child(node);
+            }
+            panel.add_child(row);
+        }
+        panel
+    }
+}
+
+impl KeyHandler for Menu {
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl Component for Menu {
+    type Props = MenuProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut menu = Self {
+            items: props.items,
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            on_select: props.on_select,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        menu.viewport = props.viewport.unwrap_or(menu.viewport);
+        menu
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.items = props.items;
+        self.width = props.width.unwrap_or(self.width);
+        self.viewport = props.viewport.unwrap_or(self.viewport);
+        self.on_select = props.on_select;
+        // The open panels may no longer match the items
+        self.close();
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok((0..self.levels.len())
+            .map(|level| self.render_panel(level))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn file_menu() -> Menu {
+        Menu {
+            items: vec![
+                MenuItem::new("new", "New").with_shortcut("Ctrl+N"),
+                MenuItem::new("open", "Open").with_icon("📂"),
+                MenuItem::new("recent", "Open Recent").with_submenu(vec![
+                    MenuItem::new("a.txt", "a.txt"),
+                    MenuItem::new("b.txt", "b.txt"),
+                ]),
+                MenuItem::divider(),
+                MenuItem {
+                    disabled: true,
+                    ..MenuItem::new("print", "Print")
+                },
+                MenuItem::new("quit", "Quit"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keyboard_navigation_into_submenu() {
+        static SELECTED: Mutex<String> = Mutex::new(String::new());
+
+        let mut menu = file_menu();
+        menu.on_select = Some(|id| *SELECTED.lock().unwrap() = id.to_string());
+        menu.open_from(Rect::new(10.0, 10.0, 80.0, 30.0));
+        assert_eq!(menu.panel_rect(0).unwrap().x(), 10.0);
+
+        // The divider and the disabled item are skipped, wrapping round
+        for _ in 0..3 {
+            menu.handle_key("ArrowDown");
+        }
+        assert_eq!(menu.highlighted(0), Some(2));
+        menu.handle_key("ArrowDown");
+        assert_eq!(menu.highlighted(0), Some(5));
+        menu.handle_key("ArrowDown");
+        assert_eq!(menu.highlighted(0), Some(0));
+
+        menu.handle_key("ArrowUp");
+        menu.handle_key("ArrowUp");
+        assert!(menu.handle_key("ArrowRight"));
+        assert_eq!(menu.depth(), 2);
+        assert_eq!(menu.highlighted(1), Some(0));
+        let (parent, submenu) = (menu.panel_rect(0).unwrap(), menu.panel_rect(1).unwrap());
+        assert_eq!(submenu.x(), parent.max_x());
+        assert_eq!(submenu.y(), parent.y() + 2.0 * ROW_HEIGHT);
+
+        let panels = menu.render().unwrap();
+        assert_eq!(panels.len(), 2);
+        let recent = &panels[0].children()[2];
+        assert_eq!(recent.attributes().get("aria-expanded").unwrap(), "true");
+
+        menu.handle_key("ArrowLeft");
+        assert_eq!(menu.depth(), 1);
+        menu.handle_key("Enter");
+        menu.handle_key("ArrowDown");
+        menu.handle_key("Enter");
+        assert!(!menu.is_open());
+        assert_eq!(*SELECTED.lock().unwrap(), "b.txt");
+    }
+
+    #[test]
+    fn test_type_ahead_and_hover() {
+        let mut menu = file_menu();
+        menu.open_at((700.0, 500.0));
+        // Flipped above the point and shifted left to stay on screen
+        let panel = menu.panel_rect(0).unwrap();
+        assert!(panel.max_x() <= 800.0 && panel.max_y() <= 500.0);
+
+        let start = Instant::now();
+        menu.handle_key_at("o", start);
+        assert_eq!(menu.highlighted(0), Some(1));
+        menu.handle_key_at("o", start + Duration::from_millis(100));
+        assert_eq!(menu.highlighted(0), Some(2));
+        // "op" still matches "Open Recent", so the highlight stays put
+        menu.handle_key_at("p", start + Duration::from_millis(200));
+        assert_eq!(menu.highlighted(0), Some(2));
+        // Disabled items are never found
+        menu.handle_key_at("p", start + Duration::from_secs(2));
+        assert_eq!(menu.highlighted(0), Some(2));
+
+        // Hovering a row with a submenu opens it
+        let row = row_offset(&menu.items, 2);
+        menu.pointer_move((panel.x() + 20.0, panel.y() + row + 5.0));
+        assert_eq!(menu.depth(), 2);
+        assert!(menu.pointer_down((0.0, 0.0)));
+        assert!(!menu.is_open());
+    }
+}
@@ -6,44 +6,106 @@
 
 // Input components
 pub mod button;
+pub mod checkbox;
 pub mod input;
+pub mod radio;
+pub mod rating;
+pub mod select;
+pub mod slider;
+pub mod stepper;
+pub mod switch;
+pub mod textarea;
 
 // Layout components
 pub mod layout;
+pub mod list;
+pub mod scroll;
 
 // Data display
+pub mod accordion;
+pub mod avatar;
+pub mod badge;
 pub mod card;
+pub mod divider;
+pub mod image;
+pub mod table;
+pub mod tree;
+
+// Feedback
+pub mod alert;
+pub mod skeleton;
+
+// Navigation
+pub mod breadcrumb;
+pub mod menu;
+pub mod pagination;
+pub mod tabs;
+
+// Overlays
+pub mod drawer;
+pub mod modal;
+pub mod popover;
+pub mod toast;
+pub mod tooltip;
 
 // Re-export commonly used components
-pub use button::Button;
+pub use accordion::{Accordion, AccordionItem};
+pub use alert::{Alert, AlertVariant};
+pub use avatar::{Avatar, AvatarGroup, AvatarShape};
+pub use badge::{Badge, BadgeVariant, Chip};
+pub use breadcrumb::{Breadcrumb, BreadcrumbItem, Crumb};
+pub use button::{Button, ButtonSize, ButtonVariant};
 pub use card::Card;
-pub use input::Input;
-pub use layout::Layout;
+pub use checkbox::Checkbox;
+pub use divider::{Divider, DividerOrientation};
+pub use drawer::{Drawer, DrawerSide};
+pub use image::{Image, ImageStatus};
+pub use input::{Input, InputState, Validator};
+pub use layout::{Grid, GridTrack, HStack, Layout, Spacer, VStack, ZStack};
+pub use list::VirtualList;
+pub use menu::{Menu, MenuItem};
+pub use modal::Modal;
+pub use pagination::{PageItem, Pagination};
+pub use popover::Popover;
+pub use radio::{Radio, RadioGroup};
+pub use rating::Rating;
+pub use scroll::{ScrollDirection, ScrollView};
+pub use select::{MultiSelect, Select};
+pub use skeleton::{Skeleton, SkeletonText, SkeletonVariant};
+pub use slider::Slider;
+pub use stepper::{StepDirection, Stepper};
+pub use switch::Switch;
+pub use table::{Column, SortDirection, Table};
+pub use tabs::{Tab, Tabs};
+pub use textarea::Textarea;
+pub use toast::{Severity, Toast, ToastManager};
+pub use tooltip::Tooltip;
+pub use tree::{Tree, TreeNode};
 
 /*
 TODO: Component Roadmap
 
 Phase 1 - Core Components:
-- [ ] Select
-- [ ] Checkbox
+- [x] Select
+- [x] Checkbox
 - [ ] Container
-- [ ] Stack
+- [x] Stack
 
 Phase 2 - Data Display:
-- [ ] Table
-- [ ] List
+- [x] Table
+- [x] List
 - [ ] Progress
 - [ ] Spinner
 
 Phase 3 - Navigation & Overlay:
-- [ ] Menu
-- [ ] Tabs
-- [ ] Modal
-- [ ] Dialog
+- [x] Menu
+- [x] Tabs
+- [x] Modal
+- [x] Dialog
 
 Phase 4 - Advanced Components:
-- [ ] DataGrid
-- [ ] TreeView
+- [x] DataGrid
+- [x] TreeView
 - [ ] FileUpload
 - [ ] RichTextEditor
 
@@ -0,0 +1,359 @@
+// Modal component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::is_focusable;
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::components::card::Card;
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::{Point, Rect};
+use crate::style::Color;
+use std::any::Any;
+
+/// Stacking order for modals, above popups such as select lists
+const MODAL_Z_INDEX: i32 = 2000;
+
+fn count_focusable(node: &Node) -> usize {
+    usize::from(is_focusable(node)) + node.children().iter().map(count_focusable).sum::<usize>()
+}
+
+/// Mark the `target`th focusable node (in tree order) as focused and give it
+/// the theme's focus ring
+fn mark_focused(node: &mut Node, target: usize, seen: &mut usize, theme: &Theme) {
+    if is_focusable(node) {
+        if *seen == target {
+            node.add_attribute("data-focused".to_string(), "true".to_string());
+            theme.apply_focus_ring(node.style_mut());
+        }
+        *seen += 1;
+    }
+    for child in node.children_mut() {
+        mark_focused(child, target, seen, theme);
+    }
+}
+
+/// Modal dialog component
+#[derive(Debug)]
+pub struct Modal {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Whether the modal is shown
+    pub open: bool,
+    /// Dialog title
+    pub title: Option<String>,
+    /// Content rendered inside the dialog card
+    pub content: Vec<Node>,
+    /// Width of the dialog card
+    pub width: f32,
+    /// Height of the dialog card
+    pub height: f32,
+    /// Size of the viewport the backdrop covers
+    pub viewport: (f32, f32),
+    /// Whether clicking the backdrop closes the modal
+    pub close_on_backdrop: bool,
+    /// Close event handler
+    pub on_close: Option<fn()>,
+    /// Index of the focused element among the content's focusable nodes
+    focused: Option<usize>,
+    /// Theme the dialog is colored with
+    pub theme: Theme,
+}
+
+/// Modal props
+#[derive(Debug, Clone)]
+pub struct ModalProps {
+    /// Whether the modal is shown
+    pub open: Option<bool>,
+    /// Dialog title
+    pub title: Option<String>,
+    /// Content rendered inside the dialog card
+    pub content: Option<Vec<Node>>,
+    /// Width of the dialog card
+    pub width: Option<f32>,
+    /// Height of the dialog card
+    pub height: Option<f32>,
+    /// Size of the viewport the backdrop covers
+    pub viewport: Option<(f32, f32)>,
+    /// Whether clicking the backdrop closes the modal
+    pub close_on_backdrop: Option<bool>,
+    /// Close event handler
+    pub on_close: Option<fn()>,
+}
+
+impl Default for Modal {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            open: false,
+            title: None,
+            content: Vec::new(),
+            width: 480.0,
+            height: 320.0,
+            viewport: (800.0, 600.0),
+            close_on_backdrop: true,
+            on_close: None,
+            focused: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Modal {
+    /// Rectangle of the dialog card, centered in the viewport
+    pub fn dialog_rect(&self) -> Rect {
+        let (viewport_width, viewport_height) = self.viewport;
+        Rect::new(
+            ((viewport_width - self.width) / 2.0).max(0.0),
+            ((viewport_height - self.height) / 2.0).max(0.0),
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Close the modal and fire `on_close`
+    pub fn close(&mut self) {
+        if !self.open {
+            return;
+        }
+        self.open = false;
+        self.focused = None;
+        if let Some(on_close) = self.on_close {
+            on_close();
+        }
+    }
+
+    /// Handle a click at a point in viewport coordinates
+    ///
+    /// Clicks outside the dialog land on the backdrop and close the modal
+    /// when `close_on_backdrop` is set.
+    pub fn click(&mut self, point: (f32, f32)) {
+        let inside = self
+            .dialog_rect()
+            .contains_point(Point::new(point.0, point.1));
+        if self.open && !inside && self.close_on_backdrop {
+            self.close();
+        }
+    }
+
+    /// Index of the focused element among the content's focusable nodes
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    fn focusable_count(&self) -> usize {
+        self.content.iter().map(count_focusable).sum()
+    }
+
+    /// Move focus to the next focusable element, wrapping to the first so
+    /// focus never leaves the dialog
+    pub fn focus_next(&mut self) {
+        let count = self.focusable_count();
+        if count > 0 {
+            self.focused = Some(self.focused.map_or(0, |index| (index + 1) % count));
+        }
+    }
+
+    /// Move focus to the previous focusable element, wrapping to the last
+    pub fn focus_previous(&mut self) {
+        let count = self.focusable_count();
+        if count > 0 {
+            self.focused = Some(
+                self.focused
+                    .map_or(count - 1, |index| (index + count - 1) % count),
+            );
+        }
+    }
+
+    /// Handle a key press while the modal is open; returns whether the key was
+    /// handled
+    ///
+    /// Escape closes the modal and Tab cycles focus within it. Every key is
+    /// swallowed while open so nothing behind the modal reacts.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if !self.open {
+            return false;
+        }
+        match key {
+            "Escape" => self.close(),
+            "Tab" => self.focus_next(),
+            _ => {}
+        }
+        true
+    }
+}
+
+impl KeyHandler for Modal {
+    /// As [`Modal::handle_key`], with Shift-Tab cycling focus backwards
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        if !event.is_down() {
+            return self.open;
+        }
+        if self.open && event.key == "Tab" && event.modifiers.shift {
+            self.focus_previous();
+            return true;
+        }
+        self.handle_key(&event.key)
+    }
+}
+
+impl Component for Modal {
+    type Props = ModalProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut modal = Self {
+            theme: Theme::current(&context),
+            open: props.open.unwrap_or(false),
+            title: props.title,
+            content: props.content.unwrap_or_default(),
+            close_on_backdrop: props.close_on_backdrop.unwrap_or(true),
+            on_close: props.on_close,
+            ..Default::default()
+        };
+        modal.width = props.width.unwrap_or(modal.width);
+        modal.height = props.height.unwrap_or(modal.height);
+        modal.viewport = props.viewport.unwrap_or(modal.viewport);
+        modal
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.open = props.open.unwrap_or(self.open);
+        self.title = props.title;
+        if let Some(content) = props.content {
+            self.content = content;
+            self.focused = None;
+        }
+        self.width = props.width.unwrap_or(self.width);
+        self.height = props.height.unwrap_or(self.height);
+        self.viewport = props.viewport.unwrap_or(self.viewport);
+        self.close_on_backdrop = props.close_on_backdrop.unwrap_or(self.close_on_backdrop);
+        self.on_close = props.on_close;
+        if !self.open {
+            self.focused = None;
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        if !self.open {
+            return Ok(vec![]);
+        }
+
+        let (viewport_width, viewport_height) = self.viewport;
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, viewport_width, viewport_height));
+        root.style_mut().z_index = Some(MODAL_Z_INDEX);
+
+        let mut backdrop = Node::default();
+        backdrop.set_layout_rect(Rect::new(0.0, 0.0, viewport_width, viewport_height));
+        backdrop.style_mut().background_color = Some(Color::Rgba(0.0, 0.0, 0.0, 0.5));
+        root.add_child(backdrop);
+
+        let mut card = Card::default();
+        card.title = self.title.clone();
+        card.elevation = 4;
+        card.border_radius = format!("{}px", self.theme.radius(RadiusSize::Lg));
+        card.padding = format!("{}px", self.theme.spacing(4));
+        card.theme = self.theme.clone();
+        let (content_x, content_y) = card.content_origin();
+        let mut dialog = card.render()?.pop().unwrap_or_default();
+        dialog.set_layout_rect(self.dialog_rect());
+        dialog.add_attribute("role".to_string(), "dialog".to_string());
+        dialog.add_attribute("aria-modal".to_string(), "true".to_string());
+        if let Some(title) = &self.title {
+            dialog.add_attribute("aria-label".to_string(), title.clone());
+        }
+
+        // Content is positioned in a container below the card's title
+        let mut body = Node::default();
+        body.set_layout_rect(Rect::new(
+            content_x,
+            content_y,
+            (self.width - 2.0 * content_x).max(0.0),
+            (self.height - content_y - content_x).max(0.0),
+        ));
+        let mut seen = 0;
+        for node in &self.content {
+            let mut node = node.clone();
+            if let Some(focused) = self.focused {
+                mark_focused(&mut node, focused, &mut seen, &self.theme);
+            }
+            body.add_child(node);
+        }
+        dialog.add_child(body);
+        root.add_child(dialog);
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button() -> Node {
+        let mut node = Node::default();
+        node.add_attribute("role".to_string(), "button".to_string());
+        node
+    }
+
+    #[test]
+    fn test_backdrop_and_escape_close() {
+        let mut modal = Modal {
+            open: true,
+            ..Default::default()
+        };
+
+        // Inside the centered dialog
+        modal.click((400.0, 300.0));
+        assert!(modal.open);
+        modal.click((10.0, 10.0));
+        assert!(!modal.open);
+
+        modal.open = true;
+        modal.close_on_backdrop = false;
+        modal.click((10.0, 10.0));
+        assert!(modal.open);
+        assert!(modal.handle_key("Escape"));
+        assert!(!modal.open);
+        assert!(modal.render().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_focus_is_trapped() {
+        let mut disabled = button();
+        disabled.add_attribute("aria-disabled".to_string(), "true".to_string());
+        let mut modal = Modal {
+            open: true,
+            content: vec![button(), disabled, button()],
+            ..Default::default()
+        };
+
+        modal.handle_key("Tab");
+        modal.handle_key("Tab");
+        assert_eq!(modal.focused(), Some(1));
+        modal.handle_key("Tab");
+        assert_eq!(modal.focused(), Some(0));
+        modal.focus_previous();
+        assert_eq!(modal.focused(), Some(1));
+
+        let nodes = modal.render().unwrap();
+        let root = &nodes[0];
+        assert_eq!(root.style().z_index, Some(MODAL_Z_INDEX));
+        let dialog = &root.children()[1];
+        assert_eq!(dialog.attributes().get("role").unwrap(), "dialog");
+        let body = dialog.children().last().unwrap();
+        assert!(body.children()[2].attributes().contains_key("data-focused"));
+        assert!(!body.children()[0].attributes().contains_key("data-focused"));
+    }
+}
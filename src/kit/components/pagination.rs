@@ -0,0 +1,355 @@
+// Pagination component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::Rect;
+use crate::style::{BorderRadius, Color};
+use std::any::Any;
+
+/// Width and height of each control
+const BUTTON_SIZE: f32 = 32.0;
+
+/// Space between controls
+const BUTTON_GAP: f32 = 4.0;
+
+/// Default number of pages shown either side of the current one
+const DEFAULT_SIBLING_COUNT: usize = 1;
+
+/// One slot in the row of page numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    /// A numbered page, counting from 1
+    Page(usize),
+    /// A run of pages collapsed to "…"
+    Ellipsis,
+}
+
+/// Pagination component
+#[derive(Debug)]
+pub struct Pagination {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Number of pages
+    pub total_pages: usize,
+    /// The page being shown, counting from 1
+    pub current_page: usize,
+    /// How many pages to show either side of the current one before
+    /// collapsing the rest
+    pub sibling_count: usize,
+    /// Called with the new page when it changes
+    pub on_page_change: Option<fn(usize)>,
+    /// Theme providing colors and radius
+    pub theme: Theme,
+}
+
+/// Pagination props
+#[derive(Debug, Clone)]
+pub struct PaginationProps {
+    /// Number of pages
+    pub total_pages: usize,
+    /// The page being shown
+    pub current_page: Option<usize>,
+    /// Pages shown either side of the current one
+    pub sibling_count: Option<usize>,
+    /// Called with the new page when it changes
+    pub on_page_change: Option<fn(usize)>,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            total_pages: 1,
+            current_page: 1,
+            sibling_count: DEFAULT_SIBLING_COUNT,
+            on_page_change: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Pagination {
+    /// Pagination over `total_pages` pages, starting on the first
+    pub fn new(total_pages: usize) -> Self {
+        Self {
+            total_pages,
+            ..Default::default()
+        }
+    }
+
+    /// The page numbers to show, with collapsed runs as ellipses
+    ///
+    /// The first and last pages always show, along with `sibling_count`
+    /// pages either side of the current one. An ellipsis only replaces two
+    /// or more pages; a gap of one shows the page itself, so the row keeps
+    /// the same number of slots as the current page moves.
+    pub fn items(&self) -> Vec<PageItem> {
+        let total = self.total_pages;
+        let current = self.current_page.clamp(1, total.max(1));
+        // First, last, current, its siblings, and two ellipses
+        let slots = 2 * self.sibling_count + 5;
+        if total <= slots {
+            return (1..=total).map(PageItem::Page).collect();
+        }
+
+        let left = current.saturating_sub(self.sibling_count).max(1);
+        let right = (current + self.sibling_count).min(total);
+        let left_ellipsis = left > 3;
+        let right_ellipsis = right + 2 < total;
+        // Pages shown together at an end when that end isn't collapsed
+        let edge_run = 2 * self.sibling_count + 3;
+
+        let mut items = Vec::with_capacity(slots);
+        match (left_ellipsis, right_ellipsis) {
+            (false, _) => {
+                items.extend((1..=edge_run).map(PageItem::Page));
+                items.extend([PageItem::Ellipsis, PageItem::Page(total)]);
+            }
+            (true, false) => {
+                items.extend([PageItem::Page(1), PageItem::Ellipsis]);
+                items.extend((total + 1 - edge_run..=total).map(PageItem::Page));
+            }
+            (true, true) => {
+                items.extend([PageItem::Page(1), PageItem::Ellipsis]);
+                items.extend((left..=right).map(PageItem::Page));
+                items.extend([PageItem::Ellipsis, PageItem::Page(total)]);
+            }
+        }
+        items
+    }
+
+    /// Go to `page`, clamped to the valid range, and fire `on_page_change`
+    /// if it changed
+    pub fn set_page(&mut self, page: usize) {
+        let page = page.clamp(1, self.total_pages.max(1));
+        if page == self.current_page {
+            return;
+        }
+        self.current_page = page;
+        if let Some(on_page_change) = self.on_page_change {
+            on_page_change(page);
+        }
+    }
+
+    /// Whether there is a page before the current one
+    pub fn has_prev(&self) -> bool {
+        self.current_page > 1
+    }
+
+    /// Whether there is a page after the current one
+    pub fn has_next(&self) -> bool {
+        self.current_page < self.total_pages
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Left and Right step a page, Home and End jump to the ends.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        match key {
+            "ArrowLeft" => self.set_page(self.current_page.saturating_sub(1)),
+            "ArrowRight" => self.set_page(self.current_page + 1),
+            "Home" => self.set_page(1),
+            "End" => self.set_page(self.total_pages),
+            _ => return false,
+        }
+        true
+    }
+
+    fn control(&self, text: &str, label: &str, enabled: bool) -> Node {
+        let radius = self.theme.radius(RadiusSize::Sm);
+        let mut node = Node::default();
+        node.add_attribute("role".to_string(), "button".to_string());
+        node.add_attribute("aria-label".to_string(), label.to_string());
+        if !enabled {
+            node.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+        register_focusable(&mut node, !enabled);
+        node.set_text(text);
+        let color = if enabled {
+            &self.theme.text_color
+        } else {
+            &self.theme.muted_text_color
+        };
+        let style = node.style_mut();
+        style.color = Some(Color::Hex(color.clone()));
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        node
+    }
+}
+
+impl Component for Pagination {
+    type Props = PaginationProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            total_pages: props.total_pages,
+            current_page: props.current_page.unwrap_or(1),
+            sibling_count: props.sibling_count.unwrap_or(DEFAULT_SIBLING_COUNT),
+            on_page_change: props.on_page_change,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.total_pages = props.total_pages;
+        self.current_page = props
+            .current_page
+            .unwrap_or(self.current_page)
+            .clamp(1, self.total_pages.max(1));
+        self.sibling_count = props.sibling_count.unwrap_or(self.sibling_count);
+        self.on_page_change = props.on_page_change;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "navigation".to_string());
+        root.add_attribute("aria-label".to_string(), "Pagination".to_string());
+
+        let (prev, next) = (self.has_prev(), self.has_next());
+        let mut controls = vec![
+            self.control("«", "First page", prev),
+            self.control("‹", "Previous page", prev),
+        ];
+        for item in self.items() {
+            controls.push(match item {
+                PageItem::Page(page) => {
+                    let mut node = self.control(&page.to_string(), &format!("Page {page}"), true);
+                    if page == self.current_page {
+                        node.add_attribute("aria-current".to_string(), "page".to_string());
+                        node.style_mut().background_color =
+                            Some(Color::Hex(self.theme.primary_color.clone()));
+                        node.style_mut().color = Some(Color::Hex("#ffffff".to_string()));
+                    }
+                    node
+                }
+                PageItem::Ellipsis => {
+                    let mut node = Node::default();
+                    node.add_attribute("aria-hidden".to_string(), "true".to_string());
+                    node.set_text("…");
+                    node.style_mut().color = Some(Color::Hex(self.theme.muted_text_color.clone()));
+                    node
+                }
+            });
+        }
+        controls.push(self.control("›", "Next page", next));
+        controls.push(self.control("»", "Last page", next));
+
+        let step = BUTTON_SIZE + BUTTON_GAP;
+        let width = (controls.len() as f32 * step - BUTTON_GAP).max(0.0);
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, BUTTON_SIZE));
+        for (index, mut node) in controls.into_iter().enumerate() {
+            node.set_layout_rect(Rect::new(
+                index as f32 * step,
+                0.0,
+                BUTTON_SIZE,
+                BUTTON_SIZE,
+            ));
+            root.add_child(node);
+        }
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PageItem::{Ellipsis, Page};
+
+    fn at(page: usize, total: usize) -> Vec<PageItem> {
+        Pagination {
+            current_page: page,
+            ..Pagination::new(total)
+        }
+        .items()
+    }
+
+    #[test]
+    fn test_ellipsis_collapses_middle_pages() {
+        assert_eq!(
+            at(50, 100),
+            [
+                Page(1),
+                Ellipsis,
+                Page(49),
+                Page(50),
+                Page(51),
+                Ellipsis,
+                Page(100)
+            ]
+        );
+        assert_eq!(
+            at(3, 100),
+            [
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(100)
+            ]
+        );
+        assert_eq!(
+            at(98, 100),
+            [
+                Page(1),
+                Ellipsis,
+                Page(96),
+                Page(97),
+                Page(98),
+                Page(99),
+                Page(100)
+            ]
+        );
+        assert_eq!(at(2, 5), (1..=5).map(Page).collect::<Vec<_>>());
+
+        let wide = Pagination {
+            current_page: 50,
+            sibling_count: 2,
+            ..Pagination::new(100)
+        };
+        assert_eq!(wide.items().len(), 9);
+    }
+
+    #[test]
+    fn test_prev_and_next_disable_at_the_ends() {
+        let mut pagination = Pagination::new(10);
+        let root = &pagination.render().unwrap()[0];
+        let disabled = |node: &Node| node.attributes().contains_key("aria-disabled");
+        assert!(disabled(&root.children()[1]));
+        assert!(!disabled(root.children().last().unwrap()));
+        assert_eq!(
+            root.children()[2].attributes().get("aria-current").unwrap(),
+            "page"
+        );
+
+        assert!(pagination.handle_key("End"));
+        assert_eq!(pagination.current_page, 10);
+        let root = &pagination.render().unwrap()[0];
+        assert!(!disabled(&root.children()[0]));
+        assert!(disabled(root.children().last().unwrap()));
+
+        pagination.set_page(0);
+        assert_eq!(pagination.current_page, 1);
+    }
+}
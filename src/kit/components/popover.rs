@@ -0,0 +1,403 @@
+// Popover component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::components::tooltip::Placement;
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::layout::{EdgeValues, Point, Rect, Size};
+use crate::style::{BorderRadius, BoxShadow, Color, EdgeColors, Transform};
+use std::any::Any;
+
+/// Stacking order for popovers, level with other popups such as the
+/// Select listbox
+const POPOVER_Z_INDEX: i32 = 1000;
+
+/// Default gap between the trigger and the popover, leaving room for the
+/// arrow
+const DEFAULT_OFFSET: f32 = 8.0;
+
+/// Width and height of the arrow square, which is turned 45° so half of it
+/// pokes out towards the trigger
+const ARROW_SIZE: f32 = 10.0;
+
+/// Popover component, interactive content floating beside a trigger
+///
+/// Unlike a [`Tooltip`](super::tooltip::Tooltip) it stays open until it
+/// is explicitly closed, by pressing the trigger again, pressing outside,
+/// or Escape.
+#[derive(Debug)]
+pub struct Popover {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// The element the popover opens from; its layout rect anchors the
+    /// popover
+    pub trigger: Option<Node>,
+    /// Popover content, laid out inside the padding by its own rects
+    pub content: Vec<Node>,
+    /// Whether the popover is showing
+    pub open: bool,
+    /// Preferred placement relative to the trigger
+    pub placement: Placement,
+    /// Gap between the trigger and the popover
+    pub offset: f32,
+    /// Whether to draw an arrow pointing at the trigger
+    pub arrow: bool,
+    /// Size of the viewport the popover is kept inside
+    pub viewport: (f32, f32),
+    /// Called when the popover closes
+    pub on_close: Option<fn()>,
+    /// Theme providing surface colors and spacing
+    pub theme: Theme,
+}
+
+/// Popover props
+#[derive(Debug, Clone, Default)]
+pub struct PopoverProps {
+    /// The element the popover opens from
+    pub trigger: Option<Node>,
+    /// Popover content
+    pub content: Vec<Node>,
+    /// Whether the popover is showing
+    pub open: Option<bool>,
+    /// Preferred placement relative to the trigger
+    pub placement: Option<Placement>,
+    /// Gap between the trigger and the popover
+    pub offset: Option<f32>,
+    /// Whether to draw an arrow pointing at the trigger
+    pub arrow: Option<bool>,
+    /// Size of the viewport the popover is kept inside
+    pub viewport: Option<(f32, f32)>,
+    /// Called when the popover closes
+    pub on_close: Option<fn()>,
+}
+
+impl Default for Popover {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            trigger: None,
+            content: Vec::new(),
+            open: false,
+            placement: Placement::Bottom,
+            offset: DEFAULT_OFFSET,
+            arrow: false,
+            viewport: (800.0, 600.0),
+            on_close: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Popover {
+    /// Close the popover, notifying `on_close` if it was open
+    pub fn close(&mut self) {
+        if self.open {
+            self.open = false;
+            if let Some(on_close) = self.on_close {
+                on_close();
+            }
+        }
+    }
+
+    /// Open the popover if it is closed, and close it if it is open
+    pub fn toggle(&mut self) {
+        if self.open {
+            self.close();
+        } else {
+            self.open = true;
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Escape closes an open popover.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if key != "Escape" || !self.open {
+            return false;
+        }
+        self.close();
+        true
+    }
+
+    /// Handle a pointer press at `point`, in the trigger's coordinate space;
+    /// returns whether it opened or closed the popover
+    ///
+    /// Pressing the trigger toggles the popover, pressing outside both the
+    /// trigger and the popover closes it, and presses on the popover itself
+    /// are left to its content.
+    pub fn pointer_down(&mut self, point: (f32, f32)) -> bool {
+        let point = Point::new(point.0, point.1);
+        if self.anchor().contains_point(point) {
+            self.toggle();
+            return true;
+        }
+        if self.open && !self.popover_rect().contains_point(point) {
+            self.close();
+            return true;
+        }
+        false
+    }
+
+    fn anchor(&self) -> Rect {
+        self.trigger
+            .as_ref()
+            .map_or(Rect::zero(), Node::layout_rect)
+    }
+
+    fn padding(&self) -> f32 {
+        self.theme.spacing(3)
+    }
+
+    /// Size of the popover: its content's extent plus padding
+    pub fn size(&self) -> Size {
+        let (width, height) = self.content.iter().fold((0.0f32, 0.0f32), |(w, h), node| {
+            let rect = node.layout_rect();
+            (w.max(rect.max_x()), h.max(rect.max_y()))
+        });
+        let padding = self.padding();
+        Size::new(width + 2.0 * padding, height + 2.0 * padding)
+    }
+
+    /// The placement actually used and where the popover sits: beside the
+    /// trigger, flipped and shifted as needed to stay on screen
+    fn placed(&self) -> (Placement, Rect) {
+        let viewport = Size::new(self.viewport.0, self.viewport.1);
+        self.placement
+            .place(self.anchor(), self.size(), self.offset, viewport)
+    }
+
+    /// The placement actually used, after flipping away from viewport edges
+    pub fn resolved_placement(&self) -> Placement {
+        self.placed().0
+    }
+
+    /// Where the popover sits, in the trigger's coordinate space
+    pub fn popover_rect(&self) -> Rect {
+        self.placed().1
+    }
+
+    /// The arrow square, in the popover's coordinates, on the edge facing
+    /// the trigger and lined up with the trigger's center as far as the
+    /// rounded corners allow
+    fn arrow_rect(&self, rect: Rect) -> Rect {
+        let anchor = self.anchor();
+        let inset = self.theme.radius(RadiusSize::Md) + 1.0;
+        let half = ARROW_SIZE / 2.0;
+        let along = |center: f32, start: f32, length: f32| {
+            let lowest = inset;
+            let highest = (length - inset - ARROW_SIZE).max(lowest);
+            (center - start - half).clamp(lowest, highest)
+        };
+        let center_x = anchor.x() + anchor.width() / 2.0;
+        let center_y = anchor.y() + anchor.height() / 2.0;
+        let (x, y) = match self.resolved_placement() {
+            Placement::Bottom => (along(center_x, rect.x(), rect.width()), -half),
+            Placement::Top => (
+                along(center_x, rect.x(), rect.width()),
+                rect.height() - half,
+            ),
+            Placement::Right => (-half, along(center_y, rect.y(), rect.height())),
+            Placement::Left => (
+                rect.width() - half,
+                along(center_y, rect.y(), rect.height()),
+            ),
+        };
+        Rect::new(x, y, ARROW_SIZE, ARROW_SIZE)
+    }
+}
+
+impl KeyHandler for Popover {
+    /// Escape closes the popover; other keys bubble on
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl Component for Popover {
+    type Props = PopoverProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut popover = Self {
+            trigger: props.trigger,
+            content: props.content,
+            open: props.open.unwrap_or(false),
+            placement: props.placement.unwrap_or(Placement::Bottom),
+            offset: props.offset.unwrap_or(DEFAULT_OFFSET),
+            arrow: props.arrow.unwrap_or(false),
+            on_close: props.on_close,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        popover.viewport = props.viewport.unwrap_or(popover.viewport);
+        popover
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.trigger = props.trigger;
+        self.content = props.content;
+        self.open = props.open.unwrap_or(self.open);
+        self.placement = props.placement.unwrap_or(self.placement);
+        self.offset = props.offset.unwrap_or(self.offset);
+        self.arrow = props.arrow.unwrap_or(self.arrow);
+        self.viewport = props.viewport.unwrap_or(self.viewport);
+        self.on_close = props.on_close;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut nodes: Vec<Node> = self.trigger.iter().cloned().collect();
+        if let Some(trigger) = nodes.first_mut() {
+            trigger.add_attribute("aria-haspopup".to_string(), "dialog".to_string());
+            trigger.add_attribute("aria-expanded".to_string(), self.open.to_string());
+        }
+        if !self.open {
+            return Ok(nodes);
+        }
+
+        // Like a tooltip, the popover is a sibling of the trigger in the
+        // trigger's coordinate space
+        let rect = self.popover_rect();
+        let surface = Color::Hex(self.theme.surface_color.clone());
+        let border = Color::Hex(self.theme.border_color.clone());
+        let mut popover = Node::default();
+        popover.add_attribute("role".to_string(), "dialog".to_string());
+        popover.set_layout_rect(rect);
+        let radius = self.theme.radius(RadiusSize::Md);
+        let style = popover.style_mut();
+        style.background_color = Some(surface.clone());
+        style.border_width = Some(EdgeValues::uniform(1.0));
+        style.border_color = Some(EdgeColors {
+            top: border.clone(),
+            right: border.clone(),
+            bottom: border.clone(),
+            left: border.clone(),
+        });
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        style.box_shadow = Some(vec![BoxShadow::new(
+            0.0,
+            4.0,
+            16.0,
+            Color::Rgba(0.0, 0.0, 0.0, 0.15),
+        )]);
+        style.z_index = Some(POPOVER_Z_INDEX);
+
+        if self.arrow {
+            // Painted first, so the popover's content covers its inner half
+            let mut arrow = Node::default();
+            arrow.add_attribute(
+                "data-placement".to_string(),
+                format!("{:?}", self.resolved_placement()).to_lowercase(),
+            );
+            arrow.set_layout_rect(self.arrow_rect(rect));
+            let style = arrow.style_mut();
+            style.background_color = Some(surface);
+            style.transform = Some(Transform::Rotate(45.0));
+            popover.add_child(arrow);
+        }
+
+        let padding = self.padding();
+        for node in &self.content {
+            let mut node = node.clone();
+            let inner = node.layout_rect();
+            node.set_layout_rect(Rect::new(
+                inner.x() + padding,
+                inner.y() + padding,
+                inner.width(),
+                inner.height(),
+            ));
+            popover.add_child(node);
+        }
+
+        nodes.push(popover);
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn anchored(x: f32, y: f32) -> Popover {
+        let mut trigger = Node::default();
+        trigger.set_layout_rect(Rect::new(x, y, 80.0, 30.0));
+        let mut body = Node::default();
+        body.set_layout_rect(Rect::new(0.0, 0.0, 176.0, 96.0));
+        Popover {
+            trigger: Some(trigger),
+            content: vec![body],
+            open: true,
+            arrow: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_flips_and_shifts_to_stay_on_screen() {
+        let popover = anchored(100.0, 100.0);
+        let rect = popover.popover_rect();
+        assert_eq!(rect.y(), 130.0 + DEFAULT_OFFSET);
+        assert_eq!(rect.width(), 176.0 + 2.0 * popover.padding());
+
+        // Near the bottom it flips above, with the arrow on its bottom edge
+        let popover = anchored(300.0, 560.0);
+        assert_eq!(popover.resolved_placement(), Placement::Top);
+        let rect = popover.popover_rect();
+        assert_eq!(rect.max_y(), 560.0 - DEFAULT_OFFSET);
+        let nodes = popover.render().unwrap();
+        assert_eq!(nodes[1].style().z_index, Some(POPOVER_Z_INDEX));
+        let arrow = nodes[1].children()[0].layout_rect();
+        assert_eq!(arrow.y(), rect.height() - ARROW_SIZE / 2.0);
+
+        // Near the right edge it slides left, and the arrow still points at
+        // the trigger's center
+        let popover = anchored(740.0, 100.0);
+        let rect = popover.popover_rect();
+        assert_eq!(rect.max_x(), 800.0);
+        let nodes = popover.render().unwrap();
+        let arrow = nodes[1].children()[0].layout_rect();
+        assert_eq!(arrow.y(), -ARROW_SIZE / 2.0);
+        assert!((rect.x() + arrow.x() + ARROW_SIZE / 2.0 - 780.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_closes_on_outside_press_and_escape() {
+        static CLOSED: AtomicBool = AtomicBool::new(false);
+
+        let mut popover = anchored(100.0, 100.0);
+        popover.on_close = Some(|| CLOSED.store(true, Ordering::SeqCst));
+
+        // Presses inside the content are left alone
+        let inside = popover.popover_rect();
+        assert!(!popover.pointer_down((inside.x() + 10.0, inside.y() + 10.0)));
+        assert!(popover.open);
+
+        assert!(popover.pointer_down((600.0, 500.0)));
+        assert!(!popover.open);
+        assert!(CLOSED.swap(false, Ordering::SeqCst));
+        assert_eq!(popover.render().unwrap().len(), 1);
+
+        assert!(popover.pointer_down((110.0, 110.0)));
+        assert!(popover.open);
+        assert!(popover.handle_key_event(&KeyEvent::down("Escape")));
+        assert!(!popover.open);
+        assert!(CLOSED.load(Ordering::SeqCst));
+    }
+}
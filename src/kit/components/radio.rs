@@ -0,0 +1,351 @@
+// Radio and RadioGroup components for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::{EdgeValues, Rect};
+use crate::style::{BorderRadius, Color, EdgeColors};
+use std::any::Any;
+
+/// Diameter of the radio circle in logical pixels
+const CIRCLE_SIZE: f32 = 16.0;
+
+/// Diameter of the selection dot
+const DOT_SIZE: f32 = 8.0;
+
+/// Gap between the circle and its label
+const LABEL_GAP: f32 = 8.0;
+
+/// Vertical gap between radios in a group
+const ROW_GAP: f32 = 8.0;
+
+fn circle_radius(size: f32) -> BorderRadius {
+    let radius = size / 2.0;
+    BorderRadius {
+        top_left: radius,
+        top_right: radius,
+        bottom_right: radius,
+        bottom_left: radius,
+    }
+}
+
+/// Radio button component
+#[derive(Debug, Default)]
+pub struct Radio {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Value reported to the group when selected
+    pub value: String,
+    /// Label shown next to the circle
+    pub label: String,
+    /// Whether this radio is selected
+    pub checked: bool,
+    /// Whether the radio is disabled
+    pub disabled: bool,
+    /// Theme the circle and label are colored with
+    pub theme: Theme,
+}
+
+/// Radio props
+#[derive(Debug, Clone)]
+pub struct RadioProps {
+    /// Value reported to the group when selected
+    pub value: String,
+    /// Label shown next to the circle
+    pub label: Option<String>,
+    /// Whether this radio is selected
+    pub checked: Option<bool>,
+    /// Whether the radio is disabled
+    pub disabled: Option<bool>,
+}
+
+impl Component for Radio {
+    type Props = RadioProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            theme: Theme::current(&context),
+            id: ComponentId::new(),
+            value: props.value,
+            label: props.label.unwrap_or_default(),
+            checked: props.checked.unwrap_or(false),
+            disabled: props.disabled.unwrap_or(false),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.value = props.value;
+        self.label = props.label.unwrap_or_default();
+        self.checked = props.checked.unwrap_or(self.checked);
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let accent = Color::Hex(if self.disabled {
+            self.theme.disabled_color.clone()
+        } else {
+            self.theme.primary_color.clone()
+        });
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "radio".to_string());
+        root.add_attribute("aria-checked".to_string(), self.checked.to_string());
+        root.add_attribute("value".to_string(), self.value.clone());
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+
+        let mut circle = Node::default();
+        circle.set_layout_rect(Rect::new(0.0, 0.0, CIRCLE_SIZE, CIRCLE_SIZE));
+        let style = circle.style_mut();
+        style.border_width = Some(EdgeValues::new(1.5, 1.5, 1.5, 1.5));
+        style.border_color = Some(EdgeColors {
+            top: accent.clone(),
+            right: accent.clone(),
+            bottom: accent.clone(),
+            left: accent.clone(),
+        });
+        style.border_radius = Some(circle_radius(CIRCLE_SIZE));
+
+        if self.checked {
+            let offset = (CIRCLE_SIZE - DOT_SIZE) / 2.0;
+            let mut dot = Node::default();
+            dot.set_layout_rect(Rect::new(offset, offset, DOT_SIZE, DOT_SIZE));
+            dot.style_mut().background_color = Some(accent);
+            dot.style_mut().border_radius = Some(circle_radius(DOT_SIZE));
+            circle.add_child(dot);
+        }
+        root.add_child(circle);
+
+        let mut width = CIRCLE_SIZE;
+        if !self.label.is_empty() {
+            let mut label_node = Node::default();
+            label_node.set_layout_rect(Rect::new(CIRCLE_SIZE + LABEL_GAP, 0.0, 0.0, CIRCLE_SIZE));
+            label_node.set_text(self.label.clone());
+            label_node.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+            root.add_child(label_node);
+            width += LABEL_GAP;
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, CIRCLE_SIZE));
+
+        Ok(vec![root])
+    }
+}
+
+/// Group of mutually exclusive radio buttons
+#[derive(Debug, Default)]
+pub struct RadioGroup {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Value of the selected option
+    pub value: String,
+    /// Options as `(value, label)` pairs
+    pub options: Vec<(String, String)>,
+    /// Whether the whole group is disabled
+    pub disabled: bool,
+    /// Change event handler, called with the newly selected value
+    pub on_change: Option<fn(&str)>,
+    /// Theme passed on to each radio
+    pub theme: Theme,
+}
+
+/// RadioGroup props
+#[derive(Debug, Clone)]
+pub struct RadioGroupProps {
+    /// Value of the selected option
+    pub value: Option<String>,
+    /// Options as `(value, label)` pairs
+    pub options: Vec<(String, String)>,
+    /// Whether the whole group is disabled
+    pub disabled: Option<bool>,
+    /// Change event handler, called with the newly selected value
+    pub on_change: Option<fn(&str)>,
+}
+
+impl RadioGroup {
+    /// Index of the selected option, if the value matches one
+    pub fn selected_index(&self) -> Option<usize> {
+        self.options
+            .iter()
+            .position(|(value, _)| *value == self.value)
+    }
+
+    /// Select the option with the given value, as when its radio is clicked
+    ///
+    /// Unknown values and clicks on a disabled group are ignored. `on_change`
+    /// only fires when the selection actually changes.
+    pub fn select(&mut self, value: &str) {
+        if self.disabled || self.value == value {
+            return;
+        }
+        if !self.options.iter().any(|(option, _)| option == value) {
+            return;
+        }
+        self.value = value.to_string();
+        if let Some(on_change) = self.on_change {
+            on_change(&self.value);
+        }
+    }
+
+    /// Move the selection in response to a key press
+    ///
+    /// `ArrowDown`/`ArrowRight` select the next option and `ArrowUp`/
+    /// `ArrowLeft` the previous one, wrapping at either end. Returns whether
+    /// the key was handled.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        let forward = match key {
+            "ArrowDown" | "ArrowRight" => true,
+            "ArrowUp" | "ArrowLeft" => false,
+            _ => return false,
+        };
+        if self.disabled || self.options.is_empty() {
+            return false;
+        }
+
+        let count = self.options.len();
+        let next = match (self.selected_index(), forward) {
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        let value = self.options[next].0.clone();
+        self.select(&value);
+        true
+    }
+}
+
+impl Component for RadioGroup {
+    type Props = RadioGroupProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            theme: Theme::current(&context),
+            id: ComponentId::new(),
+            value: props.value.unwrap_or_default(),
+            options: props.options,
+            disabled: props.disabled.unwrap_or(false),
+            on_change: props.on_change,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        if let Some(value) = props.value {
+            self.value = value;
+        }
+        self.options = props.options;
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.on_change = props.on_change;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "radiogroup".to_string());
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+        for (value, label) in &self.options {
+            let radio = Radio {
+                value: value.clone(),
+                label: label.clone(),
+                checked: *value == self.value,
+                disabled: self.disabled,
+                theme: self.theme.clone(),
+                ..Default::default()
+            };
+            for mut node in radio.render()? {
+                let rect = node.layout_rect();
+                width = width.max(rect.size.width);
+                node.set_layout_rect(Rect::new(0.0, y, rect.size.width, rect.size.height));
+                root.add_child(node);
+            }
+            y += CIRCLE_SIZE + ROW_GAP;
+        }
+
+        let height = if self.options.is_empty() {
+            0.0
+        } else {
+            y - ROW_GAP
+        };
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> RadioGroup {
+        RadioGroup {
+            value: "b".to_string(),
+            options: vec![
+                ("a".to_string(), "A".to_string()),
+                ("b".to_string(), "B".to_string()),
+                ("c".to_string(), "C".to_string()),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_arrow_keys_wrap_selection() {
+        let mut group = group();
+
+        assert!(group.handle_key("ArrowDown"));
+        assert_eq!(group.value, "c");
+        assert!(group.handle_key("ArrowRight"));
+        assert_eq!(group.value, "a");
+        assert!(group.handle_key("ArrowUp"));
+        assert_eq!(group.value, "c");
+        assert!(!group.handle_key("Enter"));
+
+        group.select("missing");
+        assert_eq!(group.value, "c");
+    }
+
+    #[test]
+    fn test_render_selects_single_radio() {
+        let nodes = group().render().unwrap();
+        let root = &nodes[0];
+        assert_eq!(root.attributes().get("role").unwrap(), "radiogroup");
+
+        let checked: Vec<_> = root
+            .children()
+            .iter()
+            .filter(|radio| radio.attributes().get("aria-checked").unwrap() == "true")
+            .map(|radio| radio.attributes().get("value").unwrap().as_str())
+            .collect();
+        assert_eq!(checked, vec!["b"]);
+        assert_eq!(root.children()[2].layout_rect().origin.y, 48.0);
+    }
+}
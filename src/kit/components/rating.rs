@@ -0,0 +1,390 @@
+// Rating component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::events::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use crate::style::Color;
+use std::any::Any;
+
+/// Width and height of each star
+const STAR_SIZE: f32 = 24.0;
+
+/// Space between stars
+const STAR_GAP: f32 = 4.0;
+
+/// Default number of stars
+const DEFAULT_MAX: u8 = 5;
+
+/// A five-pointed star filling a 24×24 box
+const STAR_PATH: &str =
+    "M12 2L14.81 8.63 22 9.24 16.54 13.97 18.18 21 12 17.27 5.82 21 7.46 13.97 2 9.24 9.19 8.63z";
+
+/// The left half of [`STAR_PATH`], drawn over an empty star for a half
+const HALF_STAR_PATH: &str = "M12 2L9.19 8.63 2 9.24 7.46 13.97 5.82 21 12 17.27z";
+
+/// How much of a star is filled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fill {
+    Empty,
+    Half,
+    Full,
+}
+
+/// Rating component, a row of stars showing or choosing a score
+#[derive(Debug)]
+pub struct Rating {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Current score, from 0 to `max`
+    pub value: f32,
+    /// Number of stars
+    pub max: u8,
+    /// Whether half stars can be chosen
+    pub allow_half: bool,
+    /// Shows the score without letting it change
+    pub readonly: bool,
+    /// Called with the new score when it changes
+    pub on_change: Option<fn(f32)>,
+    /// Score previewed under the pointer
+    hover: Option<f32>,
+    /// Theme providing the star colors
+    pub theme: Theme,
+}
+
+/// Rating props
+#[derive(Debug, Clone, Default)]
+pub struct RatingProps {
+    /// Current score
+    pub value: Option<f32>,
+    /// Number of stars
+    pub max: Option<u8>,
+    /// Whether half stars can be chosen
+    pub allow_half: Option<bool>,
+    /// Shows the score without letting it change
+    pub readonly: Option<bool>,
+    /// Called with the new score when it changes
+    pub on_change: Option<fn(f32)>,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            value: 0.0,
+            max: DEFAULT_MAX,
+            allow_half: false,
+            readonly: false,
+            on_change: None,
+            hover: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Rating {
+    /// A rating out of `max` stars showing `value`
+    pub fn new(value: f32, max: u8) -> Self {
+        Self {
+            value: value.clamp(0.0, max as f32),
+            max,
+            ..Default::default()
+        }
+    }
+
+    /// How far the arrow keys move the score
+    pub fn step(&self) -> f32 {
+        if self.allow_half {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Set the score, snapped to a whole or half star and clamped to
+    /// `0..=max`, and fire `on_change` if it changed
+    pub fn set_value(&mut self, value: f32) {
+        if self.readonly {
+            return;
+        }
+        let value = self.snap(value);
+        if value == self.value {
+            return;
+        }
+        self.value = value;
+        if let Some(on_change) = self.on_change {
+            on_change(value);
+        }
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let step = self.step();
+        ((value / step).round() * step).clamp(0.0, self.max as f32)
+    }
+
+    /// The score a press at `x` along the row would choose
+    ///
+    /// Anywhere on a star picks that star; with `allow_half` its left half
+    /// picks half a star less. The gap after a star belongs to it.
+    pub fn value_at(&self, x: f32) -> f32 {
+        let step = STAR_SIZE + STAR_GAP;
+        let x = x.max(0.0);
+        let index = ((x / step).floor() as u8).min(self.max.saturating_sub(1));
+        let offset = x - index as f32 * step;
+        let whole = index as f32 + 1.0;
+        if self.allow_half && offset < STAR_SIZE / 2.0 {
+            whole - 0.5
+        } else {
+            whole
+        }
+        .min(self.max as f32)
+    }
+
+    /// Preview the score under the pointer at `x`
+    pub fn hover_at(&mut self, x: f32) {
+        if !self.readonly {
+            self.hover = Some(self.value_at(x));
+        }
+    }
+
+    /// End the preview when the pointer leaves
+    pub fn hover_out(&mut self) {
+        self.hover = None;
+    }
+
+    /// The score being previewed, if the pointer is over the stars
+    pub fn hovered(&self) -> Option<f32> {
+        self.hover
+    }
+
+    /// Choose the score at `x` along the row
+    pub fn click(&mut self, x: f32) {
+        self.set_value(self.value_at(x));
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Left and Right move by a star, or half a star with `allow_half`;
+    /// Home clears the score and End fills every star.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if self.readonly {
+            return false;
+        }
+        match key {
+            "ArrowLeft" | "ArrowDown" => self.set_value(self.value - self.step()),
+            "ArrowRight" | "ArrowUp" => self.set_value(self.value + self.step()),
+            "Home" => self.set_value(0.0),
+            "End" => self.set_value(self.max as f32),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Width of the row of stars
+    pub fn width(&self) -> f32 {
+        (self.max as f32 * (STAR_SIZE + STAR_GAP) - STAR_GAP).max(0.0)
+    }
+
+    fn fill(shown: f32, index: u8) -> Fill {
+        let filled = shown - index as f32;
+        if filled >= 1.0 {
+            Fill::Full
+        } else if filled >= 0.5 {
+            Fill::Half
+        } else {
+            Fill::Empty
+        }
+    }
+
+    fn star(&self, fill: Fill) -> Node {
+        let filled = Color::Hex(self.theme.warning_color.clone());
+        let empty = Color::Hex(self.theme.border_color.clone());
+        let mut node = Node::default();
+        node.add_attribute("path".to_string(), STAR_PATH.to_string());
+        node.style_mut().color = Some(if fill == Fill::Full {
+            filled.clone()
+        } else {
+            empty
+        });
+        if fill == Fill::Half {
+            let mut half = Node::default();
+            half.set_layout_rect(Rect::new(0.0, 0.0, STAR_SIZE, STAR_SIZE));
+            half.add_attribute("path".to_string(), HALF_STAR_PATH.to_string());
+            half.style_mut().color = Some(filled);
+            node.add_child(half);
+        }
+        node
+    }
+}
+
+impl Component for Rating {
+    type Props = RatingProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let max = props.max.unwrap_or(DEFAULT_MAX);
+        Self {
+            value: props.value.unwrap_or(0.0).clamp(0.0, max as f32),
+            max,
+            allow_half: props.allow_half.unwrap_or(false),
+            readonly: props.readonly.unwrap_or(false),
+            on_change: props.on_change,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.max = props.max.unwrap_or(self.max);
+        self.value = props
+            .value
+            .unwrap_or(self.value)
+            .clamp(0.0, self.max as f32);
+        self.allow_half = props.allow_half.unwrap_or(self.allow_half);
+        self.readonly = props.readonly.unwrap_or(self.readonly);
+        self.on_change = props.on_change;
+        if self.readonly {
+            self.hover = None;
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "slider".to_string());
+        root.add_attribute("aria-valuemin".to_string(), "0".to_string());
+        root.add_attribute("aria-valuemax".to_string(), self.max.to_string());
+        root.add_attribute("aria-valuenow".to_string(), self.value.to_string());
+        root.add_attribute(
+            "aria-valuetext".to_string(),
+            format!("{} of {} stars", self.value, self.max),
+        );
+        if self.readonly {
+            root.add_attribute("aria-readonly".to_string(), "true".to_string());
+        } else {
+            register_focusable(&mut root, false);
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width(), STAR_SIZE));
+
+        // The preview replaces the score while the pointer is over the row
+        let shown = self.hover.unwrap_or(self.value);
+        for index in 0..self.max {
+            let mut star = self.star(Self::fill(shown, index));
+            star.set_layout_rect(Rect::new(
+                index as f32 * (STAR_SIZE + STAR_GAP),
+                0.0,
+                STAR_SIZE,
+                STAR_SIZE,
+            ));
+            root.add_child(star);
+        }
+        Ok(vec![root])
+    }
+}
+
+impl KeyHandler for Rating {
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl PointerHandler for Rating {
+    /// Moving over the stars previews a score and a primary press chooses it
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool {
+        if self.readonly {
+            return false;
+        }
+        match event.kind {
+            PointerEventKind::Move | PointerEventKind::Enter => {
+                self.hover_at(event.local.0);
+                true
+            }
+            PointerEventKind::Leave => {
+                self.hover_out();
+                true
+            }
+            PointerEventKind::Down if event.is_primary() => {
+                self.click(event.local.0);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    static CHANGES: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+
+    fn fills(rating: &Rating) -> Vec<&'static str> {
+        let root = &rating.render().unwrap()[0];
+        root.children()
+            .iter()
+            .map(
+                |star| match (star.children().len(), star.style().color.as_ref()) {
+                    (1, _) => "half",
+                    (_, Some(Color::Hex(color))) if *color == rating.theme.warning_color => "full",
+                    _ => "empty",
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn test_click_sets_whole_and_half_stars() {
+        let mut rating = Rating::new(0.0, 5);
+        rating.on_change = Some(|value| CHANGES.lock().unwrap().push(value));
+        let step = STAR_SIZE + STAR_GAP;
+
+        rating.click(2.0 * step + 4.0);
+        assert_eq!(rating.value, 3.0);
+
+        rating.allow_half = true;
+        rating.click(2.0 * step + 4.0);
+        assert_eq!(rating.value, 2.5);
+        assert_eq!(fills(&rating), ["full", "full", "half", "empty", "empty"]);
+        rating.click(4.0 * step + 20.0);
+        assert_eq!(rating.value, 5.0);
+        assert_eq!(*CHANGES.lock().unwrap(), [3.0, 2.5, 5.0]);
+
+        rating.readonly = true;
+        rating.click(0.0);
+        assert!(!rating.handle_key("Home"));
+        assert_eq!(rating.value, 5.0);
+    }
+
+    #[test]
+    fn test_hover_previews_and_keys_step() {
+        let mut rating = Rating::new(1.0, 5);
+        rating.hover_at(3.0 * (STAR_SIZE + STAR_GAP) + 20.0);
+        assert_eq!(rating.hovered(), Some(4.0));
+        assert_eq!(fills(&rating), ["full", "full", "full", "full", "empty"]);
+        rating.hover_out();
+        assert_eq!(fills(&rating), ["full", "empty", "empty", "empty", "empty"]);
+
+        assert!(rating.handle_key("ArrowRight"));
+        assert_eq!(rating.value, 2.0);
+        rating.allow_half = true;
+        assert!(rating.handle_key("ArrowLeft"));
+        assert_eq!(rating.value, 1.5);
+        assert!(rating.handle_key("End"));
+        assert!(rating.handle_key("ArrowRight"));
+        assert_eq!(rating.value, 5.0);
+    }
+}
@@ -0,0 +1,555 @@
+// ScrollView component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::{Point, Rect, Size};
+use crate::style::{BorderRadius, Color, Overflow};
+use std::any::Any;
+
+/// Default viewport width
+const DEFAULT_WIDTH: f32 = 300.0;
+
+/// Default viewport height
+const DEFAULT_HEIGHT: f32 = 200.0;
+
+/// Thickness of a scrollbar thumb
+const SCROLLBAR_THICKNESS: f32 = 6.0;
+
+/// Gap between a scrollbar thumb and the viewport edge
+const SCROLLBAR_INSET: f32 = 2.0;
+
+/// Shortest a thumb gets, however long the content, so it stays grabbable
+const MIN_THUMB_LENGTH: f32 = 24.0;
+
+/// Axes a scroll view scrolls along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollDirection {
+    /// Scroll up and down
+    #[default]
+    Vertical,
+    /// Scroll left and right
+    Horizontal,
+    /// Scroll along both axes
+    Both,
+}
+
+impl ScrollDirection {
+    fn horizontal(self) -> bool {
+        matches!(self, ScrollDirection::Horizontal | ScrollDirection::Both)
+    }
+
+    fn vertical(self) -> bool {
+        matches!(self, ScrollDirection::Vertical | ScrollDirection::Both)
+    }
+}
+
+/// What a pointer press is dragging
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    /// The content itself, which follows the pointer
+    Content {
+        from: (f32, f32),
+        offset: (f32, f32),
+    },
+    /// A scrollbar thumb, which moves the content the opposite way and
+    /// faster, by the content-to-track ratio
+    Thumb {
+        vertical: bool,
+        from: f32,
+        offset: f32,
+    },
+}
+
+/// Scroll view component
+///
+/// Children are laid out in content coordinates, and whatever falls outside
+/// the viewport is clipped unless `overflow` is `Visible`.
+#[derive(Debug)]
+pub struct ScrollView {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Axes the view scrolls along
+    pub direction: ScrollDirection,
+    /// Viewport width
+    pub width: f32,
+    /// Viewport height
+    pub height: f32,
+    /// Scrolled content, positioned by each child's layout rect
+    pub children: Vec<Node>,
+    /// Scroll event handler, called with the new offset
+    pub on_scroll: Option<fn((f32, f32))>,
+    /// When scrollbars show: `Auto` once the content overflows, `Scroll`
+    /// always along the axes the view scrolls, and `Hidden` never, though
+    /// the view still scrolls; `Visible` doesn't clip either
+    pub overflow: Overflow,
+    /// How far the content is scrolled, always within its bounds
+    offset: (f32, f32),
+    /// Drag in progress, if any
+    drag: Option<Drag>,
+    /// Theme the scrollbar is colored with
+    pub theme: Theme,
+}
+
+/// Scroll view props
+#[derive(Debug, Clone)]
+pub struct ScrollViewProps {
+    /// Axes the view scrolls along
+    pub direction: Option<ScrollDirection>,
+    /// Viewport width
+    pub width: Option<f32>,
+    /// Viewport height
+    pub height: Option<f32>,
+    /// Scroll offset
+    pub offset: Option<(f32, f32)>,
+    /// Scrolled content
+    pub children: Vec<Node>,
+    /// Scroll event handler
+    pub on_scroll: Option<fn((f32, f32))>,
+    /// When scrollbars show
+    pub overflow: Option<Overflow>,
+}
+
+impl Default for ScrollView {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            direction: ScrollDirection::default(),
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            children: Vec::new(),
+            on_scroll: None,
+            overflow: Overflow::Auto,
+            offset: (0.0, 0.0),
+            drag: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl ScrollView {
+    /// A scroll view standing in for `container`, with its size, children,
+    /// and `overflow`, scrolling along both axes
+    ///
+    /// This is how a node with `Scroll` or `Auto` overflow gets scrollbars.
+    pub fn for_container(container: &Node) -> Self {
+        let rect = container.layout_rect();
+        Self {
+            direction: ScrollDirection::Both,
+            width: rect.width(),
+            height: rect.height(),
+            children: container.children().to_vec(),
+            overflow: container.style().overflow.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    /// Extent of the children, from the content origin to their furthest
+    /// edges
+    pub fn content_size(&self) -> Size {
+        self.children
+            .iter()
+            .map(|child| child.layout_rect())
+            .fold(Size::zero(), |size, rect| {
+                Size::new(size.width.max(rect.max_x()), size.height.max(rect.max_y()))
+            })
+    }
+
+    /// Furthest the content can scroll along each axis; zero on axes the
+    /// view doesn't scroll or where the content fits
+    pub fn max_offset(&self) -> (f32, f32) {
+        let content = self.content_size();
+        let limit = |scrolls: bool, content: f32, viewport: f32| {
+            if scrolls {
+                (content - viewport).max(0.0)
+            } else {
+                0.0
+            }
+        };
+        (
+            limit(self.direction.horizontal(), content.width, self.width),
+            limit(self.direction.vertical(), content.height, self.height),
+        )
+    }
+
+    /// Current scroll offset
+    pub fn offset(&self) -> (f32, f32) {
+        self.offset
+    }
+
+    /// Scroll to `offset`, clamped to the content bounds, notifying
+    /// `on_scroll` if the offset changed
+    pub fn scroll_to(&mut self, offset: (f32, f32)) {
+        let (max_x, max_y) = self.max_offset();
+        let clamp = |value: f32, max: f32| {
+            if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, max)
+            }
+        };
+        let offset = (clamp(offset.0, max_x), clamp(offset.1, max_y));
+        if offset == self.offset {
+            return;
+        }
+        self.offset = offset;
+        if let Some(on_scroll) = self.on_scroll {
+            on_scroll(offset);
+        }
+    }
+
+    /// Scroll by `delta` from the current offset
+    pub fn scroll_by(&mut self, delta: (f32, f32)) {
+        self.scroll_to((self.offset.0 + delta.0, self.offset.1 + delta.1));
+    }
+
+    /// Handle a wheel event, returning whether the view scrolled
+    ///
+    /// Positive deltas scroll towards the end of the content. A view that
+    /// only scrolls horizontally takes vertical wheel movement sideways, so
+    /// a plain mouse wheel still works on it.
+    pub fn wheel(&mut self, delta: (f32, f32)) -> bool {
+        let delta = match self.direction {
+            ScrollDirection::Horizontal if delta.0 == 0.0 => (delta.1, 0.0),
+            _ => delta,
+        };
+        let before = self.offset;
+        self.scroll_by(delta);
+        self.offset != before
+    }
+
+    /// Handle a pointer press at `point` in viewport coordinates, starting a
+    /// drag of the scrollbar thumb under it or else of the content
+    pub fn press(&mut self, point: (f32, f32)) {
+        let on_thumb = |rect: Option<Rect>| {
+            rect.is_some_and(|rect| rect.contains_point(Point::new(point.0, point.1)))
+        };
+        self.drag = Some(if on_thumb(self.thumb_rect(true)) {
+            Drag::Thumb {
+                vertical: true,
+                from: point.1,
+                offset: self.offset.1,
+            }
+        } else if on_thumb(self.thumb_rect(false)) {
+            Drag::Thumb {
+                vertical: false,
+                from: point.0,
+                offset: self.offset.0,
+            }
+        } else {
+            Drag::Content {
+                from: point,
+                offset: self.offset,
+            }
+        });
+    }
+
+    /// Handle pointer movement while dragging
+    pub fn drag_to(&mut self, point: (f32, f32)) {
+        match self.drag {
+            Some(Drag::Content { from, offset }) => {
+                self.scroll_to((offset.0 - (point.0 - from.0), offset.1 - (point.1 - from.1)));
+            }
+            Some(Drag::Thumb {
+                vertical,
+                from,
+                offset,
+            }) => {
+                let (position, viewport, content, max) = if vertical {
+                    (
+                        point.1,
+                        self.height,
+                        self.content_size().height,
+                        self.max_offset().1,
+                    )
+                } else {
+                    (
+                        point.0,
+                        self.width,
+                        self.content_size().width,
+                        self.max_offset().0,
+                    )
+                };
+                let travel = viewport - self.thumb_length(viewport, content);
+                if travel > 0.0 {
+                    let scrolled = offset + (position - from) / travel * max;
+                    if vertical {
+                        self.scroll_to((self.offset.0, scrolled));
+                    } else {
+                        self.scroll_to((scrolled, self.offset.1));
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Handle the pointer being released
+    pub fn release(&mut self) {
+        self.drag = None;
+    }
+
+    /// Whether a drag is in progress
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Length of a thumb along a `viewport`-long track showing `content`
+    fn thumb_length(&self, viewport: f32, content: f32) -> f32 {
+        (viewport * viewport / content.max(f32::EPSILON))
+            .clamp(MIN_THUMB_LENGTH.min(viewport), viewport)
+    }
+
+    /// The `vertical` or horizontal scrollbar thumb in viewport
+    /// coordinates, or `None` if `overflow` shows no scrollbar that way
+    ///
+    /// The thumb's share of the track matches the viewport's share of the
+    /// content, and it sits as far along the track as the content is
+    /// scrolled; over content that fits, it fills the track.
+    pub fn thumb_rect(&self, vertical: bool) -> Option<Rect> {
+        let content = self.content_size();
+        let (max_x, max_y) = self.max_offset();
+        let (scrolls, max) = if vertical {
+            (self.direction.vertical(), max_y)
+        } else {
+            (self.direction.horizontal(), max_x)
+        };
+        let shown = match self.overflow {
+            Overflow::Scroll => scrolls,
+            Overflow::Auto => max > 0.0,
+            Overflow::Visible | Overflow::Hidden => false,
+        };
+        if !shown {
+            return None;
+        }
+        let progress = |offset: f32| if max > 0.0 { offset / max } else { 0.0 };
+        if vertical {
+            let length = self.thumb_length(self.height, content.height);
+            let y = progress(self.offset.1) * (self.height - length);
+            let x = self.width - SCROLLBAR_THICKNESS - SCROLLBAR_INSET;
+            Some(Rect::new(x, y, SCROLLBAR_THICKNESS, length))
+        } else {
+            let length = self.thumb_length(self.width, content.width);
+            let x = progress(self.offset.0) * (self.width - length);
+            let y = self.height - SCROLLBAR_THICKNESS - SCROLLBAR_INSET;
+            Some(Rect::new(x, y, length, SCROLLBAR_THICKNESS))
+        }
+    }
+
+    /// Scrollbar thumb node for one axis
+    fn thumb(&self, rect: Rect, vertical: bool) -> Node {
+        let (offset, max) = if vertical {
+            (self.offset.1, self.max_offset().1)
+        } else {
+            (self.offset.0, self.max_offset().0)
+        };
+        let mut thumb = Node::default();
+        thumb.set_layout_rect(rect);
+        thumb.add_attribute("role".to_string(), "scrollbar".to_string());
+        thumb.add_attribute(
+            "aria-orientation".to_string(),
+            if vertical { "vertical" } else { "horizontal" }.to_string(),
+        );
+        thumb.add_attribute("aria-valuemin".to_string(), "0".to_string());
+        thumb.add_attribute("aria-valuemax".to_string(), "100".to_string());
+        let value = if max > 0.0 { offset / max * 100.0 } else { 0.0 };
+        thumb.add_attribute("aria-valuenow".to_string(), format!("{value:.0}"));
+        let style = thumb.style_mut();
+        style.background_color = Some(Color::Hex(self.theme.border_color.clone()));
+        let radius = SCROLLBAR_THICKNESS / 2.0;
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        thumb
+    }
+}
+
+impl Component for ScrollView {
+    type Props = ScrollViewProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut view = Self {
+            direction: props.direction.unwrap_or_default(),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            height: props.height.unwrap_or(DEFAULT_HEIGHT),
+            children: props.children,
+            overflow: props.overflow.unwrap_or(Overflow::Auto),
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        view.scroll_to(props.offset.unwrap_or_default());
+        view.on_scroll = props.on_scroll;
+        view
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.direction = props.direction.unwrap_or(self.direction);
+        self.width = props.width.unwrap_or(self.width);
+        self.height = props.height.unwrap_or(self.height);
+        self.children = props.children;
+        self.on_scroll = props.on_scroll;
+        self.overflow = props.overflow.unwrap_or(self.overflow);
+        // New content or a resized viewport can leave the old offset out
+        // of bounds
+        self.scroll_to(props.offset.unwrap_or(self.offset));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, self.height));
+        // The renderer clips the content to the viewport unless overflow is
+        // visible
+        root.style_mut().overflow = Some(self.overflow);
+
+        let content_size = self.content_size();
+        let mut content = Node::default();
+        content.set_layout_rect(Rect::new(
+            -self.offset.0,
+            -self.offset.1,
+            content_size.width,
+            content_size.height,
+        ));
+        for child in &self.children {
+            content.add_child(child.clone());
+        }
+        root.add_child(content);
+
+        for vertical in [true, false] {
+            if let Some(rect) = self.thumb_rect(vertical) {
+                root.add_child(self.thumb(rect, vertical));
+            }
+        }
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tall_view() -> ScrollView {
+        let mut child = Node::default();
+        child.set_layout_rect(Rect::new(0.0, 0.0, 100.0, 800.0));
+        ScrollView {
+            width: 100.0,
+            height: 200.0,
+            children: vec![child],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scroll_to_clamps_to_content() {
+        let mut view = tall_view();
+        assert_eq!(view.max_offset(), (0.0, 600.0));
+
+        view.scroll_to((50.0, 250.0));
+        assert_eq!(view.offset(), (0.0, 250.0));
+        view.scroll_to((0.0, 9000.0));
+        assert_eq!(view.offset(), (0.0, 600.0));
+        view.scroll_to((0.0, -10.0));
+        assert_eq!(view.offset(), (0.0, 0.0));
+
+        assert!(view.wheel((0.0, 40.0)));
+        assert!(view.wheel((0.0, -100.0)));
+        assert_eq!(view.offset(), (0.0, 0.0));
+        assert!(!view.wheel((0.0, -1.0)));
+    }
+
+    #[test]
+    fn test_thumb_reflects_ratio_and_offset() {
+        let mut view = tall_view();
+        // The viewport shows a quarter of the content
+        let thumb = view.thumb_rect(true).unwrap();
+        assert_eq!((thumb.y(), thumb.height()), (0.0, 50.0));
+        assert_eq!(view.thumb_rect(false), None);
+
+        view.scroll_to((0.0, 300.0));
+        assert_eq!(view.thumb_rect(true).unwrap().y(), 75.0);
+
+        let root = &view.render().unwrap()[0];
+        assert_eq!(root.style().overflow, Some(Overflow::Auto));
+        assert_eq!(root.children()[0].layout_rect().y(), -300.0);
+        let scrollbar = &root.children()[1];
+        assert_eq!(
+            scrollbar.attributes().get("aria-valuenow"),
+            Some(&"50".to_string())
+        );
+    }
+
+    #[test]
+    fn test_overflow_decides_scrollbars() {
+        // Auto shows a scrollbar only where the content overflows
+        let mut view = tall_view();
+        view.direction = ScrollDirection::Both;
+        assert!(view.thumb_rect(true).is_some());
+        assert_eq!(view.thumb_rect(false), None);
+
+        // Scroll shows both, the one over fitting content filling its track
+        view.overflow = Overflow::Scroll;
+        let thumb = view.thumb_rect(false).unwrap();
+        assert_eq!((thumb.x(), thumb.width()), (0.0, 100.0));
+        let root = &view.render().unwrap()[0];
+        assert_eq!(root.children().len(), 3);
+        assert_eq!(
+            root.children()[2].attributes().get("aria-valuenow"),
+            Some(&"0".to_string())
+        );
+
+        // Hidden still scrolls, just without scrollbars
+        view.overflow = Overflow::Hidden;
+        assert_eq!(view.thumb_rect(true), None);
+        view.scroll_by((0.0, 100.0));
+        assert_eq!(view.offset(), (0.0, 100.0));
+
+        let mut container = Node::default();
+        container.set_layout_rect(Rect::new(10.0, 10.0, 100.0, 200.0));
+        container.style_mut().overflow = Some(Overflow::Auto);
+        container.add_child(tall_view().children.remove(0));
+        let view = ScrollView::for_container(&container);
+        assert_eq!((view.width, view.height), (100.0, 200.0));
+        assert_eq!(view.overflow, Overflow::Auto);
+        assert_eq!(view.max_offset(), (0.0, 600.0));
+    }
+
+    #[test]
+    fn test_drag_content_and_thumb() {
+        let mut view = tall_view();
+        view.press((50.0, 150.0));
+        view.drag_to((50.0, 50.0));
+        assert_eq!(view.offset(), (0.0, 100.0));
+        view.release();
+
+        // Dragging the thumb along its 150px of travel covers all 600px
+        let thumb = view.thumb_rect(true).unwrap();
+        view.press((thumb.x() + 1.0, thumb.y() + 1.0));
+        view.drag_to((thumb.x() + 1.0, thumb.y() + 38.5));
+        assert_eq!(view.offset(), (0.0, 250.0));
+        view.release();
+        assert!(!view.is_dragging());
+
+        let mut sideways = ScrollView {
+            direction: ScrollDirection::Horizontal,
+            width: 100.0,
+            ..tall_view()
+        };
+        sideways.children[0].set_layout_rect(Rect::new(0.0, 0.0, 400.0, 50.0));
+        assert!(sideways.wheel((0.0, 30.0)));
+        assert_eq!(sideways.offset(), (30.0, 0.0));
+    }
+}
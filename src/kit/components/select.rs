@@ -0,0 +1,742 @@
+// Select and MultiSelect components for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::focus::register_focusable;
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::theme::Theme;
+use crate::layout::{EdgeValues, Rect};
+use crate::style::{BorderRadius, BoxShadow, Color, EdgeColors};
+use std::any::Any;
+
+/// Default width of the trigger and popup
+const DEFAULT_WIDTH: f32 = 200.0;
+
+/// Height of the trigger and of each option row
+const ROW_HEIGHT: f32 = 32.0;
+
+/// Gap between the trigger and the popup list
+const POPUP_GAP: f32 = 4.0;
+
+/// Horizontal padding inside the trigger and option rows
+const PADDING: f32 = 8.0;
+
+/// Stacking order for the popup, so it paints above sibling content
+const POPUP_Z_INDEX: i32 = 1000;
+
+/// Rough per-character width used to size chips until text is measured by
+/// layout
+const CHIP_CHAR_WIDTH: f32 = 7.0;
+
+/// Height of a chip in the multi-select trigger
+const CHIP_HEIGHT: f32 = 20.0;
+
+fn uniform_radius(radius: f32) -> BorderRadius {
+    BorderRadius {
+        top_left: radius,
+        top_right: radius,
+        bottom_right: radius,
+        bottom_left: radius,
+    }
+}
+
+fn outline(node: &mut Node, color: &str) {
+    let color = Color::Hex(color.to_string());
+    let style = node.style_mut();
+    style.border_width = Some(EdgeValues::new(1.0, 1.0, 1.0, 1.0));
+    style.border_color = Some(EdgeColors {
+        top: color.clone(),
+        right: color.clone(),
+        bottom: color.clone(),
+        left: color,
+    });
+    style.border_radius = Some(uniform_radius(4.0));
+}
+
+/// Open/highlight state of a popup option list, shared by both selects
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Listbox {
+    open: bool,
+    highlighted: Option<usize>,
+}
+
+/// What a key press asks the owning select to do
+enum ListboxAction {
+    /// Nothing beyond the listbox's own state change
+    None,
+    /// Choose the option at this index
+    Choose(usize),
+}
+
+impl Listbox {
+    fn open(&mut self, initial: Option<usize>, len: usize) {
+        self.open = true;
+        self.highlighted = initial.or(if len > 0 { Some(0) } else { None });
+    }
+
+    fn close(&mut self) {
+        self.open = false;
+        self.highlighted = None;
+    }
+
+    /// Apply a key press; returns `None` if the key wasn't handled
+    fn handle_key(
+        &mut self,
+        key: &str,
+        initial: Option<usize>,
+        len: usize,
+    ) -> Option<ListboxAction> {
+        if !self.open {
+            return match key {
+                "ArrowDown" | "ArrowUp" | "Enter" | " " => {
+                    self.open(initial, len);
+                    Some(ListboxAction::None)
+                }
+                _ => None,
+            };
+        }
+
+        match key {
+            "ArrowDown" if len > 0 => {
+                self.highlighted = Some(self.highlighted.map_or(0, |i| (i + 1).min(len - 1)));
+            }
+            "ArrowUp" if len > 0 => {
+                self.highlighted = Some(self.highlighted.map_or(0, |i| i.saturating_sub(1)));
+            }
+            "Enter" => {
+                return Some(match self.highlighted {
+                    Some(index) if index < len => ListboxAction::Choose(index),
+                    _ => ListboxAction::None,
+                });
+            }
+            "Escape" => self.close(),
+            _ => return None,
+        }
+        Some(ListboxAction::None)
+    }
+
+    /// Render the popup list below a trigger of the given width
+    fn render(
+        &self,
+        options: &[(String, String)],
+        width: f32,
+        theme: &Theme,
+        is_selected: impl Fn(&str) -> bool,
+    ) -> Node {
+        let mut popup = Node::default();
+        popup.add_attribute("role".to_string(), "listbox".to_string());
+        popup.set_layout_rect(Rect::new(
+            0.0,
+            ROW_HEIGHT + POPUP_GAP,
+            width,
+            ROW_HEIGHT * options.len() as f32,
+        ));
+        outline(&mut popup, &theme.border_color);
+        let style = popup.style_mut();
+        style.background_color = Some(Color::Hex(theme.surface_color.clone()));
+        style.box_shadow = Some(vec![BoxShadow::new(
+            0.0,
+            2.0,
+            8.0,
+            Color::Rgba(0.0, 0.0, 0.0, 0.15),
+        )]);
+        style.z_index = Some(POPUP_Z_INDEX);
+
+        for (index, (value, label)) in options.iter().enumerate() {
+            let selected = is_selected(value);
+            let mut row = Node::default();
+            row.add_attribute("role".to_string(), "option".to_string());
+            row.add_attribute("aria-selected".to_string(), selected.to_string());
+            row.add_attribute("value".to_string(), value.clone());
+            row.set_layout_rect(Rect::new(0.0, ROW_HEIGHT * index as f32, width, ROW_HEIGHT));
+            if self.highlighted == Some(index) {
+                row.style_mut().background_color = Some(Color::Hex(theme.highlight_color.clone()));
+            }
+
+            let mut text = Node::default();
+            text.set_layout_rect(Rect::new(PADDING, 0.0, width - 2.0 * PADDING, ROW_HEIGHT));
+            text.set_text(label.clone());
+            text.style_mut().color = Some(Color::Hex(if selected {
+                theme.primary_color.clone()
+            } else {
+                theme.text_color.clone()
+            }));
+            row.add_child(text);
+            popup.add_child(row);
+        }
+
+        popup
+    }
+}
+
+/// Create the combobox root and its trigger box
+fn combobox(listbox: &Listbox, width: f32, disabled: bool, theme: &Theme) -> (Node, Node) {
+    let mut root = Node::default();
+    root.add_attribute("role".to_string(), "combobox".to_string());
+    root.add_attribute("aria-haspopup".to_string(), "listbox".to_string());
+    root.add_attribute("aria-expanded".to_string(), listbox.open.to_string());
+    if disabled {
+        root.add_attribute("aria-disabled".to_string(), "true".to_string());
+    }
+    register_focusable(&mut root, disabled);
+    root.set_layout_rect(Rect::new(0.0, 0.0, width, ROW_HEIGHT));
+
+    let mut trigger = Node::default();
+    trigger.set_layout_rect(Rect::new(0.0, 0.0, width, ROW_HEIGHT));
+    outline(&mut trigger, &theme.border_color);
+    let background = if disabled {
+        &theme.secondary_color
+    } else {
+        &theme.surface_color
+    };
+    trigger.style_mut().background_color = Some(Color::Hex(background.clone()));
+
+    (root, trigger)
+}
+
+fn placeholder_node(placeholder: &str, width: f32, theme: &Theme) -> Node {
+    let mut node = Node::default();
+    node.set_layout_rect(Rect::new(PADDING, 0.0, width - 2.0 * PADDING, ROW_HEIGHT));
+    node.set_text(placeholder);
+    node.style_mut().color = Some(Color::Hex(theme.muted_text_color.clone()));
+    node
+}
+
+/// Single-value select component
+#[derive(Debug)]
+pub struct Select {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Options as `(value, label)` pairs
+    pub options: Vec<(String, String)>,
+    /// Value of the selected option
+    pub selected: Option<String>,
+    /// Text shown when nothing is selected
+    pub placeholder: String,
+    /// Whether the select is disabled
+    pub disabled: bool,
+    /// Width of the trigger and popup
+    pub width: f32,
+    /// Select event handler, called with the chosen value
+    pub on_select: Option<fn(&str)>,
+    /// Popup state
+    listbox: Listbox,
+    /// Theme the trigger and popup are colored with
+    pub theme: Theme,
+}
+
+/// Select props
+#[derive(Debug, Clone)]
+pub struct SelectProps {
+    /// Options as `(value, label)` pairs
+    pub options: Vec<(String, String)>,
+    /// Value of the selected option
+    pub selected: Option<String>,
+    /// Text shown when nothing is selected
+    pub placeholder: Option<String>,
+    /// Whether the select is disabled
+    pub disabled: Option<bool>,
+    /// Width of the trigger and popup
+    pub width: Option<f32>,
+    /// Select event handler, called with the chosen value
+    pub on_select: Option<fn(&str)>,
+}
+
+impl Default for Select {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            options: Vec::new(),
+            selected: None,
+            placeholder: "Select...".to_string(),
+            disabled: false,
+            width: DEFAULT_WIDTH,
+            on_select: None,
+            listbox: Listbox::default(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Select {
+    /// Whether the popup list is open
+    pub fn is_open(&self) -> bool {
+        self.listbox.open
+    }
+
+    /// Index of the highlighted option while the popup is open
+    pub fn highlighted(&self) -> Option<usize> {
+        self.listbox.highlighted
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_deref()?;
+        self.options.iter().position(|(value, _)| value == selected)
+    }
+
+    /// Open or close the popup, as when the trigger is clicked
+    pub fn toggle_open(&mut self) {
+        if self.listbox.open {
+            self.listbox.close();
+        } else if !self.disabled {
+            self.listbox.open(self.selected_index(), self.options.len());
+        }
+    }
+
+    /// Close the popup without changing the selection
+    pub fn close(&mut self) {
+        self.listbox.close();
+    }
+
+    /// Choose the option at `index`, closing the popup and firing `on_select`
+    pub fn select_index(&mut self, index: usize) {
+        if self.disabled {
+            return;
+        }
+        let Some((value, _)) = self.options.get(index) else {
+            return;
+        };
+        self.selected = Some(value.clone());
+        self.listbox.close();
+        if let Some(on_select) = self.on_select {
+            on_select(value);
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Arrow keys, Enter, and Space open the popup. While open, Up/Down move
+    /// the highlight, Enter chooses it, and Escape closes the popup.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if self.disabled {
+            return false;
+        }
+        match self
+            .listbox
+            .handle_key(key, self.selected_index(), self.options.len())
+        {
+            Some(ListboxAction::Choose(index)) => {
+                self.select_index(index);
+                true
+            }
+            Some(ListboxAction::None) => true,
+            None => false,
+        }
+    }
+}
+
+impl KeyHandler for Select {
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl Component for Select {
+    type Props = SelectProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            theme: Theme::current(&context),
+            options: props.options,
+            selected: props.selected,
+            placeholder: props.placeholder.unwrap_or_else(|| "Select...".to_string()),
+            disabled: props.disabled.unwrap_or(false),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            on_select: props.on_select,
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.options = props.options;
+        self.selected = props.selected;
+        if let Some(placeholder) = props.placeholder {
+            self.placeholder = placeholder;
+        }
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.width = props.width.unwrap_or(self.width);
+        self.on_select = props.on_select;
+        if self.disabled {
+            self.listbox.close();
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let (mut root, mut trigger) =
+            combobox(&self.listbox, self.width, self.disabled, &self.theme);
+
+        match self.selected_index() {
+            Some(index) => {
+                let mut text = Node::default();
+                text.set_layout_rect(Rect::new(
+                    PADDING,
+                    0.0,
+                    self.width - 2.0 * PADDING,
+                    ROW_HEIGHT,
+                ));
+                text.set_text(self.options[index].1.clone());
+                text.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+                trigger.add_child(text);
+            }
+            None => trigger.add_child(placeholder_node(&self.placeholder, self.width, &self.theme)),
+        }
+        root.add_child(trigger);
+
+        // The popup is the last child so it also paints after the trigger
+        if self.listbox.open {
+            let selected = self.selected.as_deref();
+            root.add_child(
+                self.listbox
+                    .render(&self.options, self.width, &self.theme, |value| {
+                        Some(value) == selected
+                    }),
+            );
+        }
+
+        Ok(vec![root])
+    }
+}
+
+/// Select component that allows choosing several values
+#[derive(Debug)]
+pub struct MultiSelect {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Options as `(value, label)` pairs
+    pub options: Vec<(String, String)>,
+    /// Values of the chosen options, in the order they were chosen
+    pub selected: Vec<String>,
+    /// Text shown when nothing is selected
+    pub placeholder: String,
+    /// Whether the select is disabled
+    pub disabled: bool,
+    /// Width of the trigger and popup
+    pub width: f32,
+    /// Change event handler, called with the full selection
+    pub on_change: Option<fn(&[String])>,
+    /// Popup state
+    listbox: Listbox,
+    /// Theme the trigger, chips, and popup are colored with
+    pub theme: Theme,
+}
+
+/// MultiSelect props
+#[derive(Debug, Clone)]
+pub struct MultiSelectProps {
+    /// Options as `(value, label)` pairs
+    pub options: Vec<(String, String)>,
+    /// Values of the chosen options
+    pub selected: Option<Vec<String>>,
+    /// Text shown when nothing is selected
+    pub placeholder: Option<String>,
+    /// Whether the select is disabled
+    pub disabled: Option<bool>,
+    /// Width of the trigger and popup
+    pub width: Option<f32>,
+    /// Change event handler, called with the full selection
+    pub on_change: Option<fn(&[String])>,
+}
+
+impl Default for MultiSelect {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            options: Vec::new(),
+            selected: Vec::new(),
+            placeholder: "Select...".to_string(),
+            disabled: false,
+            width: DEFAULT_WIDTH,
+            on_change: None,
+            listbox: Listbox::default(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl MultiSelect {
+    /// Whether the popup list is open
+    pub fn is_open(&self) -> bool {
+        self.listbox.open
+    }
+
+    /// Index of the highlighted option while the popup is open
+    pub fn highlighted(&self) -> Option<usize> {
+        self.listbox.highlighted
+    }
+
+    /// Open or close the popup, as when the trigger is clicked
+    pub fn toggle_open(&mut self) {
+        if self.listbox.open {
+            self.listbox.close();
+        } else if !self.disabled {
+            self.listbox.open(None, self.options.len());
+        }
+    }
+
+    /// Close the popup without changing the selection
+    pub fn close(&mut self) {
+        self.listbox.close();
+    }
+
+    /// Add or remove the option at `index` and fire `on_change`
+    ///
+    /// The popup stays open so several options can be picked in a row.
+    pub fn toggle_index(&mut self, index: usize) {
+        if self.disabled {
+            return;
+        }
+        let Some((value, _)) = self.options.get(index) else {
+            return;
+        };
+        match self.selected.iter().position(|chosen| chosen == value) {
+            Some(position) => {
+                self.selected.remove(position);
+            }
+            None => self.selected.push(value.clone()),
+        }
+        if let Some(on_change) = self.on_change {
+            on_change(&self.selected);
+        }
+    }
+
+    /// Remove a chosen value, as when its chip is dismissed
+    pub fn remove(&mut self, value: &str) {
+        if let Some(index) = self.options.iter().position(|(option, _)| option == value) {
+            if self.selected.iter().any(|chosen| chosen == value) {
+                self.toggle_index(index);
+            }
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Behaves like [`Select::handle_key`], except Enter toggles the
+    /// highlighted option and leaves the popup open.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if self.disabled {
+            return false;
+        }
+        match self.listbox.handle_key(key, None, self.options.len()) {
+            Some(ListboxAction::Choose(index)) => {
+                self.toggle_index(index);
+                true
+            }
+            Some(ListboxAction::None) => true,
+            None => false,
+        }
+    }
+
+    fn label_for(&self, value: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(option, _)| option == value)
+            .map(|(_, label)| label.as_str())
+    }
+}
+
+impl KeyHandler for MultiSelect {
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        event.is_down() && !event.modifiers.is_command() && self.handle_key(&event.key)
+    }
+}
+
+impl Component for MultiSelect {
+    type Props = MultiSelectProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            theme: Theme::current(&context),
+            options: props.options,
+            selected: props.selected.unwrap_or_default(),
+            placeholder: props.placeholder.unwrap_or_else(|| "Select...".to_string()),
+            disabled: props.disabled.unwrap_or(false),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            on_change: props.on_change,
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.options = props.options;
+        if let Some(selected) = props.selected {
+            self.selected = selected;
+        }
+        if let Some(placeholder) = props.placeholder {
+            self.placeholder = placeholder;
+        }
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.width = props.width.unwrap_or(self.width);
+        self.on_change = props.on_change;
+        if self.disabled {
+            self.listbox.close();
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let (mut root, mut trigger) =
+            combobox(&self.listbox, self.width, self.disabled, &self.theme);
+        root.add_attribute("aria-multiselectable".to_string(), "true".to_string());
+
+        let chips: Vec<&str> = self
+            .selected
+            .iter()
+            .filter_map(|value| self.label_for(value))
+            .collect();
+        if chips.is_empty() {
+            trigger.add_child(placeholder_node(&self.placeholder, self.width, &self.theme));
+        }
+
+        let mut x = PADDING / 2.0;
+        for label in chips {
+            let width = label.chars().count() as f32 * CHIP_CHAR_WIDTH + 2.0 * PADDING;
+            let mut chip = Node::default();
+            chip.set_layout_rect(Rect::new(
+                x,
+                (ROW_HEIGHT - CHIP_HEIGHT) / 2.0,
+                width,
+                CHIP_HEIGHT,
+            ));
+            chip.style_mut().background_color =
+                Some(Color::Hex(self.theme.highlight_color.clone()));
+            chip.style_mut().border_radius = Some(uniform_radius(CHIP_HEIGHT / 2.0));
+
+            let mut text = Node::default();
+            text.set_layout_rect(Rect::new(PADDING, 0.0, width - 2.0 * PADDING, CHIP_HEIGHT));
+            text.set_text(label);
+            self.theme.text_style("caption").apply(text.style_mut());
+            text.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+            chip.add_child(text);
+
+            trigger.add_child(chip);
+            x += width + PADDING / 2.0;
+        }
+        root.add_child(trigger);
+
+        if self.listbox.open {
+            root.add_child(
+                self.listbox
+                    .render(&self.options, self.width, &self.theme, |value| {
+                        self.selected.iter().any(|chosen| chosen == value)
+                    }),
+            );
+        }
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Vec<(String, String)> {
+        ["red", "green", "blue"]
+            .iter()
+            .map(|value| (value.to_string(), value.to_uppercase()))
+            .collect()
+    }
+
+    #[test]
+    fn test_keyboard_navigation() {
+        let mut select = Select {
+            options: options(),
+            ..Default::default()
+        };
+
+        assert!(select.handle_key("ArrowDown"));
+        assert!(select.is_open());
+        assert_eq!(select.highlighted(), Some(0));
+
+        select.handle_key("ArrowDown");
+        select.handle_key("ArrowDown");
+        select.handle_key("ArrowDown");
+        assert_eq!(select.highlighted(), Some(2));
+        select.handle_key("ArrowUp");
+
+        assert!(select.handle_key("Enter"));
+        assert_eq!(select.selected.as_deref(), Some("green"));
+        assert!(!select.is_open());
+
+        select.toggle_open();
+        assert_eq!(select.highlighted(), Some(1));
+        assert!(select.handle_key("Escape"));
+        assert!(!select.is_open());
+        assert!(!select.handle_key("Tab"));
+    }
+
+    #[test]
+    fn test_popup_renders_above_content() {
+        let mut select = Select {
+            options: options(),
+            selected: Some("blue".to_string()),
+            ..Default::default()
+        };
+        let closed = select.render().unwrap();
+        assert_eq!(closed[0].children().len(), 1);
+        assert_eq!(closed[0].children()[0].children()[0].text(), Some("BLUE"));
+
+        select.toggle_open();
+        let open = select.render().unwrap();
+        let popup = &open[0].children()[1];
+        assert_eq!(open[0].attributes().get("aria-expanded").unwrap(), "true");
+        assert_eq!(popup.style().z_index, Some(POPUP_Z_INDEX));
+        assert_eq!(popup.children().len(), 3);
+        assert_eq!(
+            popup.children()[2]
+                .attributes()
+                .get("aria-selected")
+                .unwrap(),
+            "true"
+        );
+    }
+
+    #[test]
+    fn test_multi_select_toggles_and_renders_chips() {
+        let mut select = MultiSelect {
+            options: options(),
+            ..Default::default()
+        };
+        select.toggle_index(2);
+        select.toggle_index(0);
+        assert_eq!(select.selected, vec!["blue", "red"]);
+
+        select.handle_key("Enter");
+        select.handle_key("Enter");
+        assert!(select.is_open());
+        assert_eq!(select.selected, vec!["blue"]);
+
+        select.remove("blue");
+        assert!(select.selected.is_empty());
+
+        select.toggle_index(1);
+        let nodes = select.render().unwrap();
+        let trigger = &nodes[0].children()[0];
+        assert_eq!(trigger.children().len(), 1);
+        assert_eq!(trigger.children()[0].children()[0].text(), Some("GREEN"));
+    }
+}
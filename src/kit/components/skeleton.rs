@@ -0,0 +1,387 @@
+// Skeleton component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::{RadiusSize, Theme};
+use crate::kit::utils::animation::KeyframeAnimation;
+use crate::kit::utils::color;
+use crate::layout::Rect;
+use crate::style::{BorderRadius, Color, LinearGradient, Overflow, Transform};
+use std::any::Any;
+
+/// Seconds for the shimmer to sweep once across
+const SHIMMER_PERIOD: f32 = 1.5;
+
+/// Width of the shimmer's bright band, as a fraction of the skeleton
+const SHIMMER_WIDTH: f32 = 0.4;
+
+/// Default width of rect and text skeletons
+const DEFAULT_WIDTH: f32 = 200.0;
+
+/// Default width of the last line of a [`SkeletonText`], as a fraction of
+/// the others
+const DEFAULT_LAST_LINE: f32 = 0.6;
+
+/// Shape of a skeleton placeholder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkeletonVariant {
+    /// A line of text, as tall as the theme's body text
+    #[default]
+    Text,
+    /// A block standing in for an image or card
+    Rect,
+    /// A circle standing in for an avatar or icon
+    Circle,
+}
+
+/// Shimmer state shared by [`Skeleton`] and [`SkeletonText`]: a sweep
+/// across the whole of a bar and its band, as a fraction of that distance
+#[derive(Debug, Clone)]
+struct Shimmer(KeyframeAnimation);
+
+impl Shimmer {
+    fn new() -> Self {
+        Self(KeyframeAnimation::sweep(SHIMMER_PERIOD, 1.0))
+    }
+
+    /// Advance `dt` seconds, wrapping round to sweep again; returns whether
+    /// the shimmer is running
+    fn tick(&mut self, dt: f32, theme: &Theme) -> bool {
+        if theme.reduced_motion {
+            return false;
+        }
+        self.0.tick(dt)
+    }
+
+    /// How far through the sweep the band is, from 0 to 1
+    fn fraction(&self) -> f32 {
+        match self.0.sample().transform {
+            Some(Transform::Translate(x, _) | Transform::TranslateX(x)) => x,
+            _ => 0.0,
+        }
+    }
+
+    /// A bar filling `rect`, with the shimmer band over it unless the theme
+    /// asks for reduced motion
+    fn bar(&self, rect: Rect, radius: f32, theme: &Theme) -> Node {
+        let base = color::darken(&theme.surface_color, 0.08);
+        let mut bar = Node::default();
+        bar.set_layout_rect(rect);
+        let style = bar.style_mut();
+        style.background_color = Some(Color::Hex(base.clone()));
+        style.overflow = Some(Overflow::Hidden);
+        style.border_radius = Some(BorderRadius {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        });
+        if theme.reduced_motion {
+            return bar;
+        }
+
+        // The band starts just off the left edge and ends just off the right
+        let band = rect.width() * SHIMMER_WIDTH;
+        let x = -band + self.fraction() * (rect.width() + band);
+        let highlight = color::lighten(&theme.surface_color, 0.04);
+        let clear = color::with_alpha(&highlight, 0.0);
+        let mut shimmer = Node::default();
+        shimmer.set_layout_rect(Rect::new(x, 0.0, band, rect.height()));
+        shimmer.style_mut().background_gradient = Some(LinearGradient::new(
+            90.0,
+            vec![
+                (0.0, Color::Hex(clear.clone())),
+                (0.5, Color::Hex(highlight)),
+                (1.0, Color::Hex(clear)),
+            ],
+        ));
+        bar.add_child(shimmer);
+        bar
+    }
+}
+
+/// Skeleton component, a placeholder shown while content loads
+#[derive(Debug)]
+pub struct Skeleton {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Shape of the placeholder
+    pub variant: SkeletonVariant,
+    /// Width, and the diameter of a circle
+    pub width: f32,
+    /// Height; text skeletons default to the body text size and circles to
+    /// their width
+    pub height: Option<f32>,
+    /// Position of the shimmer sweep
+    shimmer: Shimmer,
+    /// Theme providing the colors, radius, and motion preference
+    pub theme: Theme,
+}
+
+/// Skeleton props
+#[derive(Debug, Clone, Default)]
+pub struct SkeletonProps {
+    /// Shape of the placeholder
+    pub variant: Option<SkeletonVariant>,
+    /// Width
+    pub width: Option<f32>,
+    /// Height
+    pub height: Option<f32>,
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            variant: SkeletonVariant::Text,
+            width: DEFAULT_WIDTH,
+            height: None,
+            shimmer: Shimmer::new(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Skeleton {
+    /// A skeleton of `variant` shape
+    pub fn new(variant: SkeletonVariant) -> Self {
+        Self {
+            variant,
+            ..Default::default()
+        }
+    }
+
+    /// A circle `diameter` across
+    pub fn circle(diameter: f32) -> Self {
+        Self {
+            variant: SkeletonVariant::Circle,
+            width: diameter,
+            ..Default::default()
+        }
+    }
+
+    /// Width and height
+    pub fn size(&self) -> (f32, f32) {
+        let height = self.height.unwrap_or(match self.variant {
+            SkeletonVariant::Text => self.theme.text_style("body").font_size,
+            SkeletonVariant::Rect => self.width / 2.0,
+            SkeletonVariant::Circle => self.width,
+        });
+        (self.width, height)
+    }
+
+    /// Advance the shimmer by `dt` seconds; returns whether it is running,
+    /// which it never is under reduced motion
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.shimmer.tick(dt, &self.theme)
+    }
+}
+
+impl Component for Skeleton {
+    type Props = SkeletonProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            variant: props.variant.unwrap_or_default(),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            height: props.height,
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.variant = props.variant.unwrap_or(self.variant);
+        self.width = props.width.unwrap_or(self.width);
+        self.height = props.height;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let (width, height) = self.size();
+        let radius = match self.variant {
+            SkeletonVariant::Text => self.theme.radius(RadiusSize::Sm),
+            SkeletonVariant::Rect => self.theme.radius(RadiusSize::Md),
+            SkeletonVariant::Circle => width.min(height) / 2.0,
+        };
+        let mut root = self
+            .shimmer
+            .bar(Rect::new(0.0, 0.0, width, height), radius, &self.theme);
+        root.add_attribute("aria-hidden".to_string(), "true".to_string());
+        Ok(vec![root])
+    }
+}
+
+/// Placeholder for a paragraph, as several lines with a shorter last one
+#[derive(Debug)]
+pub struct SkeletonText {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Number of lines
+    pub lines: usize,
+    /// Width of every line but the last
+    pub width: f32,
+    /// Width of the last line, as a fraction of `width`
+    pub last_line: f32,
+    /// Position of the shimmer sweep, shared by every line
+    shimmer: Shimmer,
+    /// Theme providing the colors, text metrics, and motion preference
+    pub theme: Theme,
+}
+
+/// Skeleton text props
+#[derive(Debug, Clone, Default)]
+pub struct SkeletonTextProps {
+    /// Number of lines
+    pub lines: usize,
+    /// Width of every line but the last
+    pub width: Option<f32>,
+    /// Width of the last line, as a fraction of `width`
+    pub last_line: Option<f32>,
+}
+
+impl Default for SkeletonText {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            lines: 3,
+            width: DEFAULT_WIDTH,
+            last_line: DEFAULT_LAST_LINE,
+            shimmer: Shimmer::new(),
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl SkeletonText {
+    /// A paragraph placeholder `lines` long
+    pub fn new(lines: usize) -> Self {
+        Self {
+            lines,
+            ..Default::default()
+        }
+    }
+
+    /// Advance the shimmer by `dt` seconds; returns whether it is running,
+    /// which it never is under reduced motion
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.shimmer.tick(dt, &self.theme)
+    }
+}
+
+impl Component for SkeletonText {
+    type Props = SkeletonTextProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            lines: props.lines,
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            last_line: props.last_line.unwrap_or(DEFAULT_LAST_LINE),
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.lines = props.lines;
+        self.width = props.width.unwrap_or(self.width);
+        self.last_line = props.last_line.unwrap_or(self.last_line);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        // Lines are as tall as body text and spaced as it would be
+        let text_style = self.theme.text_style("body");
+        let height = text_style.font_size;
+        let pitch = text_style.font_size * text_style.line_height;
+        let radius = self.theme.radius(RadiusSize::Sm);
+
+        let mut root = Node::default();
+        root.add_attribute("aria-hidden".to_string(), "true".to_string());
+        let total = match self.lines {
+            0 => 0.0,
+            lines => pitch * (lines - 1) as f32 + height,
+        };
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, total));
+        for line in 0..self.lines {
+            let width = if line + 1 == self.lines && self.lines > 1 {
+                self.width * self.last_line.clamp(0.0, 1.0)
+            } else {
+                self.width
+            };
+            let rect = Rect::new(0.0, line as f32 * pitch, width, height);
+            root.add_child(self.shimmer.bar(rect, radius, &self.theme));
+        }
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shimmer_sweeps_and_respects_reduced_motion() {
+        let mut skeleton = Skeleton::circle(40.0);
+        let band_x = |skeleton: &Skeleton| {
+            let root = &skeleton.render().unwrap()[0];
+            assert_eq!(root.style().border_radius.as_ref().unwrap().top_left, 20.0);
+            root.children()[0].layout_rect().x()
+        };
+        let start = band_x(&skeleton);
+        assert_eq!(start, -40.0 * SHIMMER_WIDTH);
+        assert!(skeleton.tick(SHIMMER_PERIOD / 2.0));
+        let middle = band_x(&skeleton);
+        assert!(middle > start);
+        // The sweep wraps back to the start
+        assert!(skeleton.tick(SHIMMER_PERIOD / 2.0));
+        assert_eq!(band_x(&skeleton), start);
+
+        skeleton.theme.reduced_motion = true;
+        assert!(!skeleton.tick(0.1));
+        assert!(skeleton.render().unwrap()[0].children().is_empty());
+    }
+
+    #[test]
+    fn test_text_lines_with_shorter_last_line() {
+        let text = SkeletonText::new(3);
+        let root = &text.render().unwrap()[0];
+        let lines = root.children();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].layout_rect().width(), DEFAULT_WIDTH);
+        assert_eq!(lines[1].layout_rect().width(), DEFAULT_WIDTH);
+        assert_eq!(
+            lines[2].layout_rect().width(),
+            DEFAULT_WIDTH * DEFAULT_LAST_LINE
+        );
+        assert!(lines[1].layout_rect().y() > lines[0].layout_rect().max_y());
+        assert_eq!(root.layout_rect().height(), lines[2].layout_rect().max_y());
+
+        // A single line stays full width
+        let root = &SkeletonText::new(1).render().unwrap()[0];
+        assert_eq!(root.children()[0].layout_rect().width(), DEFAULT_WIDTH);
+    }
+}
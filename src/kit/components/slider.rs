@@ -0,0 +1,393 @@
+// Slider component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use crate::kit::theme::Theme;
+use crate::layout::{EdgeValues, Rect};
+use crate::style::{BorderRadius, Color, EdgeColors};
+use std::any::Any;
+
+/// Default track length in logical pixels
+const DEFAULT_LENGTH: f32 = 200.0;
+
+/// Thickness of the track
+const TRACK_THICKNESS: f32 = 4.0;
+
+/// Diameter of the draggable thumb
+const THUMB_SIZE: f32 = 16.0;
+
+/// Gap between the slider and its value label
+const LABEL_GAP: f32 = 8.0;
+
+fn uniform_radius(radius: f32) -> BorderRadius {
+    BorderRadius {
+        top_left: radius,
+        top_right: radius,
+        bottom_right: radius,
+        bottom_left: radius,
+    }
+}
+
+/// Slider orientation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliderOrientation {
+    /// Horizontal slider, with `min` on the left
+    #[default]
+    Horizontal,
+    /// Vertical slider, with `min` at the bottom
+    Vertical,
+}
+
+/// Slider component
+#[derive(Debug)]
+pub struct Slider {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Lower bound of the range
+    pub min: f64,
+    /// Upper bound of the range
+    pub max: f64,
+    /// Step the value snaps to; values `<= 0` disable snapping
+    pub step: f64,
+    /// Current value
+    pub value: f64,
+    /// Slider orientation
+    pub orientation: SliderOrientation,
+    /// Track length in logical pixels
+    pub length: f32,
+    /// Whether to show the current value next to the slider
+    pub show_value: bool,
+    /// Whether the slider is disabled
+    pub disabled: bool,
+    /// Change event handler, called with the new value
+    pub on_change: Option<fn(f64)>,
+    /// Whether the thumb is being dragged
+    dragging: bool,
+    /// Theme the track and thumb are colored with
+    pub theme: Theme,
+}
+
+/// Slider props
+#[derive(Debug, Clone)]
+pub struct SliderProps {
+    /// Lower bound of the range
+    pub min: Option<f64>,
+    /// Upper bound of the range
+    pub max: Option<f64>,
+    /// Step the value snaps to; values `<= 0` disable snapping
+    pub step: Option<f64>,
+    /// Current value
+    pub value: Option<f64>,
+    /// Slider orientation
+    pub orientation: Option<SliderOrientation>,
+    /// Track length in logical pixels
+    pub length: Option<f32>,
+    /// Whether to show the current value next to the slider
+    pub show_value: Option<bool>,
+    /// Whether the slider is disabled
+    pub disabled: Option<bool>,
+    /// Change event handler, called with the new value
+    pub on_change: Option<fn(f64)>,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            value: 0.0,
+            orientation: SliderOrientation::Horizontal,
+            length: DEFAULT_LENGTH,
+            show_value: false,
+            disabled: false,
+            on_change: None,
+            dragging: false,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Slider {
+    /// Upper bound of the range, never below `min`
+    fn upper(&self) -> f64 {
+        self.max.max(self.min)
+    }
+
+    /// Clamp a value to `[min, max]` and snap it to the nearest step
+    ///
+    /// Steps are counted from `min`. A non-positive or non-finite step leaves
+    /// the value unsnapped.
+    pub fn snap(&self, value: f64) -> f64 {
+        let (min, max) = (self.min, self.upper());
+        if value.is_nan() {
+            return min;
+        }
+        let value = value.clamp(min, max);
+        if self.step <= 0.0 || !self.step.is_finite() {
+            return value;
+        }
+        let snapped = min + ((value - min) / self.step).round() * self.step;
+        snapped.clamp(min, max)
+    }
+
+    /// Position of the value along the track, from 0.0 at `min` to 1.0 at `max`
+    pub fn fraction(&self) -> f32 {
+        let range = self.upper() - self.min;
+        if range <= 0.0 {
+            return 0.0;
+        }
+        ((self.value - self.min) / range).clamp(0.0, 1.0) as f32
+    }
+
+    /// Value at a point in the slider's local coordinates
+    pub fn value_at(&self, point: (f32, f32)) -> f64 {
+        let length = self.length.max(f32::EPSILON);
+        let fraction = match self.orientation {
+            SliderOrientation::Horizontal => point.0 / length,
+            SliderOrientation::Vertical => 1.0 - point.1 / length,
+        };
+        let fraction = f64::from(fraction.clamp(0.0, 1.0));
+        self.snap(self.min + fraction * (self.upper() - self.min))
+    }
+
+    /// Set the value, clamping and snapping it, and notify `on_change` if it
+    /// changed
+    pub fn set_value(&mut self, value: f64) {
+        let value = self.snap(value);
+        if value == self.value {
+            return;
+        }
+        self.value = value;
+        if let Some(on_change) = self.on_change {
+            on_change(value);
+        }
+    }
+
+    /// Handle a pointer press on the slider: jump to the pressed position and
+    /// start dragging
+    pub fn press(&mut self, point: (f32, f32)) {
+        if self.disabled {
+            return;
+        }
+        self.dragging = true;
+        self.set_value(self.value_at(point));
+    }
+
+    /// Handle pointer movement while dragging
+    pub fn drag_to(&mut self, point: (f32, f32)) {
+        if self.dragging {
+            self.set_value(self.value_at(point));
+        }
+    }
+
+    /// Handle the pointer being released
+    pub fn release(&mut self) {
+        self.dragging = false;
+    }
+
+    /// Whether the thumb is being dragged
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Rectangle spanning `start..end` along the track, `thickness` across it
+    fn along(&self, start: f32, end: f32, thickness: f32) -> Rect {
+        let cross = (THUMB_SIZE - thickness) / 2.0;
+        match self.orientation {
+            SliderOrientation::Horizontal => Rect::new(start, cross, end - start, thickness),
+            SliderOrientation::Vertical => {
+                Rect::new(cross, self.length - end, thickness, end - start)
+            }
+        }
+    }
+}
+
+impl PointerHandler for Slider {
+    /// A primary press starts a drag that follows the pointer, wherever it
+    /// goes, until the release
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool {
+        match event.kind {
+            PointerEventKind::Down if event.is_primary() && !self.disabled => {
+                self.press(event.local);
+                true
+            }
+            PointerEventKind::Move if self.dragging => {
+                self.drag_to(event.local);
+                true
+            }
+            PointerEventKind::Up if self.dragging => {
+                self.release();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Component for Slider {
+    type Props = SliderProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut slider = Self {
+            theme: Theme::current(&context),
+            min: props.min.unwrap_or(0.0),
+            max: props.max.unwrap_or(100.0),
+            step: props.step.unwrap_or(1.0),
+            orientation: props.orientation.unwrap_or_default(),
+            length: props.length.unwrap_or(DEFAULT_LENGTH),
+            show_value: props.show_value.unwrap_or(false),
+            disabled: props.disabled.unwrap_or(false),
+            on_change: props.on_change,
+            ..Default::default()
+        };
+        slider.value = slider.snap(props.value.unwrap_or(slider.min));
+        slider
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.min = props.min.unwrap_or(self.min);
+        self.max = props.max.unwrap_or(self.max);
+        self.step = props.step.unwrap_or(self.step);
+        self.orientation = props.orientation.unwrap_or(self.orientation);
+        self.length = props.length.unwrap_or(self.length);
+        self.show_value = props.show_value.unwrap_or(self.show_value);
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.on_change = props.on_change;
+        self.value = self.snap(props.value.unwrap_or(self.value));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let accent = Color::Hex(if self.disabled {
+            self.theme.disabled_color.clone()
+        } else {
+            self.theme.primary_color.clone()
+        });
+        let vertical = self.orientation == SliderOrientation::Vertical;
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "slider".to_string());
+        root.add_attribute("aria-valuemin".to_string(), self.min.to_string());
+        root.add_attribute("aria-valuemax".to_string(), self.upper().to_string());
+        root.add_attribute("aria-valuenow".to_string(), self.value.to_string());
+        root.add_attribute(
+            "aria-orientation".to_string(),
+            if vertical { "vertical" } else { "horizontal" }.to_string(),
+        );
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+
+        let mut track = Node::default();
+        track.set_layout_rect(self.along(0.0, self.length, TRACK_THICKNESS));
+        track.style_mut().background_color = Some(Color::Hex(self.theme.border_color.clone()));
+        track.style_mut().border_radius = Some(uniform_radius(TRACK_THICKNESS / 2.0));
+        root.add_child(track);
+
+        let position = self.fraction() * self.length;
+        let mut fill = Node::default();
+        fill.set_layout_rect(self.along(0.0, position, TRACK_THICKNESS));
+        fill.style_mut().background_color = Some(accent.clone());
+        fill.style_mut().border_radius = Some(uniform_radius(TRACK_THICKNESS / 2.0));
+        root.add_child(fill);
+
+        // The thumb is centred on the value's position along the track
+        let half = THUMB_SIZE / 2.0;
+        let mut thumb = Node::default();
+        thumb.set_layout_rect(self.along(position - half, position + half, THUMB_SIZE));
+        let style = thumb.style_mut();
+        style.background_color = Some(Color::Hex(self.theme.surface_color.clone()));
+        style.border_width = Some(EdgeValues::new(1.5, 1.5, 1.5, 1.5));
+        style.border_color = Some(EdgeColors {
+            top: accent.clone(),
+            right: accent.clone(),
+            bottom: accent.clone(),
+            left: accent,
+        });
+        style.border_radius = Some(uniform_radius(half));
+        root.add_child(thumb);
+
+        let (mut width, mut height) = if vertical {
+            (THUMB_SIZE, self.length)
+        } else {
+            (self.length, THUMB_SIZE)
+        };
+        if self.show_value {
+            let mut label = Node::default();
+            if vertical {
+                label.set_layout_rect(Rect::new(0.0, height + LABEL_GAP, 0.0, THUMB_SIZE));
+                height += LABEL_GAP + THUMB_SIZE;
+            } else {
+                label.set_layout_rect(Rect::new(width + LABEL_GAP, 0.0, 0.0, THUMB_SIZE));
+                width += LABEL_GAP;
+            }
+            label.set_text(self.value.to_string());
+            label.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+            root.add_child(label);
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, height));
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snap_clamps_and_guards_step() {
+        let mut slider = Slider {
+            min: 0.0,
+            max: 10.0,
+            step: 2.5,
+            ..Default::default()
+        };
+        assert_eq!(slider.snap(3.9), 5.0);
+        assert_eq!(slider.snap(-4.0), 0.0);
+        assert_eq!(slider.snap(42.0), 10.0);
+
+        slider.step = 0.0;
+        assert_eq!(slider.snap(3.9), 3.9);
+        slider.step = -1.0;
+        assert_eq!(slider.snap(11.0), 10.0);
+    }
+
+    #[test]
+    fn test_press_and_drag() {
+        let mut slider = Slider {
+            length: 100.0,
+            ..Default::default()
+        };
+        slider.press((25.2, 8.0));
+        assert_eq!(slider.value, 25.0);
+        assert!(slider.is_dragging());
+
+        slider.drag_to((150.0, 8.0));
+        assert_eq!(slider.value, 100.0);
+        slider.release();
+        slider.drag_to((10.0, 8.0));
+        assert_eq!(slider.value, 100.0);
+
+        let vertical = Slider {
+            length: 100.0,
+            orientation: SliderOrientation::Vertical,
+            ..Default::default()
+        };
+        assert_eq!(vertical.value_at((8.0, 75.0)), 25.0);
+    }
+}
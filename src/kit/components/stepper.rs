@@ -0,0 +1,537 @@
+// Stepper component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::keyboard::{KeyEvent, KeyHandler};
+use crate::kit::components::input::Input;
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use crate::style::Color;
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+/// Default width of the whole control, buttons included
+const DEFAULT_WIDTH: f32 = 160.0;
+
+/// Width of the − and + buttons
+const BUTTON_WIDTH: f32 = 32.0;
+
+/// How long a button must be held before it starts repeating
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// Interval between the first repeats
+const REPEAT_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Shortest interval repeats accelerate to
+const MIN_REPEAT_INTERVAL: Duration = Duration::from_millis(30);
+
+/// How much each repeat shortens the interval to the next
+const REPEAT_ACCELERATION: f64 = 0.8;
+
+/// Steps taken by PageUp and PageDown
+const PAGE_STEPS: f64 = 10.0;
+
+/// Which way a step goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepDirection {
+    Decrement,
+    Increment,
+}
+
+/// A held − or + button
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hold {
+    direction: StepDirection,
+    /// When the next repeat is due
+    next: Instant,
+    /// Interval after the next repeat
+    interval: Duration,
+}
+
+/// Numeric stepper component: a number field between − and + buttons
+#[derive(Debug)]
+pub struct Stepper {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Current value
+    pub value: f64,
+    /// Smallest allowed value
+    pub min: Option<f64>,
+    /// Largest allowed value
+    pub max: Option<f64>,
+    /// Amount each press changes the value by; non-positive steps fall
+    /// back to 1
+    pub step: f64,
+    /// Whether only whole numbers are allowed
+    pub integer: bool,
+    /// Formats the value for display while the field isn't being edited,
+    /// such as `|v| format!("${v:.2}")`
+    pub format: Option<fn(f64) -> String>,
+    /// Whether the stepper is disabled
+    pub disabled: bool,
+    /// Width of the whole control
+    pub width: f32,
+    /// Called with the new value when it changes
+    pub on_change: Option<fn(f64)>,
+    /// The text field, holding typed text while editing
+    input: Input,
+    /// Whether the field has focus and is being typed into
+    editing: bool,
+    /// Button being held down, for auto-repeat
+    hold: Option<Hold>,
+    /// Theme the field and buttons are colored with
+    pub theme: Theme,
+}
+
+/// Stepper props
+#[derive(Debug, Clone, Default)]
+pub struct StepperProps {
+    /// Current value
+    pub value: f64,
+    /// Smallest allowed value
+    pub min: Option<f64>,
+    /// Largest allowed value
+    pub max: Option<f64>,
+    /// Amount each press changes the value by
+    pub step: Option<f64>,
+    /// Whether only whole numbers are allowed
+    pub integer: Option<bool>,
+    /// Formats the value for display
+    pub format: Option<fn(f64) -> String>,
+    /// Whether the stepper is disabled
+    pub disabled: Option<bool>,
+    /// Width of the whole control
+    pub width: Option<f32>,
+    /// Called with the new value when it changes
+    pub on_change: Option<fn(f64)>,
+}
+
+impl Default for Stepper {
+    fn default() -> Self {
+        let mut input = Input::default();
+        input.input_type = "number".to_string();
+        Self {
+            id: ComponentId::new(),
+            value: 0.0,
+            min: None,
+            max: None,
+            step: 1.0,
+            integer: false,
+            format: None,
+            disabled: false,
+            width: DEFAULT_WIDTH,
+            on_change: None,
+            input,
+            editing: false,
+            hold: None,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Stepper {
+    /// The step actually used, falling back to 1 when `step` isn't a
+    /// positive number
+    pub fn effective_step(&self) -> f64 {
+        let step = if self.step > 0.0 && self.step.is_finite() {
+            self.step
+        } else {
+            1.0
+        };
+        if self.integer {
+            step.round().max(1.0)
+        } else {
+            step
+        }
+    }
+
+    /// Clamp `value` to the bounds, rounding it in integer mode
+    pub fn clamp(&self, value: f64) -> f64 {
+        let value = if self.integer { value.round() } else { value };
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+
+    /// Set the value, clamped, and notify `on_change` if it changed
+    pub fn set_value(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        let value = self.clamp(value);
+        if value == self.value {
+            return;
+        }
+        self.value = value;
+        self.input.value = self.raw_text();
+        if let Some(on_change) = self.on_change {
+            on_change(value);
+        }
+    }
+
+    /// Move the value `steps` steps in `direction`
+    fn step_by(&mut self, direction: StepDirection, steps: f64) {
+        if self.disabled {
+            return;
+        }
+        let delta = self.effective_step() * steps;
+        let value = match direction {
+            StepDirection::Increment => self.value + delta,
+            StepDirection::Decrement => self.value - delta,
+        };
+        // Drop the float noise that adding steps like 0.1 accumulates
+        self.set_value((value * 1e9).round() / 1e9);
+    }
+
+    /// Step once in `direction`, as a click on − or + does
+    pub fn step_once(&mut self, direction: StepDirection) {
+        self.step_by(direction, 1.0);
+    }
+
+    /// Whether a step in `direction` would change the value
+    pub fn can_step(&self, direction: StepDirection) -> bool {
+        !self.disabled
+            && match direction {
+                StepDirection::Increment => self.max.is_none_or(|max| self.value < max),
+                StepDirection::Decrement => self.min.is_none_or(|min| self.value > min),
+            }
+    }
+
+    /// A − or + button went down at `now`: step once, then keep stepping
+    /// while it is held, faster the longer it is held
+    pub fn press(&mut self, direction: StepDirection, now: Instant) {
+        self.step_once(direction);
+        self.hold = Some(Hold {
+            direction,
+            next: now + REPEAT_DELAY,
+            interval: REPEAT_INTERVAL,
+        });
+    }
+
+    /// The held button was released
+    pub fn release(&mut self) {
+        self.hold = None;
+    }
+
+    /// Whether a button is being held
+    pub fn is_held(&self) -> bool {
+        self.hold.is_some()
+    }
+
+    /// Take any repeats due by `now`; returns whether the value changed
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let before = self.value;
+        while let Some(hold) = self.hold {
+            if now < hold.next {
+                break;
+            }
+            self.step_once(hold.direction);
+            let interval = hold
+                .interval
+                .mul_f64(REPEAT_ACCELERATION)
+                .max(MIN_REPEAT_INTERVAL);
+            self.hold = Some(Hold {
+                next: hold.next + hold.interval,
+                interval,
+                ..hold
+            });
+            // Nothing more to repeat once a bound is hit
+            if !self.can_step(hold.direction) {
+                self.hold = None;
+            }
+        }
+        self.value != before
+    }
+
+    /// The value as typed into the field: plain digits, no formatting
+    fn raw_text(&self) -> String {
+        if self.integer {
+            format!("{:.0}", self.value)
+        } else {
+            self.value.to_string()
+        }
+    }
+
+    /// The text shown in the field
+    pub fn display_text(&self) -> String {
+        match self.format {
+            Some(_) if self.editing => self.input.value.clone(),
+            Some(format) => format(self.value),
+            None if self.editing => self.input.value.clone(),
+            None => self.raw_text(),
+        }
+    }
+
+    /// The field gained focus: show the raw value for editing
+    pub fn focus(&mut self) {
+        if self.disabled {
+            return;
+        }
+        self.editing = true;
+        self.input.value = self.raw_text();
+    }
+
+    /// The field lost focus: commit the typed text, clamped to the bounds,
+    /// or go back to the last value if it isn't a number
+    pub fn blur(&mut self) {
+        if !self.editing {
+            return;
+        }
+        self.editing = false;
+        if let Ok(value) = self.input.value.trim().parse::<f64>() {
+            if value.is_finite() {
+                self.set_value(value);
+            }
+        }
+        self.input.value = self.raw_text();
+    }
+
+    /// Whether the field is being typed into
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Up and Down step, PageUp and PageDown take ten steps, and Home and
+    /// End jump to the bounds. While editing, Enter commits the typed text
+    /// and Escape discards it.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if self.disabled {
+            return false;
+        }
+        match key {
+            "ArrowUp" => self.step_once(StepDirection::Increment),
+            "ArrowDown" => self.step_once(StepDirection::Decrement),
+            "PageUp" => self.step_by(StepDirection::Increment, PAGE_STEPS),
+            "PageDown" => self.step_by(StepDirection::Decrement, PAGE_STEPS),
+            "Home" => match self.min {
+                Some(min) => self.set_value(min),
+                None => return false,
+            },
+            "End" => match self.max {
+                Some(max) => self.set_value(max),
+                None => return false,
+            },
+            "Enter" if self.editing => {
+                self.blur();
+                self.focus();
+            }
+            "Escape" if self.editing => self.input.value = self.raw_text(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn button(&self, x: f32, height: f32, direction: StepDirection) -> Node {
+        let (text, label) = match direction {
+            StepDirection::Decrement => ("−", "Decrease"),
+            StepDirection::Increment => ("+", "Increase"),
+        };
+        let enabled = self.can_step(direction);
+        let mut node = Node::default();
+        node.add_attribute("role".to_string(), "button".to_string());
+        node.add_attribute("aria-label".to_string(), label.to_string());
+        if !enabled {
+            node.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+        // The field takes focus; the buttons are for the pointer
+        node.add_attribute("tabindex".to_string(), "-1".to_string());
+        node.set_layout_rect(Rect::new(x, 0.0, BUTTON_WIDTH, height));
+        node.set_text(text);
+        node.style_mut().color = Some(Color::Hex(if enabled {
+            self.theme.text_color.clone()
+        } else {
+            self.theme.muted_text_color.clone()
+        }));
+        node
+    }
+}
+
+impl KeyHandler for Stepper {
+    /// Stepping keys work whether or not the field is being edited; while
+    /// it is, characters that can appear in a number are typed into it
+    fn handle_key_event(&mut self, event: &KeyEvent) -> bool {
+        if !event.is_down() || event.modifiers.is_command() {
+            return false;
+        }
+        if self.handle_key(&event.key) {
+            return true;
+        }
+        if !self.editing {
+            return false;
+        }
+        match event.text() {
+            Some(c) if c.is_ascii_digit() || c == '-' || (c == '.' && !self.integer) => {
+                self.input.handle_key_event(event)
+            }
+            // Swallow other characters rather than let them bubble as
+            // shortcuts while typing
+            Some(_) => true,
+            None => event.key == "Backspace" && self.input.handle_key_event(event),
+        }
+    }
+}
+
+impl Component for Stepper {
+    type Props = StepperProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut stepper = Self {
+            min: props.min,
+            max: props.max,
+            step: props.step.unwrap_or(1.0),
+            integer: props.integer.unwrap_or(false),
+            format: props.format,
+            disabled: props.disabled.unwrap_or(false),
+            width: props.width.unwrap_or(DEFAULT_WIDTH),
+            on_change: props.on_change,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        stepper.input.theme = stepper.theme.clone();
+        stepper.value = stepper.clamp(props.value);
+        stepper.input.value = stepper.raw_text();
+        stepper
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.min = props.min;
+        self.max = props.max;
+        self.step = props.step.unwrap_or(self.step);
+        self.integer = props.integer.unwrap_or(self.integer);
+        self.format = props.format;
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.width = props.width.unwrap_or(self.width);
+        self.on_change = props.on_change;
+        self.value = self.clamp(props.value);
+        if !self.editing {
+            self.input.value = self.raw_text();
+        }
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut input = Input::default();
+        input.input_type = self.input.input_type.clone();
+        input.value = self.display_text();
+        input.disabled = self.disabled;
+        input.width = (self.width - 2.0 * BUTTON_WIDTH).max(0.0);
+        input.theme = self.theme.clone();
+        let mut field_root = input.render()?.remove(0);
+        let height = field_root.layout_rect().height();
+        field_root.set_layout_rect(Rect::new(BUTTON_WIDTH, 0.0, input.width, height));
+
+        // The field is the spinbutton, so assistive tech reads the value and
+        // bounds from it and the arrow keys step it
+        let field = &mut field_root.children_mut()[0];
+        field.add_attribute("role".to_string(), "spinbutton".to_string());
+        field.add_attribute("aria-valuenow".to_string(), self.value.to_string());
+        field.add_attribute("aria-valuetext".to_string(), self.display_text());
+        if let Some(min) = self.min {
+            field.add_attribute("aria-valuemin".to_string(), min.to_string());
+        }
+        if let Some(max) = self.max {
+            field.add_attribute("aria-valuemax".to_string(), max.to_string());
+        }
+
+        let mut root = Node::default();
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, height));
+        root.add_child(self.button(0.0, height, StepDirection::Decrement));
+        root.add_child(field_root);
+        root.add_child(self.button(self.width - BUTTON_WIDTH, height, StepDirection::Increment));
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_button_repeats_faster_until_the_bound() {
+        let mut stepper = Stepper {
+            max: Some(20.0),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        stepper.press(StepDirection::Increment, start);
+        assert_eq!(stepper.value, 1.0);
+
+        // Nothing repeats before the delay
+        assert!(!stepper.tick(start + REPEAT_DELAY / 2));
+        assert!(stepper.tick(start + REPEAT_DELAY));
+        assert_eq!(stepper.value, 2.0);
+
+        // The second repeat comes a full interval later, the third sooner
+        let second = start + REPEAT_DELAY + REPEAT_INTERVAL;
+        stepper.tick(second);
+        assert_eq!(stepper.value, 3.0);
+        stepper.tick(second + REPEAT_INTERVAL.mul_f64(REPEAT_ACCELERATION));
+        assert_eq!(stepper.value, 4.0);
+
+        stepper.tick(start + Duration::from_secs(10));
+        assert_eq!(stepper.value, 20.0);
+        assert!(!stepper.is_held());
+        let root = &stepper.render().unwrap()[0];
+        assert!(root.children()[2]
+            .attributes()
+            .contains_key("aria-disabled"));
+    }
+
+    #[test]
+    fn test_typed_values_clamp_on_blur() {
+        let mut stepper = Stepper {
+            min: Some(0.0),
+            max: Some(99.0),
+            integer: true,
+            format: Some(|value| format!("${value:.2}")),
+            ..Default::default()
+        };
+        assert_eq!(stepper.display_text(), "$0.00");
+
+        stepper.focus();
+        assert_eq!(stepper.display_text(), "0");
+        for key in ["Backspace", "1", "2", ".", "5", "0", "x"] {
+            stepper.handle_key_event(&KeyEvent::down(key));
+        }
+        assert_eq!(stepper.display_text(), "1250");
+        stepper.blur();
+        assert_eq!(stepper.value, 99.0);
+        assert_eq!(stepper.display_text(), "$99.00");
+
+        stepper.focus();
+        stepper.handle_key_event(&KeyEvent::down("-"));
+        stepper.blur();
+        assert_eq!(stepper.value, 99.0);
+    }
+
+    #[test]
+    fn test_non_positive_step_falls_back_to_one() {
+        let mut stepper = Stepper {
+            step: 0.0,
+            ..Default::default()
+        };
+        stepper.handle_key("ArrowUp");
+        assert_eq!(stepper.value, 1.0);
+        stepper.step = -5.0;
+        stepper.handle_key("PageDown");
+        assert_eq!(stepper.value, -9.0);
+
+        stepper.step = 0.1;
+        for _ in 0..3 {
+            stepper.handle_key("ArrowUp");
+        }
+        assert_eq!(stepper.value, -8.7);
+    }
+}
@@ -0,0 +1,309 @@
+// Switch component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::events::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use crate::kit::theme::Theme;
+use crate::kit::utils::animation::Animation;
+use crate::layout::Rect;
+use crate::style::{BorderRadius, BoxShadow, Color, TimingFunction};
+use std::any::Any;
+
+/// Width of the pill-shaped track
+const TRACK_WIDTH: f32 = 36.0;
+
+/// Height of the track
+const TRACK_HEIGHT: f32 = 20.0;
+
+/// Gap between the knob and the track edge
+const KNOB_INSET: f32 = 2.0;
+
+/// Gap between the track and its label
+const LABEL_GAP: f32 = 8.0;
+
+/// How long the knob takes to slide across, in seconds
+const KNOB_TRANSITION: f32 = 0.15;
+
+fn pill(height: f32) -> BorderRadius {
+    let radius = height / 2.0;
+    BorderRadius {
+        top_left: radius,
+        top_right: radius,
+        bottom_right: radius,
+        bottom_left: radius,
+    }
+}
+
+/// Switch component
+#[derive(Debug)]
+pub struct Switch {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Whether the switch is on
+    pub on: bool,
+    /// Whether the switch is disabled
+    pub disabled: bool,
+    /// Label shown while on
+    pub on_label: Option<String>,
+    /// Label shown while off
+    pub off_label: Option<String>,
+    /// Toggle event handler, called with the new state
+    pub on_toggle: Option<fn(bool)>,
+    /// Theme the switch is colored with
+    pub theme: Theme,
+    /// Knob position from 0.0 (off) to 1.0 (on), animated by `tick`
+    knob: Animation,
+}
+
+/// Switch props
+#[derive(Debug, Clone)]
+pub struct SwitchProps {
+    /// Whether the switch is on
+    pub on: Option<bool>,
+    /// Whether the switch is disabled
+    pub disabled: Option<bool>,
+    /// Label shown while on
+    pub on_label: Option<String>,
+    /// Label shown while off
+    pub off_label: Option<String>,
+    /// Toggle event handler, called with the new state
+    pub on_toggle: Option<fn(bool)>,
+}
+
+impl Default for Switch {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            on: false,
+            disabled: false,
+            on_label: None,
+            off_label: None,
+            on_toggle: None,
+            theme: Theme::default(),
+            knob: Animation::at_rest(0.0, TimingFunction::EaseInOut),
+        }
+    }
+}
+
+impl Switch {
+    fn target_position(&self) -> f32 {
+        if self.on {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Current knob position, from 0.0 (off) to 1.0 (on)
+    pub fn knob_position(&self) -> f32 {
+        self.knob.value()
+    }
+
+    /// Whether the knob is still sliding toward its resting position
+    pub fn is_animating(&self) -> bool {
+        !self.knob.is_finished() || self.knob.to != self.target_position()
+    }
+
+    /// Point the knob animation at the current state, at a constant speed
+    /// however far the knob has left to go
+    fn slide_knob(&mut self) {
+        let target = self.target_position();
+        let distance = (target - self.knob.value()).abs();
+        self.knob.retarget(target, KNOB_TRANSITION * distance);
+        if self.theme.reduced_motion {
+            self.knob.finish();
+        }
+    }
+
+    /// Flip the switch, as when it is clicked or Space is pressed
+    ///
+    /// The knob slides over on subsequent `tick`s, or jumps straight there
+    /// when the theme asks for reduced motion.
+    pub fn toggle(&mut self) {
+        if self.disabled {
+            return;
+        }
+        self.on = !self.on;
+        self.slide_knob();
+        if let Some(on_toggle) = self.on_toggle {
+            on_toggle(self.on);
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if key != " " || self.disabled {
+            return false;
+        }
+        self.toggle();
+        true
+    }
+
+    /// Advance the knob animation by `dt` seconds; returns whether it is
+    /// still animating
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.slide_knob();
+        self.knob.sample(dt);
+        self.is_animating()
+    }
+
+    fn label(&self) -> Option<&str> {
+        if self.on {
+            self.on_label.as_deref()
+        } else {
+            self.off_label.as_deref()
+        }
+    }
+}
+
+impl PointerHandler for Switch {
+    /// A primary press and release over the switch toggles it
+    fn handle_pointer_event(&mut self, event: &PointerEvent) -> bool {
+        if self.disabled || !event.is_primary() {
+            return false;
+        }
+        match event.kind {
+            PointerEventKind::Down => true,
+            PointerEventKind::Up => {
+                if event.within {
+                    self.toggle();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Component for Switch {
+    type Props = SwitchProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut switch = Self {
+            on: props.on.unwrap_or(false),
+            disabled: props.disabled.unwrap_or(false),
+            on_label: props.on_label,
+            off_label: props.off_label,
+            on_toggle: props.on_toggle,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        // The initial state is shown without animating into place
+        switch.knob = Animation::at_rest(switch.target_position(), TimingFunction::EaseInOut);
+        switch
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.on = props.on.unwrap_or(self.on);
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.on_label = props.on_label;
+        self.off_label = props.off_label;
+        self.on_toggle = props.on_toggle;
+        self.slide_knob();
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "switch".to_string());
+        root.add_attribute("aria-checked".to_string(), self.on.to_string());
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+            root.style_mut().opacity = Some(0.5);
+        }
+
+        let track_color = if self.on {
+            self.theme.primary_color.clone()
+        } else {
+            self.theme.border_color.clone()
+        };
+        let mut track = Node::default();
+        track.set_layout_rect(Rect::new(0.0, 0.0, TRACK_WIDTH, TRACK_HEIGHT));
+        track.style_mut().background_color = Some(Color::Hex(track_color));
+        track.style_mut().border_radius = Some(pill(TRACK_HEIGHT));
+
+        let knob_size = TRACK_HEIGHT - 2.0 * KNOB_INSET;
+        let travel = TRACK_WIDTH - knob_size - 2.0 * KNOB_INSET;
+        let mut knob = Node::default();
+        knob.set_layout_rect(Rect::new(
+            KNOB_INSET + travel * self.knob_position(),
+            KNOB_INSET,
+            knob_size,
+            knob_size,
+        ));
+        let style = knob.style_mut();
+        style.background_color = Some(Color::Hex(self.theme.background_color.clone()));
+        style.border_radius = Some(pill(knob_size));
+        style.box_shadow = Some(vec![BoxShadow::new(
+            0.0,
+            1.0,
+            2.0,
+            Color::Rgba(0.0, 0.0, 0.0, 0.2),
+        )]);
+        track.add_child(knob);
+        root.add_child(track);
+
+        let mut width = TRACK_WIDTH;
+        if let Some(label) = self.label() {
+            let mut label_node = Node::default();
+            label_node.set_layout_rect(Rect::new(TRACK_WIDTH + LABEL_GAP, 2.0, 0.0, TRACK_HEIGHT));
+            label_node.set_text(label);
+            label_node.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+            root.add_child(label_node);
+            width += LABEL_GAP;
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, TRACK_HEIGHT));
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knob_animates_toward_state() {
+        let mut switch = Switch::default();
+        assert!(switch.handle_key(" "));
+        assert!(switch.on);
+        assert!(switch.is_animating());
+
+        assert!(switch.tick(KNOB_TRANSITION / 2.0));
+        assert!((switch.knob_position() - 0.5).abs() < 1e-6);
+        assert!(!switch.tick(KNOB_TRANSITION));
+        assert_eq!(switch.knob_position(), 1.0);
+
+        let nodes = switch.render().unwrap();
+        let knob = &nodes[0].children()[0].children()[0];
+        assert_eq!(knob.layout_rect().max_x(), TRACK_WIDTH - KNOB_INSET);
+    }
+
+    #[test]
+    fn test_reduced_motion_and_disabled() {
+        let mut switch = Switch::default();
+        switch.theme.reduced_motion = true;
+        switch.on_label = Some("On".to_string());
+
+        switch.toggle();
+        assert!(!switch.is_animating());
+        let nodes = switch.render().unwrap();
+        assert_eq!(nodes[0].children()[1].text(), Some("On"));
+
+        switch.disabled = true;
+        switch.toggle();
+        assert!(switch.on);
+        assert!(!switch.handle_key(" "));
+    }
+}
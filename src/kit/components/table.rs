@@ -0,0 +1,516 @@
+// Table component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::{Dimension, FlexDirection, LayoutEngine, LayoutNode, LayoutStyle, Rect, Size};
+use crate::style::{Color, FontWeight, Overflow, TextAlign};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Height of the header row
+const HEADER_HEIGHT: f32 = 36.0;
+
+/// Height of each body row
+const ROW_HEIGHT: f32 = 32.0;
+
+/// Horizontal padding inside each cell
+const CELL_PADDING: f32 = 12.0;
+
+/// Sort direction for a sortable column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Value of the `aria-sort` attribute for this direction
+    pub fn aria_sort(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+/// A table column
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// Key looked up in each row
+    pub key: String,
+    /// Header text
+    pub header: String,
+    /// Column width; `Auto` columns share the remaining space
+    pub width: Dimension,
+    /// Alignment of the cell text
+    pub align: TextAlign,
+    /// Whether clicking the header requests a sort
+    pub sortable: bool,
+}
+
+impl Column {
+    /// Create a left-aligned, auto-width column
+    pub fn new(key: impl Into<String>, header: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            header: header.into(),
+            width: Dimension::Auto,
+            align: TextAlign::Left,
+            sortable: false,
+        }
+    }
+}
+
+/// Table component
+#[derive(Debug)]
+pub struct Table {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Columns, in display order
+    pub columns: Vec<Column>,
+    /// Rows, keyed by column key
+    pub rows: Vec<HashMap<String, String>>,
+    /// Column whose value identifies each row, so re-sorting moves rows
+    /// rather than rebuilding them; without one rows are keyed by index
+    pub row_key: Option<String>,
+    /// Total width of the table
+    pub width: f32,
+    /// Height of the visible area; rows outside it are not rendered
+    pub viewport_height: f32,
+    /// Whether alternate rows are shaded
+    pub striped: bool,
+    /// Whether the header stays in place while the body scrolls
+    pub sticky_header: bool,
+    /// Current sort column and direction, shown in the header
+    pub sort: Option<(String, SortDirection)>,
+    /// Sort event handler, called with the column key and direction
+    ///
+    /// The table only reports the request; the app reorders `rows`.
+    pub on_sort: Option<fn(&str, SortDirection)>,
+    /// Theme providing the stripe and header colors
+    pub theme: Theme,
+    /// Vertical scroll offset, in logical pixels
+    scroll_offset: f32,
+}
+
+/// Table props
+#[derive(Debug, Clone)]
+pub struct TableProps {
+    /// Columns, in display order
+    pub columns: Vec<Column>,
+    /// Rows, keyed by column key
+    pub rows: Vec<HashMap<String, String>>,
+    /// Column whose value identifies each row
+    pub row_key: Option<String>,
+    /// Total width of the table
+    pub width: Option<f32>,
+    /// Height of the visible area
+    pub viewport_height: Option<f32>,
+    /// Whether alternate rows are shaded
+    pub striped: Option<bool>,
+    /// Whether the header stays in place while the body scrolls
+    pub sticky_header: Option<bool>,
+    /// Current sort column and direction
+    pub sort: Option<(String, SortDirection)>,
+    /// Sort event handler, called with the column key and direction
+    pub on_sort: Option<fn(&str, SortDirection)>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            row_key: None,
+            width: 600.0,
+            viewport_height: 400.0,
+            striped: true,
+            sticky_header: true,
+            sort: None,
+            on_sort: None,
+            theme: Theme::default(),
+            scroll_offset: 0.0,
+        }
+    }
+}
+
+impl Table {
+    /// Horizontal `(x, width)` of each column, computed by the layout engine
+    pub fn column_layout(&self) -> Vec<(f32, f32)> {
+        let mut row = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                flex_direction: FlexDirection::Row,
+                width: Dimension::Points(self.width),
+                height: Dimension::Points(HEADER_HEIGHT),
+                ..Default::default()
+            },
+        );
+        for column in &self.columns {
+            let style = match column.width {
+                Dimension::Auto => LayoutStyle {
+                    flex_basis: Dimension::Points(0.0),
+                    flex_grow: 1.0,
+                    ..Default::default()
+                },
+                width => LayoutStyle {
+                    width,
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                },
+            };
+            row.add_child(LayoutNode::new(ComponentId::new(), style));
+        }
+
+        let mut engine = LayoutEngine::new();
+        if engine
+            .calculate_layout(&mut row, Size::new(self.width, HEADER_HEIGHT))
+            .is_err()
+        {
+            // Fall back to equal columns rather than failing to render
+            let width = self.width / self.columns.len().max(1) as f32;
+            return (0..self.columns.len())
+                .map(|index| (index as f32 * width, width))
+                .collect();
+        }
+        row.children
+            .iter()
+            .map(|cell| (cell.layout.rect.x(), cell.layout.rect.width()))
+            .collect()
+    }
+
+    /// Request a sort on a column, as when its header is clicked
+    ///
+    /// Clicking the current sort column flips the direction; any other
+    /// sortable column starts ascending.
+    pub fn click_header(&mut self, key: &str) {
+        if !self
+            .columns
+            .iter()
+            .any(|column| column.key == key && column.sortable)
+        {
+            return;
+        }
+        let direction = match &self.sort {
+            Some((current, SortDirection::Ascending)) if current == key => {
+                SortDirection::Descending
+            }
+            _ => SortDirection::Ascending,
+        };
+        self.sort = Some((key.to_string(), direction));
+        if let Some(on_sort) = self.on_sort {
+            on_sort(key, direction);
+        }
+    }
+
+    fn body_height(&self) -> f32 {
+        if self.sticky_header {
+            (self.viewport_height - HEADER_HEIGHT).max(0.0)
+        } else {
+            self.viewport_height
+        }
+    }
+
+    fn content_height(&self) -> f32 {
+        let rows = self.rows.len() as f32 * ROW_HEIGHT;
+        if self.sticky_header {
+            rows
+        } else {
+            rows + HEADER_HEIGHT
+        }
+    }
+
+    /// Current vertical scroll offset
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    /// Scroll by `dy` pixels, clamped to the content height
+    pub fn scroll_by(&mut self, dy: f32) {
+        let max = (self.content_height() - self.body_height()).max(0.0);
+        self.scroll_offset = (self.scroll_offset + dy).clamp(0.0, max);
+    }
+
+    fn cell(&self, text: &str, column: &Column, (x, width): (f32, f32), height: f32) -> Node {
+        let mut cell = Node::default();
+        cell.set_layout_rect(Rect::new(x, 0.0, width, height));
+        cell.style_mut().overflow = Some(Overflow::Hidden);
+
+        let mut label = Node::default();
+        label.set_layout_rect(Rect::new(
+            CELL_PADDING,
+            (height - 16.0) / 2.0,
+            (width - 2.0 * CELL_PADDING).max(0.0),
+            16.0,
+        ));
+        label.set_text(text);
+        label.style_mut().text_align = Some(column.align.clone());
+        cell.add_child(label);
+        cell
+    }
+
+    fn render_header(&self, layout: &[(f32, f32)]) -> Node {
+        let mut header = Node::default();
+        header.add_attribute("role".to_string(), "row".to_string());
+        header.set_layout_rect(Rect::new(0.0, 0.0, self.width, HEADER_HEIGHT));
+        header.style_mut().background_color = Some(Color::Hex(self.theme.background_color.clone()));
+
+        for (column, &bounds) in self.columns.iter().zip(layout) {
+            let sorted = self
+                .sort
+                .as_ref()
+                .filter(|(key, _)| *key == column.key)
+                .map(|(_, direction)| *direction);
+            let text = match sorted {
+                Some(SortDirection::Ascending) => format!("{} ▲", column.header),
+                Some(SortDirection::Descending) => format!("{} ▼", column.header),
+                None => column.header.clone(),
+            };
+
+            let mut cell = self.cell(&text, column, bounds, HEADER_HEIGHT);
+            cell.add_attribute("role".to_string(), "columnheader".to_string());
+            cell.add_attribute("data-key".to_string(), column.key.clone());
+            if column.sortable {
+                let aria_sort = sorted.map_or("none", SortDirection::aria_sort);
+                cell.add_attribute("aria-sort".to_string(), aria_sort.to_string());
+            }
+            if let Some(label) = cell.children_mut().first_mut() {
+                label.style_mut().font_weight = Some(FontWeight::SemiBold);
+            }
+            header.add_child(cell);
+        }
+        header
+    }
+}
+
+impl Component for Table {
+    type Props = TableProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut table = Self {
+            columns: props.columns,
+            rows: props.rows,
+            row_key: props.row_key,
+            sort: props.sort,
+            on_sort: props.on_sort,
+            theme: Theme::current(&context),
+            ..Default::default()
+        };
+        table.width = props.width.unwrap_or(table.width);
+        table.viewport_height = props.viewport_height.unwrap_or(table.viewport_height);
+        table.striped = props.striped.unwrap_or(table.striped);
+        table.sticky_header = props.sticky_header.unwrap_or(table.sticky_header);
+        table
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.columns = props.columns;
+        self.rows = props.rows;
+        self.row_key = props.row_key;
+        self.width = props.width.unwrap_or(self.width);
+        self.viewport_height = props.viewport_height.unwrap_or(self.viewport_height);
+        self.striped = props.striped.unwrap_or(self.striped);
+        self.sticky_header = props.sticky_header.unwrap_or(self.sticky_header);
+        self.sort = props.sort;
+        self.on_sort = props.on_sort;
+        self.scroll_by(0.0);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let layout = self.column_layout();
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "table".to_string());
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, self.viewport_height));
+        root.style_mut().overflow = Some(Overflow::Hidden);
+
+        let body_top = if self.sticky_header {
+            HEADER_HEIGHT
+        } else {
+            0.0
+        };
+        let mut body = Node::default();
+        body.set_layout_rect(Rect::new(0.0, body_top, self.width, self.body_height()));
+        body.style_mut().overflow = Some(Overflow::Hidden);
+
+        let mut content = Node::default();
+        content.set_layout_rect(Rect::new(
+            0.0,
+            -self.scroll_offset,
+            self.width,
+            self.content_height(),
+        ));
+
+        // A non-sticky header scrolls away with the rows
+        let rows_top = if self.sticky_header {
+            0.0
+        } else {
+            content.add_child(self.render_header(&layout));
+            HEADER_HEIGHT
+        };
+
+        // Only rows intersecting the body become nodes
+        let first_visible = ((self.scroll_offset - rows_top).max(0.0) / ROW_HEIGHT) as usize;
+        let visible_count = (self.body_height() / ROW_HEIGHT).ceil() as usize + 1;
+        let stripe = Color::Hex(self.theme.secondary_color.clone());
+        for (index, row) in self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(first_visible)
+            .take(visible_count)
+        {
+            let mut row_node = Node::default();
+            let key = self.row_key.as_ref().and_then(|column| row.get(column));
+            row_node.set_key(key.cloned().unwrap_or_else(|| index.to_string()));
+            row_node.add_attribute("role".to_string(), "row".to_string());
+            row_node.add_attribute("aria-rowindex".to_string(), (index + 1).to_string());
+            row_node.set_layout_rect(Rect::new(
+                0.0,
+                rows_top + index as f32 * ROW_HEIGHT,
+                self.width,
+                ROW_HEIGHT,
+            ));
+            if self.striped && index % 2 == 1 {
+                row_node.style_mut().background_color = Some(stripe.clone());
+            }
+            for (column, &bounds) in self.columns.iter().zip(&layout) {
+                let text = row.get(&column.key).map_or("", String::as_str);
+                let mut cell = self.cell(text, column, bounds, ROW_HEIGHT);
+                cell.set_key(column.key.clone());
+                cell.add_attribute("role".to_string(), "cell".to_string());
+                row_node.add_child(cell);
+            }
+            content.add_child(row_node);
+        }
+        body.add_child(content);
+        root.add_child(body);
+
+        if self.sticky_header {
+            root.add_child(self.render_header(&layout));
+        }
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::Patch;
+
+    fn table(rows: usize) -> Table {
+        Table {
+            columns: vec![
+                Column {
+                    width: Dimension::Points(100.0),
+                    sortable: true,
+                    ..Column::new("id", "ID")
+                },
+                Column::new("name", "Name"),
+                Column {
+                    align: TextAlign::Right,
+                    ..Column::new("size", "Size")
+                },
+            ],
+            rows: (0..rows)
+                .map(|i| {
+                    HashMap::from([
+                        ("id".to_string(), i.to_string()),
+                        ("name".to_string(), format!("file{i}")),
+                    ])
+                })
+                .collect(),
+            width: 500.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_column_layout_fills_width() {
+        let layout = table(0).column_layout();
+        assert_eq!(layout[0], (0.0, 100.0));
+        assert_eq!(layout[1], (100.0, 200.0));
+        assert_eq!(layout[2], (300.0, 200.0));
+    }
+
+    #[test]
+    fn test_sort_toggles_direction() {
+        let mut table = table(3);
+        table.click_header("id");
+        assert_eq!(
+            table.sort,
+            Some(("id".to_string(), SortDirection::Ascending))
+        );
+        table.click_header("id");
+        assert_eq!(
+            table.sort,
+            Some(("id".to_string(), SortDirection::Descending))
+        );
+
+        // Unsortable columns ignore clicks
+        table.click_header("name");
+        assert_eq!(table.sort.as_ref().unwrap().0, "id");
+    }
+
+    #[test]
+    fn test_rows_keyed_by_row_key_move_when_resorted() {
+        let mut table = table(3);
+        let before = table.render().unwrap().remove(0);
+        let content = |root: &Node| root.children()[0].children()[0].clone();
+        assert_eq!(content(&before).children()[2].key(), Some("2"));
+
+        table.row_key = Some("id".to_string());
+        let before = table.render().unwrap().remove(0);
+        table.rows.reverse();
+        let after = table.render().unwrap().remove(0);
+        assert_eq!(content(&after).children()[0].key(), Some("2"));
+        let patches = crate::component::diff(&before, &after);
+        assert!(patches
+            .iter()
+            .all(|patch| matches!(patch, Patch::Move { .. } | Patch::Update { .. })));
+    }
+
+    #[test]
+    fn test_virtualized_rows_and_sticky_header() {
+        let mut table = table(10_000);
+        table.scroll_by(ROW_HEIGHT * 500.0);
+
+        let nodes = table.render().unwrap();
+        let root = &nodes[0];
+        let content = &root.children()[0].children()[0];
+        let row_count = (table.body_height() / ROW_HEIGHT).ceil() as usize + 1;
+        assert_eq!(content.children().len(), row_count);
+        assert_eq!(
+            content.children()[0]
+                .attributes()
+                .get("aria-rowindex")
+                .unwrap(),
+            "501"
+        );
+        // Odd rows are striped
+        assert!(content.children()[1].style().background_color.is_some());
+
+        // The sticky header is painted last, on top of the body
+        let header = root.children().last().unwrap();
+        assert_eq!(header.layout_rect().y(), 0.0);
+        assert_eq!(
+            header.children()[0].attributes().get("aria-sort").unwrap(),
+            "none"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+// Tabs component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::kit::utils::animation::Animation;
+use crate::layout::Rect;
+use crate::style::{Color, TimingFunction};
+use std::any::Any;
+
+/// Height of the header row
+const HEADER_HEIGHT: f32 = 40.0;
+
+/// Default width of each tab header
+const DEFAULT_TAB_WIDTH: f32 = 120.0;
+
+/// Thickness of the active-tab underline
+const INDICATOR_HEIGHT: f32 = 2.0;
+
+/// How long the underline takes to slide between tabs, in seconds
+const INDICATOR_TRANSITION: f32 = 0.2;
+
+/// A single tab: its header label and the panel content shown when active
+#[derive(Debug, Clone, Default)]
+pub struct Tab {
+    /// Header label
+    pub label: String,
+    /// Whether the tab can be selected
+    pub disabled: bool,
+    /// Panel content shown while the tab is active
+    pub children: Vec<Node>,
+}
+
+impl Tab {
+    /// Create an enabled tab with no content
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Tabs component
+#[derive(Debug)]
+pub struct Tabs {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// The tabs, in header order
+    pub tabs: Vec<Tab>,
+    /// Index of the active tab
+    pub selected: usize,
+    /// Width of each tab header
+    pub tab_width: f32,
+    /// Change event handler, called with the newly selected index
+    pub on_change: Option<fn(usize)>,
+    /// Theme the headers and indicator are colored with
+    pub theme: Theme,
+    /// Left edge of the active-tab underline, animated by `tick`
+    indicator: Animation,
+}
+
+/// Tabs props
+#[derive(Debug, Clone)]
+pub struct TabsProps {
+    /// The tabs, in header order
+    pub tabs: Vec<Tab>,
+    /// Index of the active tab
+    pub selected: Option<usize>,
+    /// Width of each tab header
+    pub tab_width: Option<f32>,
+    /// Change event handler, called with the newly selected index
+    pub on_change: Option<fn(usize)>,
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            tabs: Vec::new(),
+            selected: 0,
+            tab_width: DEFAULT_TAB_WIDTH,
+            on_change: None,
+            theme: Theme::default(),
+            indicator: Animation::at_rest(0.0, TimingFunction::EaseInOut),
+        }
+    }
+}
+
+impl Tabs {
+    /// Activate the tab at `index`, as when its header is clicked
+    ///
+    /// Disabled and out-of-range tabs are ignored.
+    pub fn select(&mut self, index: usize) {
+        let enabled = self.tabs.get(index).is_some_and(|tab| !tab.disabled);
+        if !enabled || index == self.selected {
+            return;
+        }
+        self.selected = index;
+        self.slide_indicator();
+        if let Some(on_change) = self.on_change {
+            on_change(index);
+        }
+    }
+
+    /// Where the underline rests under the active tab
+    fn indicator_target(&self) -> f32 {
+        self.tab_width * self.selected as f32
+    }
+
+    fn slide_indicator(&mut self) {
+        self.indicator
+            .retarget(self.indicator_target(), INDICATOR_TRANSITION);
+        if self.theme.reduced_motion {
+            self.indicator.finish();
+        }
+    }
+
+    /// Current left edge of the active-tab underline
+    pub fn indicator_offset(&self) -> f32 {
+        self.indicator.value()
+    }
+
+    /// Whether the underline is still sliding to the active tab
+    pub fn is_animating(&self) -> bool {
+        !self.indicator.is_finished() || self.indicator.to != self.indicator_target()
+    }
+
+    /// Advance the underline animation by `dt` seconds; returns whether it
+    /// is still moving
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.slide_indicator();
+        self.indicator.sample(dt);
+        self.is_animating()
+    }
+
+    /// Next enabled tab after `selected` in the given direction, wrapping
+    fn next_enabled(&self, forward: bool) -> Option<usize> {
+        let count = self.tabs.len();
+        (1..count)
+            .map(|step| {
+                if forward {
+                    (self.selected + step) % count
+                } else {
+                    (self.selected + count - step) % count
+                }
+            })
+            .find(|&index| !self.tabs[index].disabled)
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Left/Right move to the previous/next enabled tab, wrapping around and
+    /// skipping disabled tabs. Home/End jump to the first/last enabled tab.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        let target = match key {
+            "ArrowRight" => self.next_enabled(true),
+            "ArrowLeft" => self.next_enabled(false),
+            "Home" => self.tabs.iter().position(|tab| !tab.disabled),
+            "End" => self.tabs.iter().rposition(|tab| !tab.disabled),
+            _ => return false,
+        };
+        if let Some(index) = target {
+            self.select(index);
+        }
+        true
+    }
+}
+
+impl Component for Tabs {
+    type Props = TabsProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut tabs = Self {
+            theme: Theme::current(&context),
+            tabs: props.tabs,
+            selected: props.selected.unwrap_or(0),
+            tab_width: props.tab_width.unwrap_or(DEFAULT_TAB_WIDTH),
+            on_change: props.on_change,
+            ..Default::default()
+        };
+        // The underline starts under the initial tab rather than sliding in
+        tabs.indicator = Animation::at_rest(tabs.indicator_target(), TimingFunction::EaseInOut);
+        tabs
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.tabs = props.tabs;
+        self.selected = props.selected.unwrap_or(self.selected);
+        self.tab_width = props.tab_width.unwrap_or(self.tab_width);
+        self.on_change = props.on_change;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let accent = Color::Hex(self.theme.primary_color.clone());
+        let width = self.tab_width * self.tabs.len() as f32;
+
+        let mut root = Node::default();
+
+        let mut header = Node::default();
+        header.add_attribute("role".to_string(), "tablist".to_string());
+        header.set_layout_rect(Rect::new(0.0, 0.0, width, HEADER_HEIGHT));
+        for (index, tab) in self.tabs.iter().enumerate() {
+            let active = index == self.selected;
+            let mut tab_node = Node::default();
+            tab_node.add_attribute("role".to_string(), "tab".to_string());
+            tab_node.add_attribute("aria-selected".to_string(), active.to_string());
+            if tab.disabled {
+                tab_node.add_attribute("aria-disabled".to_string(), "true".to_string());
+            }
+            tab_node.set_layout_rect(Rect::new(
+                self.tab_width * index as f32,
+                0.0,
+                self.tab_width,
+                HEADER_HEIGHT,
+            ));
+
+            let mut label = Node::default();
+            label.set_layout_rect(Rect::new(16.0, 12.0, self.tab_width - 32.0, 16.0));
+            label.set_text(tab.label.clone());
+            label.style_mut().color = Some(match (active, tab.disabled) {
+                (true, _) => accent.clone(),
+                (false, true) => Color::Hex(self.theme.disabled_color.clone()),
+                (false, false) => Color::Hex(self.theme.muted_text_color.clone()),
+            });
+            tab_node.add_child(label);
+            header.add_child(tab_node);
+        }
+
+        if self.selected < self.tabs.len() {
+            let mut indicator = Node::default();
+            indicator.set_layout_rect(Rect::new(
+                self.indicator_offset(),
+                HEADER_HEIGHT - INDICATOR_HEIGHT,
+                self.tab_width,
+                INDICATOR_HEIGHT,
+            ));
+            indicator.style_mut().background_color = Some(accent);
+            header.add_child(indicator);
+        }
+        root.add_child(header);
+
+        if let Some(tab) = self.tabs.get(self.selected) {
+            let mut panel = Node::default();
+            panel.add_attribute("role".to_string(), "tabpanel".to_string());
+            panel.add_attribute("aria-label".to_string(), tab.label.clone());
+            panel.set_layout_rect(Rect::new(0.0, HEADER_HEIGHT, width, 0.0));
+            for child in &tab.children {
+                panel.add_child(child.clone());
+            }
+            root.add_child(panel);
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, width, HEADER_HEIGHT));
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tabs() -> Tabs {
+        let mut text = Node::default();
+        text.set_text("Appearance settings");
+        Tabs {
+            tabs: vec![
+                Tab::new("General"),
+                Tab {
+                    disabled: true,
+                    ..Tab::new("Account")
+                },
+                Tab {
+                    children: vec![text],
+                    ..Tab::new("Appearance")
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keyboard_skips_disabled_tabs() {
+        let mut tabs = tabs();
+
+        assert!(tabs.handle_key("ArrowRight"));
+        assert_eq!(tabs.selected, 2);
+        tabs.handle_key("ArrowRight");
+        assert_eq!(tabs.selected, 0);
+        tabs.handle_key("ArrowLeft");
+        assert_eq!(tabs.selected, 2);
+
+        tabs.select(1);
+        assert_eq!(tabs.selected, 2);
+        tabs.handle_key("Home");
+        assert_eq!(tabs.selected, 0);
+        assert!(!tabs.handle_key("Enter"));
+    }
+
+    #[test]
+    fn test_render_shows_only_active_panel() {
+        let mut tabs = tabs();
+        tabs.select(2);
+        assert!(tabs.tick(INDICATOR_TRANSITION / 2.0));
+        assert_eq!(tabs.indicator_offset(), 120.0);
+        assert!(!tabs.tick(INDICATOR_TRANSITION));
+
+        let nodes = tabs.render().unwrap();
+        let root = &nodes[0];
+        let header = &root.children()[0];
+        let indicator = header.children().last().unwrap();
+        assert_eq!(indicator.layout_rect().x(), 240.0);
+
+        let panel = &root.children()[1];
+        assert_eq!(panel.attributes().get("role").unwrap(), "tabpanel");
+        assert_eq!(panel.children().len(), 1);
+        assert_eq!(panel.children()[0].text(), Some("Appearance settings"));
+    }
+}
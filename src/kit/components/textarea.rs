@@ -0,0 +1,417 @@
+// Textarea component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::{EdgeValues, Rect};
+use crate::renderer::{ApproximateTextMeasure, TextMeasure};
+use crate::style::{BorderRadius, Color, EdgeColors, Overflow};
+use std::any::Any;
+
+/// Padding between the border and the text
+const PADDING: f32 = 8.0;
+
+/// Wrap `text` into lines no wider than `max_width`
+///
+/// Explicit newlines always break. Lines break at spaces where possible; a
+/// single word wider than the line is split between characters.
+fn wrap_text(text: &str, max_width: f32, font_size: f32, measure: &dyn TextMeasure) -> Vec<String> {
+    let fits = |line: &str| measure.measure_text(line, font_size).0 <= max_width;
+    let mut lines = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            if fits(&candidate) {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for ch in word.chars() {
+                line.push(ch);
+                if !fits(&line) && line.chars().count() > 1 {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, ch.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Multi-line text input component
+#[derive(Debug)]
+pub struct Textarea {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Textarea value
+    pub value: String,
+    /// Number of visible text rows
+    pub rows: usize,
+    /// Textarea placeholder
+    pub placeholder: Option<String>,
+    /// Maximum length of the value, in characters
+    pub max_length: Option<usize>,
+    /// Width of the textarea
+    pub width: f32,
+    /// Whether the textarea grows to fit its content instead of scrolling
+    pub auto_resize: bool,
+    /// Whether the textarea is disabled
+    pub disabled: bool,
+    /// Whether the value can be selected but not edited
+    pub readonly: bool,
+    /// On change handler
+    pub on_change: Option<fn(String)>,
+    /// Vertical scroll offset of the content, in logical pixels
+    scroll_offset: f32,
+    /// Theme the box and text are colored with
+    pub theme: Theme,
+}
+
+/// Textarea props
+#[derive(Debug, Clone)]
+pub struct TextareaProps {
+    /// Textarea value
+    pub value: String,
+    /// Number of visible text rows
+    pub rows: Option<usize>,
+    /// Textarea placeholder
+    pub placeholder: Option<String>,
+    /// Maximum length of the value, in characters
+    pub max_length: Option<usize>,
+    /// Width of the textarea
+    pub width: Option<f32>,
+    /// Whether the textarea grows to fit its content instead of scrolling
+    pub auto_resize: Option<bool>,
+    /// Whether the textarea is disabled
+    pub disabled: Option<bool>,
+    /// Whether the value can be selected but not edited
+    pub readonly: Option<bool>,
+    /// On change handler
+    pub on_change: Option<fn(String)>,
+}
+
+impl Default for Textarea {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            value: String::new(),
+            rows: 3,
+            placeholder: None,
+            max_length: None,
+            width: 240.0,
+            auto_resize: false,
+            disabled: false,
+            readonly: false,
+            on_change: None,
+            scroll_offset: 0.0,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Textarea {
+    fn editable(&self) -> bool {
+        !self.disabled && !self.readonly
+    }
+
+    /// Insert text at the end of the value, truncated to `max_length`
+    pub fn insert(&mut self, text: &str) {
+        if !self.editable() {
+            return;
+        }
+        let room = self.max_length.map_or(usize::MAX, |max| {
+            max.saturating_sub(self.value.chars().count())
+        });
+        let inserted: String = text.chars().take(room).collect();
+        if inserted.is_empty() {
+            return;
+        }
+        self.value.push_str(&inserted);
+        if let Some(on_change) = self.on_change {
+            on_change(self.value.clone());
+        }
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Enter inserts a newline and Backspace deletes the last character.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        if !self.editable() {
+            return false;
+        }
+        match key {
+            "Enter" => self.insert("\n"),
+            "Backspace" => {
+                if self.value.pop().is_some() {
+                    if let Some(on_change) = self.on_change {
+                        on_change(self.value.clone());
+                    }
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// The value wrapped into display lines
+    pub fn lines(&self, measure: &dyn TextMeasure) -> Vec<String> {
+        wrap_text(
+            &self.value,
+            self.width - 2.0 * PADDING,
+            self.font_size(),
+            measure,
+        )
+    }
+
+    fn font_size(&self) -> f32 {
+        self.theme.text_style("body").font_size
+    }
+
+    fn line_height(&self, measure: &dyn TextMeasure) -> f32 {
+        measure.measure_text("", self.font_size()).1
+    }
+
+    /// Height of the text area inside the padding
+    pub fn viewport_height(&self, measure: &dyn TextMeasure) -> f32 {
+        let rows = if self.auto_resize {
+            self.rows.max(self.lines(measure).len())
+        } else {
+            self.rows
+        };
+        rows as f32 * self.line_height(measure)
+    }
+
+    /// Largest scroll offset that still shows content
+    pub fn max_scroll(&self, measure: &dyn TextMeasure) -> f32 {
+        let content = self.lines(measure).len() as f32 * self.line_height(measure);
+        (content - self.viewport_height(measure)).max(0.0)
+    }
+
+    /// Current vertical scroll offset
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    /// Scroll the content by `dy` pixels, clamped to the content height
+    pub fn scroll_by(&mut self, dy: f32, measure: &dyn TextMeasure) {
+        self.scroll_offset = (self.scroll_offset + dy).clamp(0.0, self.max_scroll(measure));
+    }
+
+    /// Render using `measure` to wrap the text
+    ///
+    /// [`Component::render`] uses [`ApproximateTextMeasure`]; callers with a
+    /// renderer should prefer this so wrapping matches the drawn text.
+    pub fn render_with(&self, measure: &dyn TextMeasure) -> Vec<Node> {
+        let line_height = self.line_height(measure);
+        let viewport = self.viewport_height(measure);
+        let height = viewport + 2.0 * PADDING;
+        let border = Color::Hex(self.theme.border_color.clone());
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "textbox".to_string());
+        root.add_attribute("aria-multiline".to_string(), "true".to_string());
+        if self.readonly {
+            root.add_attribute("aria-readonly".to_string(), "true".to_string());
+        }
+        if self.disabled {
+            root.add_attribute("aria-disabled".to_string(), "true".to_string());
+        }
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.width, height));
+        let style = root.style_mut();
+        let background = if self.disabled {
+            &self.theme.secondary_color
+        } else {
+            &self.theme.surface_color
+        };
+        style.background_color = Some(Color::Hex(background.clone()));
+        style.border_width = Some(EdgeValues::uniform(1.0));
+        style.border_color = Some(EdgeColors {
+            top: border.clone(),
+            right: border.clone(),
+            bottom: border.clone(),
+            left: border,
+        });
+        style.border_radius = Some(BorderRadius {
+            top_left: 4.0,
+            top_right: 4.0,
+            bottom_right: 4.0,
+            bottom_left: 4.0,
+        });
+
+        // Lines outside the viewport are clipped rather than skipped, so the
+        // scroll offset is a single translation of the content
+        let mut viewport_node = Node::default();
+        viewport_node.set_layout_rect(Rect::new(
+            PADDING,
+            PADDING,
+            self.width - 2.0 * PADDING,
+            viewport,
+        ));
+        viewport_node.style_mut().overflow = Some(Overflow::Hidden);
+
+        let scroll = self.scroll_offset.min(self.max_scroll(measure));
+        let mut content = Node::default();
+        let placeholder = self
+            .placeholder
+            .as_deref()
+            .filter(|_| self.value.is_empty());
+        let lines = match placeholder {
+            Some(placeholder) => wrap_text(
+                placeholder,
+                self.width - 2.0 * PADDING,
+                self.font_size(),
+                measure,
+            ),
+            None => self.lines(measure),
+        };
+        content.set_layout_rect(Rect::new(
+            0.0,
+            -scroll,
+            self.width - 2.0 * PADDING,
+            lines.len() as f32 * line_height,
+        ));
+        for (index, line) in lines.into_iter().enumerate() {
+            let mut line_node = Node::default();
+            line_node.set_layout_rect(Rect::new(
+                0.0,
+                index as f32 * line_height,
+                self.width - 2.0 * PADDING,
+                line_height,
+            ));
+            line_node.set_text(line);
+            self.theme.text_style("body").apply(line_node.style_mut());
+            let color = if placeholder.is_some() {
+                &self.theme.muted_text_color
+            } else {
+                &self.theme.text_color
+            };
+            line_node.style_mut().color = Some(Color::Hex(color.clone()));
+            content.add_child(line_node);
+        }
+        viewport_node.add_child(content);
+        root.add_child(viewport_node);
+
+        vec![root]
+    }
+}
+
+impl Component for Textarea {
+    type Props = TextareaProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut textarea = Self {
+            theme: Theme::current(&context),
+            value: props.value,
+            placeholder: props.placeholder,
+            max_length: props.max_length,
+            auto_resize: props.auto_resize.unwrap_or(false),
+            disabled: props.disabled.unwrap_or(false),
+            readonly: props.readonly.unwrap_or(false),
+            on_change: props.on_change,
+            ..Default::default()
+        };
+        textarea.rows = props.rows.unwrap_or(textarea.rows);
+        textarea.width = props.width.unwrap_or(textarea.width);
+        textarea
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.value = props.value;
+        self.rows = props.rows.unwrap_or(self.rows);
+        self.placeholder = props.placeholder;
+        self.max_length = props.max_length;
+        self.width = props.width.unwrap_or(self.width);
+        self.auto_resize = props.auto_resize.unwrap_or(self.auto_resize);
+        self.disabled = props.disabled.unwrap_or(self.disabled);
+        self.readonly = props.readonly.unwrap_or(self.readonly);
+        self.on_change = props.on_change;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        Ok(self.render_with(&ApproximateTextMeasure))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every character is 10px wide and lines are 20px tall
+    struct FixedMeasure;
+
+    impl TextMeasure for FixedMeasure {
+        fn measure_text(&self, text: &str, _size: f32) -> (f32, f32) {
+            (text.chars().count() as f32 * 10.0, 20.0)
+        }
+    }
+
+    fn textarea(value: &str) -> Textarea {
+        Textarea {
+            value: value.to_string(),
+            // 100px of text per line
+            width: 100.0 + 2.0 * PADDING,
+            rows: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_wraps_on_words_and_newlines() {
+        let lines = textarea("hello big world\nabcdefghijklm").lines(&FixedMeasure);
+        assert_eq!(lines, vec!["hello big", "world", "abcdefghij", "klm"]);
+    }
+
+    #[test]
+    fn test_scrolling_and_auto_resize() {
+        let mut area = textarea("one\ntwo\nthree\nfour");
+        assert_eq!(area.max_scroll(&FixedMeasure), 40.0);
+        area.scroll_by(100.0, &FixedMeasure);
+        assert_eq!(area.scroll_offset(), 40.0);
+
+        let nodes = area.render_with(&FixedMeasure);
+        let content = &nodes[0].children()[0].children()[0];
+        assert_eq!(content.layout_rect().y(), -40.0);
+
+        area.auto_resize = true;
+        assert_eq!(area.viewport_height(&FixedMeasure), 80.0);
+        assert_eq!(area.max_scroll(&FixedMeasure), 0.0);
+    }
+
+    #[test]
+    fn test_editing_respects_limits() {
+        let mut area = textarea("ab");
+        area.max_length = Some(4);
+        assert!(area.handle_key("Enter"));
+        area.insert("cdef");
+        assert_eq!(area.value, "ab\nc");
+        area.handle_key("Backspace");
+        assert_eq!(area.value, "ab\n");
+
+        area.readonly = true;
+        assert!(!area.handle_key("Enter"));
+        area.insert("x");
+        assert_eq!(area.value, "ab\n");
+    }
+}
@@ -0,0 +1,402 @@
+// Toast notifications for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use crate::style::{BorderRadius, BoxShadow, Color};
+use std::any::Any;
+
+/// Stacking order for toasts, above everything including modals
+const TOAST_Z_INDEX: i32 = 3000;
+
+/// Size of each toast
+const TOAST_WIDTH: f32 = 320.0;
+const TOAST_HEIGHT: f32 = 56.0;
+
+/// Space between stacked toasts, and between the stack and the viewport edge
+const TOAST_GAP: f32 = 12.0;
+
+/// How long a toast takes to slide in or fade out, in seconds
+const TOAST_TRANSITION: f32 = 0.2;
+
+/// How far a toast slides while entering, in logical pixels
+const SLIDE_DISTANCE: f32 = 24.0;
+
+/// How serious a notification is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Accent color for this severity from the theme
+    pub fn color(self, theme: &Theme) -> String {
+        match self {
+            Severity::Info => theme.info_color.clone(),
+            Severity::Success => theme.success_color.clone(),
+            Severity::Warning => theme.warning_color.clone(),
+            Severity::Error => theme.error_color.clone(),
+        }
+    }
+
+    /// Icon glyph shown beside the message
+    pub fn icon(self) -> &'static str {
+        match self {
+            Severity::Info => "ℹ",
+            Severity::Success => "✓",
+            Severity::Warning => "⚠",
+            Severity::Error => "✕",
+        }
+    }
+
+    /// ARIA role announcing a message of this severity: errors and
+    /// warnings interrupt, the rest wait their turn
+    pub fn role(self) -> &'static str {
+        match self {
+            Severity::Error | Severity::Warning => "alert",
+            Severity::Info | Severity::Success => "status",
+        }
+    }
+}
+
+/// Viewport corner the toast stack is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// A notification to show
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Message text
+    pub message: String,
+    /// How serious the notification is
+    pub severity: Severity,
+    /// How long the toast stays before dismissing itself; 0 keeps it until
+    /// it is dismissed manually
+    pub duration_ms: u64,
+}
+
+impl Toast {
+    /// Create an info toast that dismisses itself after four seconds
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            severity: Severity::Info,
+            duration_ms: 4000,
+        }
+    }
+}
+
+/// A toast being shown, with its animation state
+#[derive(Debug, Clone)]
+struct ActiveToast {
+    id: u64,
+    toast: Toast,
+    /// Seconds since the toast was shown
+    age: f32,
+    /// Seconds since the toast started leaving, once dismissed
+    leaving: Option<f32>,
+}
+
+impl ActiveToast {
+    /// How far the toast is through its enter/exit animation, from 0.0
+    /// (hidden) to 1.0 (fully shown)
+    fn presence(&self) -> f32 {
+        let entered = (self.age / TOAST_TRANSITION).min(1.0);
+        match self.leaving {
+            Some(leaving) => entered.min(1.0 - (leaving / TOAST_TRANSITION).min(1.0)),
+            None => entered,
+        }
+    }
+}
+
+/// Toast manager component, which owns and renders the toast stack
+#[derive(Debug)]
+pub struct ToastManager {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Corner the stack is anchored to
+    pub corner: Corner,
+    /// Size of the viewport the stack is positioned in
+    pub viewport: (f32, f32),
+    /// Theme providing severity colors and the reduced-motion preference
+    pub theme: Theme,
+    /// Toasts being shown, oldest first
+    toasts: Vec<ActiveToast>,
+    /// ID handed to the next toast
+    next_id: u64,
+}
+
+/// Toast manager props
+#[derive(Debug, Clone)]
+pub struct ToastManagerProps {
+    /// Corner the stack is anchored to
+    pub corner: Option<Corner>,
+    /// Size of the viewport the stack is positioned in
+    pub viewport: Option<(f32, f32)>,
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            corner: Corner::TopRight,
+            viewport: (800.0, 600.0),
+            theme: Theme::default(),
+            toasts: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl ToastManager {
+    /// Show a toast; returns an ID that can be passed to `dismiss`
+    ///
+    /// The new toast appears nearest the corner and pushes the others along.
+    pub fn show_toast(&mut self, toast: Toast) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let age = if self.theme.reduced_motion {
+            TOAST_TRANSITION
+        } else {
+            0.0
+        };
+        self.toasts.push(ActiveToast {
+            id,
+            toast,
+            age,
+            leaving: None,
+        });
+        id
+    }
+
+    /// Start dismissing a toast; it fades out on subsequent `tick`s
+    pub fn dismiss(&mut self, id: u64) {
+        let reduced_motion = self.theme.reduced_motion;
+        if let Some(active) = self.toasts.iter_mut().find(|active| active.id == id) {
+            if active.leaving.is_none() {
+                active.leaving = Some(0.0);
+            }
+        }
+        if reduced_motion {
+            self.toasts.retain(|active| active.id != id);
+        }
+    }
+
+    /// Number of toasts on screen, including ones still animating out
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether no toasts are on screen
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Advance timers and animations by `dt` seconds; returns whether any
+    /// toasts remain
+    ///
+    /// Toasts whose duration has elapsed start leaving, and toasts that
+    /// finished leaving are removed.
+    pub fn tick(&mut self, dt: f32) -> bool {
+        let dt = dt.max(0.0);
+        for active in &mut self.toasts {
+            active.age += dt;
+            match active.leaving.as_mut() {
+                Some(leaving) => *leaving += dt,
+                None => {
+                    let duration = active.toast.duration_ms as f32 / 1000.0;
+                    if active.toast.duration_ms > 0 && active.age >= duration {
+                        active.leaving = Some(0.0);
+                    }
+                }
+            }
+        }
+        let reduced_motion = self.theme.reduced_motion;
+        self.toasts.retain(|active| match active.leaving {
+            Some(leaving) => !reduced_motion && leaving < TOAST_TRANSITION,
+            None => true,
+        });
+        !self.toasts.is_empty()
+    }
+
+    /// Rect of the toast `slot` places away from the corner
+    fn slot_rect(&self, slot: usize) -> Rect {
+        let (viewport_width, viewport_height) = self.viewport;
+        let offset = TOAST_GAP + slot as f32 * (TOAST_HEIGHT + TOAST_GAP);
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => TOAST_GAP,
+            Corner::TopRight | Corner::BottomRight => viewport_width - TOAST_WIDTH - TOAST_GAP,
+        };
+        let y = match self.corner {
+            Corner::TopLeft | Corner::TopRight => offset,
+            Corner::BottomLeft | Corner::BottomRight => viewport_height - offset - TOAST_HEIGHT,
+        };
+        Rect::new(x, y, TOAST_WIDTH, TOAST_HEIGHT)
+    }
+
+    fn render_toast(&self, active: &ActiveToast, rect: Rect) -> Node {
+        let presence = active.presence();
+        // Toasts slide in from the side of the viewport they are anchored to
+        let slide = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => -SLIDE_DISTANCE,
+            Corner::TopRight | Corner::BottomRight => SLIDE_DISTANCE,
+        } * (1.0 - presence);
+
+        let mut node = Node::default();
+        node.add_attribute("role".to_string(), active.toast.severity.role().to_string());
+        node.add_attribute("data-toast-id".to_string(), active.id.to_string());
+        node.set_layout_rect(Rect::new(
+            rect.x() + slide,
+            rect.y(),
+            rect.width(),
+            rect.height(),
+        ));
+
+        let accent = Color::Hex(active.toast.severity.color(&self.theme));
+        let style = node.style_mut();
+        style.opacity = Some(presence);
+        style.background_color = Some(Color::Hex(self.theme.background_color.clone()));
+        style.border_radius = Some(BorderRadius {
+            top_left: 6.0,
+            top_right: 6.0,
+            bottom_right: 6.0,
+            bottom_left: 6.0,
+        });
+        style.box_shadow = Some(vec![BoxShadow::new(
+            0.0,
+            4.0,
+            12.0,
+            Color::Rgba(0.0, 0.0, 0.0, 0.15),
+        )]);
+
+        let mut stripe = Node::default();
+        stripe.set_layout_rect(Rect::new(0.0, 0.0, 4.0, TOAST_HEIGHT));
+        stripe.style_mut().background_color = Some(accent.clone());
+        node.add_child(stripe);
+
+        let mut icon = Node::default();
+        icon.set_layout_rect(Rect::new(16.0, 18.0, 20.0, 20.0));
+        icon.set_text(active.toast.severity.icon());
+        icon.style_mut().color = Some(accent);
+        node.add_child(icon);
+
+        let mut message = Node::default();
+        message.set_layout_rect(Rect::new(44.0, 18.0, TOAST_WIDTH - 80.0, 20.0));
+        message.set_text(active.toast.message.clone());
+        message.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+        node.add_child(message);
+
+        let mut close = Node::default();
+        close.add_attribute("role".to_string(), "button".to_string());
+        close.add_attribute("aria-label".to_string(), "Dismiss".to_string());
+        close.set_layout_rect(Rect::new(TOAST_WIDTH - 28.0, 18.0, 16.0, 20.0));
+        close.set_text("×");
+        node.add_child(close);
+
+        node
+    }
+}
+
+impl Component for ToastManager {
+    type Props = ToastManagerProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        Self {
+            corner: props.corner.unwrap_or_default(),
+            viewport: props.viewport.unwrap_or((800.0, 600.0)),
+            theme: Theme::current(&context),
+            ..Default::default()
+        }
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.corner = props.corner.unwrap_or(self.corner);
+        self.viewport = props.viewport.unwrap_or(self.viewport);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut root = Node::default();
+        root.add_attribute("aria-live".to_string(), "polite".to_string());
+        root.set_layout_rect(Rect::new(0.0, 0.0, self.viewport.0, self.viewport.1));
+        root.style_mut().z_index = Some(TOAST_Z_INDEX);
+
+        // Newest first, so it takes the slot nearest the corner
+        for (slot, active) in self.toasts.iter().rev().enumerate() {
+            root.add_child(self.render_toast(active, self.slot_rect(slot)));
+        }
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_auto_dismiss_and_animate_out() {
+        let mut manager = ToastManager::default();
+        manager.show_toast(Toast {
+            duration_ms: 1000,
+            ..Toast::new("Saved")
+        });
+        manager.tick(TOAST_TRANSITION / 2.0);
+        let nodes = manager.render().unwrap();
+        let toast = &nodes[0].children()[0];
+        assert!((toast.style().opacity.unwrap() - 0.5).abs() < 1e-6);
+
+        // Still present while fading out after its duration
+        manager.tick(1.0);
+        assert_eq!(manager.len(), 1);
+        assert!(!manager.tick(TOAST_TRANSITION));
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn test_new_toasts_push_existing_ones() {
+        let mut manager = ToastManager {
+            corner: Corner::BottomRight,
+            ..Default::default()
+        };
+        let first = manager.show_toast(Toast::new("First"));
+        manager.show_toast(Toast {
+            severity: Severity::Error,
+            ..Toast::new("Second")
+        });
+        manager.tick(TOAST_TRANSITION);
+
+        let nodes = manager.render().unwrap();
+        let stack = nodes[0].children();
+        assert_eq!(stack[0].children()[2].text(), Some("Second"));
+        assert_eq!(stack[0].attributes().get("role").unwrap(), "alert");
+        assert_eq!(stack[0].layout_rect().max_y(), 600.0 - TOAST_GAP);
+        assert!(stack[1].layout_rect().max_y() < stack[0].layout_rect().y());
+
+        manager.dismiss(first);
+        manager.tick(TOAST_TRANSITION);
+        assert_eq!(manager.len(), 1);
+    }
+}
@@ -0,0 +1,344 @@
+// Tooltip component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::{Rect, Size};
+use crate::style::{BorderRadius, Color};
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+/// Stacking order for tooltips, above popups but below modals
+const TOOLTIP_Z_INDEX: i32 = 1500;
+
+/// Gap between the child and the tooltip
+const OFFSET: f32 = 6.0;
+
+/// Horizontal padding inside the tooltip bubble
+const PADDING_X: f32 = 8.0;
+
+/// Vertical padding inside the tooltip bubble
+const PADDING_Y: f32 = 4.0;
+
+/// Rough per-character width used to size the bubble until text is measured
+/// by layout
+const CHAR_WIDTH: f32 = 6.5;
+
+/// Where a floating element is placed relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    /// The placement on the opposite side of the anchor
+    pub fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
+    /// Position a box of `size` next to `anchor`, separated by `gap`, and
+    /// centered along the shared edge
+    pub fn position(self, anchor: Rect, size: Size, gap: f32) -> Rect {
+        let center_x = anchor.x() + (anchor.width() - size.width) / 2.0;
+        let center_y = anchor.y() + (anchor.height() - size.height) / 2.0;
+        let (x, y) = match self {
+            Placement::Top => (center_x, anchor.y() - gap - size.height),
+            Placement::Bottom => (center_x, anchor.max_y() + gap),
+            Placement::Left => (anchor.x() - gap - size.width, center_y),
+            Placement::Right => (anchor.max_x() + gap, center_y),
+        };
+        Rect::new(x, y, size.width, size.height)
+    }
+
+    /// Pick this placement, or its opposite when this one would leave the
+    /// viewport and the opposite would not
+    pub fn resolve(self, anchor: Rect, size: Size, gap: f32, viewport: Size) -> Self {
+        let fits = |placement: Placement| {
+            let rect = placement.position(anchor, size, gap);
+            rect.x() >= 0.0
+                && rect.y() >= 0.0
+                && rect.max_x() <= viewport.width
+                && rect.max_y() <= viewport.height
+        };
+        if !fits(self) && fits(self.opposite()) {
+            self.opposite()
+        } else {
+            self
+        }
+    }
+
+    /// Flip the placement if the box would leave the viewport on its side of
+    /// the anchor, then slide it along the anchor's edge as far as needed to
+    /// keep it on screen; returns the placement used and where the box ends
+    /// up
+    ///
+    /// Unlike [`Placement::resolve`], only overflow away from the anchor
+    /// causes a flip, since sliding fixes overflow along it.
+    pub fn place(self, anchor: Rect, size: Size, gap: f32, viewport: Size) -> (Self, Rect) {
+        let fits = |placement: Placement| {
+            let rect = placement.position(anchor, size, gap);
+            match placement {
+                Placement::Top => rect.y() >= 0.0,
+                Placement::Bottom => rect.max_y() <= viewport.height,
+                Placement::Left => rect.x() >= 0.0,
+                Placement::Right => rect.max_x() <= viewport.width,
+            }
+        };
+        let placement = if !fits(self) && fits(self.opposite()) {
+            self.opposite()
+        } else {
+            self
+        };
+        let rect = placement.position(anchor, size, gap);
+        let shift = |start: f32, length: f32, limit: f32| start.min(limit - length).max(0.0);
+        let (x, y) = match placement {
+            Placement::Top | Placement::Bottom => {
+                (shift(rect.x(), size.width, viewport.width), rect.y())
+            }
+            Placement::Left | Placement::Right => {
+                (rect.x(), shift(rect.y(), size.height, viewport.height))
+            }
+        };
+        (placement, Rect::new(x, y, size.width, size.height))
+    }
+}
+
+/// Tooltip component
+#[derive(Debug)]
+pub struct Tooltip {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// The element the tooltip describes; its layout rect anchors the tooltip
+    pub child: Option<Node>,
+    /// Tooltip text
+    pub text: String,
+    /// Preferred placement relative to the child
+    pub placement: Placement,
+    /// How long the pointer must hover before the tooltip shows
+    pub delay_ms: u64,
+    /// Size of the viewport, used to flip placement near the edges
+    pub viewport: (f32, f32),
+    /// When the current hover started
+    hover_started: Option<Instant>,
+    /// Whether the tooltip is showing
+    visible: bool,
+    /// Theme the bubble is colored with
+    pub theme: Theme,
+}
+
+/// Tooltip props
+#[derive(Debug, Clone)]
+pub struct TooltipProps {
+    /// The element the tooltip describes
+    pub child: Option<Node>,
+    /// Tooltip text
+    pub text: String,
+    /// Preferred placement relative to the child
+    pub placement: Option<Placement>,
+    /// How long the pointer must hover before the tooltip shows
+    pub delay_ms: Option<u64>,
+    /// Size of the viewport, used to flip placement near the edges
+    pub viewport: Option<(f32, f32)>,
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            child: None,
+            text: String::new(),
+            placement: Placement::Top,
+            delay_ms: 500,
+            viewport: (800.0, 600.0),
+            hover_started: None,
+            visible: false,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Tooltip {
+    /// Whether the tooltip is showing
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// The pointer entered the child; the tooltip shows once `delay_ms` has
+    /// passed
+    pub fn hover_in(&mut self, now: Instant) {
+        if self.hover_started.is_none() {
+            self.hover_started = Some(now);
+        }
+        self.tick(now);
+    }
+
+    /// The pointer left the child; hides the tooltip immediately
+    pub fn hover_out(&mut self) {
+        self.hover_started = None;
+        self.visible = false;
+    }
+
+    /// Advance the show delay; returns whether visibility changed
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let due = self.hover_started.is_some_and(|started| {
+            now.saturating_duration_since(started) >= Duration::from_millis(self.delay_ms)
+        });
+        let changed = due && !self.visible;
+        self.visible |= due;
+        changed
+    }
+
+    fn bubble_size(&self) -> Size {
+        Size::new(
+            self.text.chars().count() as f32 * CHAR_WIDTH + 2.0 * PADDING_X,
+            self.theme.text_style("caption").font_size + 2.0 * PADDING_Y,
+        )
+    }
+
+    /// The placement actually used, after flipping away from viewport edges
+    pub fn resolved_placement(&self) -> Placement {
+        let anchor = self.child.as_ref().map_or(Rect::zero(), Node::layout_rect);
+        let viewport = Size::new(self.viewport.0, self.viewport.1);
+        self.placement
+            .resolve(anchor, self.bubble_size(), OFFSET, viewport)
+    }
+}
+
+impl Component for Tooltip {
+    type Props = TooltipProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut tooltip = Self {
+            theme: Theme::current(&context),
+            child: props.child,
+            text: props.text,
+            placement: props.placement.unwrap_or_default(),
+            ..Default::default()
+        };
+        tooltip.delay_ms = props.delay_ms.unwrap_or(tooltip.delay_ms);
+        tooltip.viewport = props.viewport.unwrap_or(tooltip.viewport);
+        tooltip
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.child = props.child;
+        self.text = props.text;
+        self.placement = props.placement.unwrap_or(self.placement);
+        self.delay_ms = props.delay_ms.unwrap_or(self.delay_ms);
+        self.viewport = props.viewport.unwrap_or(self.viewport);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let mut nodes: Vec<Node> = self.child.iter().cloned().collect();
+        if !self.visible || self.text.is_empty() {
+            return Ok(nodes);
+        }
+
+        // The tooltip is a sibling of the child, positioned in the same
+        // coordinate space as the child's rect
+        let anchor = self.child.as_ref().map_or(Rect::zero(), Node::layout_rect);
+        let size = self.bubble_size();
+        let rect = self.resolved_placement().position(anchor, size, OFFSET);
+
+        let mut bubble = Node::default();
+        bubble.add_attribute("role".to_string(), "tooltip".to_string());
+        bubble.set_layout_rect(rect);
+        let style = bubble.style_mut();
+        // The bubble inverts the theme so it stands out from the page
+        style.background_color = Some(Color::Hex(self.theme.text_color.clone()));
+        style.border_radius = Some(BorderRadius {
+            top_left: 4.0,
+            top_right: 4.0,
+            bottom_right: 4.0,
+            bottom_left: 4.0,
+        });
+        style.z_index = Some(TOOLTIP_Z_INDEX);
+
+        let text_style = self.theme.text_style("caption");
+        let mut label = Node::default();
+        label.set_layout_rect(Rect::new(
+            PADDING_X,
+            PADDING_Y,
+            size.width - 2.0 * PADDING_X,
+            text_style.font_size,
+        ));
+        label.set_text(self.text.clone());
+        label.style_mut().color = Some(Color::Hex(self.theme.background_color.clone()));
+        text_style.apply(label.style_mut());
+        bubble.add_child(label);
+
+        nodes.push(bubble);
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchored(x: f32, y: f32) -> Tooltip {
+        let mut child = Node::default();
+        child.set_layout_rect(Rect::new(x, y, 80.0, 30.0));
+        Tooltip {
+            child: Some(child),
+            text: "Save".to_string(),
+            delay_ms: 300,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_shows_after_delay() {
+        let mut tooltip = anchored(100.0, 100.0);
+        let start = Instant::now();
+
+        tooltip.hover_in(start);
+        assert!(!tooltip.is_visible());
+        assert_eq!(tooltip.render().unwrap().len(), 1);
+
+        assert!(tooltip.tick(start + Duration::from_millis(300)));
+        assert!(!tooltip.tick(start + Duration::from_millis(400)));
+        let nodes = tooltip.render().unwrap();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[1].style().z_index, Some(TOOLTIP_Z_INDEX));
+        assert!(nodes[1].layout_rect().max_y() <= 100.0 - OFFSET);
+
+        tooltip.hover_out();
+        assert!(!tooltip.is_visible());
+    }
+
+    #[test]
+    fn test_flips_near_viewport_edge() {
+        let tooltip = anchored(100.0, 0.0);
+        assert_eq!(tooltip.resolved_placement(), Placement::Bottom);
+
+        let mut tooltip = anchored(700.0, 100.0);
+        tooltip.placement = Placement::Right;
+        assert_eq!(tooltip.resolved_placement(), Placement::Left);
+
+        // Neither side fits, so the preferred placement is kept
+        tooltip.viewport = (100.0, 100.0);
+        assert_eq!(tooltip.resolved_placement(), Placement::Right);
+    }
+}
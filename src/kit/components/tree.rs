@@ -0,0 +1,494 @@
+// Tree component for OrbitKit
+
+use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::theme::Theme;
+use crate::layout::Rect;
+use crate::style::{Color, Overflow};
+use std::any::Any;
+use std::collections::HashSet;
+
+/// Default height of each row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Horizontal indentation per depth level
+const INDENT: f32 = 16.0;
+
+/// Size of the expand/collapse chevron
+const CHEVRON_SIZE: f32 = 16.0;
+
+/// Chevron pointing right (collapsed), in a 16x16 box
+const CHEVRON_RIGHT: &str = "M6 4 L10 8 L6 12 L5 11 L8 8 L5 5 Z";
+
+/// Chevron pointing down (expanded), in a 16x16 box
+const CHEVRON_DOWN: &str = "M4 6 L8 10 L12 6 L11 5 L8 8 L5 5 Z";
+
+/// A node in a [`Tree`]
+#[derive(Debug, Clone, Default)]
+pub struct TreeNode {
+    /// Identifier passed to the tree's callbacks
+    pub id: String,
+    /// Label shown in the row
+    pub label: String,
+    /// Loaded children
+    pub children: Vec<TreeNode>,
+    /// Whether the node has children that may not be loaded yet, so it shows
+    /// a chevron even while `children` is empty
+    pub has_children: bool,
+}
+
+impl TreeNode {
+    /// Create a leaf node
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add loaded children to the node
+    pub fn with_children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Whether the node can be expanded
+    pub fn is_expandable(&self) -> bool {
+        self.has_children || !self.children.is_empty()
+    }
+}
+
+/// A row of the flattened, currently expanded tree
+#[derive(Debug, Clone, Copy)]
+pub struct TreeRow<'a> {
+    /// The node shown in this row
+    pub node: &'a TreeNode,
+    /// Nesting depth, starting at 0 for root nodes
+    pub depth: usize,
+    /// Index of the parent's row, if any
+    pub parent: Option<usize>,
+}
+
+/// Tree component
+#[derive(Debug)]
+pub struct Tree {
+    /// Component ID for tracking
+    id: ComponentId,
+    /// Root nodes
+    pub roots: Vec<TreeNode>,
+    /// IDs of expanded nodes
+    pub expanded: HashSet<String>,
+    /// ID of the selected node
+    pub selected: Option<String>,
+    /// Height of the visible area; rows outside it are not rendered
+    pub viewport_height: f32,
+    /// Select event handler, called with the node ID
+    pub on_select: Option<fn(&str)>,
+    /// Toggle event handler, called with the node ID and whether it is now
+    /// expanded
+    pub on_toggle: Option<fn(&str, bool)>,
+    /// Vertical scroll offset, in logical pixels
+    scroll_offset: f32,
+    /// Theme the rows are colored with
+    pub theme: Theme,
+}
+
+/// Tree props
+#[derive(Debug, Clone)]
+pub struct TreeProps {
+    /// Root nodes
+    pub roots: Vec<TreeNode>,
+    /// IDs of expanded nodes
+    pub expanded: Option<HashSet<String>>,
+    /// ID of the selected node
+    pub selected: Option<String>,
+    /// Height of the visible area
+    pub viewport_height: Option<f32>,
+    /// Select event handler, called with the node ID
+    pub on_select: Option<fn(&str)>,
+    /// Toggle event handler, called with the node ID and whether it is now
+    /// expanded
+    pub on_toggle: Option<fn(&str, bool)>,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self {
+            id: ComponentId::new(),
+            roots: Vec::new(),
+            expanded: HashSet::new(),
+            selected: None,
+            viewport_height: 400.0,
+            on_select: None,
+            on_toggle: None,
+            scroll_offset: 0.0,
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Tree {
+    /// The rows currently shown: every root, plus the children of expanded
+    /// nodes, in display order
+    pub fn visible_rows(&self) -> Vec<TreeRow<'_>> {
+        fn visit<'a>(
+            tree: &Tree,
+            node: &'a TreeNode,
+            depth: usize,
+            parent: Option<usize>,
+            rows: &mut Vec<TreeRow<'a>>,
+        ) {
+            let index = rows.len();
+            rows.push(TreeRow {
+                node,
+                depth,
+                parent,
+            });
+            if tree.expanded.contains(&node.id) {
+                for child in &node.children {
+                    visit(tree, child, depth + 1, Some(index), rows);
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        for root in &self.roots {
+            visit(self, root, 0, None, &mut rows);
+        }
+        rows
+    }
+
+    fn find(&self, id: &str) -> Option<&TreeNode> {
+        fn find_in<'a>(nodes: &'a [TreeNode], id: &str) -> Option<&'a TreeNode> {
+            nodes.iter().find_map(|node| {
+                (node.id == id)
+                    .then_some(node)
+                    .or_else(|| find_in(&node.children, id))
+            })
+        }
+        find_in(&self.roots, id)
+    }
+
+    /// Select a node and fire `on_select`
+    pub fn select(&mut self, id: &str) {
+        if self.find(id).is_none() || self.selected.as_deref() == Some(id) {
+            return;
+        }
+        self.selected = Some(id.to_string());
+        if let Some(on_select) = self.on_select {
+            on_select(id);
+        }
+    }
+
+    /// Expand or collapse a node and fire `on_toggle`
+    ///
+    /// Expanding a node whose children aren't loaded yet still fires
+    /// `on_toggle`, which is the cue to load them.
+    pub fn set_expanded(&mut self, id: &str, expanded: bool) {
+        if !self.find(id).is_some_and(TreeNode::is_expandable) {
+            return;
+        }
+        let changed = if expanded {
+            self.expanded.insert(id.to_string())
+        } else {
+            self.expanded.remove(id)
+        };
+        if changed {
+            if let Some(on_toggle) = self.on_toggle {
+                on_toggle(id, expanded);
+            }
+        }
+    }
+
+    /// Flip a node between expanded and collapsed, as when its chevron is
+    /// clicked
+    pub fn toggle(&mut self, id: &str) {
+        let expanded = self.expanded.contains(id);
+        self.set_expanded(id, !expanded);
+    }
+
+    /// Handle a key press; returns whether the key was handled
+    ///
+    /// Up/Down move the selection between visible rows. Right expands a
+    /// collapsed node or moves to its first child; Left collapses an
+    /// expanded node or moves to its parent.
+    pub fn handle_key(&mut self, key: &str) -> bool {
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            return false;
+        }
+        let current = self
+            .selected
+            .as_deref()
+            .and_then(|id| rows.iter().position(|row| row.node.id == id));
+
+        enum Action {
+            Select(usize),
+            Expand(String, bool),
+        }
+        let action = match (key, current) {
+            ("ArrowDown", None) | ("Home", _) => Action::Select(0),
+            ("ArrowUp", None) | ("End", _) => Action::Select(rows.len() - 1),
+            ("ArrowDown", Some(index)) => Action::Select((index + 1).min(rows.len() - 1)),
+            ("ArrowUp", Some(index)) => Action::Select(index.saturating_sub(1)),
+            ("ArrowRight", Some(index)) => {
+                let node = rows[index].node;
+                if !node.is_expandable() {
+                    return true;
+                }
+                if !self.expanded.contains(&node.id) {
+                    Action::Expand(node.id.clone(), true)
+                } else if rows
+                    .get(index + 1)
+                    .is_some_and(|row| row.parent == Some(index))
+                {
+                    Action::Select(index + 1)
+                } else {
+                    return true;
+                }
+            }
+            ("ArrowLeft", Some(index)) => {
+                let row = rows[index];
+                if self.expanded.contains(&row.node.id) {
+                    Action::Expand(row.node.id.clone(), false)
+                } else if let Some(parent) = row.parent {
+                    Action::Select(parent)
+                } else {
+                    return true;
+                }
+            }
+            ("ArrowRight" | "ArrowLeft", None) => return true,
+            _ => return false,
+        };
+
+        match action {
+            Action::Select(index) => {
+                let id = rows[index].node.id.clone();
+                self.select(&id);
+                self.scroll_into_view(index);
+            }
+            Action::Expand(id, expanded) => self.set_expanded(&id, expanded),
+        }
+        true
+    }
+
+    /// Current vertical scroll offset
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
+    fn max_scroll(&self) -> f32 {
+        (self.visible_rows().len() as f32 * ROW_HEIGHT - self.viewport_height).max(0.0)
+    }
+
+    /// Scroll by `dy` pixels, clamped to the content height
+    pub fn scroll_by(&mut self, dy: f32) {
+        self.scroll_offset = (self.scroll_offset + dy).clamp(0.0, self.max_scroll());
+    }
+
+    /// Scroll just enough to show the row at `index`
+    fn scroll_into_view(&mut self, index: usize) {
+        let top = index as f32 * ROW_HEIGHT;
+        if top < self.scroll_offset {
+            self.scroll_offset = top;
+        } else if top + ROW_HEIGHT > self.scroll_offset + self.viewport_height {
+            self.scroll_offset = top + ROW_HEIGHT - self.viewport_height;
+        }
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll());
+    }
+
+    fn render_row(&self, row: &TreeRow<'_>, index: usize) -> Node {
+        let node = row.node;
+        let expanded = self.expanded.contains(&node.id);
+        let selected = self.selected.as_deref() == Some(node.id.as_str());
+
+        let mut row_node = Node::default();
+        row_node.set_key(node.id.clone());
+        row_node.add_attribute("role".to_string(), "treeitem".to_string());
+        row_node.add_attribute("aria-level".to_string(), (row.depth + 1).to_string());
+        row_node.add_attribute("aria-selected".to_string(), selected.to_string());
+        row_node.add_attribute("data-id".to_string(), node.id.clone());
+        if node.is_expandable() {
+            row_node.add_attribute("aria-expanded".to_string(), expanded.to_string());
+        }
+        row_node.set_layout_rect(Rect::new(0.0, index as f32 * ROW_HEIGHT, 0.0, ROW_HEIGHT));
+        if selected {
+            row_node.style_mut().background_color =
+                Some(Color::Hex(self.theme.highlight_color.clone()));
+        }
+
+        let indent = row.depth as f32 * INDENT;
+        if node.is_expandable() {
+            let mut chevron = Node::default();
+            chevron.set_layout_rect(Rect::new(
+                indent,
+                (ROW_HEIGHT - CHEVRON_SIZE) / 2.0,
+                CHEVRON_SIZE,
+                CHEVRON_SIZE,
+            ));
+            let path = if expanded {
+                CHEVRON_DOWN
+            } else {
+                CHEVRON_RIGHT
+            };
+            chevron.add_attribute("path".to_string(), path.to_string());
+            chevron.style_mut().color = Some(Color::Hex(self.theme.muted_text_color.clone()));
+            row_node.add_child(chevron);
+        }
+
+        let mut label = Node::default();
+        label.set_layout_rect(Rect::new(
+            indent + CHEVRON_SIZE + 4.0,
+            4.0,
+            0.0,
+            ROW_HEIGHT - 8.0,
+        ));
+        label.set_text(node.label.clone());
+        label.style_mut().color = Some(Color::Hex(self.theme.text_color.clone()));
+        row_node.add_child(label);
+
+        row_node
+    }
+}
+
+impl Component for Tree {
+    type Props = TreeProps;
+
+    fn component_id(&self) -> ComponentId {
+        self.id
+    }
+
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mut tree = Self {
+            theme: Theme::current(&context),
+            roots: props.roots,
+            expanded: props.expanded.unwrap_or_default(),
+            selected: props.selected,
+            on_select: props.on_select,
+            on_toggle: props.on_toggle,
+            ..Default::default()
+        };
+        tree.viewport_height = props.viewport_height.unwrap_or(tree.viewport_height);
+        tree
+    }
+
+    fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
+        self.roots = props.roots;
+        if let Some(expanded) = props.expanded {
+            self.expanded = expanded;
+        }
+        self.selected = props.selected;
+        self.viewport_height = props.viewport_height.unwrap_or(self.viewport_height);
+        self.on_select = props.on_select;
+        self.on_toggle = props.on_toggle;
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn render(&self) -> Result<Vec<Node>, ComponentError> {
+        let rows = self.visible_rows();
+
+        let mut root = Node::default();
+        root.add_attribute("role".to_string(), "tree".to_string());
+        root.set_layout_rect(Rect::new(0.0, 0.0, 0.0, self.viewport_height));
+        root.style_mut().overflow = Some(Overflow::Hidden);
+
+        // Only rows intersecting the viewport become nodes; the content's
+        // height still covers every row so scroll extents stay correct
+        let mut content = Node::default();
+        content.set_layout_rect(Rect::new(
+            0.0,
+            -self.scroll_offset,
+            0.0,
+            rows.len() as f32 * ROW_HEIGHT,
+        ));
+        let first = (self.scroll_offset / ROW_HEIGHT).floor() as usize;
+        let count = (self.viewport_height / ROW_HEIGHT).ceil() as usize + 1;
+        for (index, row) in rows.iter().enumerate().skip(first).take(count) {
+            content.add_child(self.render_row(row, index));
+        }
+        root.add_child(content);
+
+        Ok(vec![root])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Tree {
+        let src = TreeNode::new("src", "src").with_children(vec![
+            TreeNode::new("main", "main.rs"),
+            TreeNode::new("lib", "lib.rs"),
+        ]);
+        let target = TreeNode {
+            has_children: true,
+            ..TreeNode::new("target", "target")
+        };
+        Tree {
+            roots: vec![src, target],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keyboard_navigation() {
+        let mut tree = tree();
+
+        tree.handle_key("ArrowDown");
+        assert_eq!(tree.selected.as_deref(), Some("src"));
+        tree.handle_key("ArrowRight");
+        assert!(tree.expanded.contains("src"));
+        tree.handle_key("ArrowRight");
+        assert_eq!(tree.selected.as_deref(), Some("main"));
+        tree.handle_key("ArrowDown");
+        tree.handle_key("ArrowDown");
+        assert_eq!(tree.selected.as_deref(), Some("target"));
+
+        // Unloaded children still expand, so the app can load them
+        tree.handle_key("ArrowRight");
+        assert!(tree.expanded.contains("target"));
+        tree.handle_key("ArrowUp");
+        tree.handle_key("ArrowLeft");
+        assert_eq!(tree.selected.as_deref(), Some("src"));
+        tree.handle_key("ArrowLeft");
+        assert!(!tree.expanded.contains("src"));
+        assert_eq!(tree.visible_rows().len(), 2);
+    }
+
+    #[test]
+    fn test_renders_only_visible_rows() {
+        let children = (0..1000)
+            .map(|i| TreeNode::new(format!("file{i}"), format!("file{i}.txt")))
+            .collect();
+        let mut tree = Tree {
+            roots: vec![TreeNode::new("root", "root").with_children(children)],
+            viewport_height: 240.0,
+            ..Default::default()
+        };
+        tree.toggle("root");
+        tree.scroll_by(ROW_HEIGHT * 100.0);
+
+        let nodes = tree.render().unwrap();
+        let content = &nodes[0].children()[0];
+        assert_eq!(content.children().len(), 11);
+        let first = &content.children()[0];
+        assert_eq!(first.attributes().get("data-id").unwrap(), "file99");
+        assert_eq!(first.attributes().get("aria-level").unwrap(), "2");
+
+        let chevron_row = &tree.render_row(&tree.visible_rows()[0], 0);
+        assert_eq!(
+            chevron_row.children()[0].attributes().get("path").unwrap(),
+            CHEVRON_DOWN
+        );
+    }
+}
@@ -1,6 +1,8 @@
 // OrbitKit Component Library (now part of orbit)
 
+pub mod appearance;
 pub mod components;
+pub mod responsive;
 pub mod theme;
 pub mod utils;
 
@@ -9,9 +11,44 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION"); // This will now refer to o
 
 /// Re-export of common components for convenience
 pub mod prelude {
-    pub use crate::kit::components::button::Button;
+    pub use crate::kit::appearance::{system_color_scheme, ColorScheme};
+    pub use crate::kit::components::accordion::{Accordion, AccordionItem};
+    pub use crate::kit::components::alert::{Alert, AlertVariant};
+    pub use crate::kit::components::avatar::{Avatar, AvatarGroup, AvatarShape};
+    pub use crate::kit::components::badge::{Badge, BadgeVariant, Chip};
+    pub use crate::kit::components::breadcrumb::{Breadcrumb, BreadcrumbItem, Crumb};
+    pub use crate::kit::components::button::{Button, ButtonSize, ButtonVariant};
     pub use crate::kit::components::card::Card;
-    pub use crate::kit::components::input::Input;
-    pub use crate::kit::components::layout::Layout;
-    pub use crate::kit::theme::{Theme, ThemeProvider};
+    pub use crate::kit::components::checkbox::Checkbox;
+    pub use crate::kit::components::divider::{Divider, DividerOrientation};
+    pub use crate::kit::components::drawer::{Drawer, DrawerSide};
+    pub use crate::kit::components::image::{Image, ImageStatus};
+    pub use crate::kit::components::input::{Input, InputState, Validator};
+    pub use crate::kit::components::layout::{
+        Grid, GridTrack, HStack, Layout, Spacer, VStack, ZStack,
+    };
+    pub use crate::kit::components::list::VirtualList;
+    pub use crate::kit::components::menu::{Menu, MenuItem};
+    pub use crate::kit::components::modal::Modal;
+    pub use crate::kit::components::pagination::{PageItem, Pagination};
+    pub use crate::kit::components::popover::Popover;
+    pub use crate::kit::components::radio::{Radio, RadioGroup};
+    pub use crate::kit::components::rating::Rating;
+    pub use crate::kit::components::scroll::{ScrollDirection, ScrollView};
+    pub use crate::kit::components::select::{MultiSelect, Select};
+    pub use crate::kit::components::skeleton::{Skeleton, SkeletonText, SkeletonVariant};
+    pub use crate::kit::components::slider::Slider;
+    pub use crate::kit::components::stepper::{StepDirection, Stepper};
+    pub use crate::kit::components::switch::Switch;
+    pub use crate::kit::components::table::{Column, SortDirection, Table};
+    pub use crate::kit::components::tabs::{Tab, Tabs};
+    pub use crate::kit::components::textarea::Textarea;
+    pub use crate::kit::components::toast::{Severity, Toast, ToastManager};
+    pub use crate::kit::components::tooltip::{Placement, Tooltip};
+    pub use crate::kit::components::tree::{Tree, TreeNode};
+    pub use crate::kit::responsive::{Breakpoint, Breakpoints, Responsive};
+    pub use crate::kit::theme::{
+        use_theme, RadiusSize, TextStyle, Theme, ThemeMode, ThemeProvider, Typography,
+    };
+    pub use crate::style::BoxShadow;
 }
@@ -0,0 +1,202 @@
+// Responsive breakpoints for OrbitKit
+
+use crate::layout::Size;
+use serde::{Deserialize, Serialize};
+
+/// Viewport width tiers, smallest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Breakpoint {
+    /// Narrower than the `sm` breakpoint, such as a phone held upright
+    #[default]
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Minimum viewport width, in pixels, of each tier above `Xs`
+///
+/// The defaults are 640px for `sm`, 768px for `md`, 1024px for `lg`, and
+/// 1280px for `xl`. A width exactly on a breakpoint belongs to the larger
+/// tier.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Breakpoints {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: 640.0,
+            md: 768.0,
+            lg: 1024.0,
+            xl: 1280.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// The tier a viewport `width` pixels wide falls in
+    pub fn current(&self, width: f32) -> Breakpoint {
+        if width >= self.xl {
+            Breakpoint::Xl
+        } else if width >= self.lg {
+            Breakpoint::Lg
+        } else if width >= self.md {
+            Breakpoint::Md
+        } else if width >= self.sm {
+            Breakpoint::Sm
+        } else {
+            Breakpoint::Xs
+        }
+    }
+
+    /// Whether the breakpoints increase from `sm` to `xl`
+    pub fn is_ascending(&self) -> bool {
+        0.0 <= self.sm && self.sm <= self.md && self.md <= self.lg && self.lg <= self.xl
+    }
+}
+
+/// A value that changes with the viewport width
+///
+/// Overrides apply mobile-first: each one holds from its breakpoint upwards
+/// until a larger one takes over, and `base` covers widths below the
+/// smallest override. There is no `xl` override; `lg` carries on past `xl`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Responsive<T> {
+    pub base: T,
+    pub sm: Option<T>,
+    pub md: Option<T>,
+    pub lg: Option<T>,
+}
+
+impl<T> Responsive<T> {
+    /// A value that is `base` at every width until overridden
+    pub fn new(base: T) -> Self {
+        Self {
+            base,
+            sm: None,
+            md: None,
+            lg: None,
+        }
+    }
+
+    /// Override the value from the `sm` breakpoint up
+    pub fn sm(mut self, value: T) -> Self {
+        self.sm = Some(value);
+        self
+    }
+
+    /// Override the value from the `md` breakpoint up
+    pub fn md(mut self, value: T) -> Self {
+        self.md = Some(value);
+        self
+    }
+
+    /// Override the value from the `lg` breakpoint up
+    pub fn lg(mut self, value: T) -> Self {
+        self.lg = Some(value);
+        self
+    }
+
+    /// The value for a `breakpoint` tier
+    pub fn at(&self, breakpoint: Breakpoint) -> &T {
+        let tiers = [
+            (Breakpoint::Lg, &self.lg),
+            (Breakpoint::Md, &self.md),
+            (Breakpoint::Sm, &self.sm),
+        ];
+        tiers
+            .into_iter()
+            .filter(|(tier, _)| *tier <= breakpoint)
+            .find_map(|(_, value)| value.as_ref())
+            .unwrap_or(&self.base)
+    }
+
+    /// The value for a viewport `width` pixels wide
+    pub fn resolve(&self, width: f32, breakpoints: &Breakpoints) -> &T {
+        self.at(breakpoints.current(width))
+    }
+
+    /// The value for a layout container of `size`, for resolving styles
+    /// right before they go to the layout engine
+    pub fn resolve_for(&self, size: Size, breakpoints: &Breakpoints) -> &T {
+        self.resolve(size.width, breakpoints)
+    }
+}
+
+impl<T> From<T> for Responsive<T> {
+    fn from(base: T) -> Self {
+        Self::new(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::ComponentId;
+    use crate::layout::{Dimension, FlexDirection, LayoutEngine, LayoutNode, LayoutStyle};
+
+    #[test]
+    fn test_current_breakpoint_boundaries() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.current(0.0), Breakpoint::Xs);
+        assert_eq!(breakpoints.current(639.9), Breakpoint::Xs);
+        assert_eq!(breakpoints.current(640.0), Breakpoint::Sm);
+        assert_eq!(breakpoints.current(800.0), Breakpoint::Md);
+        assert_eq!(breakpoints.current(1024.0), Breakpoint::Lg);
+        assert_eq!(breakpoints.current(4000.0), Breakpoint::Xl);
+        assert!(breakpoints.is_ascending());
+    }
+
+    #[test]
+    fn test_responsive_falls_back_to_smaller_tiers() {
+        let columns = Responsive::new(1).md(2).lg(4);
+        let breakpoints = Breakpoints::default();
+        assert_eq!(*columns.resolve(320.0, &breakpoints), 1);
+        assert_eq!(*columns.resolve(700.0, &breakpoints), 1);
+        assert_eq!(*columns.resolve(900.0, &breakpoints), 2);
+        assert_eq!(*columns.resolve(2000.0, &breakpoints), 4);
+        assert_eq!(*Responsive::from("only").at(Breakpoint::Xl), "only");
+    }
+
+    #[test]
+    fn test_layout_stacks_on_narrow_viewports() {
+        let direction = Responsive::new(FlexDirection::Column).md(FlexDirection::Row);
+        let breakpoints = Breakpoints::default();
+
+        let second_child_origin = |width: f32| {
+            let size = Size::new(width, 400.0);
+            let mut root = LayoutNode::new(
+                ComponentId::new(),
+                LayoutStyle {
+                    flex_direction: *direction.resolve_for(size, &breakpoints),
+                    width: Dimension::Points(width),
+                    height: Dimension::Points(400.0),
+                    ..Default::default()
+                },
+            );
+            for _ in 0..2 {
+                let child = LayoutStyle {
+                    width: Dimension::Points(100.0),
+                    height: Dimension::Points(50.0),
+                    ..Default::default()
+                };
+                root.add_child(LayoutNode::new(ComponentId::new(), child));
+            }
+            LayoutEngine::new()
+                .calculate_layout(&mut root, size)
+                .unwrap();
+            let rect = root.children[1].layout.rect;
+            (rect.x(), rect.y())
+        };
+
+        assert_eq!(second_child_origin(400.0), (0.0, 50.0));
+        assert_eq!(second_child_origin(1000.0), (100.0, 0.0));
+    }
+}
@@ -1,10 +1,192 @@
 // Theme support for OrbitKit
 
 use crate::component::{Component, ComponentError, ComponentId, Context, Node};
+use crate::kit::appearance::{system_color_scheme, ColorScheme};
+use crate::kit::responsive::{Breakpoint, Breakpoints};
+use crate::kit::utils::color;
+use crate::style::{Color, FontWeight, Outline, Style};
+use serde::{Deserialize, Serialize};
 use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+/// Errors from loading or saving a theme
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    #[error("Invalid theme JSON: {0}")]
+    Json(String),
+
+    #[error("Invalid theme TOML: {0}")]
+    Toml(String),
+
+    #[error("Invalid color for `{field}`: {value:?} is not a hex, rgb(), or hsl() color")]
+    InvalidColor { field: &'static str, value: String },
+
+    #[error("Invalid base unit {0}: must be a positive number of pixels")]
+    InvalidBaseUnit(f32),
+
+    #[error("Invalid type scale ratio {0}: must be a positive number")]
+    InvalidScaleRatio(f32),
+
+    #[error("Invalid breakpoints: sm, md, lg, and xl must be non-negative and ascending")]
+    InvalidBreakpoints,
+}
+
+/// Multiples of the base unit for each spacing step
+const SPACING_SCALE: [f32; 11] = [0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0, 24.0, 32.0];
+
+/// Lightness change from a base color to its hover shade; the pressed
+/// shade moves twice as far
+const STATE_SHADE: f32 = 0.08;
+
+/// Radius large enough to round any control into a pill or circle;
+/// renderers shrink radii that don't fit the shape
+const FULL_RADIUS: f32 = 9999.0;
+
+/// Gap between a focused control and its focus ring, so the ring reads
+/// against the control's own border
+const FOCUS_RING_OFFSET: f32 = 2.0;
+
+/// Named corner radii on a theme's radius scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadiusSize {
+    None,
+    Sm,
+    #[default]
+    Md,
+    Lg,
+    Full,
+}
+
+/// Font settings for one named style on a theme's type scale
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TextStyle {
+    /// Font size in pixels
+    pub font_size: f32,
+    /// CSS font weight, from 100 to 900
+    pub font_weight: u16,
+    /// Line height as a multiple of the font size
+    pub line_height: f32,
+    /// Extra space between letters in pixels
+    pub letter_spacing: f32,
+}
+
+impl TextStyle {
+    const fn new(font_size: f32, font_weight: u16, line_height: f32, letter_spacing: f32) -> Self {
+        Self {
+            font_size,
+            font_weight,
+            line_height,
+            letter_spacing,
+        }
+    }
+
+    /// The weight as a style value, using the named weights where one fits
+    pub fn weight(&self) -> FontWeight {
+        match self.font_weight {
+            100 => FontWeight::Thin,
+            200 => FontWeight::ExtraLight,
+            300 => FontWeight::Light,
+            400 => FontWeight::Normal,
+            500 => FontWeight::Medium,
+            600 => FontWeight::SemiBold,
+            700 => FontWeight::Bold,
+            800 => FontWeight::ExtraBold,
+            900 => FontWeight::Black,
+            weight => FontWeight::Numeric(weight),
+        }
+    }
+
+    /// Line height in pixels
+    pub fn line_height_px(&self) -> f32 {
+        self.font_size * self.line_height
+    }
+
+    /// Set the font properties of `style` from this text style
+    pub fn apply(&self, style: &mut Style) {
+        style.font_size = Some(self.font_size);
+        style.font_weight = Some(self.weight());
+        style.line_height = Some(self.line_height);
+        style.letter_spacing = Some(self.letter_spacing);
+    }
+}
+
+/// A theme's type scale: named text styles and a ratio that resizes all of
+/// them together
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Typography {
+    pub h1: TextStyle,
+    pub h2: TextStyle,
+    pub h3: TextStyle,
+    pub h4: TextStyle,
+    pub h5: TextStyle,
+    pub h6: TextStyle,
+    /// Running text, and the fallback for unknown style names
+    pub body: TextStyle,
+    /// Labels, hints, and other small print
+    pub caption: TextStyle,
+    /// Labels on buttons and other controls
+    pub button: TextStyle,
+    /// Factor every size on the scale is multiplied by
+    pub scale_ratio: f32,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            h1: TextStyle::new(32.0, 700, 1.2, -0.5),
+            h2: TextStyle::new(28.0, 700, 1.25, -0.25),
+            h3: TextStyle::new(24.0, 600, 1.3, 0.0),
+            h4: TextStyle::new(20.0, 600, 1.35, 0.0),
+            h5: TextStyle::new(18.0, 600, 1.4, 0.0),
+            h6: TextStyle::new(16.0, 600, 1.4, 0.15),
+            body: TextStyle::new(14.0, 400, 1.5, 0.0),
+            caption: TextStyle::new(12.0, 400, 1.4, 0.4),
+            button: TextStyle::new(14.0, 500, 1.2, 0.25),
+            scale_ratio: 1.0,
+        }
+    }
+}
+
+impl Typography {
+    /// The style called `name`, scaled by `scale_ratio`
+    ///
+    /// Unknown names get the body style, so a typo degrades to running text
+    /// rather than failing a render.
+    pub fn style(&self, name: &str) -> TextStyle {
+        let style = match name {
+            "h1" => self.h1,
+            "h2" => self.h2,
+            "h3" => self.h3,
+            "h4" => self.h4,
+            "h5" => self.h5,
+            "h6" => self.h6,
+            "caption" => self.caption,
+            "button" => self.button,
+            _ => self.body,
+        };
+        TextStyle {
+            font_size: style.font_size * self.scale_ratio,
+            letter_spacing: style.letter_spacing * self.scale_ratio,
+            ..style
+        }
+    }
+}
+
+/// Whether `value` is `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa`
+fn is_hex_color(value: &str) -> bool {
+    value.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
 
 /// Theme for OrbitKit
-#[derive(Debug, Clone)]
+///
+/// Themes serialize to JSON and TOML with the field names below. Missing
+/// fields take their value from the light preset and unknown ones are
+/// ignored, so theme files only need to list what they change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Theme {
     /// Primary color
     pub primary_color: String,
@@ -22,16 +204,46 @@ pub struct Theme {
     pub warning_color: String,
     /// Info color
     pub info_color: String,
+    /// Color of raised surfaces such as cards, popups, and inputs
+    pub surface_color: String,
+    /// Color of outlines and dividers
+    pub border_color: String,
+    /// Color of disabled controls
+    pub disabled_color: String,
+    /// Color of secondary text such as placeholders and hints
+    pub muted_text_color: String,
+    /// Background of selected and highlighted rows
+    pub highlight_color: String,
+    /// Outline color of the focused control
+    pub focus_ring_color: String,
+    /// Outline width of the focused control
+    pub focus_ring_width: f32,
     /// Border radius
     pub border_radius: String,
+    /// Pixels per spacing step; every spacing and radius token scales with it
+    pub base_unit: f32,
     /// Font family
     pub font_family: String,
     /// Font size
     pub font_size: String,
+    /// Named text styles for headings, body text, and control labels
+    pub typography: Typography,
+    /// Viewport widths where responsive values switch tiers
+    pub breakpoints: Breakpoints,
+    /// Whether components should skip non-essential animation, like the
+    /// `prefers-reduced-motion` media query
+    pub reduced_motion: bool,
 }
 
 impl Default for Theme {
     fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Theme {
+    /// The light preset, which is also the default theme
+    pub fn light() -> Self {
         Self {
             primary_color: "#0070f3".to_string(),
             secondary_color: "#f5f5f5".to_string(),
@@ -41,11 +253,372 @@ impl Default for Theme {
             success_color: "#00cc00".to_string(),
             warning_color: "#ffcc00".to_string(),
             info_color: "#0088cc".to_string(),
+            surface_color: "#ffffff".to_string(),
+            border_color: "#d0d0d0".to_string(),
+            disabled_color: "#c0c0c0".to_string(),
+            muted_text_color: "#666666".to_string(),
+            highlight_color: "#e6f0fe".to_string(),
+            focus_ring_color: "#0070f3".to_string(),
+            focus_ring_width: 2.0,
             border_radius: "4px".to_string(),
+            base_unit: 4.0,
             font_family: "Arial, sans-serif".to_string(),
             font_size: "16px".to_string(),
+            typography: Typography::default(),
+            breakpoints: Breakpoints::default(),
+            reduced_motion: false,
         }
     }
+
+    /// The dark preset
+    pub fn dark() -> Self {
+        Self {
+            primary_color: "#3291ff".to_string(),
+            secondary_color: "#2a2a2a".to_string(),
+            text_color: "#ededed".to_string(),
+            background_color: "#121212".to_string(),
+            error_color: "#ff5555".to_string(),
+            success_color: "#33dd66".to_string(),
+            warning_color: "#ffd54f".to_string(),
+            info_color: "#4fc3f7".to_string(),
+            surface_color: "#1e1e1e".to_string(),
+            border_color: "#3a3a3a".to_string(),
+            disabled_color: "#555555".to_string(),
+            muted_text_color: "#a0a0a0".to_string(),
+            highlight_color: "#1a3354".to_string(),
+            focus_ring_color: "#3291ff".to_string(),
+            ..Self::light()
+        }
+    }
+
+    /// The light or dark preset, whichever matches the operating system's
+    /// appearance
+    ///
+    /// Falls back to light where the preference can't be detected, including
+    /// in builds without the `system-theme` feature; see
+    /// [`crate::kit::appearance`] for where each platform's preference is read.
+    pub fn from_system() -> Self {
+        if system_color_scheme().is_some_and(ColorScheme::is_dark) {
+            Self::dark()
+        } else {
+            Self::light()
+        }
+    }
+
+    /// The high-contrast preset: white on black, saturated accents, and a
+    /// thick focus ring, with no low-contrast grays
+    pub fn high_contrast() -> Self {
+        Self {
+            primary_color: "#ffff00".to_string(),
+            secondary_color: "#000000".to_string(),
+            text_color: "#ffffff".to_string(),
+            background_color: "#000000".to_string(),
+            error_color: "#ff8080".to_string(),
+            success_color: "#00ff00".to_string(),
+            warning_color: "#ffff00".to_string(),
+            info_color: "#00ffff".to_string(),
+            surface_color: "#000000".to_string(),
+            border_color: "#ffffff".to_string(),
+            disabled_color: "#3ff23f".to_string(),
+            muted_text_color: "#ffffff".to_string(),
+            highlight_color: "#000080".to_string(),
+            focus_ring_color: "#00ffff".to_string(),
+            focus_ring_width: 4.0,
+            ..Self::light()
+        }
+    }
+
+    /// Whether this theme has a dark background
+    ///
+    /// Judged from the background's luminance, so it also works for custom
+    /// palettes built on neither preset.
+    pub fn is_dark(&self) -> bool {
+        let hex = self.background_color.trim_start_matches('#');
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .map_or(0.0, |c| c as f32 / 255.0)
+        };
+        if hex.len() < 6 {
+            return false;
+        }
+        0.2126 * channel(0) + 0.7152 * channel(2) + 0.0722 * channel(4) < 0.5
+    }
+
+    /// The opposite preset's colors, keeping every other token of this theme
+    pub fn toggle(&self) -> Self {
+        let palette = if self.is_dark() {
+            Self::light()
+        } else {
+            Self::dark()
+        };
+        Self {
+            primary_color: palette.primary_color,
+            secondary_color: palette.secondary_color,
+            text_color: palette.text_color,
+            background_color: palette.background_color,
+            error_color: palette.error_color,
+            success_color: palette.success_color,
+            warning_color: palette.warning_color,
+            info_color: palette.info_color,
+            surface_color: palette.surface_color,
+            border_color: palette.border_color,
+            disabled_color: palette.disabled_color,
+            muted_text_color: palette.muted_text_color,
+            highlight_color: palette.highlight_color,
+            focus_ring_color: palette.focus_ring_color,
+            ..self.clone()
+        }
+    }
+
+    /// Space for `step` on the spacing scale
+    ///
+    /// With the default 4px base unit the steps are 0, 4, 8, 12, 16, 24, 32,
+    /// 48, 64, 96, and 128px; larger steps stay at the top of the scale.
+    pub fn spacing(&self, step: u8) -> f32 {
+        let index = (step as usize).min(SPACING_SCALE.len() - 1);
+        SPACING_SCALE[index] * self.base_unit
+    }
+
+    /// Corner radius for `size`, scaled with the base unit
+    pub fn radius(&self, size: RadiusSize) -> f32 {
+        match size {
+            RadiusSize::None => 0.0,
+            RadiusSize::Sm => self.base_unit / 2.0,
+            RadiusSize::Md => self.base_unit,
+            RadiusSize::Lg => self.base_unit * 2.0,
+            RadiusSize::Full => FULL_RADIUS,
+        }
+    }
+
+    /// This theme with spacing and radii rescaled to a new base unit
+    pub fn with_base_unit(mut self, px: f32) -> Self {
+        self.base_unit = px;
+        self.border_radius = format!("{}px", self.radius(RadiusSize::Md));
+        self
+    }
+
+    /// `fg`, or the closest color to it that has at least `ratio` WCAG
+    /// contrast against `bg`
+    ///
+    /// The color is moved towards black or white, whichever contrasts more
+    /// with `bg`; if even that falls short, black or white is returned.
+    /// Colors that aren't hex are returned unchanged.
+    pub fn ensure_contrast_ratio(fg: &str, bg: &str, ratio: f32) -> String {
+        let Some(current) = color::contrast_ratio(fg, bg) else {
+            return fg.to_string();
+        };
+        if current >= ratio {
+            return fg.to_string();
+        }
+        let black = color::contrast_ratio("#000000", bg).unwrap_or(0.0);
+        let white = color::contrast_ratio("#ffffff", bg).unwrap_or(0.0);
+        let target = if black >= white { "#000000" } else { "#ffffff" };
+
+        // Contrast grows monotonically along the mix, so bisect for the
+        // smallest change that passes
+        let passes = |t: f32| {
+            color::mix(fg, target, t)
+                .and_then(|mixed| color::contrast_ratio(&mixed, bg))
+                .is_some_and(|contrast| contrast >= ratio)
+        };
+        if !passes(1.0) {
+            return target.to_string();
+        }
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..16 {
+            let mid = (low + high) / 2.0;
+            if passes(mid) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+        color::mix(fg, target, high).unwrap_or_else(|| target.to_string())
+    }
+
+    /// `base` as it looks under the pointer: darker on light themes and
+    /// lighter on dark ones, so it stands out from the background either way
+    pub fn hover_color(&self, base: &str) -> String {
+        self.shade(base, STATE_SHADE)
+    }
+
+    /// `base` as it looks while pressed, a step past the hover shade
+    pub fn active_color(&self, base: &str) -> String {
+        self.shade(base, 2.0 * STATE_SHADE)
+    }
+
+    fn shade(&self, base: &str, amount: f32) -> String {
+        if self.is_dark() {
+            color::lighten(base, amount)
+        } else {
+            color::darken(base, amount)
+        }
+    }
+
+    /// The outline drawn around whichever control has keyboard focus
+    pub fn focus_ring(&self) -> Outline {
+        Outline {
+            width: self.focus_ring_width,
+            color: Color::Hex(self.focus_ring_color.clone()),
+            offset: FOCUS_RING_OFFSET,
+        }
+    }
+
+    /// Outline `style` as the focused control, leaving its own border alone
+    pub fn apply_focus_ring(&self, style: &mut Style) {
+        style.outline = Some(self.focus_ring());
+    }
+
+    /// The text style called `name` on this theme's type scale
+    pub fn text_style(&self, name: &str) -> TextStyle {
+        self.typography.style(name)
+    }
+
+    /// The breakpoint tier a viewport `width` pixels wide falls in
+    pub fn current_breakpoint(&self, width: f32) -> Breakpoint {
+        self.breakpoints.current(width)
+    }
+
+    /// Parse a theme from JSON
+    ///
+    /// Colors may be written in any form [`color::parse_color`] accepts; they
+    /// are stored as hex.
+    pub fn from_json(s: &str) -> Result<Self, ThemeError> {
+        let mut theme: Self =
+            serde_json::from_str(s).map_err(|e| ThemeError::Json(e.to_string()))?;
+        theme.normalize_colors();
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Parse a theme from TOML, accepting the same color forms as JSON
+    pub fn from_toml(s: &str) -> Result<Self, ThemeError> {
+        let mut theme: Self = toml::from_str(s).map_err(|e| ThemeError::Toml(e.to_string()))?;
+        theme.normalize_colors();
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Rewrite every parseable palette color as hex, leaving the rest for
+    /// `validate` to report
+    fn normalize_colors(&mut self) {
+        let palette = [
+            &mut self.primary_color,
+            &mut self.secondary_color,
+            &mut self.text_color,
+            &mut self.background_color,
+            &mut self.error_color,
+            &mut self.success_color,
+            &mut self.warning_color,
+            &mut self.info_color,
+            &mut self.surface_color,
+            &mut self.border_color,
+            &mut self.disabled_color,
+            &mut self.muted_text_color,
+            &mut self.highlight_color,
+            &mut self.focus_ring_color,
+        ];
+        for value in palette {
+            if let Ok(parsed) = color::parse_color(value) {
+                *value = color::to_hex(&parsed);
+            }
+        }
+    }
+
+    /// Serialize the theme as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, ThemeError> {
+        serde_json::to_string_pretty(self).map_err(|e| ThemeError::Json(e.to_string()))
+    }
+
+    /// Serialize the theme as TOML
+    pub fn to_toml(&self) -> Result<String, ThemeError> {
+        toml::to_string_pretty(self).map_err(|e| ThemeError::Toml(e.to_string()))
+    }
+
+    /// Check that every palette entry is a hex color
+    pub fn validate(&self) -> Result<(), ThemeError> {
+        let colors = [
+            ("primary_color", &self.primary_color),
+            ("secondary_color", &self.secondary_color),
+            ("text_color", &self.text_color),
+            ("background_color", &self.background_color),
+            ("error_color", &self.error_color),
+            ("success_color", &self.success_color),
+            ("warning_color", &self.warning_color),
+            ("info_color", &self.info_color),
+            ("surface_color", &self.surface_color),
+            ("border_color", &self.border_color),
+            ("disabled_color", &self.disabled_color),
+            ("muted_text_color", &self.muted_text_color),
+            ("highlight_color", &self.highlight_color),
+            ("focus_ring_color", &self.focus_ring_color),
+        ];
+        if let Some((field, value)) = colors.into_iter().find(|(_, value)| !is_hex_color(value)) {
+            return Err(ThemeError::InvalidColor {
+                field,
+                value: value.clone(),
+            });
+        }
+        if !(self.base_unit.is_finite() && self.base_unit > 0.0) {
+            return Err(ThemeError::InvalidBaseUnit(self.base_unit));
+        }
+        let ratio = self.typography.scale_ratio;
+        if !(ratio.is_finite() && ratio > 0.0) {
+            return Err(ThemeError::InvalidScaleRatio(ratio));
+        }
+        if !self.breakpoints.is_ascending() {
+            return Err(ThemeError::InvalidBreakpoints);
+        }
+        Ok(())
+    }
+
+    /// The theme provided by the nearest `ThemeProvider` in `context`, or
+    /// the default theme outside of one
+    ///
+    /// This reads the theme once; components that should re-render when it
+    /// changes read it through [`use_theme`] instead.
+    pub fn current(context: &Context) -> Self {
+        context
+            .context_provider()
+            .consume::<Theme>()
+            .unwrap_or_default()
+    }
+}
+
+/// Read the current theme for the component `component_id`, and subscribe
+/// it to changes
+///
+/// Works from any context descended from a `ThemeProvider`'s, however deep;
+/// when the provider's theme changes, an update is scheduled for every
+/// component that read it this way. Outside of a provider this returns the
+/// default theme and subscribes to nothing.
+pub fn use_theme(context: &Context, component_id: ComponentId) -> Theme {
+    if let Some(ThemeConsumers(consumers)) = context.context_provider().consume() {
+        if let Ok(mut consumers) = consumers.lock() {
+            if !consumers.contains(&component_id) {
+                consumers.push(component_id);
+            }
+        }
+    }
+    Theme::current(context)
+}
+
+/// Components subscribed to a provider's theme through [`use_theme`]
+#[derive(Debug, Clone, Default)]
+struct ThemeConsumers(Arc<Mutex<Vec<ComponentId>>>);
+
+/// Whether a `ThemeProvider` follows the operating system's appearance
+///
+/// Serializable so apps can persist the choice from a theme picker, with
+/// `Auto` as its "System" option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeMode {
+    /// Keep whatever theme was set
+    #[default]
+    Manual,
+    /// Switch between light and dark with the system preference
+    Auto,
 }
 
 /// Theme provider component
@@ -57,6 +630,14 @@ pub struct ThemeProvider {
     pub theme: Theme,
     /// Child content
     pub children: Option<String>,
+    /// Context the theme is provided through
+    context: Context,
+    /// Components to update when the theme changes
+    consumers: ThemeConsumers,
+    /// Whether the theme follows the system preference
+    mode: ThemeMode,
+    /// Last detected system preference
+    system_scheme: Option<ColorScheme>,
 }
 
 /// Theme provider props
@@ -66,6 +647,83 @@ pub struct ThemeProviderProps {
     pub theme: Option<Theme>,
     /// Child content
     pub children: Option<String>,
+    /// Whether to follow the system preference
+    pub mode: Option<ThemeMode>,
+}
+
+impl ThemeProvider {
+    /// Switch to a new theme at runtime
+    ///
+    /// The theme replaces the one in context, so `Theme::current` returns it
+    /// from then on, and an update is scheduled for the provider and every
+    /// component subscribed through [`use_theme`], so they re-render with
+    /// the new colors.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.provide();
+    }
+
+    /// Switch between the light and dark presets
+    pub fn toggle_theme(&mut self) {
+        self.set_theme(self.theme.toggle());
+    }
+
+    /// Whether the theme follows the system preference
+    pub fn mode(&self) -> ThemeMode {
+        self.mode
+    }
+
+    /// Start or stop following the system preference
+    ///
+    /// Switching to `Auto` detects the preference and matches it at once.
+    pub fn set_mode(&mut self, mode: ThemeMode) {
+        self.mode = mode;
+        if mode == ThemeMode::Auto {
+            self.refresh_system_scheme();
+        }
+    }
+
+    /// The system preference as last detected, for showing alongside a
+    /// "System" option; `None` if it couldn't be, or hasn't been, detected
+    pub fn system_scheme(&self) -> Option<ColorScheme> {
+        self.system_scheme
+    }
+
+    /// Detect the system preference again, as when the app regains focus
+    pub fn refresh_system_scheme(&mut self) {
+        self.set_system_scheme(system_color_scheme());
+    }
+
+    /// Record a change in the system preference, as reported by a window's
+    /// theme change event
+    ///
+    /// The desktop adapter calls this for the provider given to its
+    /// `set_theme_provider`; on other platforms the app calls it when it
+    /// learns of a change.
+    ///
+    /// In `Auto` mode the theme switches to the matching preset's colors,
+    /// keeping its other tokens, with light standing in for an unknown
+    /// preference. A theme set explicitly in the meantime is switched the
+    /// same way the next time the preference changes.
+    pub fn set_system_scheme(&mut self, scheme: Option<ColorScheme>) {
+        self.system_scheme = scheme;
+        let dark = scheme.is_some_and(ColorScheme::is_dark);
+        if self.mode == ThemeMode::Auto && self.theme.is_dark() != dark {
+            self.toggle_theme();
+        }
+    }
+
+    fn provide(&self) {
+        // Providing only fails on a poisoned lock, where there is no
+        // meaningful recovery; descendants keep the previous theme
+        let _ = self.context.context_provider().provide(self.theme.clone());
+        self.context.schedule_update(self.id);
+        if let Ok(consumers) = self.consumers.0.lock() {
+            for &consumer in consumers.iter() {
+                self.context.schedule_update(consumer);
+            }
+        }
+    }
 }
 
 impl Component for ThemeProvider {
@@ -75,16 +733,46 @@ impl Component for ThemeProvider {
         self.id
     }
 
-    fn create(props: Self::Props, _context: Context) -> Self {
-        Self {
+    fn create(props: Self::Props, context: Context) -> Self {
+        let mode = props.mode.unwrap_or_default();
+        let system_scheme = match mode {
+            ThemeMode::Auto => system_color_scheme(),
+            ThemeMode::Manual => None,
+        };
+        let theme = match (props.theme, mode) {
+            (Some(theme), _) => theme,
+            (None, ThemeMode::Auto) => Theme::from_system(),
+            (None, ThemeMode::Manual) => Theme::default(),
+        };
+        let mut provider = Self {
             id: ComponentId::new(),
-            theme: props.theme.unwrap_or_default(),
+            theme,
             children: props.children,
+            context,
+            consumers: ThemeConsumers::default(),
+            mode,
+            system_scheme,
+        };
+        // A theme given in auto mode still has its colors matched to the
+        // system's, before anything reads it
+        if mode == ThemeMode::Auto
+            && provider.theme.is_dark() != system_scheme.is_some_and(ColorScheme::is_dark)
+        {
+            provider.theme = provider.theme.toggle();
         }
+        let provided = provider.context.context_provider();
+        let _ = provided.provide(provider.theme.clone());
+        let _ = provided.provide(provider.consumers.clone());
+        provider
     }
 
     fn update(&mut self, props: Self::Props) -> Result<(), ComponentError> {
-        self.theme = props.theme.unwrap_or_else(|| self.theme.clone());
+        if let Some(theme) = props.theme {
+            self.set_theme(theme);
+        }
+        if let Some(mode) = props.mode {
+            self.set_mode(mode);
+        }
         self.children = props.children;
         Ok(())
     }
@@ -103,3 +791,227 @@ impl Component for ThemeProvider {
         Ok(vec![])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_and_toggle() {
+        assert!(!Theme::light().is_dark());
+        assert!(Theme::dark().is_dark());
+
+        let custom = Theme {
+            font_family: "Inter".to_string(),
+            ..Theme::light()
+        };
+        let toggled = custom.toggle();
+        assert_eq!(toggled.background_color, Theme::dark().background_color);
+        assert_eq!(toggled.font_family, "Inter");
+        assert_eq!(toggled.toggle().background_color, "#ffffff");
+    }
+
+    #[test]
+    fn test_contrast_ratios_and_high_contrast_preset() {
+        let ratio = |fg, bg| color::contrast_ratio(fg, bg).unwrap();
+        assert!((ratio("#000000", "#ffffff") - 21.0).abs() < 0.01);
+        assert!((ratio("#777777", "#ffffff") - 4.48).abs() < 0.01);
+        assert!((ratio("#767676", "#ffffff") - 4.54).abs() < 0.01);
+        assert_eq!(ratio("#abc", "#aabbcc"), 1.0);
+
+        for theme in [Theme::light(), Theme::dark(), Theme::high_contrast()] {
+            for fg in [&theme.text_color, &theme.muted_text_color] {
+                assert!(color::meets_aa(fg, &theme.background_color), "{fg}");
+            }
+        }
+        let high = Theme::high_contrast();
+        assert!(high.is_dark());
+        assert!(ratio(&high.text_color, &high.background_color) >= 21.0 - 0.01);
+        assert!(high.focus_ring_width > Theme::light().focus_ring_width);
+
+        let adjusted = Theme::ensure_contrast_ratio("#999999", "#ffffff", 4.5);
+        assert!(ratio(&adjusted, "#ffffff") >= 4.5);
+        assert!(ratio(&adjusted, "#ffffff") < 4.7);
+        assert_eq!(
+            Theme::ensure_contrast_ratio("#333333", "#ffffff", 4.5),
+            "#333333"
+        );
+        assert_eq!(
+            Theme::ensure_contrast_ratio("#777777", "#777777", 30.0),
+            "#000000"
+        );
+    }
+
+    #[test]
+    fn test_spacing_and_radius_scale_with_base_unit() {
+        let theme = Theme::default();
+        assert_eq!(theme.spacing(0), 0.0);
+        assert_eq!(theme.spacing(4), 16.0);
+        assert_eq!(theme.spacing(5), 24.0);
+        assert_eq!(theme.spacing(200), theme.spacing(10));
+        assert_eq!(theme.radius(RadiusSize::Md), 4.0);
+
+        let large = theme.with_base_unit(8.0);
+        assert_eq!(large.spacing(4), 32.0);
+        assert_eq!(large.radius(RadiusSize::Lg), 16.0);
+        assert_eq!(large.border_radius, "8px");
+        assert_eq!(large.radius(RadiusSize::Full), FULL_RADIUS);
+    }
+
+    #[test]
+    fn test_type_scale() {
+        let mut theme = Theme::default();
+        let sizes: Vec<f32> = ["h1", "h2", "h3", "h4", "h5", "h6", "body"]
+            .iter()
+            .map(|name| theme.text_style(name).font_size)
+            .collect();
+        assert!(sizes.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(theme.text_style("h1").font_size > theme.text_style("body").font_size);
+        assert_eq!(theme.text_style("nonsense"), theme.text_style("body"));
+
+        theme.typography.scale_ratio = 1.5;
+        assert_eq!(theme.text_style("body").font_size, 21.0);
+        assert_eq!(theme.text_style("h1").weight(), FontWeight::Bold);
+        assert!(theme.toggle().typography == theme.typography);
+    }
+
+    #[test]
+    fn test_round_trip_custom_theme() {
+        let custom = Theme {
+            primary_color: "#ff6600".to_string(),
+            font_family: "Inter, sans-serif".to_string(),
+            reduced_motion: true,
+            ..Theme::dark()
+        };
+
+        let from_json = Theme::from_json(&custom.to_json().unwrap()).unwrap();
+        let from_toml = Theme::from_toml(&custom.to_toml().unwrap()).unwrap();
+        for theme in [from_json, from_toml] {
+            assert_eq!(theme.primary_color, "#ff6600");
+            assert_eq!(theme.font_family, "Inter, sans-serif");
+            assert_eq!(theme.background_color, Theme::dark().background_color);
+            assert!(theme.reduced_motion);
+        }
+
+        let scaled = Theme::from_toml("[typography]\nscale_ratio = 1.25\n").unwrap();
+        assert_eq!(scaled.text_style("body").font_size, 17.5);
+        assert_eq!(scaled.typography.h1, Typography::default().h1);
+        assert!(matches!(
+            Theme::from_json(r#"{"typography": {"scale_ratio": 0}}"#),
+            Err(ThemeError::InvalidScaleRatio(_))
+        ));
+        assert!(matches!(
+            Theme::from_toml("[breakpoints]\nmd = 2000\n"),
+            Err(ThemeError::InvalidBreakpoints)
+        ));
+    }
+
+    #[test]
+    fn test_partial_and_invalid_input() {
+        let theme = Theme::from_toml("primary_color = \"#123456\"\nunknown = 1\n").unwrap();
+        assert_eq!(theme.primary_color, "#123456");
+        assert_eq!(theme.text_color, Theme::light().text_color);
+
+        let theme =
+            Theme::from_json(r##"{"primary_color": "rgb(255, 0, 0)", "text_color": "#abc"}"##)
+                .unwrap();
+        assert_eq!(theme.primary_color, "#ff0000");
+        assert_eq!(theme.text_color, "#aabbcc");
+
+        let err = Theme::from_json(r#"{"text_color": "blue"}"#).unwrap_err();
+        assert!(matches!(
+            err,
+            ThemeError::InvalidColor {
+                field: "text_color",
+                ..
+            }
+        ));
+        assert!(matches!(
+            Theme::from_json("{\"primary_color\": "),
+            Err(ThemeError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_theme_updates_context() {
+        let context = Context::new();
+        assert!(!Theme::current(&context).is_dark());
+
+        let mut provider = ThemeProvider::create(
+            ThemeProviderProps {
+                theme: None,
+                children: None,
+                mode: None,
+            },
+            context.clone(),
+        );
+        provider.toggle_theme();
+        assert!(Theme::current(&context).is_dark());
+        assert!(context.has_pending_update(provider.component_id()));
+    }
+
+    #[test]
+    fn test_nested_consumer_sees_updated_theme() {
+        let context = Context::new();
+        let mut provider = ThemeProvider::create(
+            ThemeProviderProps {
+                theme: None,
+                children: None,
+                mode: None,
+            },
+            context.clone(),
+        );
+
+        // A component several levels down, with nothing between it and the
+        // provider handing the theme along
+        let nested = context.child().child().child();
+        let consumer = ComponentId::new();
+        assert!(!use_theme(&nested, consumer).is_dark());
+        assert!(!nested.has_pending_update(consumer));
+
+        provider.set_theme(Theme::dark());
+        assert!(use_theme(&nested, consumer).is_dark());
+        assert!(nested.has_pending_update(consumer));
+
+        // Outside of any provider there's nothing to subscribe to
+        let outside = Context::new().child();
+        assert!(!use_theme(&outside, consumer).is_dark());
+    }
+
+    #[test]
+    fn test_auto_mode_follows_system_scheme() {
+        // Without the `system-theme` feature nothing is detected
+        assert_eq!(
+            Theme::from_system().is_dark(),
+            system_color_scheme().is_some_and(ColorScheme::is_dark)
+        );
+
+        let context = Context::new();
+        let mut provider = ThemeProvider::create(
+            ThemeProviderProps {
+                theme: Some(Theme {
+                    font_family: "Inter".to_string(),
+                    ..Theme::light()
+                }),
+                children: None,
+                mode: Some(ThemeMode::Auto),
+            },
+            context.clone(),
+        );
+        provider.set_system_scheme(Some(ColorScheme::Dark));
+        assert_eq!(provider.system_scheme(), Some(ColorScheme::Dark));
+        assert!(Theme::current(&context).is_dark());
+        assert_eq!(Theme::current(&context).font_family, "Inter");
+        assert!(context.has_pending_update(provider.component_id()));
+
+        // An unknown preference falls back to light
+        provider.set_system_scheme(None);
+        assert!(!provider.theme.is_dark());
+
+        // Manual mode only records the preference
+        provider.set_mode(ThemeMode::Manual);
+        provider.set_system_scheme(Some(ColorScheme::Dark));
+        assert!(!provider.theme.is_dark());
+        assert_eq!(provider.system_scheme(), Some(ColorScheme::Dark));
+    }
+}
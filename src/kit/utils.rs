@@ -1,33 +1,251 @@
 // Utility functions for OrbitKit
 
+pub use color::{parse_color, to_hex, ColorParseError};
+
 /// Color utilities
 pub mod color {
-    /// Lighten a color by a percentage
-    pub fn lighten(color: &str, _amount: f32) -> String {
-        // For now, just a placeholder
-        // In a real implementation, this would lighten the color
-        color.to_string()
+    use crate::style::Color;
+
+    /// Minimum WCAG AA contrast ratio for body text
+    pub const AA_CONTRAST: f32 = 4.5;
+
+    /// Errors from [`parse_color`]
+    #[derive(Debug, Clone, PartialEq, thiserror::Error)]
+    pub enum ColorParseError {
+        #[error("Unrecognized color {0:?}: expected #hex, rgb(), rgba(), hsl(), or hsla()")]
+        UnknownFormat(String),
+
+        #[error("Invalid hex color {0:?}: expected 3, 4, 6, or 8 hex digits after #")]
+        InvalidHex(String),
+
+        #[error("Invalid color component {token:?} in {input:?}")]
+        InvalidComponent { token: String, input: String },
+
+        #[error("Expected 3 or 4 components in {input:?}, found {found}")]
+        ComponentCount { input: String, found: usize },
+    }
+
+    /// Parse a CSS color into normalized RGBA
+    ///
+    /// Accepts `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, and the `rgb()`,
+    /// `rgba()`, `hsl()`, and `hsla()` functions with comma- or
+    /// space-separated components. Channels may be 0-255 or percentages,
+    /// alpha 0-1 or a percentage, and hue is in degrees.
+    pub fn parse_color(s: &str) -> Result<Color, ColorParseError> {
+        let input = s.trim();
+        if let Some(digits) = input.strip_prefix('#') {
+            return parse_hex(input, digits);
+        }
+
+        let lower = input.to_ascii_lowercase();
+        let (function, args) = lower
+            .strip_suffix(')')
+            .and_then(|call| call.split_once('('))
+            .ok_or_else(|| ColorParseError::UnknownFormat(input.to_string()))?;
+        let tokens: Vec<&str> = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+            .collect();
+        if !matches!(tokens.len(), 3 | 4) {
+            return Err(ColorParseError::ComponentCount {
+                input: input.to_string(),
+                found: tokens.len(),
+            });
+        }
+
+        let invalid = |token: &str| ColorParseError::InvalidComponent {
+            token: token.to_string(),
+            input: input.to_string(),
+        };
+        // A number or percentage, scaled so that `max` (or 100%) is 1
+        let fraction = |token: &str, max: f32| {
+            let (number, scale) = match token.strip_suffix('%') {
+                Some(percent) => (percent, 100.0),
+                None => (token, max),
+            };
+            number
+                .parse::<f32>()
+                .ok()
+                .map(|value| value / scale)
+                .filter(|value| (0.0..=1.0).contains(value))
+                .ok_or_else(|| invalid(token))
+        };
+        let alpha = match tokens.get(3) {
+            Some(token) => fraction(token, 1.0)?,
+            None => 1.0,
+        };
+
+        match function.trim() {
+            "rgb" | "rgba" => Ok(Color::Rgba(
+                fraction(tokens[0], 255.0)?,
+                fraction(tokens[1], 255.0)?,
+                fraction(tokens[2], 255.0)?,
+                alpha,
+            )),
+            "hsl" | "hsla" => {
+                let hue = tokens[0]
+                    .trim_end_matches("deg")
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|hue| hue.is_finite())
+                    .ok_or_else(|| invalid(tokens[0]))?;
+                let saturation = fraction(tokens[1], 100.0)?;
+                let lightness = fraction(tokens[2], 100.0)?;
+                let (r, g, b, a) = Color::Hsl(hue, saturation, lightness, alpha)
+                    .to_rgba()
+                    .ok_or_else(|| invalid(tokens[0]))?;
+                Ok(Color::Rgba(r, g, b, a))
+            }
+            _ => Err(ColorParseError::UnknownFormat(input.to_string())),
+        }
+    }
+
+    fn parse_hex(input: &str, digits: &str) -> Result<Color, ColorParseError> {
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidHex(input.to_string()));
+        }
+        // Shorthand repeats each digit, so #abc is #aabbcc
+        let expanded: String = match digits.len() {
+            3 | 4 => digits.chars().flat_map(|c| [c, c]).collect(),
+            6 | 8 => digits.to_string(),
+            _ => return Err(ColorParseError::InvalidHex(input.to_string())),
+        };
+        let channel = |i: usize| {
+            expanded.get(i..i + 2).map_or(1.0, |pair| {
+                u8::from_str_radix(pair, 16).unwrap_or(0) as f32 / 255.0
+            })
+        };
+        Ok(Color::Rgba(channel(0), channel(2), channel(4), channel(6)))
+    }
+
+    /// Format a color as `#rrggbb`, or `#rrggbbaa` when it isn't opaque
+    ///
+    /// Colors that can't be resolved on their own, such as `currentColor`,
+    /// come out fully transparent.
+    pub fn to_hex(color: &Color) -> String {
+        let (r, g, b, a) = color.to_rgba().unwrap_or((0.0, 0.0, 0.0, 0.0));
+        let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        if byte(a) == 255 {
+            format!("#{:02x}{:02x}{:02x}", byte(r), byte(g), byte(b))
+        } else {
+            format!(
+                "#{:02x}{:02x}{:02x}{:02x}",
+                byte(r),
+                byte(g),
+                byte(b),
+                byte(a)
+            )
+        }
+    }
+
+    /// Red, green, and blue bytes of a CSS color string; alpha is ignored
+    fn channels(color: &str) -> Option<(u8, u8, u8)> {
+        let (r, g, b, _) = parse_color(color).ok()?.to_rgba()?;
+        let byte = |c: f32| (c * 255.0).round() as u8;
+        Some((byte(r), byte(g), byte(b)))
+    }
+
+    /// WCAG relative luminance of a color, from 0 for black to 1 for white
+    pub fn relative_luminance(color: &str) -> Option<f32> {
+        let (r, g, b) = channels(color)?;
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// WCAG contrast ratio between two colors, from 1 to 21
+    pub fn contrast_ratio(a: &str, b: &str) -> Option<f32> {
+        let (a, b) = (relative_luminance(a)?, relative_luminance(b)?);
+        let (light, dark) = if a > b { (a, b) } else { (b, a) };
+        Some((light + 0.05) / (dark + 0.05))
     }
 
-    /// Darken a color by a percentage
-    pub fn darken(color: &str, _amount: f32) -> String {
-        // For now, just a placeholder
-        // In a real implementation, this would darken the color
-        color.to_string()
+    /// Whether `fg` text on `bg` meets WCAG AA; unparseable colors fail
+    pub fn meets_aa(fg: &str, bg: &str) -> bool {
+        contrast_ratio(fg, bg).is_some_and(|ratio| ratio >= AA_CONTRAST)
     }
 
-    /// Convert RGB to HSL
-    pub fn rgb_to_hsl(_r: u8, _g: u8, _b: u8) -> (f32, f32, f32) {
-        // For now, just a placeholder
-        // In a real implementation, this would convert RGB to HSL
-        (0.0, 0.0, 0.0)
+    /// `from` moved `t` of the way to `to`, as `#rrggbb`
+    pub(crate) fn mix(from: &str, to: &str, t: f32) -> Option<String> {
+        let (a, b) = (channels(from)?, channels(to)?);
+        let channel = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * t).round() as u8;
+        Some(format!(
+            "#{:02x}{:02x}{:02x}",
+            channel(a.0, b.0),
+            channel(a.1, b.1),
+            channel(a.2, b.2)
+        ))
     }
 
-    /// Convert HSL to RGB
-    pub fn hsl_to_rgb(_h: f32, _s: f32, _l: f32) -> (u8, u8, u8) {
-        // For now, just a placeholder
-        // In a real implementation, this would convert HSL to RGB
-        (0, 0, 0)
+    /// Shift a color's HSL lightness by `delta`, keeping its alpha;
+    /// unparseable colors are returned unchanged
+    fn shift_lightness(color: &str, delta: f32) -> String {
+        let Some((r, g, b, a)) = parse_color(color).ok().and_then(|c| c.to_rgba()) else {
+            return color.to_string();
+        };
+        let byte = |c: f32| (c * 255.0).round() as u8;
+        let (h, s, l) = rgb_to_hsl(byte(r), byte(g), byte(b));
+        let (r, g, b) = hsl_to_rgb(h, s, (l + delta).clamp(0.0, 1.0));
+        let channel = |c: u8| c as f32 / 255.0;
+        to_hex(&Color::Rgba(channel(r), channel(g), channel(b), a))
+    }
+
+    /// Lighten a color, adding `amount` (0 to 1) to its HSL lightness
+    pub fn lighten(color: &str, amount: f32) -> String {
+        shift_lightness(color, amount)
+    }
+
+    /// Darken a color, taking `amount` (0 to 1) from its HSL lightness
+    pub fn darken(color: &str, amount: f32) -> String {
+        shift_lightness(color, -amount)
+    }
+
+    /// A color with its alpha replaced by `alpha` (0 to 1), as `#rrggbbaa`
+    /// unless fully opaque; unparseable colors are returned unchanged
+    pub fn with_alpha(color: &str, alpha: f32) -> String {
+        match parse_color(color).ok().and_then(|c| c.to_rgba()) {
+            Some((r, g, b, _)) => to_hex(&Color::Rgba(r, g, b, alpha.clamp(0.0, 1.0))),
+            None => color.to_string(),
+        }
+    }
+
+    /// Convert RGB to HSL, with hue in degrees and saturation and lightness
+    /// from 0 to 1
+    pub fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (h, s, l)
+    }
+
+    /// Convert HSL (hue in degrees, saturation and lightness from 0 to 1)
+    /// to RGB
+    pub fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+        let (r, g, b, _) = Color::Hsl(h, s.clamp(0.0, 1.0), l.clamp(0.0, 1.0), 1.0)
+            .to_rgba()
+            .unwrap_or((0.0, 0.0, 0.0, 1.0));
+        let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        (byte(r), byte(g), byte(b))
     }
 }
 
@@ -42,6 +260,11 @@ pub mod string {
         }
     }
 
+    /// Parse a pixel length such as `"16px"`, treating anything else as zero
+    pub fn parse_px(value: &str) -> f32 {
+        value.trim().trim_end_matches("px").parse().unwrap_or(0.0)
+    }
+
     /// Capitalize the first letter of a string
     pub fn capitalize(s: &str) -> String {
         let mut chars = s.chars();
@@ -73,3 +296,687 @@ pub mod math {
         a + (b - a) * t
     }
 }
+
+/// Easing curves, a tweened value for component transitions, and keyframed
+/// animations for ones that loop
+pub mod animation {
+    use crate::clock::{Clock, FrameClock};
+    use crate::component::TransitionProperty;
+    use crate::style::{StepPosition, Style, TimingFunction, Transform};
+    use std::sync::Arc;
+
+    /// Progress unchanged
+    pub fn linear(t: f32) -> f32 {
+        t.clamp(0.0, 1.0)
+    }
+
+    /// CSS `ease-in`: starts slowly and speeds up
+    pub fn ease_in(t: f32) -> f32 {
+        cubic_bezier(0.42, 0.0, 1.0, 1.0)(t)
+    }
+
+    /// CSS `ease-out`: starts quickly and slows down
+    pub fn ease_out(t: f32) -> f32 {
+        cubic_bezier(0.0, 0.0, 0.58, 1.0)(t)
+    }
+
+    /// CSS `ease-in-out`: slow at both ends, symmetric around the midpoint
+    pub fn ease_in_out(t: f32) -> f32 {
+        cubic_bezier(0.42, 0.0, 0.58, 1.0)(t)
+    }
+
+    /// The CSS `cubic-bezier()` curve through (0, 0), (x1, y1), (x2, y2),
+    /// and (1, 1)
+    ///
+    /// `x1` and `x2` are clamped to 0..=1 so the curve stays a function of
+    /// time.
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
+        let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+        // Bernstein form of one coordinate with endpoints 0 and 1
+        let curve = |a: f32, b: f32, s: f32| {
+            let u = 1.0 - s;
+            3.0 * u * u * s * a + 3.0 * u * s * s * b + s * s * s
+        };
+        let slope = |a: f32, b: f32, s: f32| {
+            let u = 1.0 - s;
+            3.0 * u * u * a + 6.0 * u * s * (b - a) + 3.0 * s * s * (1.0 - b)
+        };
+
+        move |t: f32| {
+            let x = t.clamp(0.0, 1.0);
+            // Solve curve(x1, x2, s) = x for the curve parameter s: Newton's
+            // method first, falling back to bisection where the slope is flat
+            let mut s = x;
+            let mut solved = false;
+            for _ in 0..8 {
+                let error = curve(x1, x2, s) - x;
+                if error.abs() < 1e-6 {
+                    solved = true;
+                    break;
+                }
+                let d = slope(x1, x2, s);
+                if d.abs() < 1e-6 {
+                    break;
+                }
+                s = (s - error / d).clamp(0.0, 1.0);
+            }
+            if !solved {
+                let (mut low, mut high) = (0.0, 1.0);
+                s = x;
+                for _ in 0..32 {
+                    if curve(x1, x2, s) < x {
+                        low = s;
+                    } else {
+                        high = s;
+                    }
+                    s = (low + high) / 2.0;
+                }
+            }
+            curve(y1, y2, s)
+        }
+    }
+
+    /// Eased progress for linear progress `t` under a CSS timing function
+    pub fn ease(timing: &TimingFunction, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match timing {
+            TimingFunction::Linear => t,
+            TimingFunction::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0)(t),
+            TimingFunction::EaseIn => ease_in(t),
+            TimingFunction::EaseOut => ease_out(t),
+            TimingFunction::EaseInOut => ease_in_out(t),
+            TimingFunction::CubicBezier(x1, y1, x2, y2) => cubic_bezier(*x1, *y1, *x2, *y2)(t),
+            TimingFunction::Steps(0, _) => t,
+            TimingFunction::Steps(steps, position) => {
+                let steps = *steps as f32;
+                match position {
+                    StepPosition::Start => (t * steps).ceil() / steps,
+                    StepPosition::End => (t * steps).floor() / steps,
+                }
+            }
+        }
+    }
+
+    /// A value tweened from `from` to `to` over `duration` seconds
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Animation {
+        /// Value at the start
+        pub from: f32,
+        /// Value at the end
+        pub to: f32,
+        /// Length in seconds; zero or less finishes immediately
+        pub duration: f32,
+        /// Curve applied to the linear progress
+        pub easing: TimingFunction,
+        /// Seconds advanced so far
+        pub elapsed: f32,
+        /// Time source for `update`, if one was given
+        clock: Option<FrameClock>,
+    }
+
+    impl Animation {
+        /// Start an animation from `from` to `to`
+        pub fn new(from: f32, to: f32, duration: f32, easing: TimingFunction) -> Self {
+            Self {
+                from,
+                to,
+                duration,
+                easing,
+                elapsed: 0.0,
+                clock: None,
+            }
+        }
+
+        /// Start an animation from `from` to `to` that `update` advances by
+        /// the time passed on `clock`
+        pub fn with_clock(
+            from: f32,
+            to: f32,
+            duration: f32,
+            easing: TimingFunction,
+            clock: Arc<dyn Clock>,
+        ) -> Self {
+            Self {
+                clock: Some(FrameClock::new(clock)),
+                ..Self::new(from, to, duration, easing)
+            }
+        }
+
+        /// Advance by the time passed on the animation's clock since it was
+        /// created or last updated, and return the eased value
+        ///
+        /// Without a clock the animation stays where it is.
+        pub fn update(&mut self) -> f32 {
+            let dt = self.clock.as_mut().map_or(0.0, FrameClock::delta);
+            self.sample(dt)
+        }
+
+        /// A finished animation resting at `value`
+        pub fn at_rest(value: f32, easing: TimingFunction) -> Self {
+            Self::new(value, value, 0.0, easing)
+        }
+
+        /// Linear progress from 0 to 1
+        pub fn progress(&self) -> f32 {
+            if self.duration <= 0.0 {
+                1.0
+            } else {
+                (self.elapsed / self.duration).clamp(0.0, 1.0)
+            }
+        }
+
+        /// The eased value at the current point
+        pub fn value(&self) -> f32 {
+            self.from + (self.to - self.from) * ease(&self.easing, self.progress())
+        }
+
+        /// Advance by `dt` seconds and return the eased value
+        pub fn sample(&mut self, dt: f32) -> f32 {
+            self.elapsed = (self.elapsed + dt.max(0.0)).min(self.duration.max(0.0));
+            self.value()
+        }
+
+        /// Whether the animation has reached `to`
+        pub fn is_finished(&self) -> bool {
+            self.progress() >= 1.0
+        }
+
+        /// Head for `to` from wherever the animation is now, taking
+        /// `duration` seconds
+        ///
+        /// Retargeting to the current destination leaves a running animation
+        /// alone, so calling this every frame doesn't stall it.
+        pub fn retarget(&mut self, to: f32, duration: f32) {
+            if to == self.to {
+                return;
+            }
+            self.from = self.value();
+            self.to = to;
+            self.duration = duration;
+            self.elapsed = 0.0;
+        }
+
+        /// Jump straight to `to`
+        pub fn finish(&mut self) {
+            self.from = self.to;
+            self.elapsed = self.duration.max(0.0);
+        }
+    }
+
+    /// How many times a keyframe animation plays
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Iterations {
+        /// A number of times, possibly fractional, stopping partway through
+        /// the last
+        Count(f32),
+        /// Forever
+        Infinite,
+    }
+
+    impl Default for Iterations {
+        fn default() -> Self {
+            Iterations::Count(1.0)
+        }
+    }
+
+    /// Which way each iteration of a keyframe animation plays
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum AnimationDirection {
+        /// Every iteration from the first keyframe to the last
+        #[default]
+        Normal,
+        /// Odd iterations forwards, even ones backwards
+        Alternate,
+    }
+
+    /// Style values an animation passes through at `offset`
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Keyframe {
+        /// Point in each iteration, from 0 to 1
+        pub offset: f32,
+        /// Properties set at this point; each is interpolated between the
+        /// keyframes that set it
+        pub style: Style,
+    }
+
+    /// A looping or keyframed animation of style values, like a CSS
+    /// `@keyframes` animation
+    ///
+    /// Opacity, background color, and translate, scale, and rotate
+    /// transforms are interpolated; components advance the animation with
+    /// [`tick`](Self::tick) and apply it to the node it animates when
+    /// rendering.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct KeyframeAnimation {
+        /// Keyframes in offset order
+        pub keyframes: Vec<Keyframe>,
+        /// Seconds per iteration
+        pub duration: f32,
+        /// How many times the animation plays
+        pub iterations: Iterations,
+        /// Which way each iteration plays
+        pub direction: AnimationDirection,
+        /// Curve applied between each pair of keyframes
+        pub easing: TimingFunction,
+        /// Seconds advanced so far
+        pub elapsed: f32,
+        /// Time source for `update`, if one was given
+        clock: Option<FrameClock>,
+    }
+
+    impl KeyframeAnimation {
+        /// An animation without keyframes taking `duration` seconds per
+        /// iteration, played once with `ease`
+        pub fn new(duration: f32) -> Self {
+            Self {
+                keyframes: Vec::new(),
+                duration,
+                iterations: Iterations::default(),
+                direction: AnimationDirection::Normal,
+                easing: TimingFunction::Ease,
+                elapsed: 0.0,
+                clock: None,
+            }
+        }
+
+        /// Have `update` advance the animation by the time passed on
+        /// `clock`, starting now
+        pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+            self.clock = Some(FrameClock::new(clock));
+            self
+        }
+
+        /// Advance by the time passed on the animation's clock since it was
+        /// given one or last updated; returns whether it is still running
+        ///
+        /// Without a clock the animation stays where it is.
+        pub fn update(&mut self) -> bool {
+            let dt = self.clock.as_mut().map_or(0.0, FrameClock::delta);
+            self.tick(dt)
+        }
+
+        /// Add a keyframe `percent` of the way through each iteration
+        pub fn keyframe(mut self, percent: f32, style: Style) -> Self {
+            let offset = (percent / 100.0).clamp(0.0, 1.0);
+            let index = self
+                .keyframes
+                .partition_point(|keyframe| keyframe.offset <= offset);
+            self.keyframes.insert(index, Keyframe { offset, style });
+            self
+        }
+
+        /// Set how many times the animation plays
+        pub fn iterations(mut self, iterations: Iterations) -> Self {
+            self.iterations = iterations;
+            self
+        }
+
+        /// Set which way each iteration plays
+        pub fn direction(mut self, direction: AnimationDirection) -> Self {
+            self.direction = direction;
+            self
+        }
+
+        /// Set the curve applied between keyframes
+        pub fn easing(mut self, easing: TimingFunction) -> Self {
+            self.easing = easing;
+            self
+        }
+
+        /// A full clockwise turn every `duration` seconds, forever, as for a
+        /// loading spinner
+        pub fn spin(duration: f32) -> Self {
+            let turn = |degrees| Style {
+                transform: Some(Transform::Rotate(degrees)),
+                ..Style::default()
+            };
+            Self::new(duration)
+                .keyframe(0.0, turn(0.0))
+                .keyframe(100.0, turn(360.0))
+                .iterations(Iterations::Infinite)
+                .easing(TimingFunction::Linear)
+        }
+
+        /// Opacity dipping to half and back every `duration` seconds,
+        /// forever, as for a placeholder or a live indicator
+        pub fn pulse(duration: f32) -> Self {
+            Self::new(duration)
+                .keyframe(0.0, opacity(1.0))
+                .keyframe(50.0, opacity(0.5))
+                .keyframe(100.0, opacity(1.0))
+                .iterations(Iterations::Infinite)
+                .easing(TimingFunction::EaseInOut)
+        }
+
+        /// Opacity rising from transparent to opaque once, over `duration`
+        /// seconds
+        pub fn fade(duration: f32) -> Self {
+            Self::new(duration)
+                .keyframe(0.0, opacity(0.0))
+                .keyframe(100.0, opacity(1.0))
+        }
+
+        /// A sweep along the x axis from 0 to `distance` every `duration`
+        /// seconds, forever, as for a skeleton's shimmer or an indeterminate
+        /// progress bar
+        pub fn sweep(duration: f32, distance: f32) -> Self {
+            let at = |x| Style {
+                transform: Some(Transform::TranslateX(x)),
+                ..Style::default()
+            };
+            Self::new(duration)
+                .keyframe(0.0, at(0.0))
+                .keyframe(100.0, at(distance))
+                .iterations(Iterations::Infinite)
+                .easing(TimingFunction::EaseInOut)
+        }
+
+        /// Advance by `dt` seconds; returns whether the animation is still
+        /// running
+        pub fn tick(&mut self, dt: f32) -> bool {
+            self.elapsed += dt.max(0.0);
+            if let Some(total) = self.total() {
+                self.elapsed = self.elapsed.min(total);
+            } else if self.duration > 0.0 {
+                // Looping forever, only the point in the cycle matters
+                let cycle = self.duration * 2.0;
+                self.elapsed %= cycle;
+            }
+            !self.is_finished()
+        }
+
+        /// Whether every iteration has played
+        pub fn is_finished(&self) -> bool {
+            self.total().is_some_and(|total| self.elapsed >= total)
+        }
+
+        /// Seconds the whole animation takes, or `None` if it never ends
+        fn total(&self) -> Option<f32> {
+            match self.iterations {
+                _ if self.duration <= 0.0 => Some(0.0),
+                Iterations::Count(count) => Some(count.max(0.0) * self.duration),
+                Iterations::Infinite => None,
+            }
+        }
+
+        /// Point in the current iteration, from 0 to 1, after direction is
+        /// taken into account
+        pub fn progress(&self) -> f32 {
+            if self.duration <= 0.0 {
+                return 1.0;
+            }
+            let mut iteration = (self.elapsed / self.duration).floor();
+            let mut progress = self.elapsed / self.duration - iteration;
+            // Having played every iteration, the animation rests at the end
+            // of the last rather than the start of the next
+            if self.is_finished() && progress == 0.0 && self.elapsed > 0.0 {
+                iteration -= 1.0;
+                progress = 1.0;
+            }
+            if self.direction == AnimationDirection::Alternate && iteration % 2.0 == 1.0 {
+                1.0 - progress
+            } else {
+                progress
+            }
+        }
+
+        /// The animated properties at the current point
+        pub fn sample(&self) -> Style {
+            self.sample_at(self.progress())
+        }
+
+        /// The animated properties `progress` of the way through an
+        /// iteration, from 0 to 1
+        ///
+        /// Before its first keyframe a property holds that keyframe's
+        /// value, and after its last, the last one's.
+        pub fn sample_at(&self, progress: f32) -> Style {
+            let mut style = Style::default();
+            for property in TransitionProperty::ALL {
+                let frames: Vec<&Keyframe> = self
+                    .keyframes
+                    .iter()
+                    .filter(|keyframe| property.is_set(&keyframe.style))
+                    .collect();
+                let (Some(first), Some(last)) = (frames.first(), frames.last()) else {
+                    continue;
+                };
+                let after = frames
+                    .iter()
+                    .position(|keyframe| keyframe.offset > progress);
+                let (from, to) = match after {
+                    None => (last, last),
+                    Some(0) => (first, first),
+                    Some(index) => (&frames[index - 1], &frames[index]),
+                };
+                let span = to.offset - from.offset;
+                match (property.read(&from.style), property.read(&to.style)) {
+                    (Some(a), Some(b)) if span > 0.0 && progress > from.offset => {
+                        let t = ease(&self.easing, (progress - from.offset) / span);
+                        let value: Vec<f32> =
+                            a.iter().zip(&b).map(|(a, b)| a + (b - a) * t).collect();
+                        property.write(&mut style, &value);
+                    }
+                    // On a keyframe, or between values that can't be
+                    // interpolated, the earlier keyframe's value holds
+                    _ => property.copy(&from.style, &mut style),
+                }
+            }
+            style
+        }
+
+        /// Set the animated properties in `style` to their current values
+        pub fn apply(&self, style: &mut Style) {
+            style.merge(&self.sample());
+        }
+    }
+
+    fn opacity(value: f32) -> Style {
+        Style {
+            opacity: Some(value),
+            ..Style::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    use crate::kit::theme::Theme;
+
+    use crate::style::TimingFunction;
+
+    #[test]
+    fn test_ease_in_out_is_symmetric() {
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            let sum = animation::ease_in_out(t) + animation::ease_in_out(1.0 - t);
+            assert!((sum - 1.0).abs() < 1e-4, "t = {t}");
+        }
+        assert_eq!(animation::ease_in_out(0.5), 0.5);
+        assert!(animation::ease_in(0.25) < 0.25 && animation::ease_out(0.25) > 0.25);
+        assert_eq!(animation::linear(2.0), 1.0);
+
+        let ease = animation::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+        assert_eq!((ease(0.0), ease(1.0)), (0.0, 1.0));
+        assert!((animation::ease(&TimingFunction::Ease, 0.5) - ease(0.5)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_animation_samples_and_finishes() {
+        let mut anim = animation::Animation::new(10.0, 20.0, 0.2, TimingFunction::Linear);
+        assert_eq!(anim.sample(0.1), 15.0);
+        assert!(!anim.is_finished());
+        assert_eq!(anim.sample(5.0), 20.0);
+        assert!(anim.is_finished());
+        assert_eq!(anim.sample(-1.0), 20.0);
+
+        anim.retarget(0.0, 0.4);
+        assert_eq!(anim.sample(0.2), 10.0);
+        anim.finish();
+        assert_eq!(anim.value(), 0.0);
+        assert!(animation::Animation::at_rest(3.0, TimingFunction::Ease).is_finished());
+    }
+
+    #[test]
+    fn test_keyframed_rotation_at_half_way() {
+        use crate::style::{Style, Transform};
+        use animation::{AnimationDirection, Iterations, KeyframeAnimation};
+
+        let mut spin = KeyframeAnimation::spin(1.0);
+        assert_eq!(
+            spin.sample_at(0.5).transform,
+            Some(Transform::Rotate(180.0))
+        );
+        // Looping forever, the animation never finishes
+        assert!(spin.tick(2.25));
+        assert_eq!(spin.sample().transform, Some(Transform::Rotate(90.0)));
+
+        // Alternating iterations play backwards, and a count runs out
+        let scale = |x| Style {
+            transform: Some(Transform::Scale(x, x)),
+            ..Style::default()
+        };
+        let mut grow = KeyframeAnimation::new(1.0)
+            .keyframe(0.0, scale(1.0))
+            .keyframe(100.0, scale(2.0))
+            .easing(TimingFunction::Linear)
+            .direction(AnimationDirection::Alternate)
+            .iterations(Iterations::Count(2.0));
+        assert!(grow.tick(1.25));
+        assert_eq!(grow.sample().transform, Some(Transform::Scale(1.75, 1.75)));
+        assert!(!grow.tick(5.0));
+        assert_eq!(grow.progress(), 0.0);
+        assert_eq!(grow.sample().transform, Some(Transform::Scale(1.0, 1.0)));
+
+        // Properties interpolate only between keyframes that set them
+        let pulse = KeyframeAnimation::pulse(1.0);
+        assert_eq!(pulse.sample_at(0.5).opacity, Some(0.5));
+        assert!(pulse.sample_at(0.25).transform.is_none());
+        assert_eq!(KeyframeAnimation::fade(0.3).sample().opacity, Some(0.0));
+    }
+
+    #[test]
+    fn test_mock_clock_drives_exact_eased_values() {
+        use crate::clock::MockClock;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let clock = MockClock::new();
+        let mut anim = animation::Animation::with_clock(
+            0.0,
+            100.0,
+            1.0,
+            TimingFunction::EaseIn,
+            Arc::new(clock.clone()),
+        );
+        assert_eq!(anim.update(), 0.0);
+
+        clock.advance(Duration::from_millis(500));
+        let expected = 100.0 * animation::ease_in(0.5);
+        assert_eq!(anim.update(), expected);
+        // Nothing moves until the clock does
+        assert_eq!(anim.update(), expected);
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(anim.update(), 100.0);
+        assert!(anim.is_finished());
+
+        let mut spin = animation::KeyframeAnimation::spin(2.0).with_clock(Arc::new(clock.clone()));
+        clock.advance(Duration::from_millis(500));
+        assert!(spin.update());
+        assert_eq!(
+            spin.sample().transform,
+            Some(crate::style::Transform::Rotate(90.0))
+        );
+    }
+
+    #[test]
+    fn test_hsl_of_primaries_and_gray() {
+        assert_eq!(color::rgb_to_hsl(255, 0, 0), (0.0, 1.0, 0.5));
+        assert_eq!(color::rgb_to_hsl(0, 255, 0), (120.0, 1.0, 0.5));
+        assert_eq!(color::rgb_to_hsl(0, 0, 255), (240.0, 1.0, 0.5));
+        let (h, s, l) = color::rgb_to_hsl(128, 128, 128);
+        assert_eq!((h, s), (0.0, 0.0));
+        assert!((l - 128.0 / 255.0).abs() < 1e-6);
+
+        for rgb in [
+            (255, 0, 0),
+            (0, 255, 0),
+            (0, 0, 255),
+            (128, 128, 128),
+            (18, 52, 86),
+        ] {
+            let (h, s, l) = color::rgb_to_hsl(rgb.0, rgb.1, rgb.2);
+            assert_eq!(color::hsl_to_rgb(h, s, l), rgb);
+        }
+        // Going through 8-bit RGB only loses what the bytes can't hold
+        for (h, s, l) in [(30.0, 0.8, 0.4), (200.0, 0.5, 0.5), (310.0, 0.25, 0.75)] {
+            let (r, g, b) = color::hsl_to_rgb(h, s, l);
+            let (h2, s2, l2) = color::rgb_to_hsl(r, g, b);
+            assert!((h - h2).abs() < 1.0 && (s - s2).abs() < 0.01 && (l - l2).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_lighten_darken_and_alpha() {
+        assert_eq!(color::lighten("#808080", 0.1), "#9a9a9a");
+        assert_eq!(color::darken("#ff0000", 0.5), "#000000");
+        assert_eq!(color::lighten("#ffffff", 0.2), "#ffffff");
+        assert_eq!(color::darken("#ff000080", 0.1), "#cc000080");
+        assert_eq!(color::with_alpha("#0070f3", 0.5), "#0070f380");
+        assert_eq!(color::with_alpha("not a color", 0.5), "not a color");
+
+        let base = "#0070f3";
+        let light = Theme::light();
+        let dark = Theme::dark();
+        let luminance = |c: &str| color::relative_luminance(c).unwrap();
+        assert!(luminance(&light.hover_color(base)) < luminance(base));
+        assert!(luminance(&light.active_color(base)) < luminance(&light.hover_color(base)));
+        assert!(luminance(&dark.hover_color(base)) > luminance(base));
+    }
+
+    #[test]
+    fn test_parse_color_forms() {
+        let hex = |s| to_hex(&parse_color(s).unwrap());
+        assert_eq!(hex("#abc"), "#aabbcc");
+        assert_eq!(hex("#ABCD"), "#aabbccdd");
+        assert_eq!(hex("#0070f3"), "#0070f3");
+        assert_eq!(hex("#0070f380"), "#0070f380");
+        assert_eq!(hex("rgb(255, 128, 0)"), "#ff8000");
+        assert_eq!(hex("rgba(0, 0, 255, 0.5)"), "#0000ff80");
+        assert_eq!(hex("rgb(100% 0% 0% / 25%)"), "#ff000040");
+        assert_eq!(hex("hsl(120, 100%, 50%)"), "#00ff00");
+        assert_eq!(hex("HSL(240deg 100% 25%)"), "#000080");
+        assert_eq!(
+            parse_color("#fff").unwrap(),
+            Color::Rgba(1.0, 1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_parse_color_errors_name_the_bad_token() {
+        assert_eq!(
+            parse_color("rgb(255, lots, 0)"),
+            Err(ColorParseError::InvalidComponent {
+                token: "lots".to_string(),
+                input: "rgb(255, lots, 0)".to_string(),
+            })
+        );
+        let err = parse_color("rgb(300, 0, 0)").unwrap_err();
+        assert!(err.to_string().contains("\"300\""), "{err}");
+        assert!(matches!(
+            parse_color("#abcde"),
+            Err(ColorParseError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            parse_color("rgb(1, 2)"),
+            Err(ColorParseError::ComponentCount { found: 2, .. })
+        ));
+        assert!(matches!(
+            parse_color("blue"),
+            Err(ColorParseError::UnknownFormat(_))
+        ));
+    }
+}
@@ -223,9 +223,15 @@ impl Default for EdgeValues {
     }
 }
 
+/// Per-edge padding or margin around a box
+pub type EdgeInsets = EdgeValues;
+
 /// Layout style properties for a node
 #[derive(Debug, Clone, PartialEq)]
 pub struct LayoutStyle {
+    /// Whether the node takes part in layout at all
+    pub display: Display,
+
     // Position
     pub position_type: PositionType,
     pub top: Dimension,
@@ -240,6 +246,8 @@ pub struct LayoutStyle {
     pub min_height: Dimension,
     pub max_width: Dimension,
     pub max_height: Dimension,
+    /// Width divided by height, used to size whichever of the two is auto
+    pub aspect_ratio: Option<f32>,
 
     // Flexbox container properties
     pub flex_direction: FlexDirection,
@@ -254,6 +262,10 @@ pub struct LayoutStyle {
     pub flex_basis: Dimension,
     pub align_self: Option<AlignItems>,
 
+    // Grid item properties
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+
     // Spacing
     pub margin: EdgeValues,
     pub padding: EdgeValues,
@@ -261,16 +273,35 @@ pub struct LayoutStyle {
     pub gap: Gap,
 }
 
+/// How a node is placed, and what its `top`/`right`/`bottom`/`left`
+/// offsets do
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PositionType {
+    /// Placed by the normal flow; the offsets are ignored
     #[default]
+    Static,
+    /// Placed by the normal flow, then shifted by the offsets
     Relative,
+    /// Taken out of the flow and placed by the offsets within the padding
+    /// box of the nearest positioned ancestor, or the viewport
     Absolute,
 }
 
+/// Whether a node is laid out and painted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Display {
+    /// Laid out by its parent, with its children in a flex container
+    #[default]
+    Flex,
+    /// Removed, with its subtree, from layout and paint; unlike hidden
+    /// visibility, it takes up no space
+    None,
+}
+
 impl Default for LayoutStyle {
     fn default() -> Self {
         Self {
+            display: Display::default(),
             position_type: PositionType::default(),
             top: Dimension::default(),
             right: Dimension::default(),
@@ -282,6 +313,7 @@ impl Default for LayoutStyle {
             min_height: Dimension::default(),
             max_width: Dimension::default(),
             max_height: Dimension::default(),
+            aspect_ratio: None,
             flex_direction: FlexDirection::default(),
             flex_wrap: FlexWrap::default(),
             justify_content: JustifyContent::default(),
@@ -291,6 +323,8 @@ impl Default for LayoutStyle {
             flex_shrink: 1.0,
             flex_basis: Dimension::Auto,
             align_self: None,
+            grid_column: GridPlacement::default(),
+            grid_row: GridPlacement::default(),
             margin: EdgeValues::default(),
             padding: EdgeValues::default(),
             border: EdgeValues::default(),
@@ -490,15 +524,26 @@ impl LayoutEngine {
             }
         }
 
+        if node.style.display == Display::None {
+            hide(node);
+            return Ok(());
+        }
+
+        // The viewport is the containing block at the root
+        let viewport = Rect::new(0.0, 0.0, container_size.width, container_size.height);
+        if node.style.position_type == PositionType::Absolute {
+            return self.layout_absolute(node, viewport, Point::zero());
+        }
+
         self.stats.cache_misses += 1;
 
-        // Calculate this node's size and position
+        // Calculate this node's size; its position is its own
         self.calculate_node_size(node, container_size)?;
-        self.calculate_node_position(node)?;
 
         // Layout children using flexbox algorithm
         if !node.children.is_empty() {
-            self.layout_flex_children(node)?;
+            let block = relative_to(viewport, node.layout.rect.origin);
+            self.layout_flex_children(node, block)?;
         }
 
         // Mark as clean and cache the result
@@ -508,6 +553,76 @@ impl LayoutEngine {
         Ok(())
     }
 
+    /// Layout the children of a node whose rect its parent has already
+    /// decided, keeping that rect rather than resizing it from its own style
+    ///
+    /// `containing_block` is the one absolutely positioned descendants
+    /// inherit, relative to the node's border box.
+    fn layout_assigned(
+        &mut self,
+        node: &mut LayoutNode,
+        containing_block: Rect,
+    ) -> Result<(), LayoutError> {
+        self.stats.cache_misses += 1;
+
+        let style = &node.style;
+        let rect = node.layout.rect;
+        node.layout.content_rect = Rect::new(
+            rect.x() + style.padding.left + style.border.left,
+            rect.y() + style.padding.top + style.border.top,
+            (rect.width() - style.padding.horizontal() - style.border.horizontal()).max(0.0),
+            (rect.height() - style.padding.vertical() - style.border.vertical()).max(0.0),
+        );
+
+        if !node.children.is_empty() {
+            self.layout_flex_children(node, containing_block)?;
+        }
+
+        node.layout.is_dirty = false;
+        self.layout_cache.insert(node.id, node.layout.clone());
+        Ok(())
+    }
+
+    /// Layout an absolutely positioned node within `block`, its containing
+    /// block relative to the parent's border box
+    ///
+    /// Axes with no offsets keep the node where the flow would start it,
+    /// at `static_origin`.
+    fn layout_absolute(
+        &mut self,
+        node: &mut LayoutNode,
+        block: Rect,
+        static_origin: Point,
+    ) -> Result<(), LayoutError> {
+        self.calculate_node_size(node, block.size)?;
+
+        let style = &node.style;
+        let size = node.layout.rect.size;
+        let (x, width) = absolute_span(
+            (style.left, style.right),
+            (style.margin.left, style.margin.right),
+            matches!(style.width, Dimension::Auto),
+            size.width,
+            block.width(),
+        );
+        let (y, height) = absolute_span(
+            (style.top, style.bottom),
+            (style.margin.top, style.margin.bottom),
+            matches!(style.height, Dimension::Auto),
+            size.height,
+            block.height(),
+        );
+        node.layout.rect = Rect::new(
+            x.map_or(static_origin.x + style.margin.left, |x| block.x() + x),
+            y.map_or(static_origin.y + style.margin.top, |y| block.y() + y),
+            width,
+            height,
+        );
+
+        let block = relative_to(block, node.layout.rect.origin);
+        self.layout_assigned(node, block)
+    }
+
     /// Calculate the size of a node
     fn calculate_node_size(
         &self,
@@ -536,6 +651,17 @@ impl LayoutEngine {
             _ => style.height.resolve(container_size.height),
         };
 
+        // An aspect ratio fills in whichever dimension is auto, the height
+        // when both are; explicit dimensions on both axes win over it
+        let (width, height) = match style.aspect_ratio.filter(|ratio| *ratio > 0.0) {
+            Some(ratio) => match (style.width, style.height) {
+                (_, Dimension::Auto) => (width, width / ratio),
+                (Dimension::Auto, _) => (height * ratio, height),
+                _ => (width, height),
+            },
+            None => (width, height),
+        };
+
         // Apply min/max constraints (only if not Auto)
         let final_width = {
             let mut w = width;
@@ -565,44 +691,63 @@ impl LayoutEngine {
         // Calculate content rect (excluding padding and border)
         let content_x = node.layout.rect.x() + style.padding.left + style.border.left;
         let content_y = node.layout.rect.y() + style.padding.top + style.border.top;
-        let content_width = final_width - style.padding.horizontal() - style.border.horizontal();
-        let content_height = final_height - style.padding.vertical() - style.border.vertical();
+        let content_width =
+            (final_width - style.padding.horizontal() - style.border.horizontal()).max(0.0);
+        let content_height =
+            (final_height - style.padding.vertical() - style.border.vertical()).max(0.0);
 
         node.layout.content_rect = Rect::new(content_x, content_y, content_width, content_height);
 
         Ok(())
     }
 
-    /// Calculate the position of a node
-    fn calculate_node_position(&self, node: &mut LayoutNode) -> Result<(), LayoutError> {
-        let style = &node.style;
-
-        match style.position_type {
-            PositionType::Relative => {
-                // Position will be set by parent's layout algorithm
-                // For now, keep current position
-            }
-            PositionType::Absolute => {
-                // Position relative to containing block
-                let x = style.left.resolve(0.0); // TODO: Use actual containing block size
-                let y = style.top.resolve(0.0);
-                node.layout.rect.origin = Point::new(x, y);
-            }
-        }
-
-        Ok(())
-    }
     /// Layout children using flexbox algorithm
-    fn layout_flex_children(&mut self, parent: &mut LayoutNode) -> Result<(), LayoutError> {
+    ///
+    /// `containing_block` is the one the parent inherited for absolutely
+    /// positioned descendants, relative to its border box.
+    fn layout_flex_children(
+        &mut self,
+        parent: &mut LayoutNode,
+        containing_block: Rect,
+    ) -> Result<(), LayoutError> {
         if parent.children.is_empty() {
             return Ok(());
         }
 
+        // A positioned parent contains its absolutely positioned descendants
+        // in its padding box
+        let block = if parent.style.position_type == PositionType::Static {
+            containing_block
+        } else {
+            let border = parent.style.border;
+            let size = parent.layout.rect.size;
+            Rect::new(
+                border.left,
+                border.top,
+                (size.width - border.horizontal()).max(0.0),
+                (size.height - border.vertical()).max(0.0),
+            )
+        };
+
         let parent_content_size = parent.layout.content_rect.size;
         let parent_style = &parent.style;
+        // Children are placed relative to the parent's border box, inset to
+        // its content box by padding and border
+        let content_origin = Point::new(
+            parent.layout.content_rect.x() - parent.layout.rect.x(),
+            parent.layout.content_rect.y() - parent.layout.rect.y(),
+        );
+
+        // Undisplayed children are left out altogether
+        for child in &mut parent.children {
+            if child.style.display == Display::None {
+                hide(child);
+            }
+        }
 
         // Separate absolutely positioned children
         let (absolute_children, relative_children): (Vec<_>, Vec<_>) = (0..parent.children.len())
+            .filter(|&i| parent.children[i].style.display != Display::None)
             .partition(|&i| parent.children[i].style.position_type == PositionType::Absolute);
 
         // Layout relatively positioned children with flexbox
@@ -614,15 +759,19 @@ impl LayoutEngine {
                 self.layout_flex_multiline(
                     &mut parent.children,
                     &relative_children,
+                    content_origin,
                     parent_content_size,
                     parent_style,
+                    block,
                 )?;
             } else {
                 self.layout_flex_line(
                     &mut parent.children,
                     &relative_children,
+                    content_origin,
                     parent_content_size,
                     parent_style,
+                    block,
                 )?;
             }
         }
@@ -630,18 +779,20 @@ impl LayoutEngine {
         // Layout absolutely positioned children
         for &child_index in &absolute_children {
             let child = &mut parent.children[child_index];
-            self.layout_node(child, parent_content_size)?;
+            self.layout_absolute(child, block, content_origin)?;
         }
 
         Ok(())
     }
-    /// Layout a single flex line
+    /// Layout a single flex line whose content box starts at `origin`
     fn layout_flex_line(
         &mut self,
         children: &mut [LayoutNode],
         child_indices: &[usize],
+        origin: Point,
         container_size: Size,
         parent_style: &LayoutStyle,
+        containing_block: Rect,
     ) -> Result<(), LayoutError> {
         let flex_direction = parent_style.flex_direction;
         let is_row = matches!(
@@ -669,16 +820,25 @@ impl LayoutEngine {
         let mut total_basis_size = 0.0;
         let mut total_flex_grow = 0.0;
         let mut total_flex_shrink = 0.0;
+        let margins = collapsed_margins(children, child_indices, flex_direction);
 
-        for &child_index in child_indices {
+        for (&child_index, margin) in child_indices.iter().zip(&margins) {
             let child = &children[child_index];
+            let (main_margin, cross_margin) = if is_row {
+                (margin.horizontal(), margin.vertical())
+            } else {
+                (margin.vertical(), margin.horizontal())
+            };
 
             // Calculate basis size
             let basis_size = self.calculate_flex_basis_size(child, container_size, is_row)?;
             let main_size = self.resolve_main_size(child, basis_size, is_row);
-            let cross_size = self.resolve_cross_size(child, cross_axis_size, is_row);
+            let cross_size =
+                self.resolve_cross_size(child, (cross_axis_size - cross_margin).max(0.0), is_row);
+            let (main_size, cross_size) =
+                self.apply_aspect_ratio(child, main_size, cross_size, is_row);
 
-            total_basis_size += main_size;
+            total_basis_size += main_size + main_margin;
             total_flex_grow += child.style.flex_grow;
             total_flex_shrink += child.style.flex_shrink;
 
@@ -687,6 +847,8 @@ impl LayoutEngine {
                 basis_size,
                 main_size,
                 cross_size,
+                main_margin,
+                cross_margin,
                 flex_grow: child.style.flex_grow,
                 flex_shrink: child.style.flex_shrink,
             });
@@ -722,25 +884,46 @@ impl LayoutEngine {
         for (i, &child_index) in child_indices.iter().enumerate() {
             let child = &mut children[child_index];
             let item = &item_data[i];
+            let margin = margins[i];
             let main_pos = positions[i];
 
-            // Calculate cross-axis position
+            // Calculate cross-axis position of the margin box
             let cross_pos = self.calculate_cross_axis_position(
                 child,
-                item.cross_size,
+                item.cross_size + item.cross_margin,
                 cross_axis_size,
                 parent_style.align_items,
             );
 
-            // Set final layout
+            // Set final layout, stepping in from the margin box by the
+            // leading margins
             if is_row {
-                child.layout.rect = Rect::new(main_pos, cross_pos, item.main_size, item.cross_size);
+                child.layout.rect = Rect::new(
+                    origin.x + main_pos + margin.left,
+                    origin.y + cross_pos + margin.top,
+                    item.main_size,
+                    item.cross_size,
+                );
             } else {
-                child.layout.rect = Rect::new(cross_pos, main_pos, item.cross_size, item.main_size);
+                child.layout.rect = Rect::new(
+                    origin.x + cross_pos + margin.left,
+                    origin.y + main_pos + margin.top,
+                    item.cross_size,
+                    item.main_size,
+                );
+            }
+
+            // Relative positioning shifts the child from where the flow put
+            // it, leaving its siblings be
+            if child.style.position_type == PositionType::Relative {
+                let offset = relative_offset(&child.style, container_size);
+                child.layout.rect.origin.x += offset.x;
+                child.layout.rect.origin.y += offset.y;
             }
 
-            // Recursively layout this child
-            self.layout_node(child, child.layout.rect.size)?;
+            // Recursively layout this child within the rect just assigned
+            let block = relative_to(containing_block, child.layout.rect.origin);
+            self.layout_assigned(child, block)?;
         }
 
         Ok(())
@@ -821,6 +1004,33 @@ impl LayoutEngine {
         }
     }
 
+    /// Size an item's auto dimension from its aspect ratio: the cross size
+    /// follows a definite main size, and otherwise the main size follows the
+    /// cross size, whether given or stretched
+    fn apply_aspect_ratio(
+        &self,
+        child: &LayoutNode,
+        main_size: f32,
+        cross_size: f32,
+        is_row: bool,
+    ) -> (f32, f32) {
+        let Some(ratio) = child.style.aspect_ratio.filter(|ratio| *ratio > 0.0) else {
+            return (main_size, cross_size);
+        };
+        let (main_dimension, cross_dimension, main_per_cross) = if is_row {
+            (child.style.width, child.style.height, ratio)
+        } else {
+            (child.style.height, child.style.width, 1.0 / ratio)
+        };
+        let main_definite = !matches!(main_dimension, Dimension::Auto)
+            || !matches!(child.style.flex_basis, Dimension::Auto);
+        match (main_definite, cross_dimension) {
+            (true, Dimension::Auto) => (main_size, main_size / main_per_cross),
+            (false, _) => (cross_size * main_per_cross, cross_size),
+            _ => (main_size, cross_size),
+        }
+    }
+
     /// Distribute available space among flex items
     fn distribute_flex_space(
         &self,
@@ -864,7 +1074,7 @@ impl LayoutEngine {
             return positions;
         }
 
-        let total_item_size: f32 = items.iter().map(|item| item.main_size).sum();
+        let total_item_size: f32 = items.iter().map(FlexItemData::outer_main_size).sum();
         let total_gaps = gap_size * (items.len() as f32 - 1.0).max(0.0);
         let remaining_space = container_size - total_item_size - total_gaps;
 
@@ -873,21 +1083,21 @@ impl LayoutEngine {
                 let mut current_pos = 0.0;
                 for item in items {
                     positions.push(current_pos);
-                    current_pos += item.main_size + gap_size;
+                    current_pos += item.outer_main_size() + gap_size;
                 }
             }
             JustifyContent::FlexEnd => {
                 let mut current_pos = remaining_space;
                 for item in items {
                     positions.push(current_pos);
-                    current_pos += item.main_size + gap_size;
+                    current_pos += item.outer_main_size() + gap_size;
                 }
             }
             JustifyContent::Center => {
                 let mut current_pos = remaining_space / 2.0;
                 for item in items {
                     positions.push(current_pos);
-                    current_pos += item.main_size + gap_size;
+                    current_pos += item.outer_main_size() + gap_size;
                 }
             }
             JustifyContent::SpaceBetween => {
@@ -898,7 +1108,7 @@ impl LayoutEngine {
                     let mut current_pos = 0.0;
                     for item in items {
                         positions.push(current_pos);
-                        current_pos += item.main_size + space_between;
+                        current_pos += item.outer_main_size() + space_between;
                     }
                 }
             }
@@ -907,7 +1117,7 @@ impl LayoutEngine {
                 let mut current_pos = space_around / 2.0;
                 for item in items {
                     positions.push(current_pos);
-                    current_pos += item.main_size + space_around;
+                    current_pos += item.outer_main_size() + space_around;
                 }
             }
             JustifyContent::SpaceEvenly => {
@@ -915,7 +1125,7 @@ impl LayoutEngine {
                 let mut current_pos = space_evenly;
                 for item in items {
                     positions.push(current_pos);
-                    current_pos += item.main_size + space_evenly;
+                    current_pos += item.outer_main_size() + space_evenly;
                 }
             }
         }
@@ -924,7 +1134,7 @@ impl LayoutEngine {
             let positions_len = positions.len();
             for (i, pos) in positions.iter_mut().enumerate() {
                 let item_index = positions_len - 1 - i;
-                *pos = container_size - *pos - items[item_index].main_size;
+                *pos = container_size - *pos - items[item_index].outer_main_size();
             }
             positions.reverse();
         }
@@ -961,8 +1171,10 @@ impl LayoutEngine {
         &mut self,
         children: &mut [LayoutNode],
         child_indices: &[usize],
+        origin: Point,
         container_size: Size,
         parent_style: &LayoutStyle,
+        containing_block: Rect,
     ) -> Result<(), LayoutError> {
         let flex_direction = parent_style.flex_direction;
         let is_row = matches!(
@@ -977,7 +1189,13 @@ impl LayoutEngine {
         } else {
             container_size.height
         }; // Break items into lines
-        let lines = self.break_into_lines(children, child_indices, main_axis_size, is_row)?;
+        let main_gap = if is_row {
+            parent_style.gap.column
+        } else {
+            parent_style.gap.row
+        };
+        let lines =
+            self.break_into_lines(children, child_indices, main_axis_size, main_gap, is_row)?;
 
         if lines.is_empty() {
             return Ok(());
@@ -1018,25 +1236,21 @@ impl LayoutEngine {
                 Size::new(container_size.width, main_axis_size)
             };
 
-            // Layout this line
-            self.layout_flex_line(children, line_indices, line_container, parent_style)?;
-            // First, recursively layout all children in this line
-            for &child_index in line_indices {
-                let child = &mut children[child_index];
-                self.layout_node(child, child.layout.rect.size)?;
-            }
-
-            // Then set the cross axis position for all items in this line
-            for &child_index in line_indices {
-                let child = &mut children[child_index];
-                if is_row {
-                    // Row layout - update Y position (cross axis)
-                    child.layout.rect.origin.y = current_cross_pos;
-                } else {
-                    // Column layout - update X position (cross axis)
-                    child.layout.rect.origin.x = current_cross_pos;
-                }
-            }
+            // Layout this line, offset along the cross axis past the
+            // lines before it
+            let line_origin = if is_row {
+                Point::new(origin.x, origin.y + current_cross_pos)
+            } else {
+                Point::new(origin.x + current_cross_pos, origin.y)
+            };
+            self.layout_flex_line(
+                children,
+                line_indices,
+                line_origin,
+                line_container,
+                parent_style,
+                containing_block,
+            )?;
 
             // Move to the next line
             current_cross_pos += line_cross_sizes[i] + cross_gap;
@@ -1046,13 +1260,19 @@ impl LayoutEngine {
         if is_reverse {
             let total_cross_size = current_cross_pos - cross_gap;
             for &child_index in child_indices {
-                let child = &mut children[child_index];
+                let layout = &mut children[child_index].layout;
+                // Descendants are relative to the child, so only its own
+                // rects need to move
                 if is_row {
-                    child.layout.rect.origin.y =
-                        total_cross_size - child.layout.rect.origin.y - child.layout.rect.height();
+                    let y = layout.rect.y() - origin.y;
+                    let shift = total_cross_size - 2.0 * y - layout.rect.height();
+                    layout.rect.origin.y += shift;
+                    layout.content_rect.origin.y += shift;
                 } else {
-                    child.layout.rect.origin.x =
-                        total_cross_size - child.layout.rect.origin.x - child.layout.rect.width();
+                    let x = layout.rect.x() - origin.x;
+                    let shift = total_cross_size - 2.0 * x - layout.rect.width();
+                    layout.rect.origin.x += shift;
+                    layout.content_rect.origin.x += shift;
                 }
             }
         }
@@ -1066,6 +1286,7 @@ impl LayoutEngine {
         children: &[LayoutNode],
         child_indices: &[usize],
         main_axis_size: f32,
+        gap_size: f32,
         is_row: bool,
     ) -> Result<Vec<Vec<usize>>, LayoutError> {
         // Simple but effective algorithm for breaking items into lines
@@ -1073,19 +1294,12 @@ impl LayoutEngine {
         let mut current_line = Vec::new();
         let mut current_line_size = 0.0;
 
-        // Get the appropriate gap size
-        let gap_size = if is_row {
-            children.first().map_or(0.0, |child| child.style.gap.column)
-        } else {
-            children.first().map_or(0.0, |child| child.style.gap.row)
-        };
-
         for &child_index in child_indices {
             let child = &children[child_index];
             let item_size = if is_row {
-                child.style.width.resolve(main_axis_size)
+                child.style.width.resolve(main_axis_size) + child.style.margin.horizontal()
             } else {
-                child.style.height.resolve(main_axis_size)
+                child.style.height.resolve(main_axis_size) + child.style.margin.vertical()
             };
 
             // Calculate the additional size needed if this isn't the first item (for gap)
@@ -1133,24 +1347,88 @@ impl LayoutEngine {
                 let child = &children[index];
                 if is_row {
                     // For row layout, the cross size is the height
-                    match child.style.height {
+                    let height = match child.style.height {
                         Dimension::Points(h) => h,
                         Dimension::Percent(p) => p * child.layout.rect.height(),
                         Dimension::Auto => child.layout.rect.height(),
-                    }
+                    };
+                    height + child.style.margin.vertical()
                 } else {
                     // For column layout, the cross size is the width
-                    match child.style.width {
+                    let width = match child.style.width {
                         Dimension::Points(w) => w,
                         Dimension::Percent(p) => p * child.layout.rect.width(),
                         Dimension::Auto => child.layout.rect.width(),
-                    }
+                    };
+                    width + child.style.margin.horizontal()
                 }
             })
             .fold(0.0, f32::max)
     }
 }
 
+/// Collapse a node with `display: none` to nothing
+fn hide(node: &mut LayoutNode) {
+    node.layout = LayoutResult {
+        is_dirty: false,
+        ..LayoutResult::default()
+    };
+}
+
+/// `rect` in the space of a node whose origin is at `origin` in its own
+fn relative_to(rect: Rect, origin: Point) -> Rect {
+    Rect::new(
+        rect.x() - origin.x,
+        rect.y() - origin.y,
+        rect.width(),
+        rect.height(),
+    )
+}
+
+/// How far a relatively positioned node shifts from its place in the flow;
+/// `left` wins over `right` and `top` over `bottom`
+fn relative_offset(style: &LayoutStyle, container_size: Size) -> Point {
+    let shift = |start: Dimension, end: Dimension, size: f32| match (start, end) {
+        (Dimension::Auto, end) => -end.resolve(size),
+        (start, _) => start.resolve(size),
+    };
+    Point::new(
+        shift(style.left, style.right, container_size.width),
+        shift(style.top, style.bottom, container_size.height),
+    )
+}
+
+/// Offset from the start of the containing block and size of an absolutely
+/// positioned node along one axis, from its start and end `insets` and
+/// `margins`; the offset is `None` when neither inset is given
+///
+/// Insets on both sides stretch an auto size between them.
+fn absolute_span(
+    insets: (Dimension, Dimension),
+    margins: (f32, f32),
+    auto_size: bool,
+    size: f32,
+    block_size: f32,
+) -> (Option<f32>, f32) {
+    match insets {
+        (Dimension::Auto, Dimension::Auto) => (None, size),
+        (Dimension::Auto, end) => (
+            Some(block_size - end.resolve(block_size) - margins.1 - size),
+            size,
+        ),
+        (start, Dimension::Auto) => (Some(start.resolve(block_size) + margins.0), size),
+        (start, end) => {
+            let start = start.resolve(block_size);
+            let size = if auto_size {
+                (block_size - start - end.resolve(block_size) - margins.0 - margins.1).max(0.0)
+            } else {
+                size
+            };
+            (Some(start + margins.0), size)
+        }
+    }
+}
+
 /// Data for a flex item during layout calculation
 #[derive(Debug, Clone)]
 struct FlexItemData {
@@ -1160,10 +1438,51 @@ struct FlexItemData {
     basis_size: f32,
     main_size: f32,
     cross_size: f32,
+    /// Margins on both main-axis edges, after collapsing
+    main_margin: f32,
+    /// Margins on both cross-axis edges
+    cross_margin: f32,
     flex_grow: f32,
     flex_shrink: f32,
 }
 
+impl FlexItemData {
+    /// Main-axis size of the item's margin box
+    fn outer_main_size(&self) -> f32 {
+        self.main_size + self.main_margin
+    }
+}
+
+/// The margins of each child in a flex line, with the facing margins of
+/// vertically adjacent siblings collapsed to the larger of the two
+///
+/// Only positive margins collapse. The smaller margin is dropped from the
+/// later sibling, so the gap between the two boxes is the larger margin.
+fn collapsed_margins(
+    children: &[LayoutNode],
+    child_indices: &[usize],
+    flex_direction: FlexDirection,
+) -> Vec<EdgeInsets> {
+    let mut margins: Vec<EdgeInsets> = child_indices
+        .iter()
+        .map(|&index| children[index].style.margin)
+        .collect();
+    for i in 1..margins.len() {
+        let previous = margins[i - 1];
+        let margin = &mut margins[i];
+        match flex_direction {
+            FlexDirection::Column if previous.bottom > 0.0 && margin.top > 0.0 => {
+                margin.top -= previous.bottom.min(margin.top);
+            }
+            FlexDirection::ColumnReverse if previous.top > 0.0 && margin.bottom > 0.0 => {
+                margin.bottom -= previous.top.min(margin.bottom);
+            }
+            _ => {}
+        }
+    }
+    margins
+}
+
 /// Gap specification for flex containers
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Gap {
@@ -1181,6 +1500,48 @@ impl Gap {
     }
 }
 
+/// Where a grid item sits along one axis of its container
+///
+/// Tracks are numbered from 0. An item without a `start` is auto-placed
+/// into the next free cells, left to right and then top to bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridPlacement {
+    pub start: Option<usize>,
+    pub span: usize,
+}
+
+impl GridPlacement {
+    /// Auto-placed, one track wide
+    pub fn auto() -> Self {
+        Self {
+            start: None,
+            span: 1,
+        }
+    }
+
+    /// Auto-placed, covering `span` tracks
+    pub fn span(span: usize) -> Self {
+        Self {
+            start: None,
+            span: span.max(1),
+        }
+    }
+
+    /// Starting at track `start` and covering `span` tracks
+    pub fn at(start: usize, span: usize) -> Self {
+        Self {
+            start: Some(start),
+            span: span.max(1),
+        }
+    }
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self::auto()
+    }
+}
+
 impl Default for LayoutEngine {
     fn default() -> Self {
         Self::new()
@@ -1703,7 +2064,37 @@ mod tests {
 
         // Children should be stacked vertically
         assert_eq!(parent.children[0].layout.rect.y(), 0.0);
-        assert!(parent.children[1].layout.rect.y() > parent.children[0].layout.rect.height());
+        assert!(parent.children[1].layout.rect.y() >= parent.children[0].layout.rect.height());
+    }
+
+    #[test]
+    fn test_column_children_keep_flex_grown_heights() {
+        let mut engine = LayoutEngine::new();
+        let mut parent = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                flex_direction: FlexDirection::Column,
+                width: Dimension::Points(100.0),
+                height: Dimension::Points(300.0),
+                ..Default::default()
+            },
+        );
+        for grow in [1.0, 2.0] {
+            let child_style = LayoutStyle {
+                flex_grow: grow,
+                ..Default::default()
+            };
+            parent.add_child(LayoutNode::new(ComponentId::new(), child_style));
+        }
+
+        let result = engine.calculate_layout(&mut parent, Size::new(200.0, 400.0));
+        assert!(result.is_ok());
+
+        // Recursing into a child keeps the height flex-grow gave it, 1:2 of
+        // the 300px column, instead of resizing it from its own style
+        assert_eq!(parent.children[0].layout.rect.height(), 100.0);
+        assert_eq!(parent.children[1].layout.rect.y(), 100.0);
+        assert_eq!(parent.children[1].layout.rect.height(), 200.0);
     }
 
     #[test]
@@ -1755,4 +2146,269 @@ mod tests {
         assert_eq!(default_gap.row, 0.0);
         assert_eq!(default_gap.column, 0.0);
     }
+
+    #[test]
+    fn test_padding_insets_grandchild() {
+        let mut engine = LayoutEngine::new();
+        let mut root = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                width: Dimension::Points(300.0),
+                height: Dimension::Points(300.0),
+                padding: EdgeInsets::uniform(20.0),
+                ..Default::default()
+            },
+        );
+        let mut child = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                width: Dimension::Points(200.0),
+                height: Dimension::Points(100.0),
+                padding: EdgeInsets::uniform(10.0),
+                margin: EdgeInsets::new(0.0, 0.0, 0.0, 5.0),
+                ..Default::default()
+            },
+        );
+        child.add_child(LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                width: Dimension::Points(50.0),
+                height: Dimension::Points(30.0),
+                ..Default::default()
+            },
+        ));
+        root.add_child(child);
+
+        engine
+            .calculate_layout(&mut root, Size::new(300.0, 300.0))
+            .unwrap();
+
+        // Rects are relative to the parent's border box
+        let child = &root.children[0];
+        assert_eq!(child.layout.rect, Rect::new(25.0, 20.0, 200.0, 100.0));
+        assert_eq!(
+            child.layout.content_rect,
+            Rect::new(35.0, 30.0, 180.0, 80.0)
+        );
+        assert_eq!(
+            child.children[0].layout.rect,
+            Rect::new(10.0, 10.0, 50.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn test_vertical_margins_collapse() {
+        let margin_layout = |flex_direction| {
+            let mut parent = LayoutNode::new(
+                ComponentId::new(),
+                LayoutStyle {
+                    flex_direction,
+                    width: Dimension::Points(400.0),
+                    height: Dimension::Points(400.0),
+                    ..Default::default()
+                },
+            );
+            for margin in [
+                EdgeInsets::new(0.0, 20.0, 20.0, 0.0),
+                EdgeInsets::new(10.0, 0.0, 0.0, 10.0),
+            ] {
+                parent.add_child(LayoutNode::new(
+                    ComponentId::new(),
+                    LayoutStyle {
+                        width: Dimension::Points(50.0),
+                        height: Dimension::Points(50.0),
+                        margin,
+                        ..Default::default()
+                    },
+                ));
+            }
+            LayoutEngine::new()
+                .calculate_layout(&mut parent, Size::new(400.0, 400.0))
+                .unwrap();
+            parent.children[1].layout.rect
+        };
+
+        // The 20px bottom margin and 10px top margin share one 20px gap
+        let stacked = margin_layout(FlexDirection::Column);
+        assert_eq!((stacked.x(), stacked.y()), (10.0, 70.0));
+
+        // Horizontal margins add up
+        let side_by_side = margin_layout(FlexDirection::Row);
+        assert_eq!((side_by_side.x(), side_by_side.y()), (80.0, 10.0));
+    }
+
+    #[test]
+    fn test_aspect_ratio_resolves_auto_dimension() {
+        let media = |width, height| LayoutStyle {
+            width,
+            height,
+            aspect_ratio: Some(16.0 / 9.0),
+            ..Default::default()
+        };
+        let mut engine = LayoutEngine::new();
+
+        let mut root = LayoutNode::new(
+            ComponentId::new(),
+            media(Dimension::Points(320.0), Dimension::Auto),
+        );
+        engine
+            .calculate_layout(&mut root, Size::new(800.0, 600.0))
+            .unwrap();
+        assert_eq!(root.layout.rect.size, Size::new(320.0, 180.0));
+
+        // A fluid child takes its width from a column and its height from
+        // the ratio, in a row the height follows the given width, and
+        // explicit dimensions on both axes win
+        let mut column = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                flex_direction: FlexDirection::Column,
+                width: Dimension::Points(320.0),
+                height: Dimension::Points(600.0),
+                ..Default::default()
+            },
+        );
+        column.add_child(LayoutNode::new(
+            ComponentId::new(),
+            media(Dimension::Auto, Dimension::Auto),
+        ));
+        let mut row = LayoutNode::new(ComponentId::new(), LayoutStyle::default());
+        row.add_child(LayoutNode::new(
+            ComponentId::new(),
+            media(Dimension::Points(160.0), Dimension::Auto),
+        ));
+        row.add_child(LayoutNode::new(
+            ComponentId::new(),
+            media(Dimension::Points(100.0), Dimension::Points(100.0)),
+        ));
+        column.add_child(row);
+        engine
+            .calculate_layout(&mut column, Size::new(800.0, 600.0))
+            .unwrap();
+
+        assert_eq!(column.children[0].layout.rect.size, Size::new(320.0, 180.0));
+        let row = &column.children[1];
+        assert_eq!(row.children[0].layout.rect.size, Size::new(160.0, 90.0));
+        assert_eq!(row.children[1].layout.rect.size, Size::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn test_absolute_child_in_relative_parent() {
+        let sized = |width, height| LayoutStyle {
+            width: Dimension::Points(width),
+            height: Dimension::Points(height),
+            ..Default::default()
+        };
+        let mut root = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                padding: EdgeInsets::uniform(30.0),
+                ..sized(400.0, 400.0)
+            },
+        );
+        let mut parent = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                position_type: PositionType::Relative,
+                left: Dimension::Points(4.0),
+                padding: EdgeInsets::uniform(12.0),
+                border: EdgeInsets::uniform(5.0),
+                ..sized(200.0, 100.0)
+            },
+        );
+        // A static node between them doesn't contain the absolute child
+        let mut wrapper = LayoutNode::new(ComponentId::new(), sized(100.0, 50.0));
+        wrapper.add_child(LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                position_type: PositionType::Absolute,
+                top: Dimension::Points(10.0),
+                left: Dimension::Points(20.0),
+                ..sized(40.0, 20.0)
+            },
+        ));
+        wrapper.add_child(LayoutNode::new(ComponentId::new(), sized(30.0, 30.0)));
+        parent.add_child(wrapper);
+        parent.add_child(LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                position_type: PositionType::Absolute,
+                right: Dimension::Points(0.0),
+                bottom: Dimension::Points(0.0),
+                ..sized(40.0, 20.0)
+            },
+        ));
+        root.add_child(parent);
+        root.add_child(LayoutNode::new(ComponentId::new(), sized(50.0, 50.0)));
+
+        LayoutEngine::new()
+            .calculate_layout(&mut root, Size::new(400.0, 400.0))
+            .unwrap();
+
+        // The relative parent shifts without moving its sibling
+        let parent = &root.children[0];
+        assert_eq!(parent.layout.rect.origin, Point::new(34.0, 30.0));
+        assert_eq!(root.children[1].layout.rect.x(), 230.0);
+
+        // Offsets are from the parent's padding box, inside its border; the
+        // child's rect stays relative to the wrapper it sits in
+        let wrapper = &parent.children[0];
+        assert_eq!(wrapper.layout.rect.origin, Point::new(17.0, 17.0));
+        assert_eq!(
+            wrapper.children[0].layout.rect,
+            Rect::new(20.0 + 5.0 - 17.0, 10.0 + 5.0 - 17.0, 40.0, 20.0)
+        );
+        // and out of the flow, which starts its sibling at the wrapper's
+        // own origin
+        assert_eq!(wrapper.children[1].layout.rect.origin, Point::zero());
+        assert_eq!(
+            parent.children[1].layout.rect,
+            Rect::new(155.0, 75.0, 40.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn test_display_none_redistributes_flex_space() {
+        let mut root = LayoutNode::new(
+            ComponentId::new(),
+            LayoutStyle {
+                width: Dimension::Points(300.0),
+                height: Dimension::Points(50.0),
+                ..Default::default()
+            },
+        );
+        for _ in 0..3 {
+            let style = LayoutStyle {
+                flex_grow: 1.0,
+                ..Default::default()
+            };
+            root.add_child(LayoutNode::new(ComponentId::new(), style));
+        }
+        let mut engine = LayoutEngine::new();
+        let mut layout = |root: &mut LayoutNode| {
+            root.mark_dirty();
+            engine
+                .calculate_layout(root, Size::new(300.0, 50.0))
+                .unwrap();
+            root.children
+                .iter()
+                .map(|child| (child.layout.rect.x(), child.layout.rect.width()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            layout(&mut root),
+            [(0.0, 100.0), (100.0, 100.0), (200.0, 100.0)]
+        );
+        root.children[1].style.display = Display::None;
+        assert_eq!(
+            layout(&mut root),
+            [(0.0, 150.0), (0.0, 0.0), (150.0, 150.0)]
+        );
+        root.children[1].style.display = Display::Flex;
+        assert_eq!(
+            layout(&mut root),
+            [(0.0, 100.0), (100.0, 100.0), (200.0, 100.0)]
+        );
+    }
 }
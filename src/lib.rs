@@ -1,4 +1,5 @@
 // Core module of the Orbit UI Framework
+pub mod clock;
 pub mod component;
 pub mod component_single;
 pub mod events;
@@ -16,6 +17,7 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Re-export of common types for convenience
 pub mod prelude {
+    pub use crate::clock::{Clock, MockClock, SystemClock};
     pub use crate::component::{
         callback,
         props::{PropValidationError, PropValidator},
@@ -27,9 +29,9 @@ pub mod prelude {
         Event,
     };
     pub use crate::layout::{
-        AlignContent, AlignItems, Dimension, EdgeValues, FlexDirection, FlexWrap, JustifyContent,
-        LayoutEngine, LayoutNode, LayoutResult, LayoutStats, LayoutStyle, Point, PositionType,
-        Rect, Size,
+        AlignContent, AlignItems, Dimension, Display, EdgeInsets, EdgeValues, FlexDirection,
+        FlexWrap, GridPlacement, JustifyContent, LayoutEngine, LayoutNode, LayoutResult,
+        LayoutStats, LayoutStyle, Point, PositionType, Rect, Size,
     };
     pub use crate::renderer::Renderer;
     pub use crate::state::{
@@ -60,6 +62,11 @@ pub enum Error {
     #[error("Renderer error: {0}")]
     Renderer(String),
 
+    /// A Skia renderer failure, keeping which kind of failure it was
+    #[cfg(feature = "skia")]
+    #[error("Renderer error: {0}")]
+    Skia(#[from] renderer::RendererError),
+
     #[error("Component error: {0}")]
     Component(String),
 
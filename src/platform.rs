@@ -59,11 +59,16 @@ pub mod web {
 #[cfg(feature = "desktop")]
 pub mod desktop {
     use super::PlatformAdapter;
+    use crate::clock::SystemClock;
+    use crate::component::{diff, Node, Transitions};
+    use crate::kit::appearance::ColorScheme;
+    use crate::kit::theme::ThemeProvider;
     use crate::renderer::{Renderer, RendererType};
 
     // Add Rc and RefCell for shared mutable state
     use std::cell::RefCell;
     use std::rc::Rc;
+    use std::sync::Arc;
 
     use glium::Surface;
     use glutin::config::GlConfig;
@@ -84,6 +89,12 @@ pub mod desktop {
         event_loop: Option<EventLoop<()>>,
         running: bool,
         start_time: std::time::Instant,
+        /// Node tree painted every frame
+        root: Rc<RefCell<Node>>,
+        /// Style transitions running in `root`, advanced before each frame
+        transitions: Rc<RefCell<Transitions>>,
+        /// Provider told when the window's light or dark theme changes
+        theme_provider: Option<Rc<RefCell<ThemeProvider>>>,
     }
 
     impl DesktopAdapter {
@@ -106,9 +117,35 @@ pub mod desktop {
                 event_loop: None,
                 running: false,
                 start_time: std::time::Instant::now(),
+                root: Rc::new(RefCell::new(Node::default())),
+                transitions: Rc::new(RefCell::new(
+                    Transitions::new().with_clock(Arc::new(SystemClock::new())),
+                )),
+                theme_provider: None,
             }
         }
 
+        /// Replace the tree painted every frame with `root`
+        ///
+        /// The change is applied as patches to the current tree, so style
+        /// properties with a transition ease toward their new values over
+        /// the following frames rather than snapping.
+        pub fn set_root(&self, root: Node) {
+            let mut current = self.root.borrow_mut();
+            let patches = diff(&current, &root);
+            self.transitions
+                .borrow_mut()
+                .apply_patches(&mut current, patches);
+        }
+
+        /// Forward changes in the system's light or dark preference, as the
+        /// window reports them, to `provider`, so a provider in
+        /// [`ThemeMode::Auto`](crate::kit::theme::ThemeMode::Auto) follows
+        /// them while the app runs
+        pub fn set_theme_provider(&mut self, provider: Rc<RefCell<ThemeProvider>>) {
+            self.theme_provider = Some(provider);
+        }
+
         /// Create a new desktop adapter with a specific renderer
         pub fn new_with_renderer(renderer_type: RendererType) -> Self {
             let renderer_result = crate::renderer::create_renderer(renderer_type);
@@ -130,6 +167,11 @@ pub mod desktop {
                 event_loop: None,
                 running: false,
                 start_time: std::time::Instant::now(),
+                root: Rc::new(RefCell::new(Node::default())),
+                transitions: Rc::new(RefCell::new(
+                    Transitions::new().with_clock(Arc::new(SystemClock::new())),
+                )),
+                theme_provider: None,
             }
         }
 
@@ -270,6 +312,9 @@ pub mod desktop {
 
             // Clone the Rc for the renderer to move into the closure
             let renderer_rc = self.renderer.clone();
+            let root = self.root.clone();
+            let transitions = self.transitions.clone();
+            let theme_provider = self.theme_provider.clone();
 
             // Run the event loop with the newer API
             let _ = event_loop.run(move |event, window_target| {
@@ -282,6 +327,16 @@ pub mod desktop {
                     } => {
                         window_target.exit();
                     }
+                    Event::WindowEvent {
+                        event: WindowEvent::ThemeChanged(theme),
+                        ..
+                    } => {
+                        if let Some(provider) = &theme_provider {
+                            provider
+                                .borrow_mut()
+                                .set_system_scheme(Some(ColorScheme::from(theme)));
+                        }
+                    }
                     Event::AboutToWait => {
                         // Draw a frame
                         let mut target = display_clone.draw(); // Use cloned display
@@ -290,26 +345,25 @@ pub mod desktop {
                         // Get the elapsed time for animation
                         let _elapsed = start_time_clone.elapsed().as_secs_f32(); // Use cloned start_time
 
-                        // Create a dummy node for demonstration
-                        let node = crate::component::Node::default();
-
-                        // We should construct a proper node tree based on the elapsed time
-                        // and component definitions, but for now we're just using a dummy node
+                        // Ease any transitioning styles to where they are this frame
+                        let mut node = root.borrow_mut();
+                        transitions.borrow_mut().update(&mut node);
 
                         // Create render context with default dimensions
                         let mut render_context = crate::renderer::RenderContext::new(800, 600);
 
-                        // Render the UI
-                        if let Err(e) = renderer_rc.borrow_mut().render(&node, &mut render_context)
-                        {
-                            // Use cloned Rc and borrow_mut()
+                        // Render the UI in a frame of its own
+                        let mut renderer = renderer_rc.borrow_mut();
+                        if let Err(e) = renderer.begin_frame() {
+                            eprintln!("Begin frame error: {e}");
+                        }
+                        if let Err(e) = renderer.render(&node, &mut render_context) {
                             eprintln!("Rendering error: {e}");
                         }
 
-                        // Flush changes
-                        if let Err(e) = renderer_rc.borrow_mut().flush() {
-                            // Use cloned Rc and borrow_mut()
-                            eprintln!("Flush error: {e}");
+                        // Flush and present changes
+                        if let Err(e) = renderer.end_frame() {
+                            eprintln!("End frame error: {e}");
                         }
 
                         // Finish drawing
@@ -3,14 +3,22 @@
 // Renderer modules
 #[cfg(feature = "skia")]
 pub mod skia;
+pub mod text;
 pub mod wgpu;
 
 // Re-export renderer items
 #[cfg(feature = "skia")]
-pub use skia::{RendererError, RendererMessage, RendererResult, SkiaRenderer};
+pub use skia::{
+    FrameStats, ImageSampling, RendererBackend, RendererError, RendererHandle, RendererMessage,
+    RendererResult, SkiaRenderer, Stroke, TextRenderMode,
+};
+
+pub use text::{layout_text, TextLine};
 
 use crate::component::{ComponentId, Node};
+use crate::layout::Rect;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Types of renderers available
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,6 +91,113 @@ impl RenderContext {
     }
 }
 
+/// Text measurement used to lay out text before it is drawn
+///
+/// Components take this instead of a concrete renderer so they can wrap text
+/// with real font metrics when a renderer is available.
+pub trait TextMeasure {
+    /// Advance width and line height of `text` at font `size`
+    fn measure_text(&self, text: &str, size: f32) -> (f32, f32);
+}
+
+/// Fixed-advance text measurement for when no font data is available
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApproximateTextMeasure;
+
+impl TextMeasure for ApproximateTextMeasure {
+    fn measure_text(&self, text: &str, size: f32) -> (f32, f32) {
+        (text.chars().count() as f32 * size * 0.55, size * 1.2)
+    }
+}
+
+/// Decoded RGBA8888 pixels that a node paints into its bounds
+///
+/// Pixels are unpremultiplied, row-major, four bytes each. They are shared,
+/// so cloning a node tree doesn't copy image data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageData {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Pixel bytes, `width * height * 4` of them
+    pub pixels: Arc<[u8]>,
+}
+
+impl ImageData {
+    /// Wrap decoded pixels
+    pub fn new(width: u32, height: u32, pixels: impl Into<Arc<[u8]>>) -> Self {
+        Self {
+            width,
+            height,
+            pixels: pixels.into(),
+        }
+    }
+
+    /// Whether the dimensions are non-zero and match the pixel data
+    ///
+    /// An image that failed to decode usually shows up as one that isn't.
+    pub fn is_valid(&self) -> bool {
+        self.width > 0
+            && self.height > 0
+            && self.pixels.len() == self.width as usize * self.height as usize * 4
+    }
+}
+
+/// How an image is scaled into the node it paints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFit {
+    /// Scale to fit inside the node, keeping the aspect ratio; the image
+    /// is centered and the rest of the node left empty
+    Contain,
+    /// Scale to cover the whole node, keeping the aspect ratio; the image
+    /// is centered and the overflowing edges cropped
+    Cover,
+    /// Stretch to the node's bounds
+    #[default]
+    Fill,
+}
+
+impl ImageFit {
+    /// The part of a `width` × `height` pixel image to draw, and where in
+    /// `bounds` to draw it
+    pub fn rects(self, width: u32, height: u32, bounds: Rect) -> (Rect, Rect) {
+        let (width, height) = (width as f32, height as f32);
+        let source = Rect::new(0.0, 0.0, width, height);
+        if width <= 0.0 || height <= 0.0 || bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+            return (source, bounds);
+        }
+        let scale_x = bounds.width() / width;
+        let scale_y = bounds.height() / height;
+        match self {
+            ImageFit::Fill => (source, bounds),
+            ImageFit::Contain => {
+                let scale = scale_x.min(scale_y);
+                let (dest_width, dest_height) = (width * scale, height * scale);
+                let dest = Rect::new(
+                    bounds.x() + (bounds.width() - dest_width) / 2.0,
+                    bounds.y() + (bounds.height() - dest_height) / 2.0,
+                    dest_width,
+                    dest_height,
+                );
+                (source, dest)
+            }
+            ImageFit::Cover => {
+                let scale = scale_x.max(scale_y);
+                let (source_width, source_height) =
+                    (bounds.width() / scale, bounds.height() / scale);
+                let source = Rect::new(
+                    (width - source_width) / 2.0,
+                    (height - source_height) / 2.0,
+                    source_width,
+                    source_height,
+                );
+                (source, bounds)
+            }
+        }
+    }
+}
+
 /// Render statistics for performance monitoring
 #[derive(Debug, Default, Clone)]
 pub struct RenderStats {
@@ -101,12 +216,41 @@ pub struct RenderStats {
 }
 
 /// Enhanced renderer interface with performance monitoring
+///
+/// A frame is drawn by calling `begin_frame`, then `render` as many times
+/// as there are trees to draw into it, then `end_frame`:
+///
+/// ```text
+/// init
+/// loop {
+///     begin_frame     // clear
+///     render ...      // draw, any number of times
+///     end_frame       // flush and present
+/// }
+/// cleanup
+/// ```
+///
+/// Renderers that clear and present inside `render` can leave the frame
+/// methods as they are, so callers that only call `render` and `flush`
+/// keep working.
 pub trait Renderer {
     /// Initialize the renderer
     fn init(&mut self) -> Result<(), crate::Error> {
         Ok(()) // Default implementation does nothing
     }
 
+    /// Start a frame, clearing the target ready for `render` calls
+    fn begin_frame(&mut self) -> Result<(), crate::Error> {
+        Ok(()) // Default implementation does nothing
+    }
+
+    /// Finish the frame started by `begin_frame`, presenting what was
+    /// rendered into it
+    fn end_frame(&mut self) -> Result<(), crate::Error> {
+        // Default implementation only flushes
+        self.flush()
+    }
+
     /// Render a component tree with context and performance optimizations
     fn render(&mut self, root: &Node, context: &mut RenderContext) -> Result<(), crate::Error>;
 
@@ -284,6 +428,16 @@ impl CompositeRenderer {
 }
 
 impl Renderer for CompositeRenderer {
+    fn begin_frame(&mut self) -> Result<(), crate::Error> {
+        self.renderer_3d.begin_frame()?;
+        self.renderer_2d.begin_frame()
+    }
+
+    fn end_frame(&mut self) -> Result<(), crate::Error> {
+        self.renderer_3d.end_frame()?;
+        self.renderer_2d.end_frame()
+    }
+
     fn render(&mut self, root: &Node, context: &mut RenderContext) -> Result<(), crate::Error> {
         let start_time = std::time::Instant::now();
 
@@ -1,26 +1,50 @@
 // Skia renderer implementation for the Orbit UI framework
-use std::{error::Error, fmt, sync::Arc};
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt,
+    path::Path,
+    sync::mpsc::{self, Receiver, Sender},
+    sync::Arc,
+    thread::JoinHandle,
+    time::Duration,
+};
 
 use skia_safe::{
+    canvas::SrcRectConstraint,
+    font::Edging,
     gpu::gl::FramebufferInfo,
-    gpu::{gl::Interface, BackendRenderTarget, DirectContext, Protected, SurfaceOrigin},
-    Color, Color4f, ColorType, Paint, Surface, M44,
+    gpu::{
+        gl::Interface, BackendRenderTarget, DirectContext, Protected, PurgeResourceOptions,
+        SurfaceOrigin,
+    },
+    gradient_shader, image_filters, images, AlphaType, BlendMode, BlurStyle, Canvas, Color,
+    Color4f, ColorType, Data, EncodedImageFormat, FilterMode, Font, FontMgr, FontStyle, ImageInfo,
+    MaskFilter, MipmapMode, Paint, PaintStyle, Path as SkPath, Point, RRect, Rect, SamplingOptions,
+    Shader, Surface, TileMode, Typeface, Vector, M44,
 };
 
+use crate::clock::{Clock, SystemClock};
 use crate::component::Node;
-use crate::renderer::RenderContext;
+use crate::layout::Rect as LayoutRect;
+use crate::renderer::{layout_text, RenderContext, TextMeasure};
+use crate::style::{Filter, MixBlendMode, Overflow, Visibility};
 
 /// A message sent to the renderer thread
 #[derive(Clone)]
 pub enum RendererMessage {
     /// Initialize with dimensions
     Init { width: i32, height: i32 },
+    /// Resize the render surface
+    Resize { width: i32, height: i32 },
     /// Begin frame
     BeginFrame,
     /// End frame
     EndFrame,
     /// Render node
     Render { node: Arc<Node> },
+    /// Cap the frame rate, or lift the cap with `None`
+    SetTargetFps(Option<u32>),
     /// Shutdown renderer
     Shutdown,
 }
@@ -51,33 +75,533 @@ impl fmt::Display for RendererError {
 
 impl Error for RendererError {}
 
+/// Lets `?` carry a [`RendererResult`] error into the framework's error,
+/// keeping the [`RendererError`] variant when that is what it holds
+impl From<Box<dyn Error + Send>> for crate::Error {
+    fn from(error: Box<dyn Error + Send>) -> Self {
+        match error.downcast::<RendererError>() {
+            Ok(error) => crate::Error::Skia(*error),
+            Err(error) => crate::Error::Renderer(error.to_string()),
+        }
+    }
+}
+
+/// Outline drawn along the edge of a shape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    /// Stroke width in pixels; zero or negative widths draw nothing
+    pub width: f32,
+    /// Stroke color
+    pub color: Color4f,
+}
+
+impl Stroke {
+    /// Create a new stroke
+    pub fn new(width: f32, color: Color4f) -> Self {
+        Self { width, color }
+    }
+}
+
+/// How image pixels are sampled when scaled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageSampling {
+    /// Pick the closest source pixel, keeping hard pixel edges
+    Nearest,
+    /// Blend neighbouring source pixels
+    #[default]
+    Linear,
+}
+
+impl From<ImageSampling> for SamplingOptions {
+    fn from(sampling: ImageSampling) -> Self {
+        let filter = match sampling {
+            ImageSampling::Nearest => FilterMode::Nearest,
+            ImageSampling::Linear => FilterMode::Linear,
+        };
+        SamplingOptions::new(filter, MipmapMode::None)
+    }
+}
+
+/// Timing statistics for frames drawn between `begin_frame` and `end_frame`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FrameStats {
+    /// Duration of the most recent frame in milliseconds
+    pub last_frame_ms: f32,
+    /// Frames per second implied by the average of the recent frame durations
+    pub avg_fps: f32,
+    /// Number of frames completed
+    pub frame_count: u64,
+    /// Bytes held in Skia's GPU resource cache at the end of the most recent
+    /// frame; `None` on the raster backend
+    pub gpu_memory_bytes: Option<usize>,
+}
+
+/// Number of recent frames averaged for `FrameStats::avg_fps`
+const FPS_WINDOW: usize = 60;
+
+/// Accumulates `FrameStats` from frame begin/end timestamps
+#[derive(Debug)]
+struct FrameTimer {
+    clock: Arc<dyn Clock>,
+    frame_start: Option<Duration>,
+    recent_ms: VecDeque<f32>,
+    stats: FrameStats,
+}
+
+impl Default for FrameTimer {
+    fn default() -> Self {
+        Self::new(Arc::new(SystemClock::new()))
+    }
+}
+
+impl FrameTimer {
+    /// A timer with no frames yet, reading time from `clock`
+    fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            frame_start: None,
+            recent_ms: VecDeque::new(),
+            stats: FrameStats::default(),
+        }
+    }
+
+    fn begin(&mut self) {
+        self.frame_start = Some(self.clock.now());
+    }
+
+    /// Time since the current frame began, if one has
+    fn elapsed(&self) -> Option<Duration> {
+        self.frame_start
+            .map(|start| self.clock.now().saturating_sub(start))
+    }
+
+    fn end(&mut self, gpu_memory_bytes: Option<usize>) {
+        let Some(start) = self.frame_start.take() else {
+            return;
+        };
+        let frame_ms = self.clock.now().saturating_sub(start).as_secs_f32() * 1000.0;
+
+        if self.recent_ms.len() == FPS_WINDOW {
+            self.recent_ms.pop_front();
+        }
+        self.recent_ms.push_back(frame_ms);
+        let avg_ms = self.recent_ms.iter().sum::<f32>() / self.recent_ms.len() as f32;
+
+        self.stats = FrameStats {
+            last_frame_ms: frame_ms,
+            avg_fps: if avg_ms > 0.0 { 1000.0 / avg_ms } else { 0.0 },
+            frame_count: self.stats.frame_count + 1,
+            gpu_memory_bytes,
+        };
+    }
+}
+
+/// Surface width used when no size was requested
+const DEFAULT_WIDTH: i32 = 800;
+
+/// Surface height used when no size was requested
+const DEFAULT_HEIGHT: i32 = 600;
+
+/// Font size used for node text when the style doesn't set one
+const DEFAULT_FONT_SIZE: f32 = 16.0;
+
 /// Result from renderer operations
 pub type RendererResult = Result<(), Box<dyn std::error::Error + Send>>;
 
+/// Error returned by draw calls made before the renderer is initialized
+fn not_initialized() -> Box<dyn std::error::Error + Send> {
+    Box::new(RendererError::GeneralError(
+        "Renderer not initialized".into(),
+    ))
+}
+
+/// Reject surface sizes that Skia cannot allocate
+fn validate_dimensions(width: i32, height: i32) -> RendererResult {
+    // Skia asserts on empty render targets, so reject them up front
+    if width <= 0 || height <= 0 {
+        return Err(Box::new(RendererError::InitError(format!(
+            "Invalid surface dimensions {width}x{height}"
+        ))));
+    }
+    Ok(())
+}
+
+/// Convert a style color into a Skia color, if it can be resolved
+fn to_color4f(color: &crate::style::Color) -> Option<Color4f> {
+    color.to_rgba().map(|(r, g, b, a)| Color4f::new(r, g, b, a))
+}
+
+/// Check whether local-space bounds are visible inside the render context viewport
+fn intersects_viewport(canvas: &Canvas, bounds: &Rect, ctx: &RenderContext, scale: f32) -> bool {
+    // An unset viewport means there is nothing to cull against
+    if ctx.viewport_width == 0 || ctx.viewport_height == 0 {
+        return true;
+    }
+
+    // Device space is in physical pixels, the viewport in logical units
+    let (device_bounds, _) = canvas.local_to_device_as_3x3().map_rect(bounds);
+    device_bounds.intersects(Rect::from_wh(
+        ctx.viewport_width as f32 * scale,
+        ctx.viewport_height as f32 * scale,
+    ))
+}
+
+/// MSAA sample counts the renderer will request
+const SAMPLE_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+/// Lower a requested MSAA sample count to one the backend supports
+///
+/// `max_supported` is the driver's limit; zero means it reported nothing.
+fn supported_samples(requested: usize, max_supported: usize) -> usize {
+    let limit = if max_supported == 0 {
+        requested
+    } else {
+        requested.min(max_supported)
+    };
+    let samples = SAMPLE_COUNTS
+        .iter()
+        .copied()
+        .filter(|&count| count <= limit)
+        .max()
+        .unwrap_or(1);
+
+    if samples != requested {
+        log::warn!(
+            "{requested}x MSAA is not supported (driver max {max_supported}x), using {samples}x"
+        );
+    }
+    samples
+}
+
+/// Physical surface size for a logical size at the given scale factor
+fn physical_size(width: i32, height: i32, scale_factor: f32) -> (i32, i32) {
+    (
+        (width as f32 * scale_factor).round() as i32,
+        (height as f32 * scale_factor).round() as i32,
+    )
+}
+
+/// Split gradient stops into the color and position arrays Skia expects
+///
+/// At least two stops are required, and positions must lie in `0..=1` and
+/// never decrease.
+fn gradient_stops(
+    stops: &[(f32, Color)],
+) -> Result<(Vec<Color>, Vec<f32>), Box<dyn std::error::Error + Send>> {
+    let invalid = |msg: &str| -> Box<dyn std::error::Error + Send> {
+        Box::new(RendererError::GeneralError(format!(
+            "Invalid gradient stops: {msg}"
+        )))
+    };
+
+    if stops.len() < 2 {
+        return Err(invalid("at least two stops are required"));
+    }
+    if stops.iter().any(|(pos, _)| !(0.0..=1.0).contains(pos)) {
+        return Err(invalid("positions must be within 0..=1"));
+    }
+    if stops.windows(2).any(|pair| pair[1].0 < pair[0].0) {
+        return Err(invalid("positions must be non-decreasing"));
+    }
+
+    Ok(stops.iter().map(|&(pos, color)| (color, pos)).unzip())
+}
+
+/// Find the first command segment of an SVG path string that fails to parse
+fn invalid_svg_segment(svg: &str) -> &str {
+    let is_command = |c: char| "MmLlHhVvCcSsQqTtAaZz".contains(c);
+    let starts: Vec<usize> = svg
+        .char_indices()
+        .filter(|&(_, c)| is_command(c))
+        .map(|(i, _)| i)
+        .collect();
+
+    for (n, &start) in starts.iter().enumerate() {
+        let end = starts.get(n + 1).copied().unwrap_or(svg.len());
+        if SkPath::from_svg(&svg[..end]).is_none() {
+            return svg[start..end].trim();
+        }
+    }
+    svg.trim()
+}
+
+/// Map a style blend mode onto Skia's blend modes
+fn to_blend_mode(mode: MixBlendMode) -> BlendMode {
+    match mode {
+        MixBlendMode::Normal => BlendMode::SrcOver,
+        MixBlendMode::Multiply => BlendMode::Multiply,
+        MixBlendMode::Screen => BlendMode::Screen,
+        MixBlendMode::Overlay => BlendMode::Overlay,
+        MixBlendMode::Darken => BlendMode::Darken,
+        MixBlendMode::Lighten => BlendMode::Lighten,
+        MixBlendMode::Plus => BlendMode::Plus,
+    }
+}
+
+/// Build the stroke for a node's border
+///
+/// The renderer draws uniform borders, so the top edge's width and color are
+/// used for all four sides. Borders without a color default to black.
+fn border_stroke(style: &crate::style::Style) -> Option<Stroke> {
+    let width = style.border_width.as_ref()?.top;
+    let color = match &style.border_color {
+        Some(colors) => to_color4f(&colors.top)?,
+        None => Color4f::from(Color::BLACK),
+    };
+    Some(Stroke::new(width, color))
+}
+
+/// Convert a framework error from the `Renderer` trait into a renderer error
+fn from_framework_error(error: crate::Error) -> Box<dyn std::error::Error + Send> {
+    Box::new(RendererError::GeneralError(error.to_string()))
+}
+
+/// Where a [`SkiaRenderer`] draws
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RendererBackend {
+    /// The default framebuffer of the current GL context
+    Gl,
+    /// A CPU raster surface, needing no GPU
+    Raster,
+    /// A Vulkan image owned by Skia, set up with [`SkiaRenderer::init_vulkan`]
+    #[cfg(feature = "vulkan")]
+    Vulkan,
+}
+
+/// How glyph edges are rasterized
+///
+/// Subpixel anti-aliasing is the sharpest for small UI text on an ordinary
+/// LCD, but tints glyph edges and needs the surface's pixel layout to be
+/// known; elsewhere, and when the text is scaled or rotated, Skia draws it
+/// as plain anti-aliasing. Plain anti-aliasing looks the same on any
+/// display and under any transform, at the cost of slightly softer small
+/// text. Aliased text has hard edges that stay on the pixel grid, which
+/// suits pixel fonts but looks jagged at most sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextRenderMode {
+    /// Grayscale anti-aliasing with subpixel glyph positioning
+    Antialias,
+    /// Anti-aliasing per color channel, with subpixel glyph positioning
+    #[default]
+    SubpixelAntialias,
+    /// Hard edges, with glyphs snapped to whole pixels
+    Aliased,
+}
+
+impl TextRenderMode {
+    /// Set `font` up to draw in this mode
+    fn apply(self, font: &mut Font) {
+        let (edging, subpixel) = match self {
+            TextRenderMode::Antialias => (Edging::AntiAlias, true),
+            TextRenderMode::SubpixelAntialias => (Edging::SubpixelAntiAlias, true),
+            TextRenderMode::Aliased => (Edging::Alias, false),
+        };
+        font.set_edging(edging).set_subpixel(subpixel);
+    }
+}
+
 /// Skia renderer state
 pub(crate) struct SkiaState {
-    /// Skia GPU context
-    #[allow(dead_code)]
-    pub(crate) gr_context: DirectContext,
+    /// Backend the surface was created for
+    pub(crate) backend: RendererBackend,
+
+    /// Skia GPU context, for either GL or Vulkan; `None` for CPU raster
+    /// surfaces
+    pub(crate) gr_context: Option<DirectContext>,
 
     /// Skia render surface
     pub(crate) surface: Surface,
 
     /// Current transform stack
-    #[allow(dead_code)]
     pub(crate) transform_stack: Vec<M44>,
 
-    /// Current width
+    /// Clips and opacity layers pushed and not yet popped, innermost last
+    ///
+    /// Both are canvas saves, so they share one stack and must be popped in
+    /// the reverse of the order they were pushed.
+    pub(crate) saves: Vec<SaveKind>,
+
+    /// Current width, in logical units
     pub(crate) width: i32,
 
-    /// Current height
+    /// Current height, in logical units
     pub(crate) height: i32,
+
+    /// Physical pixels per logical unit
+    pub(crate) scale_factor: f32,
+
+    /// MSAA sample count of the surface
+    pub(crate) samples: usize,
+
+    /// Canvas save count to restore when the frame's dirty-region clip ends
+    pub(crate) frame_clip: Option<usize>,
+
+    /// Scratch paint every draw primitive paints with, reset before each use
+    paint: Paint,
+}
+
+impl SkiaState {
+    fn new(
+        backend: RendererBackend,
+        gr_context: Option<DirectContext>,
+        surface: Surface,
+        width: i32,
+        height: i32,
+        scale_factor: f32,
+    ) -> Self {
+        Self {
+            backend,
+            gr_context,
+            surface,
+            transform_stack: vec![M44::scale(scale_factor, scale_factor, 1.0)],
+            saves: Vec::new(),
+            width,
+            height,
+            scale_factor,
+            samples: 1,
+            frame_clip: None,
+            paint: Paint::default(),
+        }
+    }
+
+    /// Swap in a new surface; its canvas starts without any saved clips or layers
+    fn replace_surface(&mut self, surface: Surface) {
+        self.surface = surface;
+        self.saves.clear();
+        self.frame_clip = None;
+    }
+
+    /// Get the canvas with the current transform applied
+    pub(crate) fn canvas(&mut self) -> &Canvas {
+        let canvas = self.surface.canvas();
+        canvas.set_matrix(&top_transform(&self.transform_stack));
+        canvas
+    }
+
+    /// Get the canvas with the current transform applied, along with the
+    /// scratch paint reset to a plain fill with `antialias` and `blend_mode`
+    ///
+    /// Every draw primitive paints with this one paint rather than building
+    /// and destroying a native paint per draw. It is reset first so that
+    /// nothing set for one draw, such as a stroke or mask filter, carries
+    /// over into the next.
+    pub(crate) fn paint(
+        &mut self,
+        antialias: bool,
+        blend_mode: BlendMode,
+    ) -> (&Canvas, &mut Paint) {
+        self.paint
+            .reset()
+            .set_anti_alias(antialias)
+            .set_blend_mode(blend_mode);
+        let canvas = self.surface.canvas();
+        canvas.set_matrix(&top_transform(&self.transform_stack));
+        (canvas, &mut self.paint)
+    }
+
+    /// Number of saves of `kind` currently pushed
+    pub(crate) fn depth(&self, kind: SaveKind) -> usize {
+        self.saves.iter().filter(|&&save| save == kind).count()
+    }
+
+    /// Restore the innermost save, which must be one of `kind`
+    fn pop_save(&mut self, kind: SaveKind) -> RendererResult {
+        match self.saves.last() {
+            Some(&top) if top == kind => {
+                self.surface.canvas().restore();
+                self.saves.pop();
+                Ok(())
+            }
+            top => {
+                let message = match top {
+                    None => format!("{} called without a matching {}", kind.pop(), kind.push()),
+                    Some(top) => format!(
+                        "{} called while the innermost push is a {}; call {} first",
+                        kind.pop(),
+                        top.push(),
+                        top.pop()
+                    ),
+                };
+                Err(Box::new(RendererError::GeneralError(message)))
+            }
+        }
+    }
+}
+
+/// What a save on the canvas save stack was made for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SaveKind {
+    /// A clip from `push_clip` or `push_rounded_clip`
+    Clip,
+    /// An opacity layer from `push_opacity`
+    Layer,
+}
+
+impl SaveKind {
+    fn push(self) -> &'static str {
+        match self {
+            SaveKind::Clip => "push_clip",
+            SaveKind::Layer => "push_opacity",
+        }
+    }
+
+    fn pop(self) -> &'static str {
+        match self {
+            SaveKind::Clip => "pop_clip",
+            SaveKind::Layer => "pop_opacity",
+        }
+    }
+}
+
+/// Top of a transform stack, or the identity if it is empty
+fn top_transform(transform_stack: &[M44]) -> M44 {
+    transform_stack
+        .last()
+        .cloned()
+        .unwrap_or_else(M44::new_identity)
 }
 
 /// Skia-based renderer implementation
 pub struct SkiaRenderer {
     /// Renderer state
     pub(crate) state: Option<SkiaState>,
+
+    /// Requested surface size, applied when the renderer is initialized
+    size: Option<(i32, i32)>,
+
+    /// Backend requested; GL unless created with `new_raster` or
+    /// `with_backend`, or set up with `init_vulkan`
+    backend: RendererBackend,
+
+    /// Typefaces registered with `register_font`, keyed by family name
+    fonts: HashMap<String, Typeface>,
+
+    /// Color the canvas is cleared to at the start of each frame
+    clear_color: Color4f,
+
+    /// Blend mode applied by the draw primitives
+    blend_mode: BlendMode,
+
+    /// Whether shapes and clips are drawn with anti-aliased edges
+    antialias: bool,
+
+    /// How text edges are drawn
+    text_render_mode: TextRenderMode,
+
+    /// Physical pixels per logical unit, applied when the surface is created
+    scale_factor: f32,
+
+    /// Requested MSAA sample count for GPU surfaces
+    samples: usize,
+
+    /// Frame timing, updated by `begin_frame` and `end_frame`
+    frame_timer: FrameTimer,
+
+    /// Frame rate `end_frame` holds frames down to; unthrottled if `None`
+    target_fps: Option<u32>,
+
+    /// Union of the regions marked dirty since the last `clear_dirty`
+    dirty: Option<Rect>,
 }
 
 // Explicitly implement Send for SkiaRenderer since we control the access to the state
@@ -86,11 +610,134 @@ unsafe impl Send for SkiaRenderer {}
 impl SkiaRenderer {
     /// Create a new Skia renderer
     pub fn new() -> Self {
-        Self { state: None }
+        Self {
+            state: None,
+            size: None,
+            backend: RendererBackend::Gl,
+            fonts: HashMap::new(),
+            clear_color: Color4f::from(Color::WHITE),
+            blend_mode: BlendMode::SrcOver,
+            antialias: true,
+            text_render_mode: TextRenderMode::default(),
+            scale_factor: 1.0,
+            samples: 1,
+            frame_timer: FrameTimer::default(),
+            target_fps: None,
+            dirty: None,
+        }
+    }
+
+    /// Create a new Skia renderer that initializes on `backend`
+    ///
+    /// This picks the backend at runtime; a GPU backend that fails to
+    /// initialize falls back to raster.
+    pub fn with_backend(backend: RendererBackend) -> Self {
+        Self {
+            backend,
+            ..Self::new()
+        }
+    }
+
+    /// Create a new Skia renderer whose surface will be created at the given size
+    pub fn with_size(width: i32, height: i32) -> Self {
+        Self {
+            size: Some((width, height)),
+            ..Self::new()
+        }
+    }
+
+    /// Time frames with `clock` instead of the wall clock, so
+    /// [`FrameStats`] are deterministic under a [`MockClock`](crate::clock::MockClock)
+    ///
+    /// A frame cap still sleeps in real time, for as long as `clock` says
+    /// the frame has left.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.frame_timer = FrameTimer::new(clock);
+        self
+    }
+
+    /// Request `samples` MSAA samples per pixel for the GPU surface
+    ///
+    /// Unsupported counts fall back to the nearest supported one on init.
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Create a renderer backed by a CPU raster surface
+    ///
+    /// Raster surfaces need no GPU or GL context, so the full draw pipeline can
+    /// run in headless environments such as CI.
+    pub fn new_raster(width: i32, height: i32) -> Self {
+        Self {
+            backend: RendererBackend::Raster,
+            ..Self::with_size(width, height)
+        }
+    }
+
+    /// Surface size to use on init, falling back to 800x600
+    fn requested_size(&self) -> (i32, i32) {
+        self.size.unwrap_or((DEFAULT_WIDTH, DEFAULT_HEIGHT))
+    }
+
+    /// Initialize Skia state on the requested backend
+    ///
+    /// `samples` is the MSAA sample count for GL surfaces. Counts other than 1,
+    /// 2, 4, or 8, or above what the driver supports, are lowered to the
+    /// nearest supported count with a warning. Raster surfaces ignore it.
+    ///
+    /// If a GPU backend fails to initialize, the renderer logs why and falls
+    /// back to a raster surface, so it still works without a GPU.
+    pub(crate) fn init_skia(&mut self, width: i32, height: i32, samples: usize) -> RendererResult {
+        validate_dimensions(width, height)?;
+
+        let result = match self.backend {
+            RendererBackend::Raster => return self.init_raster(width, height),
+            RendererBackend::Gl => self.init_gl(width, height, samples),
+            // A Vulkan context can only come from the caller's device
+            #[cfg(feature = "vulkan")]
+            RendererBackend::Vulkan => Err(Box::new(RendererError::InitError(
+                "Vulkan renderers are initialized with init_vulkan".to_string(),
+            )) as Box<dyn std::error::Error + Send>),
+        };
+
+        match result {
+            Ok(()) => {
+                log::info!("Skia renderer using the {:?} backend", self.backend);
+                Ok(())
+            }
+            Err(e) => {
+                log::warn!(
+                    "Skia {:?} backend failed to initialize ({e}), falling back to raster",
+                    self.backend
+                );
+                self.init_raster(width, height)
+            }
+        }
+    }
+
+    /// Initialize onto a CPU raster surface
+    fn init_raster(&mut self, width: i32, height: i32) -> RendererResult {
+        let scale_factor = self.scale_factor;
+        let (physical_width, physical_height) = physical_size(width, height, scale_factor);
+        let surface = Self::create_raster_surface(physical_width, physical_height)?;
+        self.state = Some(SkiaState::new(
+            RendererBackend::Raster,
+            None,
+            surface,
+            width,
+            height,
+            scale_factor,
+        ));
+        log::info!("Skia renderer using the Raster backend");
+        Ok(())
     }
 
-    /// Initialize Skia state
-    pub(crate) fn init_skia(&mut self, width: i32, height: i32) -> RendererResult {
+    /// Initialize onto the default framebuffer of the current GL context
+    fn init_gl(&mut self, width: i32, height: i32, samples: usize) -> RendererResult {
+        let scale_factor = self.scale_factor;
+        let (physical_width, physical_height) = physical_size(width, height, scale_factor);
+
         // Create Skia GL interface
         let interface = Interface::new_native().ok_or_else(|| {
             let err: Box<dyn std::error::Error + Send> = Box::new(RendererError::GlError(
@@ -108,6 +755,136 @@ impl SkiaRenderer {
             err
         })?;
 
+        let max_samples = gr_context.max_surface_sample_count_for_color_type(ColorType::RGBA8888);
+        let samples = supported_samples(samples, max_samples);
+
+        let surface =
+            Self::create_gl_surface(&mut gr_context, physical_width, physical_height, samples)?;
+        let mut state = SkiaState::new(
+            RendererBackend::Gl,
+            Some(gr_context),
+            surface,
+            width,
+            height,
+            scale_factor,
+        );
+        state.samples = samples;
+        self.state = Some(state);
+
+        Ok(())
+    }
+
+    /// Initialize on an existing Vulkan device, drawing into a Vulkan image
+    /// of `width` by `height` logical units that Skia owns
+    ///
+    /// Unlike GL there is no current context to pick up, so the app hands
+    /// over its device and queue in `backend_context`. The renderer then
+    /// uses Vulkan from here on, including after a resize; calling `init`
+    /// before this fails. Finished frames can be read back with
+    /// `encode_png` or sampled from the surface's backend texture.
+    #[cfg(feature = "vulkan")]
+    pub fn init_vulkan(
+        &mut self,
+        backend_context: &skia_safe::gpu::vk::BackendContext,
+        width: i32,
+        height: i32,
+    ) -> RendererResult {
+        validate_dimensions(width, height)?;
+        let scale_factor = self.scale_factor;
+        let (physical_width, physical_height) = physical_size(width, height, scale_factor);
+
+        let mut gr_context = skia_safe::gpu::direct_contexts::make_vulkan(backend_context, None)
+            .ok_or_else(|| {
+                let err: Box<dyn std::error::Error + Send> = Box::new(RendererError::SkiaError(
+                    "Failed to create Vulkan GPU context".to_string(),
+                ));
+                err
+            })?;
+
+        let max_samples = gr_context.max_surface_sample_count_for_color_type(ColorType::RGBA8888);
+        let samples = supported_samples(self.samples, max_samples);
+
+        let surface =
+            Self::create_vulkan_surface(&mut gr_context, physical_width, physical_height, samples)?;
+        let mut state = SkiaState::new(
+            RendererBackend::Vulkan,
+            Some(gr_context),
+            surface,
+            width,
+            height,
+            scale_factor,
+        );
+        state.samples = samples;
+        self.state = Some(state);
+        self.backend = RendererBackend::Vulkan;
+        self.size = Some((width, height));
+
+        Ok(())
+    }
+
+    /// Create a surface drawing into a new Vulkan image
+    #[cfg(feature = "vulkan")]
+    fn create_vulkan_surface(
+        gr_context: &mut DirectContext,
+        width: i32,
+        height: i32,
+        samples: usize,
+    ) -> Result<Surface, Box<dyn std::error::Error + Send>> {
+        let info = ImageInfo::new(
+            (width, height),
+            ColorType::RGBA8888,
+            AlphaType::Premul,
+            None,
+        );
+        skia_safe::gpu::surfaces::render_target(
+            gr_context,
+            skia_safe::gpu::Budgeted::Yes,
+            &info,
+            samples,
+            SurfaceOrigin::TopLeft,
+            None,
+            false,
+            None,
+        )
+        .ok_or_else(|| {
+            let err: Box<dyn std::error::Error + Send> = Box::new(RendererError::SkiaError(
+                "Failed to create Vulkan surface".to_string(),
+            ));
+            err
+        })
+    }
+
+    /// Get the backend the renderer draws with
+    ///
+    /// Once initialized this is the backend actually in use, which is
+    /// `Raster` if the requested GPU backend fell back; before that it is
+    /// the one requested.
+    pub fn active_backend(&self) -> RendererBackend {
+        self.state
+            .as_ref()
+            .map_or(self.backend, |state| state.backend)
+    }
+
+    /// Create a CPU-backed surface
+    fn create_raster_surface(
+        width: i32,
+        height: i32,
+    ) -> Result<Surface, Box<dyn std::error::Error + Send>> {
+        skia_safe::surfaces::raster_n32_premul((width, height)).ok_or_else(|| {
+            let err: Box<dyn std::error::Error + Send> = Box::new(RendererError::SkiaError(
+                "Failed to create raster surface".to_string(),
+            ));
+            err
+        })
+    }
+
+    /// Create a surface wrapping the default framebuffer of an existing GL context
+    fn create_gl_surface(
+        gr_context: &mut DirectContext,
+        width: i32,
+        height: i32,
+        samples: usize,
+    ) -> Result<Surface, Box<dyn std::error::Error + Send>> {
         // Create a framebuffer info struct for GL backend
         let fb_info = FramebufferInfo {
             fboid: 0,       // Use the default framebuffer
@@ -119,7 +896,7 @@ impl SkiaRenderer {
         #[allow(deprecated)]
         let backend_render_target = BackendRenderTarget::new_gl(
             (width, height),
-            1, // samples per pixel
+            samples,
             8, // stencil bits
             fb_info,
         );
@@ -127,7 +904,7 @@ impl SkiaRenderer {
         // Create surface - note the use of the recommended function
         #[allow(deprecated)]
         let surface = Surface::from_backend_render_target(
-            &mut gr_context,
+            gr_context,
             &backend_render_target,
             SurfaceOrigin::BottomLeft,
             ColorType::RGBA8888,
@@ -141,20 +918,95 @@ impl SkiaRenderer {
             err
         })?;
 
-        self.state = Some(SkiaState {
-            gr_context,
-            surface,
-            transform_stack: vec![M44::new_identity()],
-            width,
-            height,
-        });
+        Ok(surface)
+    }
+
+    /// Resize the render surface, keeping the existing GPU context alive
+    ///
+    /// Resizing to the current dimensions is a no-op.
+    pub fn resize(&mut self, width: i32, height: i32) -> RendererResult {
+        let state = self.state.as_mut().ok_or_else(|| {
+            let err: Box<dyn std::error::Error + Send> = Box::new(RendererError::InitError(
+                "Cannot resize before the renderer is initialized".to_string(),
+            ));
+            err
+        })?;
+
+        if state.width == width && state.height == height {
+            return Ok(());
+        }
+        validate_dimensions(width, height)?;
+
+        let scale_factor = state.scale_factor;
+        Self::rebuild_surface(state, width, height, scale_factor)?;
+        self.size = Some((width, height));
+
+        Ok(())
+    }
+
+    /// Recreate the state's surface for a logical size and scale factor
+    fn rebuild_surface(
+        state: &mut SkiaState,
+        width: i32,
+        height: i32,
+        scale_factor: f32,
+    ) -> RendererResult {
+        let (physical_width, physical_height) = physical_size(width, height, scale_factor);
+        let surface = match (state.backend, &mut state.gr_context) {
+            (RendererBackend::Gl, Some(gr_context)) => {
+                Self::create_gl_surface(gr_context, physical_width, physical_height, state.samples)?
+            }
+            #[cfg(feature = "vulkan")]
+            (RendererBackend::Vulkan, Some(gr_context)) => Self::create_vulkan_surface(
+                gr_context,
+                physical_width,
+                physical_height,
+                state.samples,
+            )?,
+            _ => Self::create_raster_surface(physical_width, physical_height)?,
+        };
+        state.replace_surface(surface);
+        state.width = width;
+        state.height = height;
+        Ok(())
+    }
+
+    /// Set the ratio of physical pixels to logical units, e.g. 2.0 on a Retina display
+    ///
+    /// The surface is allocated at the logical size times the scale factor and
+    /// the scale is folded into the base transform, so all drawing, layout,
+    /// text measurement, and hit testing stay in logical units. Changing the
+    /// factor on an initialized renderer recreates the surface and discards any
+    /// pushed transforms, clips, and layers, so call it between frames.
+    pub fn set_scale_factor(&mut self, scale_factor: f32) -> RendererResult {
+        if !scale_factor.is_finite() || scale_factor <= 0.0 {
+            return Err(Box::new(RendererError::GeneralError(format!(
+                "Invalid scale factor {scale_factor}"
+            ))));
+        }
+        self.scale_factor = scale_factor;
 
+        if let Some(state) = &mut self.state {
+            if state.scale_factor != scale_factor {
+                let (width, height) = (state.width, state.height);
+                Self::rebuild_surface(state, width, height, scale_factor)?;
+                state.scale_factor = scale_factor;
+                state.transform_stack = vec![M44::scale(scale_factor, scale_factor, 1.0)];
+            }
+        }
         Ok(())
     }
 
+    /// Get the ratio of physical pixels to logical units
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
     /// Push a transform onto the stack
-    #[allow(dead_code)]
-    fn push_transform(&mut self, transform: M44) {
+    ///
+    /// The transform is combined with the current top of the stack, so nested
+    /// pushes accumulate. Draw calls apply the combined transform to the canvas.
+    pub fn push_transform(&mut self, transform: M44) {
         if let Some(state) = &mut self.state {
             let current = state
                 .transform_stack
@@ -170,8 +1022,9 @@ impl SkiaRenderer {
     }
 
     /// Pop transform from the stack
-    #[allow(dead_code)]
-    fn pop_transform(&mut self) {
+    ///
+    /// The base transform is never popped.
+    pub fn pop_transform(&mut self) {
         if let Some(state) = &mut self.state {
             if state.transform_stack.len() > 1 {
                 state.transform_stack.pop();
@@ -180,134 +1033,2701 @@ impl SkiaRenderer {
     }
 
     /// Get current transform
-    #[allow(dead_code)]
-    fn current_transform(&self) -> M44 {
+    pub fn current_transform(&self) -> M44 {
         self.state
             .as_ref()
             .and_then(|state| state.transform_stack.last().cloned())
             .unwrap_or_else(M44::new_identity)
     }
 
-    /// Render a test circle
-    pub fn draw_test_circle(&mut self) -> RendererResult {
-        let state = match &mut self.state {
-            Some(state) => state,
-            None => {
-                return Err(Box::new(RendererError::GeneralError(
-                    "Renderer not initialized".into(),
-                )))
-            }
-        };
+    /// Get the number of transforms on the stack, including the base transform
+    pub fn transform_depth(&self) -> usize {
+        self.state
+            .as_ref()
+            .map_or(0, |state| state.transform_stack.len())
+    }
 
-        let canvas = state.surface.canvas();
+    /// Run `f` with `transform` pushed, popping it again afterwards
+    pub fn with_transform<F>(&mut self, transform: M44, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.push_transform(transform);
+        f(self);
+        self.pop_transform();
+    }
 
-        // Create a blue-ish paint
-        let mut paint = skia_safe::Paint::new(skia_safe::Color4f::new(0.3, 0.5, 0.8, 1.0), None);
-        paint.set_anti_alias(true);
-        paint.set_style(skia_safe::PaintStyle::Fill);
+    /// Restrict drawing to `rect` until the matching `pop_clip`
+    ///
+    /// The rect is interpreted in the current transform's coordinate space and
+    /// intersected with any clip already in effect.
+    pub fn push_clip(&mut self, rect: Rect) -> RendererResult {
+        let antialias = self.antialias;
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        let canvas = state.canvas();
+        canvas.save();
+        canvas.clip_rect(rect, None, antialias);
+        state.saves.push(SaveKind::Clip);
+        Ok(())
+    }
 
-        // Draw a circle in the center of the canvas
-        let center_x = state.width as f32 / 2.0;
-        let center_y = state.height as f32 / 2.0;
-        let radius = state.width.min(state.height) as f32 / 4.0;
+    /// Remove the clip added by the most recent `push_clip`
+    ///
+    /// Fails if nothing is pushed, or if an opacity layer pushed since the
+    /// clip hasn't been popped yet.
+    pub fn pop_clip(&mut self) -> RendererResult {
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        state.pop_save(SaveKind::Clip)
+    }
+
+    /// Restrict drawing to `rect` with rounded corners until the matching
+    /// `pop_clip`
+    ///
+    /// Radii are given clockwise from the top-left corner, as with
+    /// `draw_round_rect_radii`.
+    pub fn push_rounded_clip(&mut self, rect: Rect, radii: [f32; 4]) -> RendererResult {
+        let antialias = self.antialias;
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        let canvas = state.canvas();
+        let rrect = RRect::new_rect_radii(
+            rect,
+            &radii.map(|radius| Vector::new(radius.max(0.0), radius.max(0.0))),
+        );
+        canvas.save();
+        canvas.clip_rrect(rrect, None, antialias);
+        state.saves.push(SaveKind::Clip);
+        Ok(())
+    }
 
-        canvas.draw_circle(skia_safe::Point::new(center_x, center_y), radius, &paint);
+    /// Get the number of clips currently pushed
+    pub fn clip_depth(&self) -> usize {
+        self.state
+            .as_ref()
+            .map_or(0, |state| state.depth(SaveKind::Clip))
+    }
 
+    /// Composite everything drawn until the matching `pop_opacity` at `alpha`
+    ///
+    /// `alpha` is clamped to `0..=1`. Drawing happens in an offscreen layer,
+    /// so overlapping shapes inside it don't show through each other.
+    pub fn push_opacity(&mut self, alpha: f32) -> RendererResult {
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        state
+            .canvas()
+            .save_layer_alpha_f(None, alpha.clamp(0.0, 1.0));
+        state.saves.push(SaveKind::Layer);
         Ok(())
     }
 
-    /// Draw an animated circle
-    pub fn draw_animated_circle(&mut self, time: f32) {
-        if let Some(state) = &mut self.state {
-            let canvas = &mut state.surface.canvas();
-            canvas.clear(Color::WHITE);
+    /// Composite the layer added by the most recent `push_opacity`
+    ///
+    /// Fails if nothing is pushed, or if a clip pushed since the layer
+    /// hasn't been popped yet.
+    pub fn pop_opacity(&mut self) -> RendererResult {
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        state.pop_save(SaveKind::Layer)
+    }
 
-            let mut paint = Paint::new(Color4f::new(1.0, 0.0, 0.0, 1.0), None);
-            paint.set_anti_alias(true);
+    /// Run `f` between a canvas save and the matching restore
+    ///
+    /// Whatever `f` leaves pushed is unwound afterwards: any transforms,
+    /// clips, and opacity layers it didn't pop are dropped along with the
+    /// save, so calls nest without leaking state into the rest of the frame.
+    pub fn with_save<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let saved = self.state.as_mut().map(|state| {
+            (
+                state.surface.canvas().save(),
+                state.transform_stack.len(),
+                state.saves.len(),
+            )
+        });
+        f(self);
 
-            let r = time.sin() * 0.5 + 0.5;
-            let g = (time + 2.0).sin() * 0.5 + 0.5;
-            let b = (time + 4.0).sin() * 0.5 + 0.5;
-            // Create color from ARGB components
-            let a = 255;
-            let r_byte = (r * 255.0) as u8;
-            let g_byte = (g * 255.0) as u8;
-            let b_byte = (b * 255.0) as u8;
-            let color = Color::from_argb(a, r_byte, g_byte, b_byte);
-            paint.set_color(color);
+        if let (Some(state), Some((save_count, transforms, saves))) = (&mut self.state, saved) {
+            state.surface.canvas().restore_to_count(save_count);
+            state.transform_stack.truncate(transforms);
+            state.saves.truncate(saves);
+        }
+    }
+
+    /// Fill `rect` with corners rounded by `radius`
+    pub fn draw_round_rect(&mut self, rect: Rect, radius: f32, color: Color4f) -> RendererResult {
+        self.draw_round_rect_radii(rect, [radius; 4], color)
+    }
+
+    /// Fill `rect` with independent corner radii
+    ///
+    /// Radii are given clockwise from the top-left corner: top-left,
+    /// top-right, bottom-right, bottom-left. When every radius is zero this is
+    /// an ordinary rect fill.
+    pub fn draw_round_rect_radii(
+        &mut self,
+        rect: Rect,
+        radii: [f32; 4],
+        color: Color4f,
+    ) -> RendererResult {
+        self.draw_styled_round_rect(rect, radii, Some(color), None)
+    }
+
+    /// Fill and/or stroke `rect` with independent corner radii
+    ///
+    /// The fill is drawn first so the stroke sits on top of it. The stroke is
+    /// centered on the edge of `rect`, as with any Skia stroke.
+    pub fn draw_styled_round_rect(
+        &mut self,
+        rect: Rect,
+        radii: [f32; 4],
+        fill: Option<Color4f>,
+        stroke: Option<Stroke>,
+    ) -> RendererResult {
+        let (canvas, paint) = self.base_paint()?;
+
+        let radii = radii.map(|radius| radius.max(0.0));
+        let rrect = if radii.iter().all(|&radius| radius == 0.0) {
+            None
+        } else {
+            Some(RRect::new_rect_radii(
+                rect,
+                &radii.map(|radius| Vector::new(radius, radius)),
+            ))
+        };
+
+        let draw = |paint: &Paint| match &rrect {
+            Some(rrect) => {
+                canvas.draw_rrect(rrect, paint);
+            }
+            None => {
+                canvas.draw_rect(rect, paint);
+            }
+        };
+
+        if let Some(color) = fill {
+            paint.set_color4f(color, None);
+            draw(paint);
+        }
 
-            canvas.draw_circle((200.0, 200.0), 100.0, &paint);
+        // A zero stroke width means hairline to Skia, but nothing to us
+        if let Some(stroke) = stroke.filter(|stroke| stroke.width > 0.0) {
+            paint.set_color4f(stroke.color, None);
+            paint.set_style(PaintStyle::Stroke);
+            paint.set_stroke_width(stroke.width);
+            draw(paint);
         }
+
+        Ok(())
     }
-}
 
-impl Default for SkiaRenderer {
-    fn default() -> Self {
-        Self::new()
+    /// Fill `rect` with a linear gradient running from `start` to `end`
+    ///
+    /// Each stop is a `(position, color)` pair with the position in `0..=1`.
+    pub fn draw_linear_gradient(
+        &mut self,
+        rect: Rect,
+        start: Point,
+        end: Point,
+        stops: &[(f32, Color)],
+    ) -> RendererResult {
+        let (colors, positions) = gradient_stops(stops)?;
+        let shader = gradient_shader::linear(
+            (start, end),
+            colors.as_slice(),
+            positions.as_slice(),
+            TileMode::Clamp,
+            None,
+            None,
+        );
+        self.fill_with_shader(rect, shader)
     }
-}
 
-// Implement the Renderer trait for SkiaRenderer
-impl crate::renderer::Renderer for SkiaRenderer {
-    fn init(&mut self) -> Result<(), crate::Error> {
-        // Use default dimensions for now
+    /// Fill `rect` with a radial gradient centered on `center`
+    ///
+    /// Each stop is a `(position, color)` pair with the position in `0..=1`.
+    pub fn draw_radial_gradient(
+        &mut self,
+        rect: Rect,
+        center: Point,
+        radius: f32,
+        stops: &[(f32, Color)],
+    ) -> RendererResult {
+        let (colors, positions) = gradient_stops(stops)?;
+        let shader = gradient_shader::radial(
+            center,
+            radius,
+            colors.as_slice(),
+            positions.as_slice(),
+            TileMode::Clamp,
+            None,
+            None,
+        );
+        self.fill_with_shader(rect, shader)
+    }
+
+    fn fill_with_shader(&mut self, rect: Rect, shader: Option<Shader>) -> RendererResult {
+        let shader = shader.ok_or_else(|| -> Box<dyn std::error::Error + Send> {
+            Box::new(RendererError::SkiaError(
+                "Failed to create gradient shader".into(),
+            ))
+        })?;
+        let (canvas, paint) = self.base_paint()?;
+        paint.set_shader(shader);
+        canvas.draw_rect(rect, paint);
+
+        Ok(())
+    }
+
+    /// Draw a drop shadow for a rounded rect
+    ///
+    /// `blur` is a CSS-style blur radius; a blur of zero draws a hard-edged
+    /// copy of the shape shifted by `offset`. Draw the shadow before the shape
+    /// it belongs to so the shape covers it.
+    pub fn draw_shadow(
+        &mut self,
+        rect: Rect,
+        radius: f32,
+        blur: f32,
+        offset: Point,
+        color: Color,
+    ) -> RendererResult {
+        self.draw_shadow_radii(rect, [radius; 4], blur, offset, color)
+    }
+
+    /// Draw a style `BoxShadow` for a rounded rect with the given corner radii
+    ///
+    /// Inset shadows are not supported yet and are skipped.
+    pub fn draw_box_shadow(
+        &mut self,
+        rect: Rect,
+        radii: [f32; 4],
+        shadow: &crate::style::BoxShadow,
+    ) -> RendererResult {
+        if shadow.inset {
+            return Ok(());
+        }
+        let Some(color) = to_color4f(&shadow.color) else {
+            return Ok(());
+        };
+
+        let spread = shadow.spread_radius;
+        self.draw_shadow_radii(
+            rect.with_outset((spread, spread)),
+            radii.map(|radius| (radius + spread).max(0.0)),
+            shadow.blur_radius,
+            Point::new(shadow.offset_x, shadow.offset_y),
+            color.to_color(),
+        )
+    }
+
+    fn draw_shadow_radii(
+        &mut self,
+        rect: Rect,
+        radii: [f32; 4],
+        blur: f32,
+        offset: Point,
+        color: Color,
+    ) -> RendererResult {
+        let (canvas, paint) = self.base_paint()?;
+        paint.set_color(color);
+        if blur > 0.0 {
+            // Skia blurs by standard deviation, which is half the CSS blur radius
+            paint.set_mask_filter(MaskFilter::blur(BlurStyle::Normal, blur / 2.0, None));
+        }
+
+        let rect = rect.with_offset(offset);
+        let radii = radii.map(|radius| radius.max(0.0));
+        if radii.iter().all(|&radius| radius == 0.0) {
+            canvas.draw_rect(rect, paint);
+        } else {
+            let rrect =
+                RRect::new_rect_radii(rect, &radii.map(|radius| Vector::new(radius, radius)));
+            canvas.draw_rrect(rrect, paint);
+        }
+
+        Ok(())
+    }
+
+    /// Fill the shape described by SVG path data, e.g. `"M0 0 L10 0 L10 10 Z"`
+    ///
+    /// `transform` is applied on top of the current transform, which makes it
+    /// easy to scale an icon's path into place.
+    pub fn draw_path(
+        &mut self,
+        svg_path: &str,
+        color: Color4f,
+        transform: Option<M44>,
+    ) -> RendererResult {
+        // Skia's parser takes a C string, so an embedded NUL can never be valid
+        let path = if svg_path.contains('\0') {
+            None
+        } else {
+            SkPath::from_svg(svg_path)
+        };
+        let path = path.ok_or_else(|| -> Box<dyn std::error::Error + Send> {
+            let segment = invalid_svg_segment(&svg_path.replace('\0', ""));
+            Box::new(RendererError::GeneralError(format!(
+                "Invalid SVG path data near '{segment}'"
+            )))
+        })?;
+
         if self.state.is_none() {
-            self.init_skia(800, 600)
-                .map_err(|e| crate::Error::Renderer(format!("{e}")))?;
+            return Err(not_initialized());
+        }
+        self.push_transform(transform.unwrap_or_else(M44::new_identity));
+        if let Ok((canvas, paint)) = self.base_paint() {
+            paint.set_color4f(color, None);
+            canvas.draw_path(&path, paint);
         }
+        self.pop_transform();
+
         Ok(())
     }
 
-    fn render(&mut self, _root: &Node, _context: &mut RenderContext) -> Result<(), crate::Error> {
-        // Initialize if not already done
+    /// Draw an RGBA8888 image scaled to fill `dest`
+    ///
+    /// `pixels` holds `img_w * img_h` unpremultiplied pixels in row-major
+    /// order, four bytes each.
+    pub fn draw_image(
+        &mut self,
+        pixels: &[u8],
+        img_w: i32,
+        img_h: i32,
+        dest: Rect,
+        sampling: ImageSampling,
+    ) -> RendererResult {
+        self.paint_image(pixels, img_w, img_h, None, dest, sampling, 0.0)
+    }
+
+    /// Draw the `src` part of an RGBA8888 image, in image pixels, scaled
+    /// to fill `dest`
+    pub fn draw_image_region(
+        &mut self,
+        pixels: &[u8],
+        img_w: i32,
+        img_h: i32,
+        src: Rect,
+        dest: Rect,
+        sampling: ImageSampling,
+    ) -> RendererResult {
+        self.paint_image(pixels, img_w, img_h, Some(src), dest, sampling, 0.0)
+    }
+
+    /// Draw an image, softened by a CSS-style `blur` radius when non-zero
+    #[allow(clippy::too_many_arguments)]
+    fn paint_image(
+        &mut self,
+        pixels: &[u8],
+        img_w: i32,
+        img_h: i32,
+        src: Option<Rect>,
+        dest: Rect,
+        sampling: ImageSampling,
+        blur: f32,
+    ) -> RendererResult {
+        let invalid = |msg: String| -> Box<dyn std::error::Error + Send> {
+            Box::new(RendererError::GeneralError(msg))
+        };
+
+        if img_w <= 0 || img_h <= 0 {
+            return Err(invalid(format!("Invalid image dimensions {img_w}x{img_h}")));
+        }
+        let expected = img_w as usize * img_h as usize * 4;
+        if pixels.len() != expected {
+            return Err(invalid(format!(
+                "Image data is {} bytes, expected {expected} for {img_w}x{img_h} RGBA",
+                pixels.len()
+            )));
+        }
+
         if self.state.is_none() {
-            // Use default dimensions for now
-            self.init_skia(800, 600)
-                .map_err(|e| crate::Error::Renderer(format!("{e}")))?;
+            return Err(not_initialized());
+        }
+        let info = ImageInfo::new(
+            (img_w, img_h),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let image = images::raster_from_data(&info, Data::new_copy(pixels), img_w as usize * 4)
+            .ok_or_else(|| invalid("Failed to create image from pixel data".into()))?;
+
+        let (canvas, paint) = self.base_paint()?;
+        if blur > 0.0 {
+            // Skia blurs by standard deviation, which is half the CSS blur radius
+            paint.set_image_filter(image_filters::blur(
+                (blur / 2.0, blur / 2.0),
+                TileMode::Clamp,
+                None,
+                None::<image_filters::CropRect>,
+            ));
         }
+        canvas.draw_image_rect_with_sampling_options(
+            image,
+            src.as_ref().map(|src| (src, SrcRectConstraint::Fast)),
+            dest,
+            sampling,
+            paint,
+        );
 
-        // Simple implementation - just draw a test circle for now
-        self.draw_test_circle()
-            .map_err(|e| crate::Error::Renderer(format!("{e}")))
+        Ok(())
     }
 
-    fn flush(&mut self) -> Result<(), crate::Error> {
-        // Skia surface doesn't have a flush method like we were expecting
-        // Instead, we'll create a snapshot which will finalize any pending drawing operations
+    /// Paint a node and its descendants depth-first
+    ///
+    /// Each node's layout rect is relative to its parent, so a translation to
+    /// the node's origin is pushed for the duration of its subtree. Nodes with
+    /// an opacity below 1 are painted into an opacity layer. Hidden nodes
+    /// keep the space layout gave them but paint nothing, nor do their
+    /// descendants; undisplayed nodes have no space to keep.
+    fn render_node(&mut self, node: &Node, ctx: &RenderContext) -> RendererResult {
+        let opacity = node
+            .style()
+            .opacity
+            .map_or(1.0, |opacity| opacity.clamp(0.0, 1.0));
+        let hidden = matches!(
+            node.style().visibility,
+            Some(Visibility::Hidden | Visibility::Collapse)
+        );
+        // Fully transparent and hidden subtrees can't contribute any pixels
+        if opacity == 0.0 || hidden || !node.is_displayed() {
+            return Ok(());
+        }
+
+        let rect = node.layout_rect();
+        self.push_transform(M44::translate(rect.x(), rect.y(), 0.0));
+
+        let layered = opacity < 1.0;
+        if layered {
+            self.push_opacity(opacity)?;
+        }
+        let result = self.paint_node(node, ctx);
+        if layered {
+            self.pop_opacity()?;
+        }
+
+        self.pop_transform();
+        result
+    }
+
+    /// Paint a node's own decoration, text, and children in its local space
+    fn paint_node(&mut self, node: &Node, ctx: &RenderContext) -> RendererResult {
+        let rect = node.layout_rect();
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        let bounds = Rect::from_wh(rect.width(), rect.height());
+        let scale = state.scale_factor;
+        let visible = intersects_viewport(state.canvas(), &bounds, ctx, scale);
+
+        // The node's blend mode applies to its own painting, not its children
+        let blend_mode = node
+            .style()
+            .mix_blend_mode
+            .map_or(self.blend_mode, to_blend_mode);
+        let previous = std::mem::replace(&mut self.blend_mode, blend_mode);
+        let decorated = self.paint_decoration(node, bounds, visible);
+        self.blend_mode = previous;
+        decorated?;
+
+        // Nested clips intersect, so a child never paints outside any
+        // clipping ancestor
+        let clips = node.style().overflow.is_some_and(Overflow::clips);
+        if clips {
+            self.push_clip(bounds)?;
+        }
+
+        // Children may overflow their parent, so they are always visited
+        let result = node
+            .children_in_paint_order()
+            .into_iter()
+            .try_for_each(|child| self.render_node(child, ctx));
+
+        if clips {
+            self.pop_clip()?;
+        }
+        result
+    }
+
+    /// Paint a node's shadows, background, image, border, path, and text
+    fn paint_decoration(&mut self, node: &Node, bounds: Rect, visible: bool) -> RendererResult {
+        let style = node.style();
+        if visible {
+            let radii = style.border_radius.as_ref().map_or([0.0; 4], |r| {
+                [r.top_left, r.top_right, r.bottom_right, r.bottom_left]
+            });
+
+            // Outer shadows sit behind the node's own fill
+            for shadow in style.box_shadow.iter().flatten() {
+                self.draw_box_shadow(bounds, radii, shadow)?;
+            }
+
+            let fill = style.background_color.as_ref().and_then(to_color4f);
+            let stroke = border_stroke(style);
+            if fill.is_some() {
+                self.draw_styled_round_rect(bounds, radii, fill, None)?;
+            }
+
+            // Gradients paint over the fill, clipped to the corners
+            if let Some(gradient) = &style.background_gradient {
+                let ((x0, y0), (x1, y1)) = gradient.endpoints(bounds.width(), bounds.height());
+                let stops: Vec<(f32, Color)> = gradient
+                    .stops
+                    .iter()
+                    .map(|(position, color)| {
+                        let color = to_color4f(color).map_or(Color::TRANSPARENT, Color4f::to_color);
+                        (*position, color)
+                    })
+                    .collect();
+                self.push_rounded_clip(bounds, radii)?;
+                let drawn = self.draw_linear_gradient(
+                    bounds,
+                    Point::new(bounds.left() + x0, bounds.top() + y0),
+                    Point::new(bounds.left() + x1, bounds.top() + y1),
+                    &stops,
+                );
+                self.pop_clip()?;
+                drawn?;
+            }
+
+            // Images are scaled into the node as its fit directs, clipped
+            // to its corner radius, beneath the border. Invalid images are
+            // skipped so whatever fallback the node shows stays visible.
+            if let Some(image) = node.image().filter(|image| image.is_valid()) {
+                let fit_bounds =
+                    LayoutRect::new(bounds.left(), bounds.top(), bounds.width(), bounds.height());
+                let (src, dest) = node
+                    .image_fit()
+                    .rects(image.width, image.height, fit_bounds);
+                let blur = style
+                    .filter
+                    .iter()
+                    .flatten()
+                    .fold(0.0, |blur, filter| match filter {
+                        Filter::Blur(radius) => blur + radius,
+                        _ => blur,
+                    });
+                self.push_rounded_clip(bounds, radii)?;
+                let drawn = self.paint_image(
+                    &image.pixels,
+                    image.width as i32,
+                    image.height as i32,
+                    Some(Rect::from_xywh(src.x(), src.y(), src.width(), src.height())),
+                    Rect::from_xywh(dest.x(), dest.y(), dest.width(), dest.height()),
+                    ImageSampling::Linear,
+                    blur,
+                );
+                self.pop_clip()?;
+                drawn?;
+            }
+
+            if let Some(stroke) = stroke {
+                // Inset the border so it's drawn inside the node's bounds
+                let border_rect = bounds.with_inset((stroke.width / 2.0, stroke.width / 2.0));
+                self.draw_styled_round_rect(border_rect, radii, None, Some(stroke))?;
+            }
+
+            // Outlines, such as the focus ring, sit outside the bounds and
+            // follow the corners out by the same distance
+            if let Some(outline) = &style.outline {
+                if let Some(color) = to_color4f(&outline.color) {
+                    let outset = outline.offset + outline.width / 2.0;
+                    self.draw_styled_round_rect(
+                        bounds.with_outset((outset, outset)),
+                        radii.map(|radius| if radius > 0.0 { radius + outset } else { 0.0 }),
+                        None,
+                        Some(Stroke::new(outline.width, color)),
+                    )?;
+                }
+            }
+        }
+
+        // The background and border cover the padding, but paths and text
+        // sit in the content box inside it
+        let padding = style.padding();
+        let content = Rect::from_xywh(
+            bounds.left() + padding.left,
+            bounds.top() + padding.top,
+            (bounds.width() - padding.horizontal()).max(0.0),
+            (bounds.height() - padding.vertical()).max(0.0),
+        );
+
+        // Icon-style nodes carry SVG path data, filled with the text color
+        if let Some(path) = node.attributes().get("path") {
+            let color = style
+                .color
+                .as_ref()
+                .and_then(to_color4f)
+                .unwrap_or_else(|| Color4f::from(Color::BLACK));
+            self.push_transform(M44::translate(content.left(), content.top(), 0.0));
+            let drawn = self.draw_path(path, color, None);
+            self.pop_transform();
+            drawn?;
+        }
+
+        if let Some(text) = node.text().filter(|text| !text.is_empty()) {
+            let size = style.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+            let font = self.font(style.font_family.as_deref(), size);
+            let color = style
+                .color
+                .as_ref()
+                .and_then(to_color4f)
+                .map_or(Color::BLACK, |color| color.to_color());
+
+            // Lines are broken and aligned within the content box, each
+            // baseline one ascent below the line's top
+            let lines = layout_text(text, size, content.width(), style, &FontMeasure(&font));
+            let (_, metrics) = font.metrics();
+            for line in lines {
+                let x = content.left() + line.x;
+                let y = content.top() + line.y - metrics.ascent;
+                if line.word_spacing > 0.0 {
+                    // Justified lines are drawn a word at a time, spread out
+                    let space = font.measure_str(" ", None).0 + line.word_spacing;
+                    let mut x = x;
+                    for word in line.text.split(' ') {
+                        self.draw_str(word, x, y, &font, color)?;
+                        x += font.measure_str(word, None).0 + space;
+                    }
+                } else {
+                    self.draw_str(&line.text, x, y, &font, color)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encode the current contents of the surface as PNG bytes
+    pub fn encode_png(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send>> {
+        let state = self.state.as_ref().ok_or_else(not_initialized)?;
+
+        // Surface and context handles are reference counted, so cloning them
+        // lets us snapshot without requiring `&mut self`
+        let image = state.surface.clone().image_snapshot();
+        let mut gr_context = state.gr_context.clone();
+        let data = image
+            .encode(gr_context.as_mut(), EncodedImageFormat::PNG, None)
+            .ok_or_else(|| -> Box<dyn std::error::Error + Send> {
+                Box::new(RendererError::GeneralError(
+                    "Failed to encode surface as PNG".into(),
+                ))
+            })?;
+
+        Ok(data.as_bytes().to_vec())
+    }
+
+    /// Write the current contents of the surface to `path` as a PNG file
+    pub fn save_png(&self, path: &Path) -> RendererResult {
+        let bytes = self.encode_png()?;
+        std::fs::write(path, bytes).map_err(|e| -> Box<dyn std::error::Error + Send> {
+            Box::new(RendererError::GeneralError(format!(
+                "Failed to write {}: {e}",
+                path.display()
+            )))
+        })
+    }
+
+    /// Read the current contents of the surface into `buf` as unpremultiplied
+    /// RGBA, row by row from the top left
+    ///
+    /// `buf` must hold exactly `width * height * 4` bytes for the surface's
+    /// size in physical pixels. Unlike [`SkiaRenderer::encode_png`] nothing is
+    /// allocated, so a capture loop can reuse one buffer for every frame.
+    pub fn read_pixels(&self, buf: &mut [u8]) -> RendererResult {
+        let state = self.state.as_ref().ok_or_else(not_initialized)?;
+        let (width, height) = (state.surface.width(), state.surface.height());
+        let expected = width as usize * height as usize * 4;
+        if buf.len() != expected {
+            return Err(Box::new(RendererError::GeneralError(format!(
+                "Pixel buffer holds {} bytes but a {width}x{height} surface needs {expected}",
+                buf.len()
+            ))));
+        }
+
+        // As in `encode_png`, a cloned handle reads without `&mut self`
+        let info = ImageInfo::new(
+            (width, height),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        if !state
+            .surface
+            .clone()
+            .read_pixels(&info, buf, width as usize * 4, (0, 0))
+        {
+            return Err(Box::new(RendererError::GeneralError(
+                "Failed to read surface pixels".into(),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Set the color the canvas is cleared to at the start of each frame
+    ///
+    /// Defaults to white. A fully transparent color leaves the surface
+    /// transparent so it can be composited over other layers.
+    pub fn set_clear_color(&mut self, color: Color4f) {
+        self.clear_color = color;
+    }
+
+    /// Get the color the canvas is cleared to at the start of each frame
+    pub fn clear_color(&self) -> Color4f {
+        self.clear_color
+    }
+
+    /// Mark `rect` (in logical units) as needing a redraw
+    ///
+    /// Marked regions accumulate until `clear_dirty` is called.
+    pub fn mark_dirty(&mut self, rect: Rect) {
+        match &mut self.dirty {
+            Some(region) => region.join(rect),
+            None => self.dirty = Some(rect),
+        }
+    }
+
+    /// Forget all regions marked dirty, typically after `end_frame`
+    pub fn clear_dirty(&mut self) {
+        self.dirty = None;
+    }
+
+    /// Get the union of the regions marked dirty, if any
+    pub fn dirty_region(&self) -> Option<Rect> {
+        self.dirty
+    }
+
+    /// Start a new frame by clearing the canvas to the clear color
+    ///
+    /// If any regions are marked dirty, clearing and all drawing until
+    /// `end_frame` are clipped to their union; otherwise the whole surface is
+    /// redrawn.
+    pub fn begin_frame(&mut self) -> RendererResult {
+        let clear_color = self.clear_color;
+        let dirty = self.dirty;
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+
+        // Drop a dirty clip left behind by a frame that was never ended
+        if let Some(count) = state.frame_clip.take() {
+            state.surface.canvas().restore_to_count(count);
+        }
+
+        match dirty {
+            Some(region) => {
+                state.frame_clip = Some(state.canvas().save());
+                let canvas = state.canvas();
+                canvas.clip_rect(region, None, true);
+                canvas.clear(clear_color);
+            }
+            None => {
+                state.surface.canvas().clear(clear_color);
+            }
+        }
+
+        self.frame_timer.begin();
+        Ok(())
+    }
+
+    /// Finish the current frame, flushing pending drawing and recording its duration
+    pub fn end_frame(&mut self) -> RendererResult {
         if let Some(state) = &mut self.state {
-            // Create a snapshot to ensure drawing operations are completed
-            let _ = state.surface.image_snapshot();
-            Ok(())
-        } else {
-            Ok(()) // No state to flush
+            if let Some(count) = state.frame_clip.take() {
+                state.surface.canvas().restore_to_count(count);
+            }
         }
+        crate::renderer::Renderer::flush(self).map_err(from_framework_error)?;
+
+        // Sleep off whatever the frame has left of its interval, so time
+        // spent drawing counts towards it
+        if let (Some(fps), Some(elapsed)) = (self.target_fps, self.frame_timer.elapsed()) {
+            let interval = Duration::from_secs_f64(1.0 / f64::from(fps));
+            if let Some(remaining) = interval.checked_sub(elapsed) {
+                std::thread::sleep(remaining);
+            }
+        }
+
+        let gpu_memory_bytes = self.gpu_memory_usage();
+        self.frame_timer.end(gpu_memory_bytes);
+        Ok(())
     }
 
-    fn cleanup(&mut self) -> Result<(), crate::Error> {
-        // Set state to None to drop all resources
-        self.state = None;
+    /// Get timing statistics for completed frames
+    pub fn frame_stats(&self) -> FrameStats {
+        self.frame_timer.stats
+    }
+
+    /// Cap the frame rate at `fps` frames per second, or `None` to run
+    /// unthrottled
+    ///
+    /// `end_frame` sleeps until a frame has lasted `1 / fps` seconds, so a
+    /// render loop doesn't spin flat out; [`FrameStats::avg_fps`] then
+    /// reports the achieved rate. A cap of zero counts as `None`.
+    pub fn set_target_fps(&mut self, fps: Option<u32>) {
+        self.target_fps = fps.filter(|&fps| fps > 0);
+    }
+
+    /// Get the frame rate cap
+    pub fn target_fps(&self) -> Option<u32> {
+        self.target_fps
+    }
+
+    /// Get the bytes held in Skia's GPU resource cache
+    ///
+    /// `None` on the raster backend, or before the renderer is initialized.
+    /// Steady growth over a long session points at a resource leak.
+    pub fn gpu_memory_usage(&self) -> Option<usize> {
+        let gr_context = self.state.as_ref()?.gr_context.as_ref()?;
+        Some(gr_context.resource_cache_usage().resource_bytes)
+    }
+
+    /// Get the most bytes Skia's GPU resource cache may hold before it
+    /// starts evicting; `None` on the raster backend
+    pub fn gpu_memory_limit(&self) -> Option<usize> {
+        let gr_context = self.state.as_ref()?.gr_context.as_ref()?;
+        Some(gr_context.resource_cache_limit())
+    }
+
+    /// Free GPU resources that nothing is using, such as cached textures of
+    /// images no longer drawn
+    ///
+    /// Does nothing on the raster backend.
+    pub fn purge_unused_gpu_resources(&mut self) {
+        if let Some(gr_context) = self
+            .state
+            .as_mut()
+            .and_then(|state| state.gr_context.as_mut())
+        {
+            gr_context.purge_unlocked_resources(PurgeResourceOptions::AllResources);
+        }
+    }
+
+    /// Set the blend mode used by subsequent draw calls
+    ///
+    /// Defaults to `BlendMode::SrcOver`, i.e. normal alpha compositing.
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Get the blend mode used by draw calls
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Run `f` with `blend_mode` active, restoring the previous mode afterwards
+    pub fn with_blend_mode<F>(&mut self, blend_mode: BlendMode, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let previous = std::mem::replace(&mut self.blend_mode, blend_mode);
+        f(self);
+        self.blend_mode = previous;
+    }
+
+    /// Turn anti-aliasing of shape and clip edges on or off
+    ///
+    /// On by default. Turning it off gives hard, pixel-stepped edges, for
+    /// pixel art or crisp hairlines.
+    pub fn set_antialias(&mut self, enabled: bool) {
+        self.antialias = enabled;
+    }
+
+    /// Get whether shape and clip edges are anti-aliased
+    pub fn antialias(&self) -> bool {
+        self.antialias
+    }
+
+    /// The canvas and the scratch paint, reset to the current blend mode and
+    /// anti-aliasing setting
+    fn base_paint(&mut self) -> Result<(&Canvas, &mut Paint), Box<dyn std::error::Error + Send>> {
+        let (antialias, blend_mode) = (self.antialias, self.blend_mode);
+        let state = self.state.as_mut().ok_or_else(not_initialized)?;
+        Ok(state.paint(antialias, blend_mode))
+    }
+
+    /// Register a font from raw font file bytes under `family`
+    ///
+    /// Registered families take precedence over system fonts of the same name.
+    pub fn register_font(&mut self, data: &[u8], family: &str) -> RendererResult {
+        let typeface = FontMgr::new().new_from_data(data, None).ok_or_else(|| {
+            let err: Box<dyn std::error::Error + Send> = Box::new(RendererError::GeneralError(
+                format!("Failed to load font data for family '{family}'"),
+            ));
+            err
+        })?;
+        self.fonts.insert(family.to_string(), typeface);
         Ok(())
     }
 
-    fn name(&self) -> &str {
-        "SkiaRenderer"
+    /// Build a font for `family`, falling back to the system default typeface
+    fn font(&self, family: Option<&str>, size: f32) -> Font {
+        let font_mgr = FontMgr::new();
+        let typeface = family
+            .and_then(|family| {
+                self.fonts
+                    .get(family)
+                    .cloned()
+                    .or_else(|| font_mgr.match_family_style(family, FontStyle::normal()))
+            })
+            .or_else(|| font_mgr.legacy_make_typeface(None, FontStyle::normal()));
+
+        let mut font = match typeface {
+            Some(typeface) => Font::from_typeface(typeface, size),
+            None => {
+                let mut font = Font::default();
+                font.set_size(size);
+                font
+            }
+        };
+        self.text_render_mode.apply(&mut font);
+        font
     }
-}
 
-// Implement From for common error conversions to allow ? operator
-impl From<String> for RendererError {
-    fn from(error: String) -> Self {
-        RendererError::GeneralError(error)
+    /// Set how text edges are drawn; see [`TextRenderMode`] for the tradeoffs
+    ///
+    /// Defaults to `TextRenderMode::SubpixelAntialias`. Text is measured in
+    /// the same mode, since subpixel positioning changes advance widths.
+    pub fn set_text_render_mode(&mut self, mode: TextRenderMode) {
+        self.text_render_mode = mode;
     }
-}
 
-// This allows for converting errors when using the ? operator with the renderer
-// Add specific converters for common error types instead of a blanket implementation
-// to avoid conflicts with the built-in From<T> for T implementation
-impl From<std::io::Error> for RendererError {
-    fn from(error: std::io::Error) -> Self {
-        RendererError::GeneralError(error.to_string())
+    /// Get how text edges are drawn
+    pub fn text_render_mode(&self) -> TextRenderMode {
+        self.text_render_mode
     }
-}
 
-// Additional specific conversions can be added as needed
+    /// Draw `text` with its baseline starting at (`x`, `y`) using the default font
+    pub fn draw_text(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+    ) -> RendererResult {
+        if text.is_empty() {
+            return Ok(());
+        }
+        let font = self.font(None, size);
+        self.draw_str(text, x, y, &font, color)
+    }
+
+    /// Measure the advance width and line height of `text` in the default font
+    ///
+    /// This only consults font data, so it works before `init()`.
+    pub fn measure_text(&self, text: &str, size: f32) -> (f32, f32) {
+        let font = self.font(None, size);
+        let line_height = font.spacing();
+        if text.is_empty() {
+            return (0.0, line_height);
+        }
+        let (width, _bounds) = font.measure_str(text, None);
+        (width, line_height)
+    }
+
+    fn draw_str(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        font: &Font,
+        color: Color,
+    ) -> RendererResult {
+        let (canvas, paint) = self.base_paint()?;
+        paint.set_color(color);
+        canvas.draw_str(text, (x, y), font, paint);
+        Ok(())
+    }
+
+    /// Render a test circle
+    pub fn draw_test_circle(&mut self) -> RendererResult {
+        let state = self.state.as_ref().ok_or_else(not_initialized)?;
+
+        // Draw a blue-ish circle in the center of the canvas
+        let center_x = state.width as f32 / 2.0;
+        let center_y = state.height as f32 / 2.0;
+        let radius = state.width.min(state.height) as f32 / 4.0;
+
+        let (canvas, paint) = self.base_paint()?;
+        paint.set_color4f(Color4f::new(0.3, 0.5, 0.8, 1.0), None);
+        canvas.draw_circle(Point::new(center_x, center_y), radius, paint);
+
+        Ok(())
+    }
+
+    /// Draw an animated circle
+    pub fn draw_animated_circle(&mut self, time: f32) {
+        let (clear_color, antialias, blend_mode) =
+            (self.clear_color, self.antialias, self.blend_mode);
+        if let Some(state) = &mut self.state {
+            state.canvas().clear(clear_color);
+
+            let r = time.sin() * 0.5 + 0.5;
+            let g = (time + 2.0).sin() * 0.5 + 0.5;
+            let b = (time + 4.0).sin() * 0.5 + 0.5;
+            // Create color from ARGB components
+            let a = 255;
+            let r_byte = (r * 255.0) as u8;
+            let g_byte = (g * 255.0) as u8;
+            let b_byte = (b * 255.0) as u8;
+            let color = Color::from_argb(a, r_byte, g_byte, b_byte);
+
+            let (canvas, paint) = state.paint(antialias, blend_mode);
+            paint.set_color(color);
+            canvas.draw_circle((200.0, 200.0), 100.0, paint);
+        }
+    }
+}
+
+impl Default for SkiaRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Measures with a font already at the size being laid out
+struct FontMeasure<'a>(&'a Font);
+
+impl TextMeasure for FontMeasure<'_> {
+    fn measure_text(&self, text: &str, _size: f32) -> (f32, f32) {
+        let width = if text.is_empty() {
+            0.0
+        } else {
+            self.0.measure_str(text, None).0
+        };
+        (width, self.0.spacing())
+    }
+}
+
+impl TextMeasure for SkiaRenderer {
+    fn measure_text(&self, text: &str, size: f32) -> (f32, f32) {
+        SkiaRenderer::measure_text(self, text, size)
+    }
+}
+
+// Build a renderer from the message that would initialize it on the render thread
+impl TryFrom<RendererMessage> for SkiaRenderer {
+    type Error = RendererError;
+
+    fn try_from(message: RendererMessage) -> Result<Self, Self::Error> {
+        match message {
+            RendererMessage::Init { width, height } => Ok(Self::with_size(width, height)),
+            _ => Err(RendererError::InitError(
+                "Expected RendererMessage::Init".to_string(),
+            )),
+        }
+    }
+}
+
+impl SkiaRenderer {
+    /// Apply a single renderer message
+    pub fn handle_message(&mut self, msg: RendererMessage) -> RendererResult {
+        use crate::renderer::Renderer;
+
+        match msg {
+            RendererMessage::Init { width, height } => {
+                self.size = Some((width, height));
+                self.init_skia(width, height, self.samples)
+            }
+            RendererMessage::Resize { width, height } => self.resize(width, height),
+            RendererMessage::BeginFrame => self.begin_frame(),
+            RendererMessage::Render { node } => {
+                let state = self.state.as_ref().ok_or_else(not_initialized)?;
+                let mut context = RenderContext::new(state.width as u32, state.height as u32);
+                self.render(&node, &mut context)
+                    .map_err(from_framework_error)
+            }
+            RendererMessage::EndFrame => self.end_frame(),
+            RendererMessage::SetTargetFps(fps) => {
+                self.set_target_fps(fps);
+                Ok(())
+            }
+            RendererMessage::Shutdown => self.cleanup().map_err(from_framework_error),
+        }
+    }
+
+    /// Process messages until `Shutdown` is handled or the sender hangs up
+    ///
+    /// `SkiaRenderer` is `Send`, so this is intended to run on a dedicated
+    /// render thread, which [`RendererHandle`] sets up. The first failing
+    /// message stops the loop.
+    pub fn run_loop(&mut self, receiver: Receiver<RendererMessage>) -> RendererResult {
+        for msg in receiver {
+            let shutdown = matches!(msg, RendererMessage::Shutdown);
+            self.handle_message(msg)?;
+            if shutdown {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A [`SkiaRenderer`] running its [`run_loop`](SkiaRenderer::run_loop) on a
+/// thread of its own
+///
+/// The handle owns the sending end of the loop's channel and turns each call
+/// into the messages it stands for. Calls don't wait for the renderer; a
+/// failure stops the render thread, and is returned from [`join`](Self::join)
+/// while later calls fail to send. Dropping the handle shuts the thread down
+/// and waits for it.
+pub struct RendererHandle {
+    sender: Sender<RendererMessage>,
+    thread: Option<JoinHandle<RendererResult>>,
+}
+
+impl RendererHandle {
+    /// Start `renderer` on a new render thread
+    pub fn spawn(mut renderer: SkiaRenderer) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let thread = std::thread::spawn(move || renderer.run_loop(receiver));
+        Self {
+            sender,
+            thread: Some(thread),
+        }
+    }
+
+    /// Initialize the renderer's surface at `width` by `height`
+    pub fn init(&self, width: i32, height: i32) -> RendererResult {
+        self.send(RendererMessage::Init { width, height })
+    }
+
+    /// Draw `node` as a whole frame
+    pub fn render(&self, node: impl Into<Arc<Node>>) -> RendererResult {
+        self.send(RendererMessage::BeginFrame)?;
+        self.send(RendererMessage::Render { node: node.into() })?;
+        self.send(RendererMessage::EndFrame)
+    }
+
+    /// Resize the render surface
+    pub fn resize(&self, width: i32, height: i32) -> RendererResult {
+        self.send(RendererMessage::Resize { width, height })
+    }
+
+    /// Cap the render thread's frame rate, or `None` to run unthrottled; see
+    /// [`SkiaRenderer::set_target_fps`]
+    pub fn set_target_fps(&self, fps: Option<u32>) -> RendererResult {
+        self.send(RendererMessage::SetTargetFps(fps))
+    }
+
+    /// Ask the render thread to release its resources and exit
+    pub fn shutdown(&self) -> RendererResult {
+        self.send(RendererMessage::Shutdown)
+    }
+
+    /// Wait for the render thread to exit, returning the error that stopped
+    /// it, if any
+    ///
+    /// The thread is shut down once it has handled everything already sent,
+    /// if [`shutdown`](Self::shutdown) hasn't been called.
+    pub fn join(mut self) -> RendererResult {
+        let _ = self.shutdown();
+        self.wait()
+    }
+
+    fn send(&self, msg: RendererMessage) -> RendererResult {
+        self.sender.send(msg).map_err(|_| -> Box<dyn Error + Send> {
+            Box::new(RendererError::GeneralError(
+                "Render thread has stopped".into(),
+            ))
+        })
+    }
+
+    fn wait(&mut self) -> RendererResult {
+        match self.thread.take().map(JoinHandle::join) {
+            None => Ok(()),
+            Some(Ok(result)) => result,
+            Some(Err(_)) => Err(Box::new(RendererError::GeneralError(
+                "Render thread panicked".into(),
+            ))),
+        }
+    }
+}
+
+impl Drop for RendererHandle {
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            let _ = self.shutdown();
+            let _ = self.wait();
+        }
+    }
+}
+
+// Implement the Renderer trait for SkiaRenderer
+impl crate::renderer::Renderer for SkiaRenderer {
+    fn init(&mut self) -> Result<(), crate::Error> {
+        if self.state.is_none() {
+            let (width, height) = self.requested_size();
+            self.init_skia(width, height, self.samples)?;
+        }
+        Ok(())
+    }
+
+    /// Initializes on first use, then clears to the clear color like
+    /// [`SkiaRenderer::begin_frame`]
+    fn begin_frame(&mut self) -> Result<(), crate::Error> {
+        crate::renderer::Renderer::init(self)?;
+        Ok(SkiaRenderer::begin_frame(self)?)
+    }
+
+    /// Snapshots the surface to flush its drawing, like
+    /// [`SkiaRenderer::end_frame`]
+    fn end_frame(&mut self) -> Result<(), crate::Error> {
+        Ok(SkiaRenderer::end_frame(self)?)
+    }
+
+    fn render(&mut self, root: &Node, context: &mut RenderContext) -> Result<(), crate::Error> {
+        // Initialize if not already done
+        if self.state.is_none() {
+            let (width, height) = self.requested_size();
+            self.init_skia(width, height, self.samples)?;
+        }
+
+        Ok(self.render_node(root, context)?)
+    }
+
+    fn flush(&mut self) -> Result<(), crate::Error> {
+        // Skia surface doesn't have a flush method like we were expecting
+        // Instead, we'll create a snapshot which will finalize any pending drawing operations
+        if let Some(state) = &mut self.state {
+            // Create a snapshot to ensure drawing operations are completed
+            let _ = state.surface.image_snapshot();
+            Ok(())
+        } else {
+            Ok(()) // No state to flush
+        }
+    }
+
+    fn cleanup(&mut self) -> Result<(), crate::Error> {
+        // Set state to None to drop all resources
+        self.state = None;
+        self.frame_timer = FrameTimer::new(self.frame_timer.clock.clone());
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        match self.active_backend() {
+            RendererBackend::Gl => "SkiaRenderer",
+            RendererBackend::Raster => "RasterRenderer",
+            #[cfg(feature = "vulkan")]
+            RendererBackend::Vulkan => "SkiaVulkanRenderer",
+        }
+    }
+}
+
+// Implement From for common error conversions to allow ? operator
+impl From<String> for RendererError {
+    fn from(error: String) -> Self {
+        RendererError::GeneralError(error)
+    }
+}
+
+// This allows for converting errors when using the ? operator with the renderer
+// Add specific converters for common error types instead of a blanket implementation
+// to avoid conflicts with the built-in From<T> for T implementation
+impl From<std::io::Error> for RendererError {
+    fn from(error: std::io::Error) -> Self {
+        RendererError::GeneralError(error.to_string())
+    }
+}
+
+// Additional specific conversions can be added as needed
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::renderer::Renderer;
+    use crate::style::Color as StyleColor;
+    use std::time::Instant;
+
+    /// Read back a single pixel from the renderer's surface
+    fn pixel_at(renderer: &mut SkiaRenderer, x: i32, y: i32) -> Color {
+        let state = renderer
+            .state
+            .as_mut()
+            .expect("renderer should be initialized");
+        let info = ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let mut pixel = [0u8; 4];
+        assert!(state.surface.read_pixels(&info, &mut pixel, 4, (x, y)));
+        Color::from_argb(pixel[3], pixel[0], pixel[1], pixel[2])
+    }
+
+    #[test]
+    fn test_render_nested_nodes() {
+        let mut renderer = SkiaRenderer::new_raster(800, 600);
+        renderer.init().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 200.0, 200.0));
+        root.style_mut().background_color = Some(StyleColor::Rgba(1.0, 0.0, 0.0, 1.0));
+
+        let mut child = Node::default();
+        child.set_layout_rect(crate::layout::Rect::new(50.0, 50.0, 50.0, 50.0));
+        child.style_mut().background_color = Some(StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+        root.add_child(child);
+
+        if let Some(state) = &mut renderer.state {
+            state.surface.canvas().clear(Color::WHITE);
+        }
+        let mut context = RenderContext::new(800, 600);
+        renderer.render(&root, &mut context).unwrap();
+
+        assert_ne!(pixel_at(&mut renderer, 10, 10), Color::WHITE);
+        assert_ne!(
+            pixel_at(&mut renderer, 75, 75),
+            pixel_at(&mut renderer, 10, 10)
+        );
+        assert_eq!(pixel_at(&mut renderer, 300, 300), Color::WHITE);
+    }
+
+    #[test]
+    fn test_invalid_dimensions_rejected() {
+        let mut renderer = SkiaRenderer::with_size(0, 600);
+        let err = renderer.init_skia(0, 600, 1).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RendererError>(),
+            Some(RendererError::InitError(_))
+        ));
+
+        let err = renderer.init_skia(800, -1, 1).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RendererError>(),
+            Some(RendererError::InitError(_))
+        ));
+        assert!(renderer.state.is_none());
+
+        // Through the trait the variant survives, and so does the message
+        let err = crate::renderer::Renderer::init(&mut renderer).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::Skia(RendererError::InitError(_))
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Renderer error: Initialization error: Invalid surface dimensions 0x600"
+        );
+    }
+
+    #[test]
+    fn test_scratch_paint_resets_between_draws() {
+        let mut renderer = SkiaRenderer::new_raster(10, 10);
+        renderer.init().unwrap();
+        renderer.set_blend_mode(BlendMode::Multiply);
+        let state = renderer.state.as_mut().unwrap();
+        state
+            .paint
+            .set_style(PaintStyle::Stroke)
+            .set_blend_mode(BlendMode::Clear)
+            .set_anti_alias(false)
+            .set_color(Color::RED);
+
+        let (_, paint) = renderer.base_paint().unwrap();
+        assert_eq!(paint.style(), PaintStyle::Fill);
+        assert_eq!(paint.as_blend_mode(), Some(BlendMode::Multiply));
+        assert!(paint.is_anti_alias());
+        assert_eq!(paint.color(), Color::BLACK);
+    }
+
+    #[test]
+    fn test_antialias_toggle() {
+        // Count pixels along the diagonal edge of a triangle that are
+        // neither the fill nor the background
+        let blended_pixels = |antialias: bool| {
+            let mut renderer = SkiaRenderer::new_raster(40, 40);
+            renderer.set_antialias(antialias);
+            renderer.init().unwrap();
+            renderer.begin_frame().unwrap();
+            renderer
+                .draw_path("M0 0 L40 0 L0 40 Z", Color4f::from(Color::RED), None)
+                .unwrap();
+            (0..40)
+                .flat_map(|y| (0..40).map(move |x| (x, y)))
+                .map(|(x, y)| pixel_at(&mut renderer, x, y))
+                .filter(|&color| color != Color::RED && color != Color::WHITE)
+                .count()
+        };
+
+        assert!(SkiaRenderer::new().antialias());
+        assert!(blended_pixels(true) > 0);
+        assert_eq!(blended_pixels(false), 0);
+    }
+
+    #[test]
+    fn test_read_pixels_into_reused_buffer() {
+        let mut renderer = SkiaRenderer::new_raster(20, 10);
+        let mut buf = vec![0u8; 20 * 10 * 4];
+        assert!(renderer.read_pixels(&mut buf).is_err());
+        renderer.init().unwrap();
+
+        // Red over the left half of the white clear
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_round_rect(
+                Rect::from_xywh(0.0, 0.0, 10.0, 10.0),
+                0.0,
+                Color4f::from(Color::RED),
+            )
+            .unwrap();
+        renderer.read_pixels(&mut buf).unwrap();
+        let pixel = |buf: &[u8], x: usize, y: usize| {
+            let offset = (y * 20 + x) * 4;
+            buf[offset..offset + 4].to_vec()
+        };
+        assert_eq!(pixel(&buf, 2, 5), [255, 0, 0, 255]);
+        assert_eq!(pixel(&buf, 15, 5), [255, 255, 255, 255]);
+
+        // The same buffer takes the next frame
+        renderer.begin_frame().unwrap();
+        renderer.read_pixels(&mut buf).unwrap();
+        assert_eq!(pixel(&buf, 2, 5), [255, 255, 255, 255]);
+
+        let mut short = vec![0u8; 20 * 10 * 4 - 1];
+        assert!(renderer.read_pixels(&mut short).is_err());
+    }
+
+    #[test]
+    fn test_gpu_backend_falls_back_to_raster() {
+        // No GL context is current on a test thread, so GL can't initialize
+        let mut renderer = SkiaRenderer::with_backend(RendererBackend::Gl);
+        assert_eq!(renderer.active_backend(), RendererBackend::Gl);
+        renderer.init().unwrap();
+        assert_eq!(renderer.active_backend(), RendererBackend::Raster);
+        assert!(renderer.state.as_ref().unwrap().gr_context.is_none());
+
+        renderer.draw_test_circle().unwrap();
+        assert_ne!(pixel_at(&mut renderer, 400, 300), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn test_renderer_from_init_message() {
+        let renderer = SkiaRenderer::try_from(RendererMessage::Init {
+            width: 1024,
+            height: 768,
+        })
+        .unwrap();
+        assert_eq!(renderer.requested_size(), (1024, 768));
+        assert_eq!(SkiaRenderer::new().requested_size(), (800, 600));
+
+        assert!(SkiaRenderer::try_from(RendererMessage::Shutdown).is_err());
+    }
+
+    #[test]
+    fn test_resize_before_init_fails() {
+        let mut renderer = SkiaRenderer::new();
+        let err = renderer.resize(1024, 768).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RendererError>(),
+            Some(RendererError::InitError(_))
+        ));
+    }
+
+    #[test]
+    fn test_resize_updates_dimensions() {
+        let mut renderer = SkiaRenderer::new_raster(320, 240);
+        renderer.init().unwrap();
+
+        renderer.resize(320, 240).unwrap();
+        renderer.resize(640, 480).unwrap();
+        let state = renderer.state.as_ref().unwrap();
+        assert_eq!((state.width, state.height), (640, 480));
+        assert!(renderer.resize(0, 480).is_err());
+    }
+
+    #[test]
+    fn test_transform_stack_balances() {
+        let mut renderer = SkiaRenderer::new_raster(800, 600);
+        renderer.init().unwrap();
+        assert_eq!(renderer.transform_depth(), 1);
+
+        renderer.push_transform(M44::translate(100.0, 50.0, 0.0));
+        assert_eq!(renderer.transform_depth(), 2);
+        renderer.draw_test_circle().unwrap();
+        renderer.pop_transform();
+        assert_eq!(renderer.transform_depth(), 1);
+
+        // The base transform stays in place
+        renderer.pop_transform();
+        assert_eq!(renderer.transform_depth(), 1);
+
+        renderer.with_transform(M44::translate(10.0, 10.0, 0.0), |r| {
+            assert_eq!(r.transform_depth(), 2);
+            r.draw_test_circle().unwrap();
+        });
+        assert_eq!(renderer.transform_depth(), 1);
+    }
+
+    #[test]
+    fn test_clip_restricts_drawing() {
+        let mut renderer = SkiaRenderer::new_raster(800, 600);
+        renderer.init().unwrap();
+        if let Some(state) = &mut renderer.state {
+            state.surface.canvas().clear(Color::WHITE);
+        }
+
+        renderer
+            .push_clip(Rect::from_xywh(0.0, 0.0, 100.0, 100.0))
+            .unwrap();
+        assert_eq!(renderer.clip_depth(), 1);
+        if let Some(state) = &mut renderer.state {
+            let paint = Paint::new(Color4f::new(1.0, 0.0, 0.0, 1.0), None);
+            state.canvas().draw_circle((100.0, 100.0), 300.0, &paint);
+        }
+        renderer.pop_clip().unwrap();
+        assert_eq!(renderer.clip_depth(), 0);
+
+        assert_eq!(pixel_at(&mut renderer, 50, 50), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 150, 150), Color::WHITE);
+
+        let err = renderer.pop_clip().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RendererError>(),
+            Some(RendererError::GeneralError(_))
+        ));
+    }
+
+    #[test]
+    fn test_interleaved_pops_are_rejected() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        let save_count = renderer.state.as_mut().unwrap().canvas().save_count();
+
+        renderer.push_opacity(0.5).unwrap();
+        renderer
+            .push_clip(Rect::from_xywh(0.0, 0.0, 10.0, 10.0))
+            .unwrap();
+
+        // The clip is innermost, so the layer can't be popped past it
+        let err = renderer.pop_opacity().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RendererError>(),
+            Some(RendererError::GeneralError(_))
+        ));
+        assert_eq!(renderer.clip_depth(), 1);
+        assert_eq!(renderer.state.as_ref().unwrap().depth(SaveKind::Layer), 1);
+
+        // Popping in order unwinds both
+        renderer.pop_clip().unwrap();
+        assert!(renderer.pop_clip().is_err());
+        renderer.pop_opacity().unwrap();
+        let state = renderer.state.as_mut().unwrap();
+        assert!(state.saves.is_empty());
+        assert_eq!(state.canvas().save_count(), save_count);
+    }
+
+    #[test]
+    fn test_with_save_unwinds_unbalanced_state() {
+        let mut renderer = SkiaRenderer::new_raster(200, 200);
+        renderer.init().unwrap();
+        renderer.push_transform(M44::translate(10.0, 0.0, 0.0));
+        let save_count = renderer.state.as_mut().unwrap().canvas().save_count();
+
+        renderer.with_save(|r| {
+            r.push_transform(M44::scale(2.0, 2.0, 1.0));
+            r.push_clip(Rect::from_xywh(0.0, 0.0, 20.0, 20.0)).unwrap();
+            r.with_save(|r| {
+                r.push_clip(Rect::from_xywh(0.0, 0.0, 5.0, 5.0)).unwrap();
+                r.push_opacity(0.5).unwrap();
+            });
+            // The inner call only unwound its own pushes
+            assert_eq!(r.clip_depth(), 1);
+            assert_eq!(r.transform_depth(), 3);
+        });
+
+        assert_eq!(renderer.transform_depth(), 2);
+        assert_eq!(renderer.clip_depth(), 0);
+        let state = renderer.state.as_mut().unwrap();
+        assert_eq!(state.depth(SaveKind::Layer), 0);
+        assert_eq!(state.canvas().save_count(), save_count);
+
+        // The clips are gone, so drawing reaches past them
+        renderer.pop_transform();
+        renderer
+            .draw_round_rect(
+                Rect::from_xywh(0.0, 0.0, 200.0, 200.0),
+                0.0,
+                Color4f::from(Color::RED),
+            )
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 150, 150), Color::RED);
+    }
+
+    #[test]
+    fn test_overflow_hidden_clips_children() {
+        let mut renderer = SkiaRenderer::new_raster(800, 600);
+        renderer.init().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 100.0, 100.0));
+        root.style_mut().overflow = Some(Overflow::Hidden);
+
+        let mut child = Node::default();
+        child.set_layout_rect(crate::layout::Rect::new(50.0, 50.0, 200.0, 200.0));
+        child.style_mut().background_color = Some(StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+        root.add_child(child);
+
+        if let Some(state) = &mut renderer.state {
+            state.surface.canvas().clear(Color::WHITE);
+        }
+        let mut context = RenderContext::new(800, 600);
+        renderer.render(&root, &mut context).unwrap();
+
+        assert_eq!(pixel_at(&mut renderer, 75, 75), Color::BLUE);
+        assert_eq!(pixel_at(&mut renderer, 150, 150), Color::WHITE);
+        assert_eq!(renderer.clip_depth(), 0);
+    }
+
+    #[test]
+    fn test_nested_overflow_clips_intersect() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        // A scrolling container hanging off the end of a hidden one only
+        // shows where the two overlap
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 60.0, 60.0));
+        root.style_mut().overflow = Some(Overflow::Hidden);
+        let mut inner = Node::default();
+        inner.set_layout_rect(crate::layout::Rect::new(40.0, 40.0, 40.0, 40.0));
+        inner.style_mut().overflow = Some(Overflow::Scroll);
+        let mut child = Node::default();
+        child.set_layout_rect(crate::layout::Rect::new(-40.0, -40.0, 100.0, 100.0));
+        child.style_mut().background_color = Some(StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+        inner.add_child(child);
+        root.add_child(inner);
+
+        let mut context = RenderContext::new(100, 100);
+        renderer.render(&root, &mut context).unwrap();
+        assert_eq!(pixel_at(&mut renderer, 50, 50), Color::BLUE);
+        assert_eq!(pixel_at(&mut renderer, 20, 20), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 70, 70), Color::WHITE);
+        assert_eq!(renderer.clip_depth(), 0);
+    }
+
+    #[test]
+    fn test_encode_png() {
+        assert!(SkiaRenderer::new().encode_png().is_err());
+
+        let mut renderer = SkiaRenderer::new_raster(64, 64);
+        renderer.init().unwrap();
+        renderer.draw_test_circle().unwrap();
+
+        let bytes = renderer.encode_png().unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+
+        let path = std::env::temp_dir().join("orbit_skia_encode_png.png");
+        renderer.save_png(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), bytes);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_raster_renderer() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        assert_eq!(renderer.name(), "RasterRenderer");
+        assert_eq!(renderer.active_backend(), RendererBackend::Raster);
+        assert_eq!(SkiaRenderer::new().name(), "SkiaRenderer");
+        assert_eq!(SkiaRenderer::new().active_backend(), RendererBackend::Gl);
+
+        renderer.init().unwrap();
+        let state = renderer.state.as_ref().unwrap();
+        assert!(state.gr_context.is_none());
+        assert_eq!(state.backend, RendererBackend::Raster);
+
+        renderer.draw_test_circle().unwrap();
+        renderer.flush().unwrap();
+        assert_ne!(pixel_at(&mut renderer, 50, 50), Color::TRANSPARENT);
+        assert_eq!(pixel_at(&mut renderer, 2, 2), Color::TRANSPARENT);
+
+        renderer.resize(200, 150).unwrap();
+        renderer.draw_test_circle().unwrap();
+        assert_ne!(pixel_at(&mut renderer, 100, 75), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn test_draw_text() {
+        let mut renderer = SkiaRenderer::new_raster(200, 100);
+        assert!(renderer
+            .draw_text("Hi", 0.0, 0.0, 12.0, Color::BLACK)
+            .is_err());
+        renderer.init().unwrap();
+
+        // Empty strings draw nothing and never fail
+        renderer
+            .draw_text("", 10.0, 50.0, 24.0, Color::BLACK)
+            .unwrap();
+
+        // Unknown families fall back to the default typeface
+        assert_eq!(
+            renderer.font(Some("No Such Family"), 24.0).size(),
+            renderer.font(None, 24.0).size()
+        );
+        assert!(renderer.register_font(b"not a font", "Broken").is_err());
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 200.0, 100.0));
+        root.set_text("");
+        let mut context = RenderContext::new(200, 100);
+        renderer.render(&root, &mut context).unwrap();
+    }
+
+    #[test]
+    fn test_text_render_modes() {
+        // Draw "Hi" in `mode`, returning how many pixels it inked and how
+        // many of those are partially covered
+        let render = |mode: TextRenderMode| {
+            let mut renderer = SkiaRenderer::new_raster(60, 40);
+            renderer.set_text_render_mode(mode);
+            renderer.init().unwrap();
+            renderer.begin_frame().unwrap();
+            renderer
+                .draw_text("Hi", 5.5, 30.0, 24.0, Color::BLACK)
+                .unwrap();
+            let pixels: Vec<Color> = (0..40)
+                .flat_map(|y| (0..60).map(move |x| (x, y)))
+                .map(|(x, y)| pixel_at(&mut renderer, x, y))
+                .filter(|&color| color != Color::WHITE)
+                .collect();
+            let partial = pixels
+                .iter()
+                .filter(|&&color| color != Color::BLACK)
+                .count();
+            (renderer.font(None, 24.0), pixels.len(), partial)
+        };
+
+        let (font, ..) = render(TextRenderMode::default());
+        assert_eq!(font.edging(), Edging::SubpixelAntiAlias);
+        assert!(font.is_subpixel());
+
+        let (font, inked, partial) = render(TextRenderMode::Antialias);
+        assert_eq!(font.edging(), Edging::AntiAlias);
+        assert!(font.is_subpixel());
+        // Without any system font there's nothing to compare
+        if inked > 0 {
+            assert!(partial > 0);
+        }
+
+        let (font, inked, partial) = render(TextRenderMode::Aliased);
+        assert_eq!(font.edging(), Edging::Alias);
+        assert!(!font.is_subpixel());
+        if inked > 0 {
+            assert_eq!(partial, 0);
+        }
+    }
+
+    #[test]
+    fn test_measure_text() {
+        // Measuring doesn't need a surface
+        let renderer = SkiaRenderer::new();
+
+        let (empty_width, line_height) = renderer.measure_text("", 16.0);
+        assert_eq!(empty_width, 0.0);
+        assert!(line_height > 0.0);
+
+        let (narrow, narrow_height) = renderer.measure_text("i", 16.0);
+        let (wide, wide_height) = renderer.measure_text("WWWW", 16.0);
+        assert!(narrow < wide);
+        assert_eq!(narrow_height, wide_height);
+        assert_eq!(narrow_height, line_height);
+
+        let (larger, _) = renderer.measure_text("WWWW", 32.0);
+        assert!(larger > wide);
+    }
+
+    #[test]
+    fn test_clear_color() {
+        let mut renderer = SkiaRenderer::new_raster(32, 32);
+        assert_eq!(renderer.clear_color(), Color4f::from(Color::WHITE));
+        assert!(renderer.begin_frame().is_err());
+        renderer.init().unwrap();
+
+        renderer.begin_frame().unwrap();
+        assert_eq!(pixel_at(&mut renderer, 0, 0), Color::WHITE);
+
+        renderer.set_clear_color(Color4f::new(0.0, 0.0, 1.0, 1.0));
+        renderer.begin_frame().unwrap();
+        assert_eq!(pixel_at(&mut renderer, 16, 16), Color::BLUE);
+
+        renderer.set_clear_color(Color4f::new(0.0, 0.0, 0.0, 0.0));
+        renderer.begin_frame().unwrap();
+        assert_eq!(pixel_at(&mut renderer, 16, 16).a(), 0);
+    }
+
+    #[test]
+    fn test_trait_frame_batches_renders() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        let mut context = RenderContext::new(100, 100);
+        let square = |x: f32, color: StyleColor| {
+            let mut node = Node::default();
+            node.set_layout_rect(crate::layout::Rect::new(x, 0.0, 50.0, 50.0));
+            node.style_mut().background_color = Some(color);
+            node
+        };
+
+        // The trait's begin_frame initializes on first use, and both renders
+        // land in the one frame
+        Renderer::begin_frame(&mut renderer).unwrap();
+        let red = square(0.0, StyleColor::Rgba(1.0, 0.0, 0.0, 1.0));
+        renderer.render(&red, &mut context).unwrap();
+        let blue = square(50.0, StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+        renderer.render(&blue, &mut context).unwrap();
+        Renderer::end_frame(&mut renderer).unwrap();
+
+        assert_eq!(pixel_at(&mut renderer, 25, 25), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 75, 25), Color::BLUE);
+        assert_eq!(pixel_at(&mut renderer, 25, 75), Color::WHITE);
+        assert_eq!(renderer.frame_stats().frame_count, 1);
+    }
+
+    #[test]
+    fn test_run_loop_until_shutdown() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            let mut renderer = SkiaRenderer::new_raster(1, 1);
+            let result = renderer.run_loop(receiver);
+            (result.is_ok(), renderer.state.is_none())
+        });
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 64.0, 64.0));
+        root.style_mut().background_color = Some(StyleColor::Rgba(1.0, 0.0, 0.0, 1.0));
+
+        sender
+            .send(RendererMessage::Init {
+                width: 64,
+                height: 64,
+            })
+            .unwrap();
+        sender.send(RendererMessage::BeginFrame).unwrap();
+        sender
+            .send(RendererMessage::Render {
+                node: Arc::new(root),
+            })
+            .unwrap();
+        sender.send(RendererMessage::EndFrame).unwrap();
+        sender.send(RendererMessage::Shutdown).unwrap();
+
+        // Messages after shutdown are never processed
+        let _ = sender.send(RendererMessage::BeginFrame);
+
+        let (ok, cleaned_up) = handle.join().unwrap();
+        assert!(ok);
+        assert!(cleaned_up);
+    }
+
+    #[test]
+    fn test_renderer_handle_cycle() {
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 64.0, 64.0));
+        root.style_mut().background_color = Some(StyleColor::Rgba(1.0, 0.0, 0.0, 1.0));
+
+        let handle = RendererHandle::spawn(SkiaRenderer::new_raster(1, 1));
+        handle.init(64, 64).unwrap();
+        handle.render(root.clone()).unwrap();
+        handle.resize(32, 32).unwrap();
+        handle.render(root.clone()).unwrap();
+        handle.shutdown().unwrap();
+        assert!(handle.join().is_ok());
+
+        // A failure on the render thread comes back from join; the thread
+        // may already be gone before the whole frame is sent
+        let handle = RendererHandle::spawn(SkiaRenderer::new_raster(1, 1));
+        let _ = handle.render(root);
+        let err = handle.join().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<RendererError>(),
+            Some(RendererError::GeneralError(_))
+        ));
+    }
+
+    #[test]
+    fn test_handle_message_requires_init() {
+        let mut renderer = SkiaRenderer::new_raster(64, 64);
+        assert!(renderer
+            .handle_message(RendererMessage::BeginFrame)
+            .is_err());
+        renderer
+            .handle_message(RendererMessage::Init {
+                width: 32,
+                height: 32,
+            })
+            .unwrap();
+        renderer
+            .handle_message(RendererMessage::BeginFrame)
+            .unwrap();
+        assert_eq!(renderer.state.as_ref().unwrap().width, 32);
+    }
+
+    #[test]
+    fn test_draw_round_rect() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let red = Color4f::new(1.0, 0.0, 0.0, 1.0);
+        renderer
+            .draw_round_rect(Rect::from_xywh(0.0, 0.0, 100.0, 100.0), 40.0, red)
+            .unwrap();
+
+        // Corners are cut away, the center is filled
+        assert_eq!(pixel_at(&mut renderer, 1, 1), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 98, 98), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 50, 50), Color::RED);
+
+        // Only the top-left corner is rounded
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_round_rect_radii(
+                Rect::from_xywh(0.0, 0.0, 100.0, 100.0),
+                [40.0, 0.0, 0.0, 0.0],
+                red,
+            )
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 1, 1), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 98, 1), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 98, 98), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 1, 98), Color::RED);
+    }
+
+    #[test]
+    fn test_zero_radius_matches_rect_fill() {
+        let rect = Rect::from_xywh(10.5, 10.5, 40.0, 40.0);
+        let color = Color4f::new(0.2, 0.4, 0.6, 1.0);
+
+        let mut rounded = SkiaRenderer::new_raster(64, 64);
+        rounded.init().unwrap();
+        rounded.begin_frame().unwrap();
+        rounded.draw_round_rect(rect, 0.0, color).unwrap();
+
+        let mut plain = SkiaRenderer::new_raster(64, 64);
+        plain.init().unwrap();
+        plain.begin_frame().unwrap();
+        if let Some(state) = &mut plain.state {
+            let mut paint = Paint::new(color, None);
+            paint.set_anti_alias(true);
+            state.canvas().draw_rect(rect, &paint);
+        }
+
+        assert_eq!(rounded.encode_png().unwrap(), plain.encode_png().unwrap());
+    }
+
+    #[test]
+    fn test_stroke_round_rect() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let rect = Rect::from_xywh(20.0, 20.0, 60.0, 60.0);
+        let fill = Color4f::new(0.0, 0.0, 1.0, 1.0);
+        let stroke = Stroke::new(4.0, Color4f::new(1.0, 0.0, 0.0, 1.0));
+        renderer
+            .draw_styled_round_rect(rect, [0.0; 4], Some(fill), Some(stroke))
+            .unwrap();
+
+        assert_eq!(pixel_at(&mut renderer, 50, 20), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 50, 50), Color::BLUE);
+        assert_eq!(pixel_at(&mut renderer, 50, 10), Color::WHITE);
+
+        // A half-pixel stroke is anti-aliased into partial coverage
+        renderer.begin_frame().unwrap();
+        let hairline = Stroke::new(0.5, Color4f::new(0.0, 0.0, 0.0, 1.0));
+        renderer
+            .draw_styled_round_rect(rect, [8.0; 4], None, Some(hairline))
+            .unwrap();
+        let edge = pixel_at(&mut renderer, 50, 20);
+        assert!(edge != Color::WHITE && edge != Color::BLACK);
+    }
+
+    #[test]
+    fn test_zero_width_stroke_draws_nothing() {
+        let mut renderer = SkiaRenderer::new_raster(64, 64);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+        let before = renderer.encode_png().unwrap();
+
+        let stroke = Stroke::new(0.0, Color4f::new(1.0, 0.0, 0.0, 1.0));
+        renderer
+            .draw_styled_round_rect(Rect::from_wh(32.0, 32.0), [4.0; 4], None, Some(stroke))
+            .unwrap();
+        assert_eq!(renderer.encode_png().unwrap(), before);
+    }
+
+    #[test]
+    fn test_node_border() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(10.0, 10.0, 80.0, 80.0));
+        let style = root.style_mut();
+        style.background_color = Some(StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+        style.border_width = Some(crate::layout::EdgeValues::new(4.0, 4.0, 4.0, 4.0));
+        let red = StyleColor::Rgba(1.0, 0.0, 0.0, 1.0);
+        style.border_color = Some(crate::style::EdgeColors {
+            top: red.clone(),
+            right: red.clone(),
+            bottom: red.clone(),
+            left: red,
+        });
+
+        let mut context = RenderContext::new(100, 100);
+        renderer.render(&root, &mut context).unwrap();
+
+        // The border is painted inside the node's bounds, over the fill
+        assert_eq!(pixel_at(&mut renderer, 12, 50), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 50, 50), Color::BLUE);
+        assert_eq!(pixel_at(&mut renderer, 8, 50), Color::WHITE);
+    }
+
+    #[test]
+    fn test_node_background_gradient() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 100.0, 100.0));
+        root.style_mut().background_gradient = Some(crate::style::LinearGradient::new(
+            90.0,
+            vec![
+                (0.0, StyleColor::Rgba(1.0, 0.0, 0.0, 1.0)),
+                (0.5, StyleColor::Rgba(1.0, 0.0, 0.0, 1.0)),
+                (0.5, StyleColor::Rgba(0.0, 0.0, 1.0, 1.0)),
+                (1.0, StyleColor::Rgba(0.0, 0.0, 1.0, 1.0)),
+            ],
+        ));
+
+        let mut context = RenderContext::new(100, 100);
+        renderer.render(&root, &mut context).unwrap();
+
+        // At 90 degrees the gradient runs left to right
+        assert_eq!(pixel_at(&mut renderer, 10, 50), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 90, 50), Color::BLUE);
+    }
+
+    #[test]
+    fn test_linear_gradient() {
+        let mut renderer = SkiaRenderer::new_raster(100, 10);
+        renderer.init().unwrap();
+
+        renderer
+            .draw_linear_gradient(
+                Rect::from_wh(100.0, 10.0),
+                Point::new(0.0, 0.0),
+                Point::new(100.0, 0.0),
+                &[(0.0, Color::RED), (1.0, Color::BLUE)],
+            )
+            .unwrap();
+
+        let left = pixel_at(&mut renderer, 0, 5);
+        let right = pixel_at(&mut renderer, 99, 5);
+        assert_ne!(left, right);
+        assert!(left.r() > left.b());
+        assert!(right.b() > right.r());
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+
+        renderer
+            .draw_radial_gradient(
+                Rect::from_wh(100.0, 100.0),
+                Point::new(50.0, 50.0),
+                50.0,
+                &[
+                    (0.0, Color::WHITE),
+                    (0.5, Color::GREEN),
+                    (1.0, Color::BLACK),
+                ],
+            )
+            .unwrap();
+        assert_ne!(
+            pixel_at(&mut renderer, 50, 50),
+            pixel_at(&mut renderer, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_invalid_gradient_stops() {
+        let is_general = |result: RendererResult| {
+            matches!(
+                result.unwrap_err().downcast_ref::<RendererError>(),
+                Some(RendererError::GeneralError(_))
+            )
+        };
+
+        let mut renderer = SkiaRenderer::new_raster(10, 10);
+        renderer.init().unwrap();
+        let rect = Rect::from_wh(10.0, 10.0);
+        let (start, end) = (Point::new(0.0, 0.0), Point::new(10.0, 0.0));
+
+        assert!(is_general(renderer.draw_linear_gradient(
+            rect,
+            start,
+            end,
+            &[(0.0, Color::RED)]
+        )));
+        assert!(is_general(renderer.draw_linear_gradient(
+            rect,
+            start,
+            end,
+            &[(0.6, Color::RED), (0.4, Color::BLUE)]
+        )));
+        assert!(is_general(renderer.draw_radial_gradient(
+            rect,
+            start,
+            5.0,
+            &[(0.0, Color::RED), (1.5, Color::BLUE)]
+        )));
+    }
+
+    #[test]
+    fn test_draw_shadow() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        // With no blur the shadow is a hard-edged offset copy
+        let rect = Rect::from_xywh(20.0, 20.0, 40.0, 40.0);
+        renderer
+            .draw_shadow(rect, 0.0, 0.0, Point::new(10.0, 10.0), Color::BLACK)
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 65, 65), Color::BLACK);
+        assert_eq!(pixel_at(&mut renderer, 71, 71), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 25, 25), Color::WHITE);
+
+        // A blurred shadow bleeds softly past the shape's edge
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_shadow(rect, 8.0, 12.0, Point::new(0.0, 0.0), Color::BLACK)
+            .unwrap();
+        let halo = pixel_at(&mut renderer, 40, 62);
+        assert!(halo != Color::WHITE && halo != Color::BLACK);
+    }
+
+    #[test]
+    fn test_node_box_shadow() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(10.0, 10.0, 40.0, 40.0));
+        let style = root.style_mut();
+        style.background_color = Some(StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+        style.box_shadow = Some(vec![crate::style::BoxShadow::new(
+            10.0,
+            10.0,
+            0.0,
+            StyleColor::Rgba(1.0, 0.0, 0.0, 1.0),
+        )]);
+
+        let mut context = RenderContext::new(100, 100);
+        renderer.render(&root, &mut context).unwrap();
+
+        assert_eq!(pixel_at(&mut renderer, 30, 30), Color::BLUE);
+        assert_eq!(pixel_at(&mut renderer, 55, 55), Color::RED);
+    }
+
+    #[test]
+    fn test_node_outline_surrounds_bounds() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(20.0, 20.0, 40.0, 40.0));
+        root.style_mut().outline = Some(crate::style::Outline {
+            width: 4.0,
+            color: StyleColor::Rgba(1.0, 0.0, 0.0, 1.0),
+            offset: 2.0,
+        });
+
+        let mut context = RenderContext::new(100, 100);
+        renderer.render(&root, &mut context).unwrap();
+
+        // The ring spans 2..6px outside the node's edges
+        assert_eq!(pixel_at(&mut renderer, 16, 40), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 40, 63), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 40, 40), Color::WHITE);
+    }
+
+    #[test]
+    fn test_draw_image() {
+        let mut renderer = SkiaRenderer::new_raster(40, 40);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        // A 2x1 image: red then blue
+        let pixels = [255, 0, 0, 255, 0, 0, 255, 255];
+        renderer
+            .draw_image(
+                &pixels,
+                2,
+                1,
+                Rect::from_wh(40.0, 40.0),
+                ImageSampling::Nearest,
+            )
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 5, 20), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 35, 20), Color::BLUE);
+
+        // Linear sampling blends across the seam
+        renderer
+            .draw_image(
+                &pixels,
+                2,
+                1,
+                Rect::from_wh(40.0, 40.0),
+                ImageSampling::Linear,
+            )
+            .unwrap();
+        let seam = pixel_at(&mut renderer, 20, 20);
+        assert!(seam.r() > 0 && seam.b() > 0);
+
+        // Only the chosen region is scaled into the destination
+        renderer
+            .draw_image_region(
+                &pixels,
+                2,
+                1,
+                Rect::from_xywh(1.0, 0.0, 1.0, 1.0),
+                Rect::from_wh(40.0, 40.0),
+                ImageSampling::Nearest,
+            )
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 5, 20), Color::BLUE);
+    }
+
+    #[test]
+    fn test_draw_image_rejects_bad_data() {
+        let mut renderer = SkiaRenderer::new_raster(10, 10);
+        renderer.init().unwrap();
+
+        let dest = Rect::from_wh(10.0, 10.0);
+        assert!(renderer
+            .draw_image(&[0; 12], 2, 2, dest, ImageSampling::Linear)
+            .is_err());
+        assert!(renderer
+            .draw_image(&[], 0, 0, dest, ImageSampling::Linear)
+            .is_err());
+    }
+
+    #[test]
+    fn test_draw_svg_path() {
+        let mut renderer = SkiaRenderer::new_raster(20, 20);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let red = Color4f::new(1.0, 0.0, 0.0, 1.0);
+        renderer
+            .draw_path("M0 0 L10 0 L10 10 Z", red, None)
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 7, 3), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 2, 8), Color::WHITE);
+
+        // The extra transform is applied to the path
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_path(
+                "M0 0 L10 0 L10 10 Z",
+                red,
+                Some(M44::translate(10.0, 10.0, 0.0)),
+            )
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 17, 13), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 7, 3), Color::WHITE);
+        assert_eq!(renderer.transform_depth(), 1);
+    }
+
+    #[test]
+    fn test_invalid_svg_path() {
+        let mut renderer = SkiaRenderer::new_raster(20, 20);
+        renderer.init().unwrap();
+
+        let err = renderer
+            .draw_path("M0 0 L10 0 Lfoo Z", Color4f::new(0.0, 0.0, 0.0, 1.0), None)
+            .unwrap_err();
+        match err.downcast_ref::<RendererError>() {
+            Some(RendererError::GeneralError(msg)) => assert!(msg.contains("Lf"), "{msg}"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        assert!(renderer
+            .draw_path("M0 0\0 L1 1", Color4f::new(0.0, 0.0, 0.0, 1.0), None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_opacity_layers() {
+        let mut renderer = SkiaRenderer::new_raster(100, 100);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 100.0, 100.0));
+        for (x, color) in [
+            (10.0, StyleColor::Rgba(1.0, 0.0, 0.0, 1.0)),
+            (40.0, StyleColor::Rgba(0.0, 0.0, 1.0, 1.0)),
+        ] {
+            let mut child = Node::default();
+            child.set_layout_rect(crate::layout::Rect::new(x, 10.0, 50.0, 50.0));
+            child.style_mut().background_color = Some(color);
+            child.style_mut().opacity = Some(0.5);
+            root.add_child(child);
+        }
+
+        let mut context = RenderContext::new(100, 100);
+        renderer.render(&root, &mut context).unwrap();
+
+        let red_only = pixel_at(&mut renderer, 20, 30);
+        let overlap = pixel_at(&mut renderer, 50, 30);
+        let blue_only = pixel_at(&mut renderer, 80, 30);
+        assert!(red_only.g() > 100 && red_only.r() == 255);
+        assert!(blue_only.g() > 100 && blue_only.b() == 255);
+        assert!(overlap.r() > 0 && overlap.b() > 0);
+        assert!(overlap.g() < red_only.g());
+        assert_eq!(renderer.state.as_ref().unwrap().depth(SaveKind::Layer), 0);
+    }
+
+    #[test]
+    fn test_hidden_node_keeps_its_space() {
+        use crate::kit::components::Layout;
+        use crate::layout::Size;
+
+        let mut renderer = SkiaRenderer::new_raster(60, 40);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let children = [
+            StyleColor::Rgba(1.0, 0.0, 0.0, 1.0),
+            StyleColor::Rgba(0.0, 1.0, 0.0, 1.0),
+            StyleColor::Rgba(0.0, 0.0, 1.0, 1.0),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, color)| {
+            let mut child = Node::default();
+            child.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 20.0, 40.0));
+            child.style_mut().background_color = Some(color);
+            if index == 1 {
+                child.style_mut().visibility = Some(Visibility::Hidden);
+            }
+            child
+        })
+        .collect();
+        let layout = Layout {
+            children,
+            ..Default::default()
+        };
+        let root = layout.render_in(Size::new(60.0, 40.0));
+        assert_eq!(root.children()[2].layout_rect().x(), 40.0);
+
+        let mut context = RenderContext::new(60, 40);
+        renderer.render(&root, &mut context).unwrap();
+        assert_eq!(pixel_at(&mut renderer, 10, 20), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 30, 20), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 50, 20), Color::BLUE);
+        assert_eq!(renderer.transform_depth(), 1);
+    }
+
+    #[test]
+    fn test_transparent_subtree_is_skipped() {
+        let mut renderer = SkiaRenderer::new_raster(50, 50);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+        assert!(renderer.pop_opacity().is_err());
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 50.0, 50.0));
+        root.style_mut().background_color = Some(StyleColor::Rgba(1.0, 0.0, 0.0, 1.0));
+        root.style_mut().opacity = Some(-1.0);
+
+        let mut context = RenderContext::new(50, 50);
+        renderer.render(&root, &mut context).unwrap();
+        assert_eq!(pixel_at(&mut renderer, 25, 25), Color::WHITE);
+        assert_eq!(renderer.transform_depth(), 1);
+    }
+
+    #[test]
+    fn test_multiply_white_keeps_background() {
+        let mut renderer = SkiaRenderer::new_raster(20, 20);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let rect = Rect::from_wh(20.0, 20.0);
+        let background = Color4f::new(0.2, 0.6, 0.4, 1.0);
+        renderer.draw_round_rect(rect, 0.0, background).unwrap();
+        let expected = pixel_at(&mut renderer, 10, 10);
+
+        renderer.with_blend_mode(BlendMode::Multiply, |r| {
+            r.draw_round_rect(rect, 0.0, Color4f::from(Color::WHITE))
+                .unwrap();
+        });
+        assert_eq!(renderer.blend_mode(), BlendMode::SrcOver);
+        assert_eq!(pixel_at(&mut renderer, 10, 10), expected);
+
+        // Screening black is likewise a no-op
+        renderer.set_blend_mode(BlendMode::Screen);
+        renderer
+            .draw_round_rect(rect, 0.0, Color4f::from(Color::BLACK))
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 10, 10), expected);
+    }
+
+    #[test]
+    fn test_blend_over_transparent_background() {
+        let mut renderer = SkiaRenderer::new_raster(20, 20);
+        renderer.set_clear_color(Color4f::new(0.0, 0.0, 0.0, 0.0));
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        // Over an empty backdrop both modes reduce to the source color
+        let rect = Rect::from_wh(10.0, 20.0);
+        let red = Color4f::new(1.0, 0.0, 0.0, 1.0);
+        renderer.set_blend_mode(BlendMode::Multiply);
+        renderer.draw_round_rect(rect, 0.0, red).unwrap();
+        renderer.set_blend_mode(BlendMode::Screen);
+        renderer
+            .draw_round_rect(rect.with_offset((10.0, 0.0)), 0.0, red)
+            .unwrap();
+
+        assert_eq!(pixel_at(&mut renderer, 5, 10), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 15, 10), Color::RED);
+    }
+
+    #[test]
+    fn test_node_mix_blend_mode() {
+        let mut renderer = SkiaRenderer::new_raster(40, 40);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 40.0, 40.0));
+        root.style_mut().background_color = Some(StyleColor::Rgba(0.0, 0.0, 1.0, 1.0));
+
+        let mut child = Node::default();
+        child.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 20.0, 40.0));
+        child.style_mut().background_color = Some(StyleColor::Rgba(1.0, 1.0, 1.0, 1.0));
+        child.style_mut().mix_blend_mode = Some(MixBlendMode::Multiply);
+        root.add_child(child);
+
+        let mut context = RenderContext::new(40, 40);
+        renderer.render(&root, &mut context).unwrap();
+        assert_eq!(pixel_at(&mut renderer, 10, 20), Color::BLUE);
+        assert_eq!(renderer.blend_mode(), BlendMode::SrcOver);
+    }
+
+    #[test]
+    fn test_children_paint_in_z_index_order() {
+        let mut renderer = SkiaRenderer::new_raster(40, 40);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+
+        let mut root = Node::default();
+        root.set_layout_rect(crate::layout::Rect::new(0.0, 0.0, 40.0, 40.0));
+        for (x, width, color, z_index) in [
+            (0.0, 30.0, StyleColor::Rgba(1.0, 0.0, 0.0, 1.0), Some(1)),
+            (10.0, 25.0, StyleColor::Rgba(0.0, 1.0, 0.0, 1.0), None),
+            (0.0, 40.0, StyleColor::Rgba(0.0, 0.0, 1.0, 1.0), Some(-1)),
+        ] {
+            let mut child = Node::default();
+            child.set_layout_rect(crate::layout::Rect::new(x, 0.0, width, 40.0));
+            child.style_mut().background_color = Some(color);
+            child.style_mut().z_index = z_index;
+            root.add_child(child);
+        }
+
+        // The later green node paints under the earlier, raised red one,
+        // and the last node paints behind both
+        let mut context = RenderContext::new(40, 40);
+        renderer.render(&root, &mut context).unwrap();
+        assert_eq!(pixel_at(&mut renderer, 20, 20), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 32, 20), Color::GREEN);
+        assert_eq!(pixel_at(&mut renderer, 38, 20), Color::BLUE);
+    }
+
+    #[test]
+    fn test_scale_factor() {
+        let mut renderer = SkiaRenderer::new_raster(50, 50);
+        assert_eq!(renderer.scale_factor(), 1.0);
+        assert!(renderer.set_scale_factor(0.0).is_err());
+        assert!(renderer.set_scale_factor(f32::NAN).is_err());
+
+        renderer.set_scale_factor(2.0).unwrap();
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+        {
+            let state = renderer.state.as_ref().unwrap();
+            assert_eq!((state.width, state.height), (50, 50));
+            assert_eq!((state.surface.width(), state.surface.height()), (100, 100));
+        }
+
+        // Logical coordinates are doubled on the physical surface
+        renderer
+            .draw_round_rect(
+                Rect::from_xywh(10.0, 10.0, 10.0, 10.0),
+                0.0,
+                Color4f::new(1.0, 0.0, 0.0, 1.0),
+            )
+            .unwrap();
+        assert_eq!(pixel_at(&mut renderer, 25, 25), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 15, 15), Color::WHITE);
+        assert_eq!(pixel_at(&mut renderer, 39, 39), Color::RED);
+
+        renderer.set_scale_factor(1.0).unwrap();
+        let state = renderer.state.as_ref().unwrap();
+        assert_eq!((state.surface.width(), state.surface.height()), (50, 50));
+    }
+
+    #[test]
+    fn test_supported_samples() {
+        assert_eq!(supported_samples(1, 16), 1);
+        assert_eq!(supported_samples(4, 16), 4);
+        assert_eq!(supported_samples(8, 16), 8);
+
+        // Uncommon counts round down, driver limits clamp
+        assert_eq!(supported_samples(3, 16), 2);
+        assert_eq!(supported_samples(16, 16), 8);
+        assert_eq!(supported_samples(8, 4), 4);
+        assert_eq!(supported_samples(0, 4), 1);
+        assert_eq!(supported_samples(4, 0), 4);
+
+        let renderer = SkiaRenderer::new_raster(10, 10).with_samples(4);
+        assert_eq!(renderer.samples, 4);
+    }
+
+    #[test]
+    fn test_frame_stats() {
+        let mut renderer = SkiaRenderer::new_raster(10, 10);
+        renderer.init().unwrap();
+        assert_eq!(renderer.frame_stats(), FrameStats::default());
+
+        let mut previous_count = 0;
+        for _ in 0..3 {
+            renderer.begin_frame().unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            renderer.end_frame().unwrap();
+
+            let stats = renderer.frame_stats();
+            assert_eq!(stats.frame_count, previous_count + 1);
+            assert!(stats.last_frame_ms >= 5.0);
+            assert!(stats.avg_fps > 0.0 && stats.avg_fps <= 200.0);
+            assert_eq!(stats.gpu_memory_bytes, None);
+            previous_count = stats.frame_count;
+        }
+
+        // An end without a begin doesn't count as a frame
+        renderer.end_frame().unwrap();
+        assert_eq!(renderer.frame_stats().frame_count, 3);
+
+        renderer.cleanup().unwrap();
+        assert_eq!(renderer.frame_stats(), FrameStats::default());
+    }
+
+    #[test]
+    fn test_frame_stats_follow_injected_clock() {
+        let clock = MockClock::new();
+        let mut renderer = SkiaRenderer::new_raster(10, 10).with_clock(Arc::new(clock.clone()));
+        renderer.init().unwrap();
+        for _ in 0..2 {
+            renderer.begin_frame().unwrap();
+            clock.advance(Duration::from_millis(16));
+            renderer.end_frame().unwrap();
+        }
+        let stats = renderer.frame_stats();
+        assert_eq!(stats.frame_count, 2);
+        assert_eq!(stats.last_frame_ms, 16.0);
+        assert_eq!(stats.avg_fps, 62.5);
+
+        // Cleaning up keeps the clock
+        renderer.cleanup().unwrap();
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+        clock.advance(Duration::from_millis(20));
+        renderer.end_frame().unwrap();
+        assert_eq!(renderer.frame_stats().last_frame_ms, 20.0);
+    }
+
+    #[test]
+    fn test_target_fps_throttles_frames() {
+        let mut renderer = SkiaRenderer::new_raster(10, 10);
+        renderer.init().unwrap();
+        renderer.set_target_fps(Some(0));
+        assert_eq!(renderer.target_fps(), None);
+
+        // Frames with time already spent drawing only sleep off the rest
+        renderer.set_target_fps(Some(50));
+        for _ in 0..3 {
+            renderer.begin_frame().unwrap();
+            std::thread::sleep(Duration::from_millis(5));
+            renderer.end_frame().unwrap();
+            let stats = renderer.frame_stats();
+            assert!(stats.last_frame_ms >= 20.0);
+        }
+        assert!(renderer.frame_stats().avg_fps <= 50.0);
+
+        // Through the render thread too
+        let handle = RendererHandle::spawn(SkiaRenderer::new_raster(1, 1));
+        handle.set_target_fps(Some(30)).unwrap();
+        handle.init(8, 8).unwrap();
+        let start = Instant::now();
+        for _ in 0..2 {
+            handle.render(Node::default()).unwrap();
+        }
+        assert!(handle.join().is_ok());
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[test]
+    fn test_gpu_memory_stats_absent_on_raster() {
+        let mut renderer = SkiaRenderer::new_raster(10, 10);
+        assert_eq!(renderer.gpu_memory_usage(), None);
+        renderer.init().unwrap();
+        assert_eq!(renderer.gpu_memory_usage(), None);
+        assert_eq!(renderer.gpu_memory_limit(), None);
+
+        // Purging without a GPU context is a no-op
+        renderer.purge_unused_gpu_resources();
+        renderer.draw_test_circle().unwrap();
+        assert_ne!(pixel_at(&mut renderer, 5, 5), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn test_dirty_region_clips_frame() {
+        let mut renderer = SkiaRenderer::new_raster(50, 50);
+        renderer.init().unwrap();
+        renderer.begin_frame().unwrap();
+        renderer.end_frame().unwrap();
+
+        renderer.mark_dirty(Rect::from_xywh(0.0, 0.0, 10.0, 10.0));
+        renderer.mark_dirty(Rect::from_xywh(10.0, 10.0, 10.0, 10.0));
+        assert_eq!(
+            renderer.dirty_region(),
+            Some(Rect::from_xywh(0.0, 0.0, 20.0, 20.0))
+        );
+
+        renderer.set_clear_color(Color4f::new(0.0, 0.0, 1.0, 1.0));
+        renderer.begin_frame().unwrap();
+        renderer
+            .draw_round_rect(
+                Rect::from_wh(50.0, 50.0),
+                0.0,
+                Color4f::new(1.0, 0.0, 0.0, 1.0),
+            )
+            .unwrap();
+        renderer.end_frame().unwrap();
+
+        // Only the dirty region was cleared and redrawn
+        assert_eq!(pixel_at(&mut renderer, 5, 5), Color::RED);
+        assert_eq!(pixel_at(&mut renderer, 30, 30), Color::WHITE);
+
+        // Without dirty regions the whole surface is redrawn again
+        renderer.clear_dirty();
+        assert_eq!(renderer.dirty_region(), None);
+        renderer.begin_frame().unwrap();
+        renderer.end_frame().unwrap();
+        assert_eq!(pixel_at(&mut renderer, 30, 30), Color::BLUE);
+    }
+}
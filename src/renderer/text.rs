@@ -0,0 +1,206 @@
+//! Breaking node text into aligned lines
+//!
+//! [`layout_text`] applies a node's `white-space`, `text-overflow`, and
+//! `text-align` to its text, measuring with whatever [`TextMeasure`] the
+//! renderer has, so every renderer breaks and places lines the same way.
+
+use crate::renderer::TextMeasure;
+use crate::style::{Style, TextAlign, TextOverflow, WhiteSpace};
+
+/// Appended to lines cut short by `text-overflow: ellipsis`
+const ELLIPSIS: &str = "\u{2026}";
+
+/// One line of laid-out text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLine {
+    /// Text on the line
+    pub text: String,
+    /// Offset of the line's start from the left of the content box
+    pub x: f32,
+    /// Offset of the line's top from the top of the content box
+    pub y: f32,
+    /// Advance width of the text
+    pub width: f32,
+    /// Extra space added at each space in the line, for justified text
+    pub word_spacing: f32,
+}
+
+/// Break `text` into lines for a content box `max_width` wide, at font
+/// `size`, as `style` directs
+///
+/// A `max_width` of zero, as for a node layout hasn't sized, leaves lines
+/// unbroken and untruncated. Lines are spaced by the style's line height,
+/// or else the measured one.
+pub fn layout_text(
+    text: &str,
+    size: f32,
+    max_width: f32,
+    style: &Style,
+    measure: &dyn TextMeasure,
+) -> Vec<TextLine> {
+    let white_space = style.white_space.unwrap_or_default();
+    let bounded = max_width > 0.0;
+    let advance = |line: &str| measure.measure_text(line, size).0;
+
+    // Each line, with whether it's the last of its paragraph
+    let mut breaks: Vec<(String, bool)> = Vec::new();
+    match white_space {
+        WhiteSpace::Pre => breaks.extend(text.split('\n').map(|line| (line.to_string(), true))),
+        WhiteSpace::NoWrap => breaks.push((collapse(text), true)),
+        WhiteSpace::Normal => {
+            let mut line = String::new();
+            for word in text.split_whitespace() {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{line} {word}")
+                };
+                // A word too long for a line of its own overflows it
+                if !bounded || line.is_empty() || advance(&candidate) <= max_width {
+                    line = candidate;
+                } else {
+                    breaks.push((std::mem::replace(&mut line, word.to_string()), false));
+                }
+            }
+            breaks.push((line, true));
+        }
+    }
+
+    let ellipsis = style.text_overflow == Some(TextOverflow::Ellipsis);
+    let pitch = style
+        .line_height
+        .map_or_else(|| measure.measure_text("", size).1, |height| height * size);
+    breaks
+        .into_iter()
+        .enumerate()
+        .map(|(index, (mut line, last))| {
+            if bounded && ellipsis && advance(&line) > max_width {
+                line = truncate(&line, max_width, &advance);
+            }
+            // Lines too long for the box overhang it evenly when centered
+            // and to the left when end-aligned; without a width there's no
+            // box to align within, so every line starts at the left
+            let width = advance(&line);
+            let slack = if bounded { max_width - width } else { 0.0 };
+            let (x, word_spacing) = match style.text_align {
+                Some(TextAlign::Center) => (slack / 2.0, 0.0),
+                Some(TextAlign::Right | TextAlign::End) => (slack, 0.0),
+                // Every line of a paragraph but its last is stretched to fill
+                Some(TextAlign::Justify) if !last => {
+                    let gaps = line.matches(' ').count();
+                    (
+                        0.0,
+                        if gaps > 0 {
+                            slack.max(0.0) / gaps as f32
+                        } else {
+                            0.0
+                        },
+                    )
+                }
+                _ => (0.0, 0.0),
+            };
+            TextLine {
+                text: line,
+                x,
+                y: index as f32 * pitch,
+                width,
+                word_spacing,
+            }
+        })
+        .collect()
+}
+
+/// `text` with each run of whitespace, newlines included, made one space
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The longest start of `line` that fits `max_width` with an ellipsis after
+/// it; just the ellipsis if nothing does
+fn truncate(line: &str, max_width: f32, advance: &dyn Fn(&str) -> f32) -> String {
+    let mut end = line.len();
+    for (index, _) in line.char_indices().rev() {
+        let candidate = format!("{}{ELLIPSIS}", line[..end].trim_end());
+        if advance(&candidate) <= max_width {
+            return candidate;
+        }
+        end = index;
+    }
+    ELLIPSIS.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: f32 = 10.0;
+
+    /// A character per `size` pixels, on lines twice that tall
+    struct Monospace;
+
+    impl TextMeasure for Monospace {
+        fn measure_text(&self, text: &str, size: f32) -> (f32, f32) {
+            (text.chars().count() as f32 * size, size * 2.0)
+        }
+    }
+
+    fn style(configure: impl FnOnce(&mut Style)) -> Style {
+        let mut style = Style::default();
+        configure(&mut style);
+        style
+    }
+
+    #[test]
+    fn test_nowrap_ellipsis_truncates_to_width() {
+        let style = style(|style| {
+            style.white_space = Some(WhiteSpace::NoWrap);
+            style.text_overflow = Some(TextOverflow::Ellipsis);
+        });
+        let lines = layout_text("Hello   wide\nworld", SIZE, 80.0, &style, &Monospace);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].text, "Hello w\u{2026}");
+        assert!(lines[0].width <= 80.0);
+
+        // Text that fits is left alone, and without a width nothing is cut
+        let lines = layout_text("Hello", SIZE, 80.0, &style, &Monospace);
+        assert_eq!(lines[0].text, "Hello");
+        let lines = layout_text("Hello wide world", SIZE, 0.0, &style, &Monospace);
+        assert_eq!(lines[0].text, "Hello wide world");
+    }
+
+    #[test]
+    fn test_centered_lines_wrap_at_width() {
+        let style = style(|style| {
+            style.text_align = Some(TextAlign::Center);
+            style.line_height = Some(1.5);
+        });
+        let lines = layout_text("one two three", SIZE, 100.0, &style, &Monospace);
+        let placed: Vec<_> = lines
+            .iter()
+            .map(|line| (line.text.as_str(), line.x, line.y))
+            .collect();
+        assert_eq!(placed, [("one two", 15.0, 0.0), ("three", 25.0, 15.0)]);
+
+        // An unbounded width doesn't push the line left of the node
+        let lines = layout_text("one two three", SIZE, 0.0, &style, &Monospace);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].x, 0.0);
+    }
+
+    #[test]
+    fn test_pre_keeps_newlines_and_justify_fills_lines() {
+        let pre = style(|style| style.white_space = Some(WhiteSpace::Pre));
+        let lines = layout_text("a  b\n\nc", SIZE, 10.0, &pre, &Monospace);
+        let texts: Vec<_> = lines.iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(texts, ["a  b", "", "c"]);
+        // Without a line height the measured one spaces the lines
+        assert_eq!(lines[2].y, 40.0);
+
+        let justified = style(|style| style.text_align = Some(TextAlign::Justify));
+        let lines = layout_text("aa b cc d", SIZE, 60.0, &justified, &Monospace);
+        let texts: Vec<_> = lines.iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(texts, ["aa b", "cc d"]);
+        assert_eq!(lines[0].word_spacing, 20.0);
+        assert_eq!(lines[1].word_spacing, 0.0);
+    }
+}
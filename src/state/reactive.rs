@@ -67,13 +67,13 @@ where
     T: Send + Sync + 'static,
 {
     /// Get the current value of the signal
-    pub fn get(&self) -> RwLockReadGuard<T> {
+    pub fn get(&self) -> RwLockReadGuard<'_, T> {
         // TODO: Track this read for reactive dependencies
         self.value.read().unwrap()
     }
 
     /// Get a mutable reference to the signal's value
-    pub fn get_mut(&self) -> RwLockWriteGuard<T> {
+    pub fn get_mut(&self) -> RwLockWriteGuard<'_, T> {
         self.value.write().unwrap()
     }
 
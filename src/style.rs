@@ -3,8 +3,8 @@
 #[cfg(test)]
 mod tests;
 
-use crate::component::ComponentId;
-use crate::layout::{Dimension, EdgeValues, LayoutStyle};
+use crate::component::{ComponentId, Node};
+use crate::layout::{Dimension, EdgeInsets, EdgeValues, LayoutStyle};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -120,6 +120,45 @@ impl Stylesheet {
         self.rules.push(rule);
     }
 
+    /// The style set by rules for `.class` alone, later rules overriding
+    /// earlier ones
+    ///
+    /// Declarations whose values don't parse are ignored, as in CSS.
+    pub fn class_style(&self, class: &str) -> Style {
+        let selector = format!(".{class}");
+        let engine = StyleEngine::new();
+        let context = StyleContext::default();
+        let mut style = Style::default();
+        for rule in &self.rules {
+            for matched in rule.selectors.iter().filter(|s| s.selector == selector) {
+                for property in &matched.properties {
+                    let _ = engine.apply_css_property(&mut style, property, &context);
+                }
+            }
+        }
+        style
+    }
+
+    /// Resolve the classes of `root` and every node under it into their
+    /// styles, ahead of layout and painting
+    ///
+    /// Each node's classes apply in the order it lists them, later classes
+    /// overriding earlier ones, and the node's own style overrides them all.
+    /// Nodes without classes are left alone.
+    pub fn apply(&self, root: &mut Node) {
+        if !root.classes().is_empty() {
+            let mut style = Style::default();
+            for class in root.classes() {
+                style.merge(&self.class_style(class));
+            }
+            style.merge(root.style());
+            root.set_style(style);
+        }
+        for child in root.children_mut() {
+            self.apply(child);
+        }
+    }
+
     /// Parse CSS text into a stylesheet
     pub fn parse(css: &str, scoped: bool) -> Result<Self, StyleError> {
         let mut stylesheet = Self::new();
@@ -211,15 +250,20 @@ impl std::fmt::Display for Stylesheet {
 pub struct Style {
     // Visual properties
     pub background_color: Option<Color>,
+    pub background_gradient: Option<LinearGradient>,
     pub color: Option<Color>,
     pub opacity: Option<f32>,
     pub visibility: Option<Visibility>,
+    pub overflow: Option<Overflow>,
+    pub mix_blend_mode: Option<MixBlendMode>,
+    pub pointer_events: Option<PointerEvents>,
 
     // Border properties
     pub border_width: Option<EdgeValues>,
     pub border_color: Option<EdgeColors>,
     pub border_style: Option<BorderStyle>,
     pub border_radius: Option<BorderRadius>,
+    pub outline: Option<Outline>,
 
     // Font and text properties
     pub font_family: Option<String>,
@@ -229,6 +273,8 @@ pub struct Style {
     pub line_height: Option<f32>,
     pub letter_spacing: Option<f32>,
     pub text_align: Option<TextAlign>,
+    pub white_space: Option<WhiteSpace>,
+    pub text_overflow: Option<TextOverflow>,
     pub text_decoration: Option<TextDecoration>,
     pub text_transform: Option<TextTransform>,
 
@@ -306,6 +352,88 @@ impl std::hash::Hash for Color {
     }
 }
 
+impl Color {
+    /// Resolve this color to normalized RGBA components in the 0.0..=1.0 range
+    ///
+    /// Returns `None` for values that cannot be resolved without more context,
+    /// such as `currentColor`, unknown named colors, or malformed hex strings.
+    pub fn to_rgba(&self) -> Option<(f32, f32, f32, f32)> {
+        match self {
+            Color::Rgba(r, g, b, a) => Some((*r, *g, *b, *a)),
+            Color::Transparent => Some((0.0, 0.0, 0.0, 0.0)),
+            Color::CurrentColor => None,
+            Color::Hex(hex) => Self::hex_to_rgba(hex),
+            Color::Hsl(h, s, l, a) => {
+                let (r, g, b) = Self::hsl_to_rgb(*h, *s, *l);
+                Some((r, g, b, *a))
+            }
+            Color::Named(name) => {
+                let rgb = match name.to_ascii_lowercase().as_str() {
+                    "black" => (0.0, 0.0, 0.0),
+                    "white" => (1.0, 1.0, 1.0),
+                    "red" => (1.0, 0.0, 0.0),
+                    "green" => (0.0, 128.0 / 255.0, 0.0),
+                    "blue" => (0.0, 0.0, 1.0),
+                    "yellow" => (1.0, 1.0, 0.0),
+                    "gray" | "grey" => (128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0),
+                    _ => return None,
+                };
+                Some((rgb.0, rgb.1, rgb.2, 1.0))
+            }
+        }
+    }
+
+    /// Parse `#rgb`, `#rrggbb`, or `#rrggbbaa` into normalized components
+    fn hex_to_rgba(hex: &str) -> Option<(f32, f32, f32, f32)> {
+        let digits = hex.trim().trim_start_matches('#');
+        if !digits.is_ascii() {
+            return None;
+        }
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok().map(|v| v as f32 / 255.0);
+
+        match digits.len() {
+            3 => {
+                let mut expanded = String::with_capacity(6);
+                for c in digits.chars() {
+                    expanded.push(c);
+                    expanded.push(c);
+                }
+                Self::hex_to_rgba(&expanded)
+            }
+            6 => Some((
+                channel(&digits[0..2])?,
+                channel(&digits[2..4])?,
+                channel(&digits[4..6])?,
+                1.0,
+            )),
+            8 => Some((
+                channel(&digits[0..2])?,
+                channel(&digits[2..4])?,
+                channel(&digits[4..6])?,
+                channel(&digits[6..8])?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Convert HSL (hue in degrees, saturation/lightness in 0.0..=1.0) to RGB
+    fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let h_prime = h.rem_euclid(360.0) / 60.0;
+        let x = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+        let (r, g, b) = match h_prime as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        (r + m, g + m, b + m)
+    }
+}
+
 /// Visibility values
 #[derive(Debug, Clone, PartialEq)]
 pub enum Visibility {
@@ -314,6 +442,57 @@ pub enum Visibility {
     Collapse,
 }
 
+/// Overflow handling for content that exceeds its container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Content is painted beyond the container bounds
+    #[default]
+    Visible,
+    /// Content is clipped to the container bounds
+    Hidden,
+    /// Content is clipped, with scrollbars shown whether or not it fits
+    Scroll,
+    /// Content is clipped, with scrollbars shown only once it overflows
+    Auto,
+}
+
+impl Overflow {
+    /// Whether content is clipped to the container bounds
+    pub fn clips(self) -> bool {
+        self != Overflow::Visible
+    }
+}
+
+/// How an element's colors are composited with what's behind it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MixBlendMode {
+    /// Ordinary alpha compositing
+    #[default]
+    Normal,
+    /// Multiply with the backdrop, darkening it
+    Multiply,
+    /// Inverse multiply, lightening the backdrop
+    Screen,
+    /// Multiply or screen depending on the backdrop
+    Overlay,
+    /// Keep the darker of element and backdrop
+    Darken,
+    /// Keep the lighter of element and backdrop
+    Lighten,
+    /// Add element and backdrop colors
+    Plus,
+}
+
+/// Whether an element can be the target of pointer events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointerEvents {
+    /// The element receives pointer events
+    #[default]
+    Auto,
+    /// Pointer events pass through to whatever is underneath
+    None,
+}
+
 /// Border style options
 #[derive(Debug, Clone, PartialEq)]
 pub enum BorderStyle {
@@ -380,6 +559,29 @@ pub enum TextAlign {
     End,
 }
 
+/// How whitespace in text is handled, and where lines break
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhiteSpace {
+    /// Runs of whitespace collapse to one space, and lines wrap at the
+    /// container width
+    #[default]
+    Normal,
+    /// Whitespace collapses as for `Normal`, but the text stays on one line
+    NoWrap,
+    /// Whitespace is kept as written, and lines only break at newlines
+    Pre,
+}
+
+/// How a line too long for its container ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextOverflow {
+    /// The line runs on past the container
+    #[default]
+    Clip,
+    /// The line is cut short with an ellipsis that fits
+    Ellipsis,
+}
+
 /// Text decoration
 #[derive(Debug, Clone, PartialEq)]
 pub enum TextDecoration {
@@ -453,6 +655,60 @@ pub struct BoxShadow {
     pub inset: bool,
 }
 
+impl BoxShadow {
+    /// Create an outer shadow with no spread
+    pub fn new(offset_x: f32, offset_y: f32, blur_radius: f32, color: Color) -> Self {
+        Self {
+            offset_x,
+            offset_y,
+            blur_radius,
+            spread_radius: 0.0,
+            color,
+            inset: false,
+        }
+    }
+}
+
+/// Linear gradient painted over an element's background color
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient {
+    /// Direction in degrees, as in CSS: 0 runs bottom to top and 90 left
+    /// to right
+    pub angle: f32,
+    /// `(position, color)` pairs with positions in `0..=1`, never
+    /// decreasing
+    pub stops: Vec<(f32, Color)>,
+}
+
+impl LinearGradient {
+    /// A gradient running at `angle` degrees through `stops`
+    pub fn new(angle: f32, stops: Vec<(f32, Color)>) -> Self {
+        Self { angle, stops }
+    }
+
+    /// Start and end of the gradient line across a `width` × `height` box
+    ///
+    /// As in CSS, the line passes through the center and is long enough
+    /// that the corners take the first and last stop colors.
+    pub fn endpoints(&self, width: f32, height: f32) -> ((f32, f32), (f32, f32)) {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let half = (width * sin.abs() + height * cos.abs()) / 2.0;
+        let (dx, dy) = (sin * half, -cos * half);
+        let (cx, cy) = (width / 2.0, height / 2.0);
+        ((cx - dx, cy - dy), (cx + dx, cy + dy))
+    }
+}
+
+/// Line drawn around the outside of an element's border box, without
+/// taking up layout space, as used for focus rings
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outline {
+    pub width: f32,
+    pub color: Color,
+    /// Gap between the border box and the outline
+    pub offset: f32,
+}
+
 /// Text shadow definition
 #[derive(Debug, Clone, PartialEq)]
 pub struct TextShadow {
@@ -594,14 +850,88 @@ impl Style {
     }
 
     /// Apply another style on top of this one
+    ///
+    /// Every property `other` sets replaces this style's; a layout style
+    /// replaces this one's as a whole.
     pub fn merge(&mut self, other: &Style) {
-        if let Some(color) = &other.color {
-            self.color = Some(color.clone());
-        }
-        if let Some(bg) = &other.background_color {
-            self.background_color = Some(bg.clone());
+        macro_rules! merge {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if other.$field.is_some() {
+                        self.$field = other.$field.clone();
+                    }
+                )*
+            };
         }
-        // Merge other properties...
+        merge!(
+            background_color,
+            background_gradient,
+            color,
+            opacity,
+            visibility,
+            overflow,
+            mix_blend_mode,
+            pointer_events,
+            border_width,
+            border_color,
+            border_style,
+            border_radius,
+            outline,
+            font_family,
+            font_size,
+            font_weight,
+            font_style,
+            line_height,
+            letter_spacing,
+            text_align,
+            white_space,
+            text_overflow,
+            text_decoration,
+            text_transform,
+            layout_style,
+            transform,
+            transform_origin,
+            transition_property,
+            transition_duration,
+            transition_timing_function,
+            transition_delay,
+            box_shadow,
+            text_shadow,
+            filter,
+            backdrop_filter,
+            z_index,
+            cursor,
+        );
+        self.is_dirty |= other.is_dirty;
+    }
+
+    /// Space between the node's edges and its content; the background
+    /// still covers it
+    pub fn padding(&self) -> EdgeInsets {
+        self.layout_style
+            .as_ref()
+            .map_or_else(EdgeInsets::zero, |layout| layout.padding)
+    }
+
+    /// Space kept clear around the node, separating it from its siblings
+    pub fn margin(&self) -> EdgeInsets {
+        self.layout_style
+            .as_ref()
+            .map_or_else(EdgeInsets::zero, |layout| layout.margin)
+    }
+
+    /// Set the padding, giving the node a layout style if it lacks one
+    pub fn set_padding(&mut self, padding: EdgeInsets) {
+        self.layout_style
+            .get_or_insert_with(LayoutStyle::default)
+            .padding = padding;
+    }
+
+    /// Set the margin, giving the node a layout style if it lacks one
+    pub fn set_margin(&mut self, margin: EdgeInsets) {
+        self.layout_style
+            .get_or_insert_with(LayoutStyle::default)
+            .margin = margin;
     }
 }
 
@@ -750,6 +1080,9 @@ impl StyleEngine {
         if style.text_align.is_none() {
             style.text_align = parent_style.text_align.clone();
         }
+        if style.white_space.is_none() {
+            style.white_space = parent_style.white_space;
+        }
 
         self.stats.inheritance_operations += 1;
     }
@@ -810,12 +1143,44 @@ impl StyleEngine {
             "text-align" => {
                 style.text_align = Some(self.parse_text_align(&property.value)?);
             }
+            "white-space" => {
+                style.white_space = Some(match property.value.trim() {
+                    "nowrap" => WhiteSpace::NoWrap,
+                    "pre" => WhiteSpace::Pre,
+                    _ => WhiteSpace::Normal,
+                });
+            }
+            "text-overflow" => {
+                style.text_overflow = Some(match property.value.trim() {
+                    "ellipsis" => TextOverflow::Ellipsis,
+                    _ => TextOverflow::Clip,
+                });
+            }
             "border-radius" => {
                 style.border_radius = Some(self.parse_border_radius(&property.value)?);
             }
             "z-index" => {
                 style.z_index = property.value.parse().ok();
             }
+            "overflow" => {
+                style.overflow = Some(self.parse_overflow(&property.value)?);
+            }
+            "pointer-events" => {
+                style.pointer_events = Some(match property.value.trim() {
+                    "none" => PointerEvents::None,
+                    _ => PointerEvents::Auto,
+                });
+            }
+            "visibility" => {
+                style.visibility = Some(match property.value.trim() {
+                    "hidden" => Visibility::Hidden,
+                    "collapse" => Visibility::Collapse,
+                    _ => Visibility::Visible,
+                });
+            }
+            "mix-blend-mode" => {
+                style.mix_blend_mode = Some(self.parse_mix_blend_mode(&property.value)?);
+            }
             _ => {
                 // Unknown property - could log warning in debug mode
             }
@@ -1022,6 +1387,33 @@ impl StyleEngine {
         }
     }
 
+    /// Parse overflow mode from CSS value
+    fn parse_overflow(&self, value: &str) -> Result<Overflow, StyleError> {
+        match value.trim() {
+            "visible" => Ok(Overflow::Visible),
+            "hidden" => Ok(Overflow::Hidden),
+            "scroll" => Ok(Overflow::Scroll),
+            "auto" => Ok(Overflow::Auto),
+            _ => Err(StyleError::ParseError(format!("Invalid overflow: {value}"))),
+        }
+    }
+
+    /// Parse blend mode from CSS value
+    fn parse_mix_blend_mode(&self, value: &str) -> Result<MixBlendMode, StyleError> {
+        match value.trim() {
+            "normal" => Ok(MixBlendMode::Normal),
+            "multiply" => Ok(MixBlendMode::Multiply),
+            "screen" => Ok(MixBlendMode::Screen),
+            "overlay" => Ok(MixBlendMode::Overlay),
+            "darken" => Ok(MixBlendMode::Darken),
+            "lighten" => Ok(MixBlendMode::Lighten),
+            "plus-lighter" => Ok(MixBlendMode::Plus),
+            _ => Err(StyleError::ParseError(format!(
+                "Invalid mix-blend-mode: {value}"
+            ))),
+        }
+    }
+
     /// Parse border radius from CSS value
     fn parse_border_radius(&self, value: &str) -> Result<BorderRadius, StyleError> {
         let parts: Vec<&str> = value.split_whitespace().collect();
@@ -1,7 +1,10 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::style::{CssSelector, Specificity, StyleRule, Stylesheet};
+    use crate::layout::EdgeInsets;
+    use crate::style::{
+        Color, CssSelector, LinearGradient, Specificity, Style, StyleRule, Stylesheet,
+    };
 
     #[test]
     fn test_specificity_calculation() {
@@ -93,4 +96,104 @@ mod tests {
         assert_eq!(second_rule.selectors.len(), 2);
         assert_eq!(second_rule.selectors[0].properties.len(), 2);
     }
+
+    #[test]
+    fn test_color_to_rgba() {
+        assert_eq!(
+            Color::Hex("#ff0000".to_string()).to_rgba(),
+            Some((1.0, 0.0, 0.0, 1.0))
+        );
+        assert_eq!(
+            Color::Hex("#fff".to_string()).to_rgba(),
+            Some((1.0, 1.0, 1.0, 1.0))
+        );
+        assert_eq!(
+            Color::Hex("#00000000".to_string()).to_rgba(),
+            Some((0.0, 0.0, 0.0, 0.0))
+        );
+        assert_eq!(Color::Hex("#zzz".to_string()).to_rgba(), None);
+        assert_eq!(Color::Transparent.to_rgba(), Some((0.0, 0.0, 0.0, 0.0)));
+        assert_eq!(Color::CurrentColor.to_rgba(), None);
+
+        let (r, g, b, a) = Color::Hsl(240.0, 1.0, 0.5, 1.0).to_rgba().unwrap();
+        assert!(r.abs() < 1e-6 && g.abs() < 1e-6);
+        assert!((b - 1.0).abs() < 1e-6 && (a - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_padding_and_margin_live_on_layout_style() {
+        let mut style = Style::new();
+        assert_eq!(style.padding(), EdgeInsets::zero());
+
+        style.set_padding(EdgeInsets::uniform(10.0));
+        style.set_margin(EdgeInsets::horizontal_vertical(8.0, 4.0));
+        let layout = style.layout_style.as_ref().unwrap();
+        assert_eq!(layout.padding.left, 10.0);
+        assert_eq!(layout.margin.top, 4.0);
+        assert_eq!(style.margin().right, 8.0);
+    }
+
+    #[test]
+    fn test_gradient_endpoints_follow_css_angles() {
+        let close = |(x, y): (f32, f32), (ex, ey): (f32, f32)| {
+            assert!((x - ex).abs() < 1e-3 && (y - ey).abs() < 1e-3, "{x},{y}");
+        };
+        let gradient = |angle| LinearGradient::new(angle, Vec::new());
+
+        let (start, end) = gradient(90.0).endpoints(200.0, 100.0);
+        close(start, (0.0, 50.0));
+        close(end, (200.0, 50.0));
+
+        let (start, end) = gradient(0.0).endpoints(200.0, 100.0);
+        close(start, (100.0, 100.0));
+        close(end, (100.0, 0.0));
+
+        // Diagonals reach past the box so the corners get the end colors
+        let (start, end) = gradient(45.0).endpoints(100.0, 100.0);
+        let length = ((end.0 - start.0).powi(2) + (end.1 - start.1).powi(2)).sqrt();
+        assert!((length - 100.0 * 2f32.sqrt()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_class_merge_order_and_inline_override() {
+        let css = r#"
+            .btn {
+                color: #111111;
+                background-color: #eeeeee;
+                opacity: 0.5;
+            }
+
+            .btn-primary {
+                background-color: #0070f3;
+                color: #ffffff;
+            }
+        "#;
+        let stylesheet = Stylesheet::parse(css, false).unwrap();
+        let hex = |value: &str| Some(Color::Hex(value.to_string()));
+
+        let mut root = crate::component::Node::default();
+        let mut button = crate::component::Node::default();
+        button.add_class("btn");
+        button.add_class("btn-primary");
+        button.style_mut().color = hex("#ff0000");
+        root.add_child(button.clone());
+
+        // The same classes the other way round let `.btn` win
+        let mut reversed = crate::component::Node::default();
+        reversed.add_class("btn-primary");
+        reversed.add_class("btn");
+        root.add_child(reversed);
+
+        stylesheet.apply(&mut root);
+        let primary = root.children()[0].style();
+        assert_eq!(primary.background_color, hex("#0070f3"));
+        assert_eq!(primary.opacity, Some(0.5));
+        // Inline style beats every class
+        assert_eq!(primary.color, hex("#ff0000"));
+
+        let reversed = root.children()[1].style();
+        assert_eq!(reversed.background_color, hex("#eeeeee"));
+        assert_eq!(reversed.color, hex("#111111"));
+        assert_eq!(root.style(), &Style::default());
+    }
 }